@@ -52,7 +52,7 @@ export class JsCompilation {
   addMissingDependencies(deps: Array<string>): void
   addBuildDependencies(deps: Array<string>): void
   rebuildModule(moduleIdentifiers: Array<string>, f: (...args: any[]) => any): void
-  importModule(request: string, publicPath: string | undefined | null, baseUri: string | undefined | null, originalModule: string | undefined | null, originalModuleContext: string | undefined | null, callback: (...args: any[]) => any): void
+  importModule(request: string, publicPath: string | undefined | null, baseUri: string | undefined | null, layer: string | undefined | null, originalModule: string | undefined | null, originalModuleContext: string | undefined | null, callback: (...args: any[]) => any): void
 }
 
 export class JsStats {
@@ -109,8 +109,11 @@ export interface BuiltinPlugin {
 export enum BuiltinPluginName {
   DefinePlugin = 'DefinePlugin',
   ProvidePlugin = 'ProvidePlugin',
+  EnvironmentPlugin = 'EnvironmentPlugin',
   BannerPlugin = 'BannerPlugin',
   IgnorePlugin = 'IgnorePlugin',
+  ContextReplacementPlugin = 'ContextReplacementPlugin',
+  NormalModuleReplacementPlugin = 'NormalModuleReplacementPlugin',
   ProgressPlugin = 'ProgressPlugin',
   EntryPlugin = 'EntryPlugin',
   ExternalsPlugin = 'ExternalsPlugin',
@@ -139,17 +142,32 @@ export enum BuiltinPluginName {
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
   NamedChunkIdsPlugin = 'NamedChunkIdsPlugin',
   DeterministicChunkIdsPlugin = 'DeterministicChunkIdsPlugin',
+  NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
+  NaturalChunkIdsPlugin = 'NaturalChunkIdsPlugin',
+  OccurrenceModuleIdsPlugin = 'OccurrenceModuleIdsPlugin',
+  OccurrenceChunkIdsPlugin = 'OccurrenceChunkIdsPlugin',
+  HashedModuleIdsPlugin = 'HashedModuleIdsPlugin',
+  RecordIdsPlugin = 'RecordIdsPlugin',
+  DllEntryPlugin = 'DllEntryPlugin',
+  DllReferenceAgencyPlugin = 'DllReferenceAgencyPlugin',
+  LibManifestPlugin = 'LibManifestPlugin',
+  FlagAllModulesAsUsedPlugin = 'FlagAllModulesAsUsedPlugin',
+  ManifestPlugin = 'ManifestPlugin',
+  SubresourceIntegrityPlugin = 'SubresourceIntegrityPlugin',
   RealContentHashPlugin = 'RealContentHashPlugin',
   RemoveEmptyChunksPlugin = 'RemoveEmptyChunksPlugin',
   EnsureChunkConditionsPlugin = 'EnsureChunkConditionsPlugin',
   WarnCaseSensitiveModulesPlugin = 'WarnCaseSensitiveModulesPlugin',
   DataUriPlugin = 'DataUriPlugin',
   FileUriPlugin = 'FileUriPlugin',
+  HttpUriPlugin = 'HttpUriPlugin',
+  LazyCompilationPlugin = 'LazyCompilationPlugin',
   RuntimePlugin = 'RuntimePlugin',
   JsonModulesPlugin = 'JsonModulesPlugin',
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -220,6 +238,8 @@ export interface JsAssetInfo {
   javascriptModule?: boolean
   /** related object to other assets, keyed by type of relation (only points from parent to child) */
   related: JsAssetInfoRelated
+  /** the subresource integrity of the asset */
+  integrity?: string
 }
 
 export interface JsAssetInfoRelated {
@@ -522,6 +542,7 @@ export interface JsStatsModule {
   issuerId?: string
   issuerPath: Array<JsStatsModuleIssuer>
   nameForCondition?: string
+  layer?: string
   reasons?: Array<JsStatsModuleReason>
   assets?: Array<string>
   source?: string | Buffer
@@ -655,6 +676,7 @@ export interface RawCacheGroupOptions {
   key: string
   priority?: number
   test?: RegExp | string | Function
+  layer?: RegExp | string | Function
   filename?: string
   idHint?: string
   /** What kind of chunks should be selected. */
@@ -663,10 +685,14 @@ export interface RawCacheGroupOptions {
   automaticNameDelimiter?: string
   minChunks?: number
   minSize?: number
+  minSizeReduction?: number
+  enforceSizeThreshold?: number
+  minRemainingSize?: number
   maxSize?: number
   maxAsyncSize?: number
   maxInitialSize?: number
   name?: string | false | Function
+  usedExports?: boolean
   reuseExistingChunk?: boolean
   enforce?: boolean
 }
@@ -726,6 +752,14 @@ export interface RawContainerReferencePluginOptions {
   enhanced: boolean
 }
 
+export interface RawContextReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newContentResource?: string
+  newContentRecursive?: boolean
+  newContentRegExp?: RegExp
+  newContentCreateContextMap?: Record<string, string>
+}
+
 export interface RawCopyGlobOptions {
   caseSensitiveMatch?: boolean
   dot?: boolean
@@ -794,16 +828,34 @@ export interface RawCssParserOptions {
   namedExports?: boolean
 }
 
+export interface RawDllEntryPluginOptions {
+  name: string
+  context: string
+  entries: Array<string>
+}
+
+export interface RawDllReferenceAgencyPluginOptions {
+  context: string
+  name: string
+  extensions: Array<string>
+  scope?: string
+  type: string
+  /** The `content` of the manifest, serialized as JSON */
+  content: string
+}
+
 export interface RawEntryOptions {
   name?: string
   runtime?: string
   chunkLoading?: string
+  wasmLoading?: string
   asyncChunks?: boolean
   publicPath?: string
   baseUri?: string
   filename?: string
   library?: RawLibraryOptions
   dependOn?: Array<string>
+  layer?: string
 }
 
 export interface RawEntryPluginOptions {
@@ -812,6 +864,11 @@ export interface RawEntryPluginOptions {
   options: RawEntryOptions
 }
 
+export interface RawEnvironmentPluginOptions {
+  keys: Array<string>
+  defaults: Record<string, string>
+}
+
 export interface RawEvalDevToolModulePluginOptions {
   namespace?: string
   moduleFilenameTemplate?: string | ((info: RawModuleFilenameTemplateFnCtx) => string)
@@ -819,6 +876,7 @@ export interface RawEvalDevToolModulePluginOptions {
 }
 
 export interface RawExperiments {
+  layers: boolean
   newSplitChunks: boolean
   topLevelAwait: boolean
   rspackFuture: RawRspackFuture
@@ -886,6 +944,13 @@ export interface RawGeneratorOptions {
   cssModule?: RawCssModuleGeneratorOptions
 }
 
+export interface RawHashedModuleIdsPluginOptions {
+  context?: string
+  hashFunction?: string
+  hashDigest?: string
+  hashDigestLength?: number
+}
+
 export interface RawHtmlRspackPluginOptions {
   /** emitted file name in output path */
   filename?: string
@@ -914,6 +979,21 @@ export interface RawHttpExternalsRspackPluginOptions {
   webAsync: boolean
 }
 
+export interface RawHttpResponse {
+  status: number
+  headers: Record<string, string>
+  body: Buffer
+}
+
+export interface RawHttpUriPluginOptions {
+  allowedUris: Array<string | RegExp>
+  lockfileLocation?: string
+  cacheLocation?: string
+  frozen?: boolean
+  upgrade?: boolean
+  httpClient: (url: string, headers: Record<string, string>) => Promise<RawHttpResponse>
+}
+
 export interface RawIgnorePluginOptions {
   resourceRegExp?: RegExp
   contextRegExp?: RegExp
@@ -938,6 +1018,28 @@ export interface RawJavascriptParserOptions {
   url: string
   exprContextCritical: boolean
   wrappedContextCritical: boolean
+  exportsPresence?: string
+  importExportsPresence?: string
+  reexportExportsPresence?: string
+  strictExportPresence: boolean
+}
+
+export interface RawLazyCompilationModuleArgs {
+  module: string
+  path: string
+}
+
+export interface RawLazyCompilationModuleInfo {
+  active: boolean
+  data: string
+  client: string
+}
+
+export interface RawLazyCompilationOption {
+  module: (args: RawLazyCompilationModuleArgs) => RawLazyCompilationModuleInfo
+  entries: boolean
+  imports: boolean
+  test?: RegExp | ((name: string) => boolean)
 }
 
 export interface RawLibraryAuxiliaryComment {
@@ -969,12 +1071,38 @@ export interface RawLibraryOptions {
   amdContainer?: string
 }
 
+export interface RawLibManifestPluginOptions {
+  context?: string
+  entryOnly?: boolean
+  name?: string
+  path: string
+  format?: boolean
+  type?: string
+}
+
 export interface RawLimitChunkCountPluginOptions {
   chunkOverhead?: number
   entryChunkMultiplicator?: number
   maxChunks: number
 }
 
+export interface RawManifestFile {
+  name: string
+  path: string
+  chunkName?: string
+  isInitial: boolean
+  isChunk: boolean
+  isAuxiliary: boolean
+}
+
+export interface RawManifestPluginOptions {
+  filename: string
+  publicPath?: string
+  filter?: (file: RawManifestFile) => boolean
+  /** Receives the manifest as a JSON string and returns the content of the asset */
+  serialize?: (manifest: string) => string
+}
+
 export interface RawModuleFilenameTemplateFnCtx {
   identifier: string
   shortIdentifier: string
@@ -1015,12 +1143,14 @@ export interface RawModuleRule {
   resourceFragment?: RawRuleSetCondition
   descriptionData?: Record<string, RawRuleSetCondition>
   sideEffects?: boolean
+  layer?: string
   use?: RawModuleRuleUses
   type?: string
   parser?: RawParserOptions
   generator?: RawGeneratorOptions
   resolve?: RawResolveOptions
   issuer?: RawRuleSetCondition
+  issuerLayer?: RawRuleSetCondition
   dependency?: RawRuleSetCondition
   scheme?: RawRuleSetCondition
   mimetype?: RawRuleSetCondition
@@ -1056,6 +1186,25 @@ export interface RawNodeOption {
   global: string
 }
 
+export interface RawNormalModuleReplacementData {
+  request: string
+  context: string
+  resource?: string
+}
+
+export interface RawNormalModuleReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newResource: string | ((data: RawNormalModuleReplacementData) => RawNormalModuleReplacementData)
+}
+
+export interface RawOccurrenceChunkIdsPluginOptions {
+  prioritizeInitial?: boolean
+}
+
+export interface RawOccurrenceModuleIdsPluginOptions {
+  prioritizeInitial?: boolean
+}
+
 export interface RawOptimizationOptions {
   removeAvailableModules: boolean
   sideEffects: string
@@ -1064,6 +1213,7 @@ export interface RawOptimizationOptions {
   innerGraph: boolean
   mangleExports: string
   concatenateModules: boolean
+  realContentHash: boolean
 }
 
 export interface RawOptions {
@@ -1081,8 +1231,11 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node?: RawNodeOption
+  amd?: string
   profile: boolean
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   builtins: RawBuiltins
 }
 
@@ -1248,6 +1401,8 @@ export interface RawRuleSetLogicalConditions {
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths?: Array<string | RegExp>
+  managedPaths?: Array<string | RegExp>
 }
 
 export interface RawSnapshotStrategy {
@@ -1283,11 +1438,13 @@ export interface RawSplitChunksOptions {
   minChunks?: number
   hidePathInfo?: boolean
   minSize?: number
+  minSizeReduction?: number
   enforceSizeThreshold?: number
   minRemainingSize?: number
   maxSize?: number
   maxAsyncSize?: number
   maxInitialSize?: number
+  usedExports?: boolean
 }
 
 export interface RawStatsOptions {
@@ -1301,6 +1458,10 @@ export interface RawStyleConfig {
   bool?: boolean
 }
 
+export interface RawSubresourceIntegrityPluginOptions {
+  hashFuncNames: Array<"sha256" | "sha384" | "sha512">
+}
+
 export interface RawSwcJsMinimizerRspackPluginOptions {
   extractComments?: RawExtractComments
   compress: boolean | string
@@ -64,6 +64,7 @@ impl Rspack {
       plugins,
       AsyncNodeWritableFileSystem::new(output_filesystem)
         .map_err(|e| Error::from_reason(format!("Failed to create writable filesystem: {e}",)))?,
+      None,
     );
 
     Ok(Self {
@@ -32,8 +32,8 @@ use rspack_core::{
   CompilerFinishMakeHook, CompilerMake, CompilerMakeHook, CompilerShouldEmit,
   CompilerShouldEmitHook, CompilerThisCompilation, CompilerThisCompilationHook,
   ContextModuleFactoryAfterResolve, ContextModuleFactoryAfterResolveHook,
-  ContextModuleFactoryBeforeResolve, ContextModuleFactoryBeforeResolveHook, ExecuteModuleId,
-  MakeParam, ModuleFactoryCreateData, ModuleIdentifier, NormalModuleCreateData,
+  ContextModuleFactoryBeforeResolve, ContextModuleFactoryBeforeResolveHook, ContextModuleOptions,
+  ExecuteModuleId, MakeParam, ModuleFactoryCreateData, ModuleIdentifier, NormalModuleCreateData,
   NormalModuleFactoryAfterResolve, NormalModuleFactoryAfterResolveHook,
   NormalModuleFactoryBeforeResolve, NormalModuleFactoryBeforeResolveHook,
   NormalModuleFactoryCreateModule, NormalModuleFactoryCreateModuleHook,
@@ -1231,7 +1231,11 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
 
 #[async_trait]
 impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
-  async fn run(&self, data: &mut ModuleFactoryCreateData) -> rspack_error::Result<Option<bool>> {
+  async fn run(
+    &self,
+    data: &mut ModuleFactoryCreateData,
+    _options: &mut ContextModuleOptions,
+  ) -> rspack_error::Result<Option<bool>> {
     let dependency = data
       .dependency
       .as_context_dependency_mut()
@@ -54,8 +54,11 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
+  pub amd: Option<String>,
   pub profile: bool,
   pub bail: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   pub builtins: RawBuiltins,
 }
 
@@ -80,6 +83,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
         },
         emit_asset: true,
       },
+      layers: value.experiments.layers,
       new_split_chunks: value.experiments.new_split_chunks,
       top_level_await: value.experiments.top_level_await,
       rspack_future: value.experiments.rspack_future.into(),
@@ -111,9 +115,12 @@ impl TryFrom<RawOptions> for CompilerOptions {
       snapshot,
       optimization,
       node,
+      amd: value.amd,
       dev_server: Default::default(),
       profile: value.profile,
       bail: value.bail,
+      records_input_path: value.records_input_path.map(Into::into),
+      records_output_path: value.records_output_path.map(Into::into),
       builtins,
     })
   }
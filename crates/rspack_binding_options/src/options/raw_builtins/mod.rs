@@ -2,11 +2,19 @@ mod raw_banner;
 mod raw_bundle_info;
 mod raw_copy;
 mod raw_css_extract;
+mod raw_dll;
+mod raw_environment;
 mod raw_html;
+mod raw_http_uri;
+mod raw_ids;
 mod raw_ignore;
+mod raw_lazy_compilation;
 mod raw_limit_chunk_count;
+mod raw_manifest;
 mod raw_mf;
 mod raw_progress;
+mod raw_replacement;
+mod raw_sri;
 mod raw_swc_js_minimizer;
 mod raw_to_be_deprecated;
 
@@ -15,12 +23,14 @@ use napi_derive::napi;
 use rspack_core::{BoxPlugin, Define, DefinePlugin, PluginExt, Provide, ProvidePlugin};
 use rspack_error::Result;
 use rspack_ids::{
-  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin,
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedModuleIdsPlugin,
+  NamedChunkIdsPlugin, NamedModuleIdsPlugin, NaturalChunkIdsPlugin, NaturalModuleIdsPlugin,
+  OccurrenceChunkIdsPlugin, OccurrenceModuleIdsPlugin, RecordIdsPlugin,
 };
 use rspack_napi::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
+use rspack_plugin_context_replacement::ContextReplacementPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_css::CssPlugin;
 use rspack_plugin_devtool::{
@@ -28,6 +38,9 @@ use rspack_plugin_devtool::{
   SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin,
   SourceMapDevToolPluginOptions,
 };
+use rspack_plugin_dll::{
+  DllEntryPlugin, DllReferenceAgencyPlugin, FlagAllModulesAsUsedPlugin, LibManifestPlugin,
+};
 use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
 use rspack_plugin_entry::EntryPlugin;
 use rspack_plugin_externals::{
@@ -37,36 +50,43 @@ use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
 use rspack_plugin_ignore::IgnorePlugin;
 use rspack_plugin_javascript::{
-  api_plugin::APIPlugin, FlagDependencyExportsPlugin, FlagDependencyUsagePlugin,
+  api_plugin::APIPlugin, EnvironmentPlugin, FlagDependencyExportsPlugin, FlagDependencyUsagePlugin,
   InferAsyncModulesPlugin, JsPlugin, MangleExportsPlugin, ModuleConcatenationPlugin,
   SideEffectsFlagPlugin,
 };
 use rspack_plugin_json::JsonPlugin;
 use rspack_plugin_library::enable_library_plugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
+use rspack_plugin_manifest::ManifestPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
   ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
   ProvideSharedPlugin, ShareRuntimePlugin,
 };
+use rspack_plugin_normal_module_replacement::NormalModuleReplacementPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_real_content_hash::RealContentHashPlugin;
 use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
 use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, BundlerInfoPlugin,
-  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
+  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, LazyCompilationPlugin,
+  ModuleChunkFormatPlugin, RuntimePlugin,
 };
-use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
+use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin, HttpUriPlugin};
+use rspack_plugin_sri::SubresourceIntegrityPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
-use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin};
+use rspack_plugin_wasm::{
+  enable_wasm_loading_plugin, AsyncWasmPlugin, SyncWasmPlugin, WasmFinalizeExportsPlugin,
+};
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
 pub use self::{
   raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
-  raw_html::RawHtmlRspackPluginOptions, raw_ignore::RawIgnorePluginOptions,
+  raw_html::RawHtmlRspackPluginOptions, raw_http_uri::RawHttpUriPluginOptions,
+  raw_ignore::RawIgnorePluginOptions, raw_lazy_compilation::RawLazyCompilationOption,
   raw_limit_chunk_count::RawLimitChunkCountPluginOptions, raw_mf::RawContainerPluginOptions,
   raw_progress::RawProgressPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
@@ -74,7 +94,18 @@ pub use self::{
 use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_css_extract::RawCssExtractPluginOption,
+  raw_dll::{
+    RawDllEntryPluginOptions, RawDllReferenceAgencyPluginOptions, RawLibManifestPluginOptions,
+  },
+  raw_environment::RawEnvironmentPluginOptions,
+  raw_ids::{
+    RawHashedModuleIdsPluginOptions, RawOccurrenceChunkIdsPluginOptions,
+    RawOccurrenceModuleIdsPluginOptions,
+  },
+  raw_manifest::RawManifestPluginOptions,
   raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_replacement::{RawContextReplacementPluginOptions, RawNormalModuleReplacementPluginOptions},
+  raw_sri::RawSubresourceIntegrityPluginOptions,
 };
 use crate::{
   plugins::{CssExtractRspackAdditionalDataPlugin, JsLoaderResolverPlugin},
@@ -89,8 +120,11 @@ pub enum BuiltinPluginName {
   // webpack also have these plugins
   DefinePlugin,
   ProvidePlugin,
+  EnvironmentPlugin,
   BannerPlugin,
   IgnorePlugin,
+  ContextReplacementPlugin,
+  NormalModuleReplacementPlugin,
   ProgressPlugin,
   EntryPlugin,
   ExternalsPlugin,
@@ -119,17 +153,32 @@ pub enum BuiltinPluginName {
   DeterministicModuleIdsPlugin,
   NamedChunkIdsPlugin,
   DeterministicChunkIdsPlugin,
+  NaturalModuleIdsPlugin,
+  NaturalChunkIdsPlugin,
+  OccurrenceModuleIdsPlugin,
+  OccurrenceChunkIdsPlugin,
+  HashedModuleIdsPlugin,
+  RecordIdsPlugin,
+  DllEntryPlugin,
+  DllReferenceAgencyPlugin,
+  LibManifestPlugin,
+  FlagAllModulesAsUsedPlugin,
+  ManifestPlugin,
+  SubresourceIntegrityPlugin,
   RealContentHashPlugin,
   RemoveEmptyChunksPlugin,
   EnsureChunkConditionsPlugin,
   WarnCaseSensitiveModulesPlugin,
   DataUriPlugin,
   FileUriPlugin,
+  HttpUriPlugin,
+  LazyCompilationPlugin,
   RuntimePlugin,
   JsonModulesPlugin,
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -176,6 +225,10 @@ impl BuiltinPlugin {
         let plugin = ProvidePlugin::new(downcast_into::<Provide>(self.options)?).boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::EnvironmentPlugin => plugins.push(
+        EnvironmentPlugin::new(downcast_into::<RawEnvironmentPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
       BuiltinPluginName::BannerPlugin => {
         let plugin =
           BannerPlugin::new(downcast_into::<RawBannerPluginOptions>(self.options)?.try_into()?)
@@ -187,6 +240,20 @@ impl BuiltinPlugin {
           IgnorePlugin::new(downcast_into::<RawIgnorePluginOptions>(self.options)?.into()).boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::ContextReplacementPlugin => {
+        let plugin = ContextReplacementPlugin::new(
+          downcast_into::<RawContextReplacementPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::NormalModuleReplacementPlugin => {
+        let plugin = NormalModuleReplacementPlugin::new(
+          downcast_into::<RawNormalModuleReplacementPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::ProgressPlugin => {
         let plugin =
           ProgressPlugin::new(downcast_into::<RawProgressPluginOptions>(self.options)?.into())
@@ -226,9 +293,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::ChunkPrefetchPreloadPlugin => {
         plugins.push(ChunkPrefetchPreloadPlugin::default().boxed());
@@ -312,6 +377,58 @@ impl BuiltinPlugin {
       BuiltinPluginName::DeterministicChunkIdsPlugin => {
         plugins.push(DeterministicChunkIdsPlugin::default().boxed())
       }
+      BuiltinPluginName::NaturalModuleIdsPlugin => {
+        plugins.push(NaturalModuleIdsPlugin::default().boxed())
+      }
+      BuiltinPluginName::NaturalChunkIdsPlugin => {
+        plugins.push(NaturalChunkIdsPlugin::default().boxed())
+      }
+      BuiltinPluginName::OccurrenceModuleIdsPlugin => plugins.push(
+        OccurrenceModuleIdsPlugin::new(
+          downcast_into::<RawOccurrenceModuleIdsPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::OccurrenceChunkIdsPlugin => plugins.push(
+        OccurrenceChunkIdsPlugin::new(
+          downcast_into::<RawOccurrenceChunkIdsPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::HashedModuleIdsPlugin => plugins.push(
+        HashedModuleIdsPlugin::new(
+          downcast_into::<RawHashedModuleIdsPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::RecordIdsPlugin => plugins.push(RecordIdsPlugin::default().boxed()),
+      BuiltinPluginName::DllEntryPlugin => plugins.push(
+        DllEntryPlugin::new(downcast_into::<RawDllEntryPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
+      BuiltinPluginName::DllReferenceAgencyPlugin => plugins.push(
+        DllReferenceAgencyPlugin::new(
+          downcast_into::<RawDllReferenceAgencyPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::LibManifestPlugin => plugins.push(
+        LibManifestPlugin::new(downcast_into::<RawLibManifestPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
+      BuiltinPluginName::ManifestPlugin => plugins.push(
+        ManifestPlugin::new(downcast_into::<RawManifestPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
+      BuiltinPluginName::SubresourceIntegrityPlugin => plugins.push(
+        SubresourceIntegrityPlugin::new(
+          downcast_into::<RawSubresourceIntegrityPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed(),
+      ),
+      BuiltinPluginName::FlagAllModulesAsUsedPlugin => {
+        plugins.push(FlagAllModulesAsUsedPlugin::default().boxed())
+      }
       BuiltinPluginName::RealContentHashPlugin => {
         plugins.push(RealContentHashPlugin::default().boxed())
       }
@@ -326,6 +443,17 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::DataUriPlugin => plugins.push(DataUriPlugin::default().boxed()),
       BuiltinPluginName::FileUriPlugin => plugins.push(FileUriPlugin::default().boxed()),
+      BuiltinPluginName::HttpUriPlugin => {
+        let plugin =
+          HttpUriPlugin::new(downcast_into::<RawHttpUriPluginOptions>(self.options)?.into())
+            .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::LazyCompilationPlugin => {
+        let plugin: LazyCompilationPlugin =
+          downcast_into::<RawLazyCompilationOption>(self.options)?.into();
+        plugins.push(plugin.boxed());
+      }
       BuiltinPluginName::RuntimePlugin => plugins.push(RuntimePlugin::default().boxed()),
       BuiltinPluginName::JsonModulesPlugin => plugins.push(JsonPlugin.boxed()),
       BuiltinPluginName::InferAsyncModulesPlugin => {
@@ -335,6 +463,10 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => {
+        plugins.push(SyncWasmPlugin::default().boxed());
+        plugins.push(WasmFinalizeExportsPlugin::default().boxed());
+      }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
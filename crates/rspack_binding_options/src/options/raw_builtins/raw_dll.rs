@@ -0,0 +1,74 @@
+use napi_derive::napi;
+use rspack_error::miette::IntoDiagnostic;
+use rspack_plugin_dll::{
+  DllEntryPluginOptions, DllReferenceAgencyPluginOptions, LibManifestPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDllEntryPluginOptions {
+  pub name: String,
+  pub context: String,
+  pub entries: Vec<String>,
+}
+
+impl From<RawDllEntryPluginOptions> for DllEntryPluginOptions {
+  fn from(value: RawDllEntryPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      context: value.context.into(),
+      entries: value.entries,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLibManifestPluginOptions {
+  pub context: Option<String>,
+  pub entry_only: Option<bool>,
+  pub name: Option<String>,
+  pub path: String,
+  pub format: Option<bool>,
+  pub r#type: Option<String>,
+}
+
+impl From<RawLibManifestPluginOptions> for LibManifestPluginOptions {
+  fn from(value: RawLibManifestPluginOptions) -> Self {
+    Self {
+      context: value.context.map(Into::into),
+      entry_only: value.entry_only.unwrap_or(true),
+      format: value.format.unwrap_or_default(),
+      name: value.name,
+      path: value.path,
+      r#type: value.r#type,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDllReferenceAgencyPluginOptions {
+  pub context: String,
+  pub name: String,
+  pub extensions: Vec<String>,
+  pub scope: Option<String>,
+  pub r#type: String,
+  /// The `content` of the manifest, serialized as JSON
+  pub content: String,
+}
+
+impl TryFrom<RawDllReferenceAgencyPluginOptions> for DllReferenceAgencyPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawDllReferenceAgencyPluginOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      context: value.context.into(),
+      name: value.name,
+      extensions: value.extensions,
+      scope: value.scope,
+      r#type: value.r#type,
+      content: serde_json::from_str(&value.content).into_diagnostic()?,
+    })
+  }
+}
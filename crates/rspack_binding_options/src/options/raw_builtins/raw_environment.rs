@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use napi_derive::napi;
+use rspack_plugin_javascript::EnvironmentPluginOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawEnvironmentPluginOptions {
+  pub keys: Vec<String>,
+  pub defaults: HashMap<String, String>,
+}
+
+impl From<RawEnvironmentPluginOptions> for EnvironmentPluginOptions {
+  fn from(value: RawEnvironmentPluginOptions) -> Self {
+    Self {
+      keys: value.keys,
+      defaults: value.defaults,
+    }
+  }
+}
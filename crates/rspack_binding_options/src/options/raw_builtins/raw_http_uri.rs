@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use derivative::Derivative;
+use napi::bindgen_prelude::{Buffer, Promise};
+use napi::Either;
+use napi_derive::napi;
+use rspack_napi::{
+  regexp::{JsRegExp, JsRegExpExt},
+  threadsafe_function::ThreadsafeFunction,
+};
+use rspack_plugin_schemes::{AllowedUri, HttpResponse, HttpUriPluginOptions};
+
+#[napi(object)]
+pub struct RawHttpResponse {
+  pub status: u32,
+  pub headers: HashMap<String, String>,
+  pub body: Buffer,
+}
+
+type RawHttpClient =
+  ThreadsafeFunction<(String, HashMap<String, String>), Promise<RawHttpResponse>>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawHttpUriPluginOptions {
+  #[napi(ts_type = "Array<string | RegExp>")]
+  pub allowed_uris: Vec<Either<String, JsRegExp>>,
+  pub lockfile_location: Option<String>,
+  pub cache_location: Option<String>,
+  pub frozen: Option<bool>,
+  pub upgrade: Option<bool>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(url: string, headers: Record<string, string>) => Promise<RawHttpResponse>")]
+  pub http_client: RawHttpClient,
+}
+
+impl From<RawHttpUriPluginOptions> for HttpUriPluginOptions {
+  fn from(value: RawHttpUriPluginOptions) -> Self {
+    Self {
+      allowed_uris: value
+        .allowed_uris
+        .into_iter()
+        .map(|allowed| match allowed {
+          Either::A(prefix) => AllowedUri::Prefix(prefix),
+          Either::B(regexp) => AllowedUri::Regex(regexp.to_rspack_regex()),
+        })
+        .collect(),
+      lockfile_location: value.lockfile_location.map(Into::into),
+      cache_location: value.cache_location.map(Into::into),
+      frozen: value.frozen.unwrap_or_default(),
+      upgrade: value.upgrade.unwrap_or_default(),
+      http_client: Box::new(move |url, headers| {
+        let f = value.http_client.clone();
+        Box::pin(async move {
+          let response = f.call_with_promise((url, headers)).await?;
+          Ok(HttpResponse {
+            status: response.status as u16,
+            headers: response.headers,
+            body: response.body.to_vec(),
+          })
+        })
+      }),
+    }
+  }
+}
@@ -0,0 +1,63 @@
+use napi_derive::napi;
+use rspack_ids::{
+  HashedModuleIdsPluginOptions, OccurrenceChunkIdsPluginOptions, OccurrenceModuleIdsPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawOccurrenceModuleIdsPluginOptions {
+  pub prioritize_initial: Option<bool>,
+}
+
+impl From<RawOccurrenceModuleIdsPluginOptions> for OccurrenceModuleIdsPluginOptions {
+  fn from(value: RawOccurrenceModuleIdsPluginOptions) -> Self {
+    Self {
+      prioritize_initial: value.prioritize_initial.unwrap_or_default(),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawOccurrenceChunkIdsPluginOptions {
+  pub prioritize_initial: Option<bool>,
+}
+
+impl From<RawOccurrenceChunkIdsPluginOptions> for OccurrenceChunkIdsPluginOptions {
+  fn from(value: RawOccurrenceChunkIdsPluginOptions) -> Self {
+    Self {
+      prioritize_initial: value.prioritize_initial.unwrap_or_default(),
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHashedModuleIdsPluginOptions {
+  pub context: Option<String>,
+  pub hash_function: Option<String>,
+  pub hash_digest: Option<String>,
+  pub hash_digest_length: Option<u32>,
+}
+
+impl TryFrom<RawHashedModuleIdsPluginOptions> for HashedModuleIdsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHashedModuleIdsPluginOptions) -> Result<Self, Self::Error> {
+    let default = Self::default();
+    Ok(Self {
+      context: value.context,
+      hash_function: match value.hash_function {
+        Some(hash_function) => hash_function.as_str().try_into()?,
+        None => default.hash_function,
+      },
+      hash_digest: match value.hash_digest {
+        Some(hash_digest) => hash_digest.as_str().try_into()?,
+        None => default.hash_digest,
+      },
+      hash_digest_length: value
+        .hash_digest_length
+        .map_or(default.hash_digest_length, |length| length as usize),
+    })
+  }
+}
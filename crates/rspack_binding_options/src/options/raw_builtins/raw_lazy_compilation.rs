@@ -0,0 +1,95 @@
+use derivative::Derivative;
+use napi::Either;
+use napi_derive::napi;
+use rspack_core::ModuleIdentifier;
+use rspack_error::Result;
+use rspack_napi::{
+  regexp::{JsRegExp, JsRegExpExt},
+  threadsafe_function::ThreadsafeFunction,
+};
+use rspack_plugin_runtime::{
+  LazyCompilationBackend, LazyCompilationModuleInfo, LazyCompilationPlugin, LazyCompilationTest,
+  LazyCompilationTestFn,
+};
+
+#[napi(object)]
+pub struct RawLazyCompilationModuleArgs {
+  pub module: String,
+  pub path: String,
+}
+
+#[napi(object)]
+pub struct RawLazyCompilationModuleInfo {
+  pub active: bool,
+  pub data: String,
+  pub client: String,
+}
+
+type RawLazyCompilationModule =
+  ThreadsafeFunction<RawLazyCompilationModuleArgs, RawLazyCompilationModuleInfo>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawLazyCompilationOption {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(args: RawLazyCompilationModuleArgs) => RawLazyCompilationModuleInfo")]
+  pub module: RawLazyCompilationModule,
+  pub entries: bool,
+  pub imports: bool,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "RegExp | ((name: string) => boolean)")]
+  pub test: Option<Either<JsRegExp, ThreadsafeFunction<String, bool>>>,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+struct JsBackend {
+  #[derivative(Debug = "ignore")]
+  module: RawLazyCompilationModule,
+}
+
+#[async_trait::async_trait]
+impl LazyCompilationBackend for JsBackend {
+  async fn module(
+    &self,
+    original_module: ModuleIdentifier,
+    path: String,
+  ) -> Result<LazyCompilationModuleInfo> {
+    let info = self
+      .module
+      .call_with_sync(RawLazyCompilationModuleArgs {
+        module: original_module.to_string(),
+        path,
+      })
+      .await?;
+    Ok(LazyCompilationModuleInfo {
+      active: info.active,
+      data: info.data,
+      client: info.client,
+    })
+  }
+}
+
+impl From<RawLazyCompilationOption> for LazyCompilationPlugin {
+  fn from(value: RawLazyCompilationOption) -> Self {
+    let test = value.test.map(|test| match test {
+      Either::A(regexp) => LazyCompilationTest::Regex(regexp.to_rspack_regex()),
+      Either::B(func) => {
+        let func: LazyCompilationTestFn = Box::new(move |name| {
+          let f = func.clone();
+          Box::pin(async move { f.call_with_sync(name).await })
+        });
+        LazyCompilationTest::Fn(func)
+      }
+    });
+    LazyCompilationPlugin::new(
+      Box::new(JsBackend {
+        module: value.module,
+      }),
+      value.entries,
+      value.imports,
+      test,
+    )
+  }
+}
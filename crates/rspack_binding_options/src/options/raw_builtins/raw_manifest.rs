@@ -0,0 +1,72 @@
+use derivative::Derivative;
+use napi_derive::napi;
+use rspack_error::miette::IntoDiagnostic;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_manifest::{
+  ManifestFile, ManifestFilterFn, ManifestPluginOptions, ManifestSerializeFn,
+};
+
+#[napi(object)]
+pub struct RawManifestFile {
+  pub name: String,
+  pub path: String,
+  pub chunk_name: Option<String>,
+  pub is_initial: bool,
+  pub is_chunk: bool,
+  pub is_auxiliary: bool,
+}
+
+impl From<&ManifestFile> for RawManifestFile {
+  fn from(value: &ManifestFile) -> Self {
+    Self {
+      name: value.name.clone(),
+      path: value.path.clone(),
+      chunk_name: value.chunk_name.clone(),
+      is_initial: value.is_initial,
+      is_chunk: value.is_chunk,
+      is_auxiliary: value.is_auxiliary,
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawManifestPluginOptions {
+  pub filename: String,
+  pub public_path: Option<String>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(file: RawManifestFile) => boolean")]
+  pub filter: Option<ThreadsafeFunction<RawManifestFile, bool>>,
+  /// Receives the manifest as a JSON string and returns the content of the asset
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(manifest: string) => string")]
+  pub serialize: Option<ThreadsafeFunction<String, String>>,
+}
+
+impl From<RawManifestPluginOptions> for ManifestPluginOptions {
+  fn from(value: RawManifestPluginOptions) -> Self {
+    let filter = value.filter.map(|filter| {
+      let filter: ManifestFilterFn = Box::new(move |file| {
+        let f = filter.clone();
+        let file = RawManifestFile::from(file);
+        Box::pin(async move { f.call(file).await })
+      });
+      filter
+    });
+    let serialize = value.serialize.map(|serialize| {
+      let serialize: ManifestSerializeFn = Box::new(move |manifest| {
+        let f = serialize.clone();
+        let manifest = serde_json::to_string(manifest);
+        Box::pin(async move { f.call(manifest.into_diagnostic()?).await })
+      });
+      serialize
+    });
+    Self {
+      filename: value.filename,
+      public_path: value.public_path,
+      filter,
+      serialize,
+    }
+  }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use derivative::Derivative;
+use napi::Either;
+use napi_derive::napi;
+use rspack_napi::{
+  regexp::{JsRegExp, JsRegExpExt},
+  threadsafe_function::ThreadsafeFunction,
+};
+use rspack_plugin_context_replacement::ContextReplacementPluginOptions;
+use rspack_plugin_normal_module_replacement::{
+  NewResource, NormalModuleReplacementData, NormalModuleReplacementPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawContextReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  #[napi(ts_type = "RegExp")]
+  pub new_content_reg_exp: Option<JsRegExp>,
+  pub new_content_create_context_map: Option<HashMap<String, String>>,
+}
+
+impl From<RawContextReplacementPluginOptions> for ContextReplacementPluginOptions {
+  fn from(value: RawContextReplacementPluginOptions) -> Self {
+    Self {
+      resource_reg_exp: value.resource_reg_exp.to_rspack_regex(),
+      new_content_resource: value.new_content_resource,
+      new_content_recursive: value.new_content_recursive,
+      new_content_reg_exp: value
+        .new_content_reg_exp
+        .map(|new_content_reg_exp| new_content_reg_exp.to_rspack_regex()),
+      new_content_create_context_map: value
+        .new_content_create_context_map
+        .map(|map| map.into_iter().collect()),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct RawNormalModuleReplacementData {
+  pub request: String,
+  pub context: String,
+  pub resource: Option<String>,
+}
+
+impl From<NormalModuleReplacementData> for RawNormalModuleReplacementData {
+  fn from(value: NormalModuleReplacementData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      resource: value.resource,
+    }
+  }
+}
+
+impl From<RawNormalModuleReplacementData> for NormalModuleReplacementData {
+  fn from(value: RawNormalModuleReplacementData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      resource: value.resource,
+    }
+  }
+}
+
+type RawNewResource = Either<
+  String,
+  ThreadsafeFunction<RawNormalModuleReplacementData, RawNormalModuleReplacementData>,
+>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawNormalModuleReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  #[derivative(Debug = "ignore")]
+  #[napi(
+    ts_type = "string | ((data: RawNormalModuleReplacementData) => RawNormalModuleReplacementData)"
+  )]
+  pub new_resource: RawNewResource,
+}
+
+impl From<RawNormalModuleReplacementPluginOptions> for NormalModuleReplacementPluginOptions {
+  fn from(value: RawNormalModuleReplacementPluginOptions) -> Self {
+    Self {
+      resource_reg_exp: value.resource_reg_exp.to_rspack_regex(),
+      new_resource: match value.new_resource {
+        Either::A(new_resource) => NewResource::String(new_resource),
+        Either::B(new_resource) => NewResource::Fn(Box::new(move |data| {
+          let f = new_resource.clone();
+          Box::pin(async move { Ok(f.call_with_sync(data.into()).await?.into()) })
+        })),
+      },
+    }
+  }
+}
@@ -0,0 +1,23 @@
+use napi_derive::napi;
+use rspack_plugin_sri::SubresourceIntegrityPluginOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawSubresourceIntegrityPluginOptions {
+  #[napi(ts_type = "Array<\"sha256\" | \"sha384\" | \"sha512\">")]
+  pub hash_func_names: Vec<String>,
+}
+
+impl TryFrom<RawSubresourceIntegrityPluginOptions> for SubresourceIntegrityPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSubresourceIntegrityPluginOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      hash_func_names: value
+        .hash_func_names
+        .iter()
+        .map(|name| name.parse())
+        .collect::<Result<_, _>>()?,
+    })
+  }
+}
@@ -1,8 +1,9 @@
-use std::{ops::Deref, path::PathBuf, str::FromStr};
+use std::{ops::Deref, path::PathBuf, str::FromStr, sync::Arc};
 
 use napi_derive::napi;
 use rspack_core::{rspack_sources::SourceMap, Content, ResourceData};
 use rspack_error::Diagnostic;
+use rspack_fs::AsyncNativeFileSystem;
 use rspack_loader_runner::AdditionalData;
 use rustc_hash::FxHashSet as HashSet;
 use {
@@ -323,6 +324,7 @@ pub async fn run_builtin_loader(
         .map(|m| PathBuf::from_str(m).expect("Should convert to path")),
     ),
     asset_filenames: HashSet::from_iter(loader_context.asset_filenames.into_iter()),
+    fs: Arc::new(AsyncNativeFileSystem),
     // Initialize with no diagnostic
     __diagnostics: vec![],
     __resource_data: &mut resource_data,
@@ -12,11 +12,11 @@ use rspack_core::{
   AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
   AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions, BoxLoader,
   CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
-  CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportMode, FuncUseCtx,
-  GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions, JavascriptParserOrder,
-  JavascriptParserUrl, ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions,
-  ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, ParserOptions,
-  ParserOptionsByModuleType,
+  CssModuleParserOptions, CssParserOptions, DescriptionData, DynamicImportMode, ExportPresenceMode,
+  FuncUseCtx, GeneratorOptions, GeneratorOptionsByModuleType, JavascriptParserOptions,
+  JavascriptParserOrder, JavascriptParserUrl, ModuleNoParseRule, ModuleNoParseRules,
+  ModuleNoParseTestFn, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
+  ModuleRuleUseLoader, ModuleType, ParserOptions, ParserOptionsByModuleType,
 };
 use rspack_error::error;
 use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
@@ -242,12 +242,14 @@ pub struct RawModuleRule {
   pub resource_fragment: Option<RawRuleSetCondition>,
   pub description_data: Option<HashMap<String, RawRuleSetCondition>>,
   pub side_effects: Option<bool>,
+  pub layer: Option<String>,
   pub r#use: Option<RawModuleRuleUses>,
   pub r#type: Option<String>,
   pub parser: Option<RawParserOptions>,
   pub generator: Option<RawGeneratorOptions>,
   pub resolve: Option<RawResolveOptions>,
   pub issuer: Option<RawRuleSetCondition>,
+  pub issuer_layer: Option<RawRuleSetCondition>,
   pub dependency: Option<RawRuleSetCondition>,
   pub scheme: Option<RawRuleSetCondition>,
   pub mimetype: Option<RawRuleSetCondition>,
@@ -272,9 +274,11 @@ pub struct RawParserOptions {
   pub javascript: Option<RawJavascriptParserOptions>,
 }
 
-impl From<RawParserOptions> for ParserOptions {
-  fn from(value: RawParserOptions) -> Self {
-    match value.r#type.as_str() {
+impl TryFrom<RawParserOptions> for ParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawParserOptions) -> rspack_error::Result<Self> {
+    Ok(match value.r#type.as_str() {
       "asset" => Self::Asset(
         value
           .asset
@@ -286,7 +290,7 @@ impl From<RawParserOptions> for ParserOptions {
           value
             .javascript
             .expect("should have an \"javascript\" when RawParserOptions.type is \"javascript\"")
-            .into(),
+            .try_into()?,
         )
       }
       "css" => Self::Css(
@@ -311,7 +315,7 @@ impl From<RawParserOptions> for ParserOptions {
         "Failed to resolve the RawParserOptions.type {}.",
         value.r#type
       ),
-    }
+    })
   }
 }
 
@@ -324,18 +328,37 @@ pub struct RawJavascriptParserOptions {
   pub url: String,
   pub expr_context_critical: bool,
   pub wrapped_context_critical: bool,
+  pub exports_presence: Option<String>,
+  pub import_exports_presence: Option<String>,
+  pub reexport_exports_presence: Option<String>,
+  pub strict_export_presence: bool,
 }
 
-impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
-  fn from(value: RawJavascriptParserOptions) -> Self {
-    Self {
+impl TryFrom<RawJavascriptParserOptions> for JavascriptParserOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawJavascriptParserOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       dynamic_import_mode: DynamicImportMode::from(value.dynamic_import_mode.as_str()),
       dynamic_import_preload: JavascriptParserOrder::from(value.dynamic_import_preload.as_str()),
       dynamic_import_prefetch: JavascriptParserOrder::from(value.dynamic_import_prefetch.as_str()),
       url: JavascriptParserUrl::from(value.url.as_str()),
       expr_context_critical: value.expr_context_critical,
       wrapped_context_critical: value.wrapped_context_critical,
-    }
+      exports_presence: value
+        .exports_presence
+        .map(|e| ExportPresenceMode::try_from(e.as_str()))
+        .transpose()?,
+      import_exports_presence: value
+        .import_exports_presence
+        .map(|e| ExportPresenceMode::try_from(e.as_str()))
+        .transpose()?,
+      reexport_exports_presence: value
+        .reexport_exports_presence
+        .map(|e| ExportPresenceMode::try_from(e.as_str()))
+        .transpose()?,
+      strict_export_presence: value.strict_export_presence,
+    })
   }
 }
 
@@ -819,11 +842,13 @@ impl TryFrom<RawModuleRule> for ModuleRule {
       description_data,
       r#use: uses.transpose()?.unwrap_or_default(),
       r#type: module_type,
-      parser: value.parser.map(|raw| raw.into()),
+      parser: value.parser.map(|raw| raw.try_into()).transpose()?,
       generator: value.generator.map(|raw| raw.into()),
       resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: value.side_effects,
+      layer: value.layer,
       issuer: value.issuer.map(|raw| raw.try_into()).transpose()?,
+      issuer_layer: value.issuer_layer.map(|raw| raw.try_into()).transpose()?,
       dependency: value.dependency.map(|raw| raw.try_into()).transpose()?,
       scheme: value.scheme.map(|raw| raw.try_into()).transpose()?,
       mimetype: value.mimetype.map(|raw| raw.try_into()).transpose()?,
@@ -849,7 +874,7 @@ impl TryFrom<RawModuleOptions> for ModuleOptions {
         .parser
         .map(|x| {
           x.into_iter()
-            .map(|(k, v)| Ok((ModuleType::from(k.as_str()), v.into())))
+            .map(|(k, v)| Ok((ModuleType::from(k.as_str()), v.try_into()?)))
             .collect::<std::result::Result<ParserOptionsByModuleType, rspack_error::Error>>()
         })
         .transpose()?,
@@ -14,6 +14,7 @@ pub struct RawOptimizationOptions {
   pub inner_graph: bool,
   pub mangle_exports: String,
   pub concatenate_modules: bool,
+  pub real_content_hash: bool,
 }
 
 impl TryFrom<RawOptimizationOptions> for Optimization {
@@ -28,6 +29,7 @@ impl TryFrom<RawOptimizationOptions> for Optimization {
       inner_graph: value.inner_graph,
       mangle_exports: MangleExportsOption::from(value.mangle_exports.as_str()),
       concatenate_modules: value.concatenate_modules,
+      real_content_hash: value.real_content_hash,
     })
   }
 }
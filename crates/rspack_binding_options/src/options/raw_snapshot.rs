@@ -1,5 +1,7 @@
+use napi::Either;
 use napi_derive::napi;
-use rspack_core::{SnapshotOptions, SnapshotStrategy};
+use rspack_core::{SnapshotOptions, SnapshotPathMatcher, SnapshotStrategy};
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 
 #[derive(Debug, Default)]
 #[napi(object)]
@@ -17,20 +19,44 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
   }
 }
 
+type RawSnapshotPath = Either<String, JsRegExp>;
+
+fn normalize_raw_snapshot_paths(paths: Option<Vec<RawSnapshotPath>>) -> Vec<SnapshotPathMatcher> {
+  paths
+    .unwrap_or_default()
+    .into_iter()
+    .map(|path| match path {
+      Either::A(path) => SnapshotPathMatcher::String(path),
+      Either::B(regexp) => SnapshotPathMatcher::Regex(regexp.to_rspack_regex()),
+    })
+    .collect()
+}
+
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  #[napi(ts_type = "Array<string | RegExp>")]
+  pub immutable_paths: Option<Vec<RawSnapshotPath>>,
+  #[napi(ts_type = "Array<string | RegExp>")]
+  pub managed_paths: Option<Vec<RawSnapshotPath>>,
 }
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
   fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      immutable_paths,
+      managed_paths,
+    } = value;
 
     SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
+      immutable_paths: normalize_raw_snapshot_paths(immutable_paths),
+      managed_paths: normalize_raw_snapshot_paths(managed_paths),
     }
   }
 }
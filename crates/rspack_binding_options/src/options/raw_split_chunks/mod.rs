@@ -1,3 +1,4 @@
+mod raw_split_chunk_cache_group_layer;
 mod raw_split_chunk_cache_group_test;
 mod raw_split_chunk_chunks;
 mod raw_split_chunk_name;
@@ -16,6 +17,9 @@ use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 use rspack_napi::string::JsStringExt;
 use rspack_plugin_split_chunks::ChunkNameGetter;
 
+use self::raw_split_chunk_cache_group_layer::default_cache_group_layer;
+use self::raw_split_chunk_cache_group_layer::normalize_raw_cache_group_layer;
+use self::raw_split_chunk_cache_group_layer::RawCacheGroupLayer;
 use self::raw_split_chunk_cache_group_test::default_cache_group_test;
 use self::raw_split_chunk_cache_group_test::normalize_raw_cache_group_test;
 use self::raw_split_chunk_cache_group_test::RawCacheGroupTest;
@@ -42,13 +46,14 @@ pub struct RawSplitChunksOptions {
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
   pub min_size: Option<f64>,
-  //   pub min_size_reduction: usize,
+  pub min_size_reduction: Option<f64>,
   pub enforce_size_threshold: Option<f64>,
   pub min_remaining_size: Option<f64>,
   // layer: String,
   pub max_size: Option<f64>,
   pub max_async_size: Option<f64>,
   pub max_initial_size: Option<f64>,
+  pub used_exports: Option<bool>,
 }
 
 #[derive(Derivative)]
@@ -62,6 +67,9 @@ pub struct RawCacheGroupOptions {
   #[napi(ts_type = "RegExp | string | Function")]
   #[derivative(Debug = "ignore")]
   pub test: Option<RawCacheGroupTest>,
+  #[napi(ts_type = "RegExp | string | Function")]
+  #[derivative(Debug = "ignore")]
+  pub layer: Option<RawCacheGroupLayer>,
   pub filename: Option<String>,
   //   pub enforce: bool,
   pub id_hint: Option<String>,
@@ -77,9 +85,9 @@ pub struct RawCacheGroupOptions {
   //   pub max_initial_requests: usize,
   pub min_chunks: Option<u32>,
   pub min_size: Option<f64>,
-  //   pub min_size_reduction: usize,
-  //   pub enforce_size_threshold: usize,
-  //   pub min_remaining_size: usize,
+  pub min_size_reduction: Option<f64>,
+  pub enforce_size_threshold: Option<f64>,
+  pub min_remaining_size: Option<f64>,
   // layer: String,
   pub max_size: Option<f64>,
   pub max_async_size: Option<f64>,
@@ -87,7 +95,7 @@ pub struct RawCacheGroupOptions {
   #[napi(ts_type = "string | false | Function")]
   #[derivative(Debug = "ignore")]
   pub name: Option<RawChunkOptionName>,
-  // used_exports: bool,
+  pub used_exports: Option<bool>,
   pub reuse_existing_chunk: Option<bool>,
   pub enforce: Option<bool>,
 }
@@ -121,6 +129,10 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
     let empty_sizes = SplitChunkSizes::empty();
 
     let overall_min_size = create_sizes(raw_opts.min_size);
+    let overall_min_size_reduction = create_sizes(raw_opts.min_size_reduction);
+    let overall_min_remaining_size = create_sizes(raw_opts.min_remaining_size);
+    let overall_enforce_size_threshold = create_sizes(raw_opts.enforce_size_threshold);
+    let overall_used_exports = raw_opts.used_exports.unwrap_or_default();
     let overall_max_size = create_sizes(raw_opts.max_size);
     let overall_max_async_size = create_sizes(raw_opts.max_async_size).merge(&overall_max_size);
     let overall_max_initial_size = create_sizes(raw_opts.max_initial_size).merge(&overall_max_size);
@@ -142,6 +154,24 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             &overall_min_size
           });
 
+          let min_size_reduction = create_sizes(v.min_size_reduction).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_size_reduction
+          });
+
+          let min_remaining_size = create_sizes(v.min_remaining_size).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_remaining_size
+          });
+
+          let enforce_size_threshold = create_sizes(v.enforce_size_threshold).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_enforce_size_threshold
+          });
+
           let max_size = create_sizes(v.max_size);
 
           let max_async_size = create_sizes(v.max_async_size)
@@ -186,6 +216,9 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             test: v.test.map_or(default_cache_group_test(), |test| {
               normalize_raw_cache_group_test(test)
             }),
+            layer: v.layer.map_or(default_cache_group_layer(), |layer| {
+              normalize_raw_cache_group_layer(layer)
+            }),
             chunk_filter: v.chunks.map(create_chunks_filter).unwrap_or_else(|| {
               overall_chunk_filter
                 .clone()
@@ -193,6 +226,10 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             }),
             min_chunks,
             min_size,
+            min_size_reduction,
+            min_remaining_size,
+            enforce_size_threshold,
+            used_exports: v.used_exports.unwrap_or(overall_used_exports),
             automatic_name_delimiter: v
               .automatic_name_delimiter
               .unwrap_or(overall_automatic_name_delimiter.clone()),
@@ -202,6 +239,11 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             // I would set align default value with Webpack when the options is exposed to users
             max_async_requests: u32::MAX,
             max_initial_requests: u32::MAX,
+            max_size: max_size.merge(if enforce {
+              &empty_sizes
+            } else {
+              &overall_max_size
+            }),
             max_async_size,
             max_initial_size,
             r#type,
@@ -237,6 +279,7 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             .unwrap_or_else(rspack_plugin_split_chunks::create_all_chunk_filter)
         }),
         min_size: fallback_min_size,
+        max_size: fallback_max_size.merge(&overall_max_size),
         max_async_size: fallback_max_async_size,
         max_initial_size: fallback_max_initial_size,
         automatic_name_delimiter: raw_fallback_cache_group
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use napi::bindgen_prelude::Either3;
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_split_chunks::CacheGroupLayer;
+use tokio::runtime::Handle;
+
+pub(super) type RawCacheGroupLayer =
+  Either3<String, JsRegExp, ThreadsafeFunction<Option<String>, Option<bool>>>;
+
+pub(super) fn normalize_raw_cache_group_layer(raw: RawCacheGroupLayer) -> CacheGroupLayer {
+  let handle = Handle::current();
+  match raw {
+    Either3::A(str) => CacheGroupLayer::String(str),
+    Either3::B(regexp) => CacheGroupLayer::RegExp(regexp.to_rspack_regex()),
+    Either3::C(v) => CacheGroupLayer::Fn(Arc::new(move |ctx| {
+      handle.block_on(v.call(ctx.layer.map(|layer| layer.to_string())))
+    })),
+  }
+}
+
+#[inline]
+pub(super) fn default_cache_group_layer() -> CacheGroupLayer {
+  CacheGroupLayer::Enabled
+}
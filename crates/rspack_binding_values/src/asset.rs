@@ -38,6 +38,8 @@ pub struct JsAssetInfo {
   pub javascript_module: Option<bool>,
   /// related object to other assets, keyed by type of relation (only points from parent to child)
   pub related: JsAssetInfoRelated,
+  /// the subresource integrity of the asset
+  pub integrity: Option<String>,
 }
 
 impl From<JsAssetInfo> for rspack_core::AssetInfo {
@@ -53,6 +55,7 @@ impl From<JsAssetInfo> for rspack_core::AssetInfo {
       version: String::from(""),
       source_filename: i.source_filename,
       javascript_module: i.javascript_module,
+      integrity: i.integrity,
     }
   }
 }
@@ -83,6 +86,7 @@ impl From<rspack_core::AssetInfo> for JsAssetInfo {
       content_hash: info.content_hash.into_iter().collect(),
       source_filename: info.source_filename,
       javascript_module: info.javascript_module,
+      integrity: info.integrity,
     }
   }
 }
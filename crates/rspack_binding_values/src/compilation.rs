@@ -459,15 +459,27 @@ impl JsCompilation {
     request: String,
     public_path: Option<String>,
     base_uri: Option<String>,
-    _original_module: Option<String>,
+    layer: Option<String>,
+    original_module: Option<String>,
     original_module_context: Option<String>,
     callback: JsFunction,
   ) -> Result<()> {
+    // use the layer of the issuer by default, like a regular dependency
+    let layer = layer.or_else(|| {
+      original_module.and_then(|identifier| {
+        self
+          .0
+          .get_module_graph()
+          .module_by_identifier(&identifier.into())
+          .and_then(|module| module.get_layer().cloned())
+      })
+    });
     let options = self.0.options.clone();
     let plugin_driver = self.0.plugin_driver.clone();
     let resolver_factory = self.0.resolver_factory.clone();
     let loader_resolver_factory = self.0.loader_resolver_factory.clone();
     let cache = self.0.cache.clone();
+    let input_filesystem = self.0.input_filesystem.clone();
     let dependency_factories = self.0.dependency_factories.clone();
 
     callbackify(env, callback, async {
@@ -483,10 +495,12 @@ impl JsCompilation {
           resolver_factory,
           loader_resolver_factory,
           cache,
+          input_filesystem,
           dependency_factories,
           request,
           public_path,
           base_uri,
+          layer,
           original_module_context.map(rspack_core::Context::new),
         )
         .await;
@@ -234,6 +234,7 @@ pub struct JsStatsModule {
   pub issuer_id: Option<String>,
   pub issuer_path: Vec<JsStatsModuleIssuer>,
   pub name_for_condition: Option<String>,
+  pub layer: Option<String>,
   pub reasons: Option<Vec<JsStatsModuleReason>>,
   pub assets: Option<Vec<String>>,
   pub source: Option<Either<String, Buffer>>,
@@ -273,6 +274,7 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       issuer_name: stats.issuer_name,
       issuer_id: stats.issuer_id,
       name_for_condition: stats.name_for_condition,
+      layer: stats.layer,
       issuer_path: stats.issuer_path.into_iter().map(Into::into).collect(),
       reasons: stats
         .reasons
@@ -0,0 +1,289 @@
+use std::{collections::HashMap, path::Path};
+
+use indexmap::IndexMap;
+use rspack_error::{error, Result};
+use rspack_plugin_html::config::HtmlRspackPluginOptions;
+use serde::Deserialize;
+
+/// Declarative config read from `rspack.config.json` or `rspack.config.toml`.
+///
+/// Mirrors the shape of the JavaScript config, options left out fall back to the same defaults.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Config {
+  /// Base directory for entries and loaders, defaults to the directory of the config file.
+  pub context: Option<String>,
+  pub mode: Option<ModeConfig>,
+  pub target: Option<OneOrMany>,
+  pub devtool: Option<DevtoolConfig>,
+  pub entry: Option<EntryConfig>,
+  pub output: OutputConfig,
+  pub module: ModuleConfig,
+  pub resolve: ResolveConfig,
+  pub optimization: OptimizationConfig,
+  pub experiments: ExperimentsConfig,
+  /// Caches modules in memory between builds, defaults to `true` in development.
+  pub cache: Option<bool>,
+  pub bail: bool,
+  pub profile: bool,
+  /// Rebuilds when files change, same as passing `--watch`.
+  pub watch: bool,
+  pub watch_options: WatchOptionsConfig,
+  pub plugins: Vec<PluginConfig>,
+}
+
+impl Config {
+  pub fn load(path: &Path) -> Result<Self> {
+    let content = std::fs::read_to_string(path)
+      .map_err(|e| error!("Failed to read config {}: {e}", path.display()))?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+      Some("toml") => toml::from_str(&content)
+        .map_err(|e| error!("Failed to parse config {}: {e}", path.display())),
+      Some("json") => serde_json::from_str(&content)
+        .map_err(|e| error!("Failed to parse config {}: {e}", path.display())),
+      _ => Err(error!(
+        "Unsupported config {}, expected a .json or .toml file",
+        path.display()
+      )),
+    }
+  }
+
+  pub fn is_production(&self) -> bool {
+    matches!(self.mode, None | Some(ModeConfig::Production))
+  }
+
+  pub fn is_development(&self) -> bool {
+    matches!(self.mode, Some(ModeConfig::Development))
+  }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModeConfig {
+  Development,
+  Production,
+  None,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum OneOrMany {
+  One(String),
+  Many(Vec<String>),
+}
+
+impl OneOrMany {
+  pub fn to_vec(&self) -> Vec<String> {
+    match self {
+      Self::One(item) => vec![item.clone()],
+      Self::Many(items) => items.clone(),
+    }
+  }
+}
+
+/// `false` or a devtool string like `"source-map"`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum DevtoolConfig {
+  Bool(bool),
+  String(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum EntryConfig {
+  Single(OneOrMany),
+  Named(IndexMap<String, OneOrMany>),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct OutputConfig {
+  /// Relative to the context, defaults to `dist`.
+  pub path: Option<String>,
+  pub filename: Option<String>,
+  pub chunk_filename: Option<String>,
+  pub css_filename: Option<String>,
+  pub css_chunk_filename: Option<String>,
+  pub asset_module_filename: Option<String>,
+  pub public_path: Option<String>,
+  pub unique_name: Option<String>,
+  pub clean: bool,
+  pub hash_function: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModuleConfig {
+  pub parser: ParserConfig,
+  pub rules: Vec<RuleConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ParserConfig {
+  pub javascript: JavascriptParserConfig,
+}
+
+/// Reporting of imported or reexported names which the imported module does not export.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct JavascriptParserConfig {
+  /// `"error"`, `"warn"`, `"auto"` or `false`.
+  pub exports_presence: Option<BoolOrString>,
+  pub import_exports_presence: Option<BoolOrString>,
+  pub reexport_exports_presence: Option<BoolOrString>,
+  pub strict_export_presence: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RuleConfig {
+  pub test: Option<ConditionConfig>,
+  pub include: Option<ConditionConfig>,
+  pub exclude: Option<ConditionConfig>,
+  pub resource_query: Option<ConditionConfig>,
+  pub r#type: Option<String>,
+  pub side_effects: Option<bool>,
+  pub r#use: Vec<UseConfig>,
+  pub one_of: Option<Vec<RuleConfig>>,
+  pub rules: Option<Vec<RuleConfig>>,
+}
+
+/// Strings match as path prefixes (relative ones are resolved against the context),
+/// regular expressions are written as `{ regexp = "\\.ts$", flags = "i" }`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ConditionConfig {
+  String(String),
+  Regexp {
+    regexp: String,
+    #[serde(default)]
+    flags: String,
+  },
+  Array(Vec<ConditionConfig>),
+}
+
+/// A loader request, only builtin loaders like `builtin:swc-loader` are supported.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum UseConfig {
+  Loader(String),
+  WithOptions {
+    loader: String,
+    #[serde(default)]
+    options: Option<serde_json::Value>,
+  },
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ResolveConfig {
+  pub extensions: Option<Vec<String>>,
+  /// Request prefix -> path, relative paths are resolved against the context.
+  pub alias: IndexMap<String, String>,
+  pub main_fields: Option<Vec<String>>,
+  pub main_files: Option<Vec<String>>,
+  pub condition_names: Option<Vec<String>>,
+  pub modules: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct OptimizationConfig {
+  pub minimize: Option<bool>,
+  /// `named`, `natural`, `deterministic`, `size` or `hashed`.
+  pub module_ids: Option<String>,
+  /// `named`, `natural`, `deterministic`, `size` or `total-size`.
+  pub chunk_ids: Option<String>,
+  pub side_effects: Option<BoolOrString>,
+  pub used_exports: Option<BoolOrString>,
+  pub provided_exports: Option<bool>,
+  pub inner_graph: Option<bool>,
+  pub mangle_exports: Option<BoolOrString>,
+  pub concatenate_modules: Option<bool>,
+  pub remove_available_modules: Option<bool>,
+  pub remove_empty_chunks: Option<bool>,
+  pub merge_duplicate_chunks: Option<bool>,
+  pub real_content_hash: Option<bool>,
+  /// Value of `process.env.NODE_ENV`, `false` leaves it untouched. Defaults to the mode.
+  pub node_env: Option<BoolOrString>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BoolOrString {
+  Bool(bool),
+  String(String),
+}
+
+impl std::fmt::Display for BoolOrString {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Bool(b) => b.fmt(f),
+      Self::String(s) => s.fmt(f),
+    }
+  }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExperimentsConfig {
+  pub css: Option<bool>,
+  pub top_level_await: Option<bool>,
+  pub layers: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct WatchOptionsConfig {
+  pub aggregate_timeout: Option<u64>,
+  pub poll: Option<u64>,
+  /// Globs of paths which are not watched, e.g. `**/node_modules`.
+  pub ignored: Option<OneOrMany>,
+}
+
+/// Builtin plugins, written as `{ name = "DefinePlugin", options = { ... } }`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "name", content = "options")]
+pub enum PluginConfig {
+  /// Key -> code, values which are not strings are converted to code, with strings inside them
+  /// being code too. Objects also define their properties, e.g. `process.env.NODE_ENV`.
+  #[serde(rename = "DefinePlugin")]
+  Define(HashMap<String, serde_json::Value>),
+  /// Identifier -> module request, or the request followed by the property path.
+  #[serde(rename = "ProvidePlugin")]
+  Provide(HashMap<String, OneOrMany>),
+  #[serde(rename = "EnvironmentPlugin")]
+  Environment(EnvironmentPluginConfig),
+  #[serde(rename = "BannerPlugin")]
+  Banner(BannerPluginConfig),
+  #[serde(rename = "HtmlRspackPlugin")]
+  Html(Box<HtmlRspackPluginOptions>),
+  #[serde(rename = "ProgressPlugin")]
+  Progress(ProgressPluginConfig),
+}
+
+/// A list of keys, or keys mapped to their default values.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum EnvironmentPluginConfig {
+  Keys(Vec<String>),
+  Defaults(IndexMap<String, serde_json::Value>),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct BannerPluginConfig {
+  pub banner: String,
+  pub entry_only: Option<bool>,
+  pub footer: Option<bool>,
+  pub raw: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProgressPluginConfig {
+  pub prefix: String,
+  pub profile: bool,
+}
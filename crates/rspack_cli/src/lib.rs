@@ -0,0 +1,63 @@
+#![feature(let_chains)]
+
+pub mod config;
+mod loader;
+pub mod options;
+pub mod plugins;
+
+use std::path::Path;
+
+use rspack_core::{Compiler, Stats};
+use rspack_error::Result;
+use rspack_fs::AsyncWritableFileSystem;
+
+use crate::config::Config;
+
+/// Creates a compiler from the config, `context` is the directory relative paths of the config are
+/// resolved against.
+pub fn create_compiler<T>(
+  mut config: Config,
+  context: &Path,
+  output_filesystem: T,
+) -> Result<Compiler<T>>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  let options = options::compiler_options(&config, context)?;
+  let plugins = plugins::plugins(&mut config, &options)?;
+  Ok(Compiler::new(options, plugins, output_filesystem, None))
+}
+
+/// Prints the emitted assets and the diagnostics, returns whether there were no errors.
+pub fn print_stats(stats: &Stats, elapsed_ms: Option<u128>) -> Result<bool> {
+  let (mut assets, _) = stats.get_assets();
+  assets.sort_by(|a, b| a.name.cmp(&b.name));
+  for asset in assets {
+    let emitted = if asset.emitted { " [emitted]" } else { "" };
+    println!("asset {} {}{emitted}", asset.name, format_size(asset.size));
+  }
+  stats.emit_diagnostics()?;
+
+  let errors = stats.get_errors().len();
+  let warnings = stats.get_warnings().len();
+  let status = match (errors, warnings) {
+    (0, 0) => "compiled successfully".to_string(),
+    (0, warnings) => format!("compiled with {warnings} warning(s)"),
+    (errors, _) => format!("compiled with {errors} error(s)"),
+  };
+  match elapsed_ms {
+    Some(elapsed_ms) => println!("rspack {status} in {elapsed_ms} ms"),
+    None => println!("rspack {status}"),
+  }
+  Ok(errors == 0)
+}
+
+fn format_size(size: f64) -> String {
+  if size < 1024.0 {
+    format!("{size} bytes")
+  } else if size < 1024.0 * 1024.0 {
+    format!("{:.2} KiB", size / 1024.0)
+  } else {
+    format!("{:.2} MiB", size / 1024.0 / 1024.0)
+  }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  ApplyContext, BoxLoader, CompilerOptions, Context, ModuleRuleUseLoader,
+  NormalModuleFactoryResolveLoader, Plugin, PluginContext, Resolver,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_loader_react_refresh::{ReactRefreshLoader, REACT_REFRESH_LOADER_IDENTIFIER};
+use rspack_loader_swc::{SwcLoader, SWC_LOADER_IDENTIFIER};
+
+/// Resolves the builtin loaders, there is no JavaScript runtime to run other loaders with.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct BuiltinLoaderResolverPlugin;
+
+#[plugin_hook(NormalModuleFactoryResolveLoader for BuiltinLoaderResolverPlugin)]
+async fn resolve_loader(
+  &self,
+  _context: &Context,
+  _resolver: &Resolver,
+  l: &ModuleRuleUseLoader,
+) -> Result<Option<BoxLoader>> {
+  let loader_request = l.loader.as_str();
+  let loader_options = l.options.as_deref().unwrap_or("{}");
+
+  if loader_request.starts_with(SWC_LOADER_IDENTIFIER) {
+    let options = serde_json::from_str(loader_options).map_err(|e| {
+      error!("Could not parse {SWC_LOADER_IDENTIFIER} options {loader_options}: {e}")
+    })?;
+    return Ok(Some(Arc::new(
+      SwcLoader::new(options).with_identifier(loader_request.into()),
+    )));
+  }
+  if loader_request.starts_with(REACT_REFRESH_LOADER_IDENTIFIER) {
+    return Ok(Some(Arc::new(
+      ReactRefreshLoader::default().with_identifier(loader_request.into()),
+    )));
+  }
+
+  Err(error!(
+    "Failed to resolve loader {loader_request}, only builtin loaders are supported"
+  ))
+}
+
+impl Plugin for BuiltinLoaderResolverPlugin {
+  fn name(&self) -> &'static str {
+    "BuiltinLoaderResolverPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_loader
+      .tap(resolve_loader::new(self));
+    Ok(())
+  }
+}
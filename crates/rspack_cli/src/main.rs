@@ -0,0 +1,114 @@
+use std::{path::PathBuf, process::ExitCode, time::Instant};
+
+use argh::FromArgs;
+use rspack_cli::{
+  config::{Config, ModeConfig, OneOrMany},
+  create_compiler, print_stats,
+};
+use rspack_core::{WatchOptions, Watching};
+use rspack_error::{error, Result};
+use rspack_fs::AsyncNativeFileSystem;
+
+const CONFIG_FILES: [&str; 2] = ["rspack.config.toml", "rspack.config.json"];
+
+#[derive(FromArgs)]
+/// Build with the native rspack compiler from a JSON or TOML config
+struct Args {
+  /// path of the config, defaults to rspack.config.toml or rspack.config.json in the working directory
+  #[argh(option, short = 'c')]
+  config: Option<PathBuf>,
+
+  /// overrides the mode of the config: "development" | "production" | "none"
+  #[argh(option, short = 'm', from_str_fn(parse_mode))]
+  mode: Option<ModeConfig>,
+
+  /// rebuilds when files change, until the process is stopped
+  #[argh(switch, short = 'w')]
+  watch: bool,
+}
+
+fn parse_mode(mode: &str) -> std::result::Result<ModeConfig, String> {
+  match mode {
+    "development" => Ok(ModeConfig::Development),
+    "production" => Ok(ModeConfig::Production),
+    "none" => Ok(ModeConfig::None),
+    _ => Err(format!(
+      "Unknown mode {mode}, expected development, production or none"
+    )),
+  }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args: Args = argh::from_env();
+  match run(args).await {
+    Ok(true) => ExitCode::SUCCESS,
+    Ok(false) => ExitCode::FAILURE,
+    Err(err) => {
+      eprintln!("{err:?}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+/// Builds once, or keeps rebuilding in watch mode. Returns whether the last build succeeded
+/// without errors.
+async fn run(args: Args) -> Result<bool> {
+  let cwd = std::env::current_dir().map_err(|e| error!("{e}"))?;
+  let config_path = match args.config {
+    Some(config) => cwd.join(config),
+    None => CONFIG_FILES
+      .iter()
+      .map(|file| cwd.join(file))
+      .find(|path| path.exists())
+      .ok_or_else(|| {
+        error!(
+          "No config found in {}, expected one of {}",
+          cwd.display(),
+          CONFIG_FILES.join(", ")
+        )
+      })?,
+  };
+  let mut config = Config::load(&config_path)?;
+  if let Some(mode) = args.mode {
+    config.mode = Some(mode);
+  }
+  let config_dir = config_path.parent().unwrap_or(&cwd);
+  let context = match &config.context {
+    Some(context) => config_dir.join(context),
+    None => config_dir.to_path_buf(),
+  };
+
+  if args.watch || config.watch {
+    let watch_options = WatchOptions {
+      aggregate_timeout: config.watch_options.aggregate_timeout,
+      poll: config.watch_options.poll,
+      ignored: config
+        .watch_options
+        .ignored
+        .as_ref()
+        .map(OneOrMany::to_vec)
+        .unwrap_or_default(),
+    };
+    let compiler = create_compiler(config, &context, AsyncNativeFileSystem)?;
+    let mut watching = Watching::new(compiler, watch_options);
+    let mut result = Ok(true);
+    watching
+      .watch(|stats| {
+        result = stats.and_then(|stats| print_stats(&stats, None));
+        if let Err(err) = &result {
+          eprintln!("{err:?}");
+        }
+      })
+      .await;
+    return result;
+  }
+
+  let start = Instant::now();
+  let mut compiler = create_compiler(config, &context, AsyncNativeFileSystem)?;
+  compiler.build().await?;
+  print_stats(
+    &compiler.compilation.get_stats(),
+    Some(start.elapsed().as_millis()),
+  )
+}
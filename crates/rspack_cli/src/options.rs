@@ -0,0 +1,642 @@
+use std::path::{Path, PathBuf};
+
+use rspack_core::{
+  AliasMap, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, Builtins,
+  ByDependency, CacheOptions, CompilerOptions, CrossOriginLoading, CssAutoGeneratorOptions,
+  CssAutoParserOptions, CssExportsConvention, CssGeneratorOptions, CssModuleGeneratorOptions,
+  CssModuleParserOptions, CssParserOptions, DynamicImportMode, Experiments, ExportPresenceMode,
+  GeneratorOptions, GeneratorOptionsByModuleType, IncrementalRebuild, IncrementalRebuildMakeState,
+  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, MangleExportsOption, Mode,
+  ModuleOptions, ModuleRule, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NodeOption,
+  Optimization, OutputOptions, ParserOptions, ParserOptionsByModuleType, PathInfo, Resolve,
+  RspackFuture, RuleSetCondition, SideEffectOption, SnapshotOptions, SnapshotPathMatcher,
+  SnapshotStrategy, StatsOptions, Target, TreeShaking, UsedExportsOption,
+};
+use rspack_error::Result;
+use rspack_regex::RspackRegex;
+
+use crate::config::{BoolOrString, ConditionConfig, Config, ModeConfig, RuleConfig, UseConfig};
+
+/// Whether the config targets node, browsers are targeted otherwise.
+pub fn is_node_target(config: &Config) -> bool {
+  config.target.as_ref().is_some_and(|target| {
+    target
+      .to_vec()
+      .iter()
+      .any(|t| t.starts_with("node") || t.starts_with("async-node"))
+  })
+}
+
+/// Normalizes the config into `CompilerOptions`, applying the defaults of the JavaScript API.
+pub fn compiler_options(config: &Config, context: &Path) -> Result<CompilerOptions> {
+  let production = config.is_production();
+  let development = config.is_development();
+  let node = is_node_target(config);
+  let css = config.experiments.css.unwrap_or(true);
+
+  let mode = match config.mode {
+    Some(ModeConfig::Development) => Mode::Development,
+    Some(ModeConfig::None) => Mode::None,
+    Some(ModeConfig::Production) | None => Mode::Production,
+  };
+  let target = Target::new(
+    &config
+      .target
+      .as_ref()
+      .map(|target| target.to_vec())
+      .unwrap_or_default(),
+  )?;
+
+  let cache = if config.cache.unwrap_or(development) {
+    CacheOptions::Memory(Default::default())
+  } else {
+    CacheOptions::Disabled
+  };
+  let experiments = Experiments {
+    layers: config.experiments.layers,
+    incremental_rebuild: IncrementalRebuild {
+      make: (!matches!(cache, CacheOptions::Disabled)).then(IncrementalRebuildMakeState::default),
+      emit_asset: true,
+    },
+    new_split_chunks: true,
+    top_level_await: config.experiments.top_level_await.unwrap_or(true),
+    rspack_future: RspackFuture {
+      new_treeshaking: true,
+    },
+  };
+
+  let optimization = &config.optimization;
+  let snapshot_strategy = SnapshotStrategy {
+    hash: production,
+    timestamp: true,
+  };
+
+  Ok(CompilerOptions {
+    context: context.into(),
+    dev_server: Default::default(),
+    output: output_options(config, context, node)?,
+    target,
+    mode,
+    resolve: resolve_options(config, context, node, css),
+    resolve_loader: Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    },
+    module: module_options(config, context, node, css)?,
+    stats: StatsOptions::default(),
+    snapshot: SnapshotOptions {
+      resolve: snapshot_strategy.clone(),
+      module: snapshot_strategy,
+      managed_paths: vec![SnapshotPathMatcher::node_modules()],
+      ..Default::default()
+    },
+    cache,
+    experiments,
+    node: Some(NodeOption {
+      dirname: if node { "eval-only" } else { "warn-mock" }.to_string(),
+      filename: if node { "eval-only" } else { "warn-mock" }.to_string(),
+      global: if node { "false" } else { "warn" }.to_string(),
+    }),
+    amd: None,
+    optimization: Optimization {
+      remove_available_modules: optimization.remove_available_modules.unwrap_or(true),
+      side_effects: match &optimization.side_effects {
+        Some(value) => SideEffectOption::from(value.to_string().as_str()),
+        None if production => SideEffectOption::True,
+        None => SideEffectOption::Flag,
+      },
+      provided_exports: optimization.provided_exports.unwrap_or(true),
+      used_exports: match &optimization.used_exports {
+        Some(value) => UsedExportsOption::from(value.to_string().as_str()),
+        None => UsedExportsOption::from(production.to_string().as_str()),
+      },
+      inner_graph: optimization.inner_graph.unwrap_or(production),
+      mangle_exports: match &optimization.mangle_exports {
+        Some(value) => MangleExportsOption::from(value.to_string().as_str()),
+        None => MangleExportsOption::from(production.to_string().as_str()),
+      },
+      concatenate_modules: optimization.concatenate_modules.unwrap_or(false),
+      real_content_hash: optimization.real_content_hash.unwrap_or(production),
+    },
+    profile: config.profile,
+    bail: config.bail,
+    records_input_path: None,
+    records_output_path: None,
+    builtins: Builtins {
+      // superseded by the new tree shaking
+      tree_shaking: TreeShaking::False,
+      ..Default::default()
+    },
+  })
+}
+
+fn output_options(config: &Config, context: &Path, node: bool) -> Result<OutputOptions> {
+  let output = &config.output;
+  let filename = output
+    .filename
+    .clone()
+    .unwrap_or_else(|| "[name].js".to_string());
+  let chunk_filename = output.chunk_filename.clone().unwrap_or_else(|| {
+    if ["[name]", "[id]", "[chunkhash]", "[contenthash]"]
+      .iter()
+      .any(|placeholder| filename.contains(placeholder))
+    {
+      filename.clone()
+    } else {
+      // prefix the basename so that every chunk gets its own file
+      match filename.rsplit_once('/') {
+        Some((dir, base)) => format!("{dir}/[id].{base}"),
+        None => format!("[id].{filename}"),
+      }
+    }
+  });
+  let css_filename = output
+    .css_filename
+    .clone()
+    .unwrap_or_else(|| to_css_filename(&filename));
+  let css_chunk_filename = output
+    .css_chunk_filename
+    .clone()
+    .unwrap_or_else(|| to_css_filename(&chunk_filename));
+  let unique_name = output
+    .unique_name
+    .clone()
+    .unwrap_or_else(|| package_name(context).unwrap_or_default());
+  let unique_name_id = to_identifier(&unique_name);
+  let (chunk_loading, wasm_loading, global_object) = if node {
+    ("require", "async-node", "global")
+  } else {
+    ("jsonp", "fetch", "self")
+  };
+  let worker_chunk_loading = if node { "require" } else { "import-scripts" };
+
+  Ok(OutputOptions {
+    path: context.join(output.path.as_deref().unwrap_or("dist")),
+    pathinfo: PathInfo::Bool(config.is_development()),
+    clean: output.clean,
+    public_path: output
+      .public_path
+      .clone()
+      .unwrap_or_else(|| "auto".to_string())
+      .into(),
+    asset_module_filename: output
+      .asset_module_filename
+      .clone()
+      .unwrap_or_else(|| "[hash][ext][query]".to_string())
+      .into(),
+    wasm_loading: wasm_loading.into(),
+    webassembly_module_filename: "[hash].module.wasm".to_string().into(),
+    chunk_loading: chunk_loading.into(),
+    chunk_loading_global: format!("webpackChunk{unique_name_id}"),
+    filename: filename.into(),
+    chunk_filename: chunk_filename.into(),
+    cross_origin_loading: CrossOriginLoading::Disable,
+    css_filename: css_filename.into(),
+    css_chunk_filename: css_chunk_filename.into(),
+    hot_update_main_filename: "[runtime].[fullhash].hot-update.json".to_string().into(),
+    hot_update_chunk_filename: "[id].[fullhash].hot-update.js".to_string().into(),
+    hot_update_global: format!("webpackHotUpdate{unique_name_id}"),
+    unique_name,
+    library: None,
+    enabled_library_types: None,
+    strict_module_error_handling: false,
+    global_object: global_object.to_string(),
+    import_function_name: "import".to_string(),
+    iife: true,
+    module: false,
+    trusted_types: None,
+    source_map_filename: "[file].map".to_string().into(),
+    hash_function: output
+      .hash_function
+      .as_deref()
+      .unwrap_or("md4")
+      .try_into()?,
+    hash_digest: "hex".try_into()?,
+    hash_digest_length: 20,
+    hash_salt: None.into(),
+    async_chunks: true,
+    worker_chunk_loading: worker_chunk_loading.into(),
+    worker_wasm_loading: wasm_loading.into(),
+    worker_public_path: String::new(),
+    script_type: "false".to_string(),
+  })
+}
+
+fn to_css_filename(filename: &str) -> String {
+  let (path, query) = match filename.split_once('?') {
+    Some((path, query)) => (path, Some(query)),
+    None => (filename, None),
+  };
+  let path = [".mjs", ".cjs", ".js"]
+    .iter()
+    .find_map(|ext| path.strip_suffix(ext))
+    .map(|stem| format!("{stem}.css"))
+    .unwrap_or_else(|| path.to_string());
+  match query {
+    Some(query) => format!("{path}?{query}"),
+    None => path,
+  }
+}
+
+/// The `name` in the `package.json` of the context, used as the default `output.uniqueName`.
+fn package_name(context: &Path) -> Option<String> {
+  let content = std::fs::read_to_string(context.join("package.json")).ok()?;
+  let package: serde_json::Value = serde_json::from_str(&content).ok()?;
+  package.get("name")?.as_str().map(ToString::to_string)
+}
+
+fn to_identifier(name: &str) -> String {
+  name
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect()
+}
+
+fn resolve_options(config: &Config, context: &Path, node: bool, css: bool) -> Resolve {
+  let resolve = &config.resolve;
+  let mut condition_names = vec![
+    "webpack".to_string(),
+    if config.is_development() {
+      "development"
+    } else {
+      "production"
+    }
+    .to_string(),
+  ];
+  condition_names.push(if node { "node" } else { "browser" }.to_string());
+
+  let js_extensions = strings(&[".js", ".json", ".wasm"]);
+  let (alias_fields, main_fields) = if node {
+    (vec![], strings(&["module", "..."]))
+  } else {
+    (
+      vec![strings(&["browser"])],
+      strings(&["browser", "module", "..."]),
+    )
+  };
+  let deps = |conditions: &[&str]| Resolve {
+    alias_fields: Some(alias_fields.clone()),
+    main_fields: Some(main_fields.clone()),
+    condition_names: Some(strings(conditions)),
+    extensions: Some(js_extensions.clone()),
+    ..Default::default()
+  };
+  let esm = || deps(&["import", "module", "..."]);
+  let cjs = || deps(&["require", "module", "..."]);
+  let mut by_dependency = vec![
+    ("wasm".into(), esm()),
+    ("esm".into(), esm()),
+    (
+      "url".into(),
+      Resolve {
+        prefer_relative: Some(true),
+        ..Default::default()
+      },
+    ),
+    (
+      "worker".into(),
+      Resolve {
+        prefer_relative: Some(true),
+        ..esm()
+      },
+    ),
+    ("commonjs".into(), cjs()),
+    ("amd".into(), cjs()),
+    ("unknown".into(), cjs()),
+  ];
+  if css {
+    let mut style_conditions = condition_names[..2].to_vec();
+    style_conditions.push("style".to_string());
+    by_dependency.push((
+      "css-import".into(),
+      Resolve {
+        main_files: Some(vec![]),
+        main_fields: Some(strings(&["style", "..."])),
+        condition_names: Some(style_conditions),
+        extensions: Some(strings(&[".css"])),
+        prefer_relative: Some(true),
+        ..Default::default()
+      },
+    ));
+  }
+
+  let defaults = Resolve {
+    modules: Some(strings(&["node_modules"])),
+    condition_names: Some(condition_names),
+    main_files: Some(strings(&["index"])),
+    extensions: Some(vec![]),
+    alias_fields: Some(vec![]),
+    exports_field: Some(vec![strings(&["exports"])]),
+    roots: Some(vec![context.to_string_lossy().to_string()]),
+    main_fields: Some(strings(&["main"])),
+    by_dependency: Some(ByDependency::from_iter(by_dependency)),
+    ..Default::default()
+  };
+  let alias = resolve
+    .alias
+    .iter()
+    .map(|(request, path)| {
+      let path = if path.starts_with('.') {
+        context.join(path).to_string_lossy().to_string()
+      } else {
+        path.clone()
+      };
+      (request.clone(), vec![AliasMap::Path(path)])
+    })
+    .collect::<Vec<_>>();
+  defaults.merge(Resolve {
+    extensions: resolve.extensions.clone(),
+    alias: (!alias.is_empty()).then_some(alias),
+    main_fields: resolve.main_fields.clone(),
+    main_files: resolve.main_files.clone(),
+    condition_names: resolve.condition_names.clone(),
+    modules: resolve.modules.clone(),
+    ..Default::default()
+  })
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+  items.iter().map(ToString::to_string).collect()
+}
+
+fn module_options(config: &Config, context: &Path, node: bool, css: bool) -> Result<ModuleOptions> {
+  let javascript_config = &config.module.parser.javascript;
+  let export_presence = |mode: &Option<BoolOrString>| {
+    mode
+      .as_ref()
+      .map(|mode| ExportPresenceMode::try_from(mode.to_string().as_str()))
+      .transpose()
+  };
+  let exports_presence = export_presence(&javascript_config.exports_presence)?;
+  let import_exports_presence = export_presence(&javascript_config.import_exports_presence)?;
+  let reexport_exports_presence = export_presence(&javascript_config.reexport_exports_presence)?;
+  let javascript = || {
+    ParserOptions::Javascript(JavascriptParserOptions {
+      dynamic_import_mode: DynamicImportMode::Lazy,
+      dynamic_import_preload: JavascriptParserOrder::Disable,
+      dynamic_import_prefetch: JavascriptParserOrder::Disable,
+      url: JavascriptParserUrl::Enable,
+      expr_context_critical: true,
+      wrapped_context_critical: false,
+      exports_presence,
+      import_exports_presence,
+      reexport_exports_presence,
+      strict_export_presence: javascript_config.strict_export_presence.unwrap_or_default(),
+    })
+  };
+  let mut parser = vec![
+    (
+      ModuleType::Asset,
+      ParserOptions::Asset(AssetParserOptions {
+        data_url_condition: Some(AssetParserDataUrl::Options(AssetParserDataUrlOptions {
+          max_size: Some(8096),
+        })),
+      }),
+    ),
+    (ModuleType::Js, javascript()),
+    (ModuleType::JsDynamic, javascript()),
+    (ModuleType::JsEsm, javascript()),
+  ];
+  let mut generator = vec![];
+  if css {
+    parser.extend([
+      (
+        ModuleType::Css,
+        ParserOptions::Css(CssParserOptions {
+          named_exports: Some(true),
+        }),
+      ),
+      (
+        ModuleType::CssAuto,
+        ParserOptions::CssAuto(CssAutoParserOptions {
+          named_exports: Some(true),
+        }),
+      ),
+      (
+        ModuleType::CssModule,
+        ParserOptions::CssModule(CssModuleParserOptions {
+          named_exports: Some(true),
+        }),
+      ),
+    ]);
+    // there is no document to insert the styles into on node
+    let exports_only = Some(node);
+    let local_ident_name = || Some("[uniqueName]-[id]-[local]".to_string().into());
+    generator.extend([
+      (
+        ModuleType::Css,
+        GeneratorOptions::Css(CssGeneratorOptions {
+          exports_convention: Some(CssExportsConvention::default()),
+          exports_only,
+        }),
+      ),
+      (
+        ModuleType::CssAuto,
+        GeneratorOptions::CssAuto(CssAutoGeneratorOptions {
+          exports_convention: Some(CssExportsConvention::default()),
+          exports_only,
+          local_ident_name: local_ident_name(),
+        }),
+      ),
+      (
+        ModuleType::CssModule,
+        GeneratorOptions::CssModule(CssModuleGeneratorOptions {
+          exports_convention: Some(CssExportsConvention::default()),
+          exports_only,
+          local_ident_name: local_ident_name(),
+        }),
+      ),
+    ]);
+  }
+
+  let rules = config
+    .module
+    .rules
+    .iter()
+    .map(|rule| module_rule(rule, context))
+    .collect::<Result<Vec<_>>>()?;
+
+  Ok(ModuleOptions {
+    rules: vec![
+      ModuleRule {
+        rules: Some(default_rules(css)?),
+        ..Default::default()
+      },
+      ModuleRule {
+        rules: Some(rules),
+        ..Default::default()
+      },
+    ],
+    parser: Some(ParserOptionsByModuleType::from_iter(parser)),
+    generator: Some(GeneratorOptionsByModuleType::from_iter(generator)),
+    no_parse: None,
+  })
+}
+
+fn regexp(source: &str, flags: &str) -> Result<RuleSetCondition> {
+  Ok(RuleSetCondition::Regexp(RspackRegex::with_flags(
+    source, flags,
+  )?))
+}
+
+/// The rules deciding the module type by extension, `type` in `package.json` or mimetype.
+fn default_rules(css: bool) -> Result<Vec<ModuleRule>> {
+  let typed = |condition: RuleSetCondition, module_type: &str| ModuleRule {
+    test: Some(condition),
+    r#type: Some(module_type.into()),
+    ..Default::default()
+  };
+  let esm_resolve = || Resolve {
+    by_dependency: Some(ByDependency::from_iter([(
+      "esm".into(),
+      Resolve {
+        fully_specified: Some(true),
+        ..Default::default()
+      },
+    )])),
+    ..Default::default()
+  };
+  let by_description_type = |package_type: &str, module_type: &str| -> Result<ModuleRule> {
+    Ok(ModuleRule {
+      description_data: Some(
+        [(
+          "type".to_string(),
+          RuleSetCondition::String(package_type.to_string()),
+        )]
+        .into_iter()
+        .collect(),
+      ),
+      ..typed(regexp("\\.js$", "i")?, module_type)
+    })
+  };
+
+  let mut rules = vec![
+    typed(regexp("\\.json$", "i")?, "json"),
+    ModuleRule {
+      resolve: Some(esm_resolve()),
+      ..typed(regexp("\\.mjs$", "i")?, "javascript/esm")
+    },
+    ModuleRule {
+      resolve: Some(esm_resolve()),
+      ..by_description_type("module", "javascript/esm")?
+    },
+    typed(regexp("\\.cjs$", "i")?, "javascript/dynamic"),
+    by_description_type("commonjs", "javascript/dynamic")?,
+  ];
+  if css {
+    rules.push(ModuleRule {
+      resolve: Some(Resolve {
+        fully_specified: Some(true),
+        prefer_relative: Some(true),
+        ..Default::default()
+      }),
+      ..typed(regexp("\\.css$", "i")?, "css/auto")
+    });
+  }
+  rules.push(ModuleRule {
+    dependency: Some(RuleSetCondition::String("url".to_string())),
+    one_of: Some(vec![
+      ModuleRule {
+        scheme: Some(regexp("^data$", "")?),
+        r#type: Some(ModuleType::AssetInline),
+        ..Default::default()
+      },
+      ModuleRule {
+        r#type: Some(ModuleType::AssetResource),
+        ..Default::default()
+      },
+    ]),
+    ..Default::default()
+  });
+  Ok(rules)
+}
+
+fn module_rule(rule: &RuleConfig, context: &Path) -> Result<ModuleRule> {
+  let condition = |condition: &Option<ConditionConfig>| {
+    condition
+      .as_ref()
+      .map(|condition| rule_condition(condition, context))
+      .transpose()
+  };
+  let nested = |rules: &Option<Vec<RuleConfig>>| {
+    rules
+      .as_ref()
+      .map(|rules| {
+        rules
+          .iter()
+          .map(|rule| module_rule(rule, context))
+          .collect::<Result<Vec<_>>>()
+      })
+      .transpose()
+  };
+  let r#use = rule
+    .r#use
+    .iter()
+    .map(|item| match item {
+      UseConfig::Loader(loader) => ModuleRuleUseLoader {
+        loader: loader.clone(),
+        options: None,
+      },
+      UseConfig::WithOptions { loader, options } => ModuleRuleUseLoader {
+        loader: loader.clone(),
+        options: options.as_ref().map(ToString::to_string),
+      },
+    })
+    .collect();
+
+  Ok(ModuleRule {
+    test: condition(&rule.test)?,
+    include: condition(&rule.include)?,
+    exclude: condition(&rule.exclude)?,
+    resource_query: condition(&rule.resource_query)?,
+    side_effects: rule.side_effects,
+    r#type: rule.r#type.as_deref().map(Into::into),
+    r#use: ModuleRuleUse::Array(r#use),
+    one_of: nested(&rule.one_of)?,
+    rules: nested(&rule.rules)?,
+    ..Default::default()
+  })
+}
+
+fn rule_condition(condition: &ConditionConfig, context: &Path) -> Result<RuleSetCondition> {
+  Ok(match condition {
+    ConditionConfig::String(path) => {
+      let path = PathBuf::from(path);
+      let path = if path.is_absolute() {
+        path
+      } else {
+        context.join(path)
+      };
+      RuleSetCondition::String(path.to_string_lossy().to_string())
+    }
+    ConditionConfig::Regexp {
+      regexp: source,
+      flags,
+    } => regexp(source, flags)?,
+    ConditionConfig::Array(conditions) => RuleSetCondition::Array(
+      conditions
+        .iter()
+        .map(|condition| rule_condition(condition, context))
+        .collect::<Result<Vec<_>>>()?,
+    ),
+  })
+}
+
+/// The value of `process.env.NODE_ENV`, if it should be defined.
+pub fn node_env(config: &Config) -> Option<String> {
+  match &config.optimization.node_env {
+    Some(BoolOrString::String(value)) => Some(value.clone()),
+    Some(BoolOrString::Bool(_)) => None,
+    None => match config.mode {
+      Some(ModeConfig::Development) => Some("development".to_string()),
+      Some(ModeConfig::None) => None,
+      Some(ModeConfig::Production) | None => Some("production".to_string()),
+    },
+  }
+}
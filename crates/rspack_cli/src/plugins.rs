@@ -0,0 +1,336 @@
+use rspack_core::{
+  BoxPlugin, ChunkLoading, CompilerOptions, Define, DefinePlugin, EntryOptions,
+  MangleExportsOption, PluginExt, ProvidePlugin,
+};
+use rspack_error::{error, Result};
+use rspack_ids::{
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedModuleIdsPlugin,
+  HashedModuleIdsPluginOptions, NamedChunkIdsPlugin, NamedModuleIdsPlugin, NaturalChunkIdsPlugin,
+  NaturalModuleIdsPlugin, OccurrenceChunkIdsPlugin, OccurrenceChunkIdsPluginOptions,
+  OccurrenceModuleIdsPlugin, OccurrenceModuleIdsPluginOptions,
+};
+use rspack_plugin_asset::AssetPlugin;
+use rspack_plugin_banner::{BannerContent, BannerPlugin, BannerPluginOptions};
+use rspack_plugin_css::CssPlugin;
+use rspack_plugin_devtool::{
+  Append, EvalDevToolModulePlugin, EvalDevToolModulePluginOptions, EvalSourceMapDevToolPlugin,
+  SourceMapDevToolModuleOptionsPlugin, SourceMapDevToolModuleOptionsPluginOptions,
+  SourceMapDevToolPlugin, SourceMapDevToolPluginOptions,
+};
+use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
+use rspack_plugin_entry::EntryPlugin;
+use rspack_plugin_externals::{http_externals_rspack_plugin, node_target_plugin};
+use rspack_plugin_html::HtmlRspackPlugin;
+use rspack_plugin_javascript::{
+  api_plugin::APIPlugin, EnvironmentPlugin, EnvironmentPluginOptions, FlagDependencyExportsPlugin,
+  FlagDependencyUsagePlugin, InferAsyncModulesPlugin, JsPlugin, MangleExportsPlugin,
+  ModuleConcatenationPlugin, SideEffectsFlagPlugin,
+};
+use rspack_plugin_json::JsonPlugin;
+use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
+use rspack_plugin_progress::{ProgressPlugin, ProgressPluginOptions};
+use rspack_plugin_real_content_hash::RealContentHashPlugin;
+use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
+use rspack_plugin_runtime::{
+  enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, ChunkPrefetchPreloadPlugin,
+  CommonJsChunkFormatPlugin, RuntimePlugin,
+};
+use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
+use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
+use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
+use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
+use rspack_plugin_worker::WorkerPlugin;
+
+use crate::{
+  config::{Config, DevtoolConfig, EntryConfig, EnvironmentPluginConfig, PluginConfig},
+  loader::BuiltinLoaderResolverPlugin,
+  options::{is_node_target, node_env},
+};
+
+/// The plugins of the config followed by the builtin plugins the options rely on,
+/// in the order `RspackOptionsApply` of the JavaScript API applies them.
+pub fn plugins(config: &mut Config, options: &CompilerOptions) -> Result<Vec<BoxPlugin>> {
+  let mut plugins = std::mem::take(&mut config.plugins)
+    .into_iter()
+    .map(user_plugin)
+    .collect::<Vec<_>>();
+  let production = config.is_production();
+  let css = config.experiments.css.unwrap_or(true);
+  let optimization = &config.optimization;
+
+  plugins.push(BuiltinLoaderResolverPlugin::default().boxed());
+  if is_node_target(config) {
+    plugins.push(node_target_plugin());
+    if css {
+      plugins.push(http_externals_rspack_plugin(true, false));
+    }
+    plugins.push(ChunkPrefetchPreloadPlugin::default().boxed());
+    plugins.push(CommonJsChunkFormatPlugin::default().boxed());
+  } else {
+    plugins.push(http_externals_rspack_plugin(css, false));
+    plugins.push(ChunkPrefetchPreloadPlugin::default().boxed());
+    plugins.push(ArrayPushCallbackChunkFormatPlugin::default().boxed());
+  }
+  let mut chunk_loading_types = vec![];
+  for chunk_loading in [
+    &options.output.chunk_loading,
+    &options.output.worker_chunk_loading,
+  ] {
+    if let ChunkLoading::Enable(chunk_loading_type) = chunk_loading
+      && !chunk_loading_types.contains(chunk_loading_type)
+    {
+      chunk_loading_types.push(chunk_loading_type.clone());
+    }
+  }
+  for chunk_loading_type in chunk_loading_types {
+    enable_chunk_loading_plugin(chunk_loading_type, &mut plugins);
+  }
+
+  if let Some(DevtoolConfig::String(devtool)) = &config.devtool {
+    devtool_plugins(devtool, options, &mut plugins);
+  }
+
+  plugins.push(JsPlugin::default().boxed());
+  plugins.push(JsonPlugin.boxed());
+  plugins.push(AssetPlugin::default().boxed());
+  if css {
+    plugins.push(CssPlugin::default().boxed());
+  }
+
+  let entries = match &config.entry {
+    Some(EntryConfig::Single(imports)) => vec![("main".to_string(), imports.to_vec())],
+    Some(EntryConfig::Named(entries)) => entries
+      .iter()
+      .map(|(name, imports)| (name.clone(), imports.to_vec()))
+      .collect(),
+    None => vec![("main".to_string(), vec!["./src".to_string()])],
+  };
+  for (name, imports) in entries {
+    for import in imports {
+      let entry_options = EntryOptions {
+        name: Some(name.clone()),
+        ..Default::default()
+      };
+      plugins.push(EntryPlugin::new(options.context.clone(), import, entry_options).boxed());
+    }
+  }
+
+  plugins.push(RuntimePlugin::default().boxed());
+  plugins.push(InferAsyncModulesPlugin::default().boxed());
+  plugins.push(APIPlugin::default().boxed());
+  plugins.push(DataUriPlugin::default().boxed());
+  plugins.push(FileUriPlugin::default().boxed());
+  plugins.push(EnsureChunkConditionsPlugin::default().boxed());
+  if optimization.merge_duplicate_chunks.unwrap_or(true) {
+    plugins.push(MergeDuplicateChunksPlugin::default().boxed());
+  }
+  if options.optimization.side_effects.is_enable() {
+    plugins.push(SideEffectsFlagPlugin::default().boxed());
+  }
+  if options.optimization.provided_exports {
+    plugins.push(FlagDependencyExportsPlugin::default().boxed());
+  }
+  if options.optimization.used_exports.is_enable() {
+    plugins
+      .push(FlagDependencyUsagePlugin::new(options.optimization.used_exports.is_global()).boxed());
+  }
+  if options.optimization.concatenate_modules {
+    plugins.push(ModuleConcatenationPlugin::default().boxed());
+  }
+  if options.optimization.mangle_exports.is_enable() {
+    plugins.push(
+      MangleExportsPlugin::new(!matches!(
+        options.optimization.mangle_exports,
+        MangleExportsOption::Size
+      ))
+      .boxed(),
+    );
+  }
+  if optimization.remove_empty_chunks.unwrap_or(true) {
+    plugins.push(RemoveEmptyChunksPlugin::default().boxed());
+  }
+  if options.optimization.real_content_hash {
+    plugins.push(RealContentHashPlugin::default().boxed());
+  }
+
+  let default_ids = if production { "deterministic" } else { "named" };
+  match optimization.module_ids.as_deref().unwrap_or(default_ids) {
+    "named" => plugins.push(NamedModuleIdsPlugin::default().boxed()),
+    "natural" => plugins.push(NaturalModuleIdsPlugin::default().boxed()),
+    "deterministic" => plugins.push(DeterministicModuleIdsPlugin::default().boxed()),
+    "size" => plugins.push(
+      OccurrenceModuleIdsPlugin::new(OccurrenceModuleIdsPluginOptions {
+        prioritize_initial: true,
+      })
+      .boxed(),
+    ),
+    "hashed" => {
+      plugins.push(HashedModuleIdsPlugin::new(HashedModuleIdsPluginOptions::default()).boxed())
+    }
+    module_ids => return Err(error!("moduleIds: {module_ids} is not implemented")),
+  }
+  let chunk_ids = optimization.chunk_ids.as_deref().unwrap_or(default_ids);
+  match chunk_ids {
+    "named" => plugins.push(NamedChunkIdsPlugin::new(None, None).boxed()),
+    "natural" => plugins.push(NaturalChunkIdsPlugin::default().boxed()),
+    "deterministic" => plugins.push(DeterministicChunkIdsPlugin::default().boxed()),
+    "size" | "total-size" => plugins.push(
+      OccurrenceChunkIdsPlugin::new(OccurrenceChunkIdsPluginOptions {
+        prioritize_initial: chunk_ids == "size",
+      })
+      .boxed(),
+    ),
+    chunk_ids => return Err(error!("chunkIds: {chunk_ids} is not implemented")),
+  }
+
+  if let Some(node_env) = node_env(config) {
+    plugins.push(
+      DefinePlugin::new(
+        [(
+          "process.env.NODE_ENV".to_string(),
+          serde_json::to_string(&node_env).expect("should serialize"),
+        )]
+        .into_iter()
+        .collect(),
+      )
+      .boxed(),
+    );
+  }
+  if optimization.minimize.unwrap_or(production) {
+    plugins.push(SwcJsMinimizerRspackPlugin::new(Default::default()).boxed());
+    plugins.push(SwcCssMinimizerRspackPlugin::default().boxed());
+  }
+  plugins.push(WarnCaseSensitiveModulesPlugin::default().boxed());
+  plugins.push(WorkerPlugin::default().boxed());
+
+  Ok(plugins)
+}
+
+fn devtool_plugins(devtool: &str, options: &CompilerOptions, plugins: &mut Vec<BoxPlugin>) {
+  let namespace = Some(options.output.unique_name.clone());
+  if devtool.contains("source-map") {
+    let cheap = devtool.contains("cheap");
+    let plugin_options = SourceMapDevToolPluginOptions {
+      append: devtool.contains("hidden").then_some(Append::Disabled),
+      columns: !cheap,
+      fallback_module_filename_template: None,
+      file_context: None,
+      filename: (!devtool.contains("inline")).then(|| "[file].map".to_string()),
+      module: devtool.contains("module") || !cheap,
+      module_filename_template: None,
+      namespace,
+      no_sources: devtool.contains("nosources"),
+      public_path: None,
+      source_root: None,
+      test: None,
+    };
+    plugins.push(
+      SourceMapDevToolModuleOptionsPlugin::new(SourceMapDevToolModuleOptionsPluginOptions {
+        module: plugin_options.module,
+      })
+      .boxed(),
+    );
+    if devtool.contains("eval") {
+      plugins.push(EvalSourceMapDevToolPlugin::new(plugin_options).boxed());
+    } else {
+      plugins.push(SourceMapDevToolPlugin::new(plugin_options).boxed());
+    }
+  } else if devtool.contains("eval") {
+    plugins.push(
+      EvalDevToolModulePlugin::new(EvalDevToolModulePluginOptions {
+        namespace,
+        module_filename_template: None,
+        source_url_comment: None,
+      })
+      .boxed(),
+    );
+  }
+}
+
+fn user_plugin(plugin: PluginConfig) -> BoxPlugin {
+  match plugin {
+    PluginConfig::Define(definitions) => {
+      let mut define = Define::default();
+      add_definitions(&mut define, "", &definitions);
+      DefinePlugin::new(define).boxed()
+    }
+    PluginConfig::Provide(provides) => ProvidePlugin::new(
+      provides
+        .into_iter()
+        .map(|(key, request)| (key, request.to_vec()))
+        .collect(),
+    )
+    .boxed(),
+    PluginConfig::Environment(environment) => {
+      let options = match environment {
+        EnvironmentPluginConfig::Keys(keys) => EnvironmentPluginOptions {
+          keys,
+          defaults: Default::default(),
+        },
+        EnvironmentPluginConfig::Defaults(defaults) => EnvironmentPluginOptions {
+          keys: defaults.keys().cloned().collect(),
+          defaults: defaults
+            .into_iter()
+            .map(|(key, value)| (key, value.to_string()))
+            .collect(),
+        },
+      };
+      EnvironmentPlugin::new(options).boxed()
+    }
+    PluginConfig::Banner(banner) => BannerPlugin::new(BannerPluginOptions {
+      banner: BannerContent::String(banner.banner),
+      entry_only: banner.entry_only,
+      footer: banner.footer,
+      raw: banner.raw,
+      test: None,
+      include: None,
+      exclude: None,
+    })
+    .boxed(),
+    PluginConfig::Html(html) => HtmlRspackPlugin::new(*html).boxed(),
+    PluginConfig::Progress(progress) => ProgressPlugin::new(ProgressPluginOptions {
+      prefix: progress.prefix,
+      profile: progress.profile,
+    })
+    .boxed(),
+  }
+}
+
+/// `"process.env": { "NODE_ENV": ... }` also defines `process.env.NODE_ENV`, as the JavaScript
+/// DefinePlugin does.
+fn add_definitions<'a>(
+  define: &mut Define,
+  prefix: &str,
+  definitions: impl IntoIterator<Item = (&'a String, &'a serde_json::Value)>,
+) {
+  for (key, value) in definitions {
+    define.insert(format!("{prefix}{key}"), define_code(value));
+    if let serde_json::Value::Object(properties) = value {
+      add_definitions(define, &format!("{prefix}{key}."), properties);
+    }
+  }
+}
+
+/// Strings are code, other values are converted to code.
+fn define_code(value: &serde_json::Value) -> String {
+  match value {
+    serde_json::Value::String(code) => code.clone(),
+    serde_json::Value::Array(items) => format!(
+      "[{}]",
+      items.iter().map(define_code).collect::<Vec<_>>().join(",")
+    ),
+    serde_json::Value::Object(properties) => format!(
+      "{{{}}}",
+      properties
+        .iter()
+        .map(|(key, value)| format!(
+          "{}:{}",
+          serde_json::to_string(key).expect("should serialize"),
+          define_code(value)
+        ))
+        .collect::<Vec<_>>()
+        .join(",")
+    ),
+    value => value.to_string(),
+  }
+}
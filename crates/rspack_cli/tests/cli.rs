@@ -0,0 +1,127 @@
+mod helpers;
+
+use std::{
+  fs,
+  path::{Path, PathBuf},
+  process::{Command, Output},
+};
+
+use helpers::fixture;
+
+fn rspack(cwd: &Path, args: &[&str]) -> Output {
+  Command::new(env!("CARGO_BIN_EXE_rspack"))
+    .args(args)
+    .current_dir(cwd)
+    .output()
+    .expect("should run rspack")
+}
+
+fn emitted_files(dir: &Path) -> Vec<String> {
+  let mut files = fs::read_dir(dir)
+    .expect("should read output directory")
+    .map(|entry| {
+      entry
+        .expect("should read output directory")
+        .file_name()
+        .to_string_lossy()
+        .into_owned()
+    })
+    .collect::<Vec<_>>();
+  files.sort();
+  files
+}
+
+fn find_file(dir: &Path, prefix: &str) -> PathBuf {
+  emitted_files(dir)
+    .into_iter()
+    .find(|file| file.starts_with(prefix))
+    .map(|file| dir.join(file))
+    .unwrap_or_else(|| panic!("{prefix} should be emitted in {}", dir.display()))
+}
+
+#[test]
+fn build_json_config() {
+  let dir = fixture("basic");
+  let output = rspack(dir.path(), &["--config", "rspack.config.json"]);
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  assert!(output.status.success(), "{stdout}");
+  assert!(stdout.contains("rspack compiled successfully"), "{stdout}");
+
+  let dist = dir.path().join("dist-json");
+  assert_eq!(emitted_files(&dist), vec!["0.js", "main.js"]);
+  let main = fs::read_to_string(dist.join("main.js")).unwrap();
+  assert!(main.contains("hello from rspack"));
+  // moduleIds: size assigns numeric ids, imported modules first
+  assert!(main.contains("\"0\": (function"), "{main}");
+  assert!(main.contains("__webpack_require__(\"1\")"), "{main}");
+  let lazy = fs::read_to_string(dist.join("0.js")).unwrap();
+  assert!(lazy.contains("console.log(\"lazy\")"));
+}
+
+#[test]
+fn build_toml_config() {
+  let dir = fixture("basic");
+  let output = rspack(dir.path(), &["-c", "rspack.config.toml"]);
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  assert!(output.status.success(), "{stdout}");
+
+  let dist = dir.path().join("dist-toml");
+  let files = emitted_files(&dist);
+  assert_eq!(files.len(), 2, "{files:?}");
+  let app = fs::read_to_string(find_file(&dist, "app.")).unwrap();
+  assert!(app.contains("hello from rspack"));
+  // production output is minimized
+  assert!(!app.contains("\n\n"));
+}
+
+#[test]
+fn config_is_found_in_working_directory() {
+  let dir = fixture("basic");
+  fs::remove_file(dir.path().join("rspack.config.toml")).unwrap();
+  let output = rspack(dir.path(), &["--mode", "none"]);
+  assert!(output.status.success());
+  assert!(dir.path().join("dist-json/main.js").exists());
+}
+
+#[test]
+fn exit_code_on_errors() {
+  let dir = fixture("error");
+  let output = rspack(dir.path(), &[]);
+  assert_eq!(output.status.code(), Some(1));
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  assert!(stdout.contains("compiled with 1 error(s)"), "{stdout}");
+}
+
+#[test]
+fn exit_code_on_invalid_config() {
+  let dir = fixture("basic");
+  fs::write(
+    dir.path().join("rspack.config.json"),
+    r#"{ "optimization": { "moduleIds": "unknown" } }"#,
+  )
+  .unwrap();
+  let output = rspack(dir.path(), &["-c", "rspack.config.json"]);
+  assert_eq!(output.status.code(), Some(1));
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(
+    stderr.contains("moduleIds: unknown is not implemented"),
+    "{stderr}"
+  );
+}
+
+#[test]
+fn unknown_exports_presence_is_rejected() {
+  let dir = fixture("basic");
+  fs::write(
+    dir.path().join("rspack.config.json"),
+    r#"{ "module": { "parser": { "javascript": { "exportsPresence": "warning" } } } }"#,
+  )
+  .unwrap();
+  let output = rspack(dir.path(), &["-c", "rspack.config.json"]);
+  assert_eq!(output.status.code(), Some(1));
+  let stderr = String::from_utf8_lossy(&output.stderr);
+  assert!(
+    stderr.contains("Unsupported export presence mode: 'warning'"),
+    "{stderr}"
+  );
+}
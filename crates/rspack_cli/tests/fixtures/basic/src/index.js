@@ -0,0 +1,4 @@
+import { message } from "./message";
+
+console.log(message);
+import("./lazy").then(({ default: lazy }) => lazy());
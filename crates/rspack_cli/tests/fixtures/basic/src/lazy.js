@@ -0,0 +1,3 @@
+export default function lazy() {
+	console.log("lazy");
+}
@@ -0,0 +1,30 @@
+//! Shared by the integration tests.
+
+use std::{fs, path::Path};
+
+use tempfile::TempDir;
+
+/// Copies a fixture into a temporary directory, so the emitted files don't end up in the source tree.
+pub fn fixture(name: &str) -> TempDir {
+  fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).expect("should create directory");
+    for entry in fs::read_dir(from).expect("should read fixture") {
+      let entry = entry.expect("should read fixture");
+      let target = to.join(entry.file_name());
+      if entry.path().is_dir() {
+        copy_dir(&entry.path(), &target);
+      } else {
+        fs::copy(entry.path(), target).expect("should copy fixture");
+      }
+    }
+  }
+
+  let dir = tempfile::tempdir().expect("should create temporary directory");
+  copy_dir(
+    &Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/fixtures")
+      .join(name),
+    dir.path(),
+  );
+  dir
+}
@@ -6,6 +6,8 @@ use std::{
   },
 };
 
+use rspack_fs::AsyncReadableFileSystem;
+
 use crate::CompilerOptions;
 
 mod local;
@@ -17,7 +19,7 @@ use occasion::{
   BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_memory_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
@@ -30,21 +32,21 @@ pub struct Cache {
 }
 
 impl Cache {
-  pub fn new(options: Arc<CompilerOptions>) -> Self {
-    let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone()));
+  pub fn new(options: Arc<CompilerOptions>, fs: Arc<dyn AsyncReadableFileSystem>) -> Self {
+    let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone(), fs));
     Self {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&options, "resolve_module"),
         snapshot_manager.clone(),
       ),
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&options, "build_module"),
         snapshot_manager,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_memory_storage(&options)),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_memory_storage(&options)),
     }
   }
 
@@ -52,10 +54,16 @@ impl Cache {
     self.snapshot_manager.set_modified_files(modified_files);
   }
 
-  pub fn begin_idle(&self) {
+  pub async fn begin_idle(&self) {
     if self.is_idle.load(Ordering::Relaxed) {
       self.snapshot_manager.clear();
     }
+    tokio::join!(
+      self.resolve_module_occasion.begin_idle(),
+      self.build_module_occasion.begin_idle(),
+      self.code_generate_occasion.begin_idle(),
+      self.create_chunk_assets_occasion.begin_idle(),
+    );
   }
 
   pub fn end_idle(&self) {
@@ -1,13 +1,21 @@
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use futures::Future;
 use rkyv::AlignedVec;
 use rspack_error::{Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
+use rspack_loader_runner::{Content, LoaderResult};
+use rspack_sources::SourceMap;
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
+  cache::storage::{self, Persistable},
   BoxModule, BuildExtraDataType, BuildInfo, BuildMeta, BuildResult, DependencyTemplate, Module,
   ModuleDependency, NormalModuleSource,
 };
@@ -23,16 +31,91 @@ pub struct NormalModuleStorageData {
 
 type NormalModuleStorageExtraData = HashMap<BuildExtraDataType, AlignedVec>;
 
-type Storage = dyn storage::Storage<(
-  // file system info, None when not cacheable
-  Option<Snapshot>,
-  // build result
-  TWithDiagnosticArray<BuildResult>,
-  // module data
-  Option<NormalModuleStorageData>,
-  // parser and generator data
-  Option<NormalModuleStorageExtraData>,
-)>;
+#[derive(Debug, Clone)]
+pub struct StorageItem {
+  /// File system info, None when not cacheable
+  snapshot: Option<Snapshot>,
+  /// Build result, module data and parser and generator data of a build in this process
+  build: Option<(
+    TWithDiagnosticArray<BuildResult>,
+    Option<NormalModuleStorageData>,
+    Option<NormalModuleStorageExtraData>,
+  )>,
+  /// Result of the loaders, the only part which is persisted
+  loader_result: Option<LoaderResult>,
+}
+
+type Storage = dyn storage::Storage<StorageItem>;
+
+#[derive(Serialize, Deserialize)]
+struct PersistedLoaderResult {
+  cacheable: bool,
+  file_dependencies: HashSet<PathBuf>,
+  context_dependencies: HashSet<PathBuf>,
+  missing_dependencies: HashSet<PathBuf>,
+  build_dependencies: HashSet<PathBuf>,
+  asset_filenames: HashSet<String>,
+  /// `None` for binary content, which is stored in `buffer`
+  code: Option<String>,
+  buffer: Vec<u8>,
+  source_map: Option<String>,
+}
+
+/// Build results hold dependencies and diagnostics as trait objects, which have no serialized form
+/// yet. Only the loader result is persisted, a later process parses it again instead of running the
+/// loaders, which is where most of the build time goes.
+impl Persistable for StorageItem {
+  fn encode(&self) -> Option<Vec<u8>> {
+    let snapshot = self.snapshot.as_ref()?;
+    let loader_result = self.loader_result.as_ref()?;
+    let (code, buffer) = match &loader_result.content {
+      Content::String(code) => (Some(code.clone()), vec![]),
+      Content::Buffer(buffer) => (None, buffer.clone()),
+    };
+    let persisted = PersistedLoaderResult {
+      cacheable: loader_result.cacheable,
+      file_dependencies: loader_result.file_dependencies.clone(),
+      context_dependencies: loader_result.context_dependencies.clone(),
+      missing_dependencies: loader_result.missing_dependencies.clone(),
+      build_dependencies: loader_result.build_dependencies.clone(),
+      asset_filenames: loader_result.asset_filenames.clone(),
+      code,
+      buffer,
+      source_map: match &loader_result.source_map {
+        Some(map) => Some(map.clone().to_json().ok()?),
+        None => None,
+      },
+    };
+    serde_json::to_vec(&(snapshot, persisted)).ok()
+  }
+
+  fn decode(bytes: &[u8]) -> Option<Self> {
+    let (snapshot, persisted): (Snapshot, PersistedLoaderResult) =
+      serde_json::from_slice(bytes).ok()?;
+    let loader_result = LoaderResult {
+      cacheable: persisted.cacheable,
+      file_dependencies: persisted.file_dependencies,
+      context_dependencies: persisted.context_dependencies,
+      missing_dependencies: persisted.missing_dependencies,
+      build_dependencies: persisted.build_dependencies,
+      asset_filenames: persisted.asset_filenames,
+      content: match persisted.code {
+        Some(code) => Content::String(code),
+        None => Content::Buffer(persisted.buffer),
+      },
+      source_map: match persisted.source_map {
+        Some(map) => Some(SourceMap::from_json(&map).ok()?),
+        None => None,
+      },
+      additional_data: Default::default(),
+    };
+    Some(Self {
+      snapshot: Some(snapshot),
+      build: None,
+      loader_result: Some(loader_result),
+    })
+  }
+}
 
 #[derive(Debug)]
 pub struct BuildModuleOccasion {
@@ -48,6 +131,12 @@ impl BuildModuleOccasion {
     }
   }
 
+  pub async fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle().await;
+    }
+  }
+
   pub fn remove_cache(&self, id: &Identifier) {
     if let Some(s) = self.storage.as_ref() {
       s.remove(id);
@@ -75,38 +164,57 @@ impl BuildModuleOccasion {
     if module.as_normal_module().is_some() {
       // normal module
       // TODO cache all module type
-      if let Some((snapshot, data, module_data, extra_data)) = storage.get(&id) {
-        let valid = if let Some(snapshot) = snapshot {
+      if let Some(item) = storage.get(&id) {
+        let valid = if let Some(snapshot) = &item.snapshot {
           self
             .snapshot_manager
-            .check_snapshot_valid(&snapshot)
+            .check_snapshot_valid(snapshot)
             .await
             .unwrap_or(false)
         } else {
           false
         };
-        if valid {
-          if let Some(module) = module.as_normal_module_mut() {
-            if let Some(module_data) = module_data {
-              *module.source_mut() = module_data.source;
-              *module.code_generation_dependencies_mut() = module_data.code_generation_dependencies;
-              *module.presentational_dependencies_mut() = module_data.presentational_dependencies;
-              if let (Some(build_info), Some(build_meta)) =
-                (module_data.build_info, module_data.build_meta)
-              {
-                module.set_build_info(build_info);
-                module.set_build_meta(build_meta);
+        match item.build {
+          Some((data, module_data, extra_data)) if valid => {
+            if let Some(module) = module.as_normal_module_mut() {
+              if let Some(module_data) = module_data {
+                *module.source_mut() = module_data.source;
+                *module.code_generation_dependencies_mut() =
+                  module_data.code_generation_dependencies;
+                *module.presentational_dependencies_mut() = module_data.presentational_dependencies;
+                if let (Some(build_info), Some(build_meta)) =
+                  (module_data.build_info, module_data.build_meta)
+                {
+                  module.set_build_info(build_info);
+                  module.set_build_meta(build_meta);
+                }
+              }
+              if let Some(extra_data) = extra_data {
+                module.parser_and_generator_mut().resume(&extra_data);
               }
             }
-            if let Some(extra_data) = extra_data {
-              module.parser_and_generator_mut().resume(&extra_data);
+            return Ok((Ok(data), true));
+          }
+          Some((data, ..)) => last_build_result = Some(data.inner),
+          // restored from a persistent storage, build from the loader result
+          None if valid => {
+            if let (Some(module), Some(loader_result)) =
+              (module.as_normal_module_mut(), item.loader_result)
+            {
+              module.restore_loader_result(loader_result);
             }
           }
-          return Ok((Ok(data), true));
-        } else {
-          last_build_result = Some(data.inner);
+          None => {}
         }
       };
+      // a module rebuilt in this process keeps the ast of builtin:swc-loader, it is built again
+      // by the next process instead
+      if storage.is_persistent()
+        && last_build_result.is_none()
+        && let Some(module) = module.as_normal_module_mut()
+      {
+        module.persist_loader_result();
+      }
       need_cache = true;
     }
 
@@ -116,8 +224,9 @@ impl BuildModuleOccasion {
 
     if need_cache {
       let module = module
-        .as_normal_module()
+        .as_normal_module_mut()
         .expect("Only normal module supports build cache");
+      let loader_result = module.take_loader_result();
       // only resume the build_meta to make sure other modules will not be affected
       if matches!(module.source(), NormalModuleSource::BuiltFailed(_))
         && let Some(last_result) = last_build_result
@@ -169,23 +278,116 @@ impl BuildModuleOccasion {
         module.parser_and_generator().store(&mut extra_data);
         storage.set(
           id,
-          (
-            Some(snapshot),
-            data.clone(),
-            Some(NormalModuleStorageData {
-              source: module.source().clone(),
-              code_generation_dependencies: module.code_generation_dependencies().clone(),
-              presentational_dependencies: module.presentational_dependencies().clone(),
-              build_info: module.build_info().cloned(),
-              build_meta: module.build_meta().cloned(),
-            }),
-            Some(extra_data),
-          ),
+          StorageItem {
+            snapshot: Some(snapshot),
+            build: Some((
+              data.clone(),
+              Some(NormalModuleStorageData {
+                source: module.source().clone(),
+                code_generation_dependencies: module.code_generation_dependencies().clone(),
+                presentational_dependencies: module.presentational_dependencies().clone(),
+                build_info: module.build_info().cloned(),
+                build_meta: module.build_meta().cloned(),
+              }),
+              Some(extra_data),
+            )),
+            loader_result,
+          },
         );
       } else if matches!(module.source(), NormalModuleSource::BuiltSucceed(_)) {
-        storage.set(id, (None, data.clone(), None, None));
+        storage.set(
+          id,
+          StorageItem {
+            snapshot: None,
+            build: Some((data.clone(), None, None)),
+            loader_result: None,
+          },
+        );
       }
     }
     Ok((Ok(data), false))
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn loader_result(content: Content, source_map: Option<SourceMap>) -> LoaderResult {
+    LoaderResult {
+      cacheable: true,
+      file_dependencies: HashSet::from_iter([PathBuf::from("/src/index.js")]),
+      context_dependencies: Default::default(),
+      missing_dependencies: HashSet::from_iter([PathBuf::from("/src/missing.js")]),
+      build_dependencies: Default::default(),
+      asset_filenames: HashSet::from_iter(["image.png".to_string()]),
+      content,
+      source_map,
+      additional_data: Default::default(),
+    }
+  }
+
+  fn item(loader_result: Option<LoaderResult>) -> StorageItem {
+    StorageItem {
+      snapshot: Some(Snapshot {
+        file_update_times: Default::default(),
+        file_hashes: HashMap::from_iter([(PathBuf::from("/src/index.js"), 42)]),
+        managed_items: Default::default(),
+      }),
+      build: None,
+      loader_result,
+    }
+  }
+
+  #[test]
+  fn test_persist_loader_result() {
+    let map =
+      SourceMap::from_json(r#"{"version":3,"sources":["index.js"],"names":[],"mappings":"AAAA"}"#)
+        .expect("should be a valid source map");
+    let bytes = item(Some(loader_result(
+      Content::String("export default 1;".to_string()),
+      Some(map.clone()),
+    )))
+    .encode()
+    .expect("should persist the loader result");
+
+    let restored = StorageItem::decode(&bytes).expect("should restore the loader result");
+    assert!(restored.build.is_none());
+    let snapshot = restored.snapshot.expect("should restore the snapshot");
+    assert_eq!(
+      snapshot.file_hashes.get(Path::new("/src/index.js")),
+      Some(&42)
+    );
+    let restored = restored
+      .loader_result
+      .expect("should restore the loader result");
+    let expected = loader_result(Content::String("export default 1;".to_string()), None);
+    assert!(restored.content == expected.content);
+    assert_eq!(restored.source_map, Some(map));
+    assert!(restored.cacheable);
+    assert_eq!(restored.file_dependencies, expected.file_dependencies);
+    assert_eq!(restored.missing_dependencies, expected.missing_dependencies);
+    assert_eq!(restored.asset_filenames, expected.asset_filenames);
+  }
+
+  #[test]
+  fn test_persist_binary_loader_result() {
+    let content = Content::Buffer(vec![0, 159, 146, 150]);
+    let bytes = item(Some(loader_result(content.clone(), None)))
+      .encode()
+      .expect("should persist the loader result");
+    let restored = StorageItem::decode(&bytes)
+      .and_then(|item| item.loader_result)
+      .expect("should restore the loader result");
+    assert!(restored.content == content);
+    assert_eq!(restored.source_map, None);
+  }
+
+  #[test]
+  fn test_skip_items_without_loader_result_or_snapshot() {
+    assert!(item(None).encode().is_none());
+    let mut item = item(Some(loader_result(Content::String(String::new()), None)));
+    item.snapshot = None;
+    assert!(item.encode().is_none());
+  }
+}
@@ -6,6 +6,8 @@ use crate::{get_runtime_key, RuntimeSpec, RuntimeSpecSet};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
+/// Generated code depends on module ids, chunk ids and compiler options, which the module graph
+/// hash used as key doesn't cover, so it is only cached in memory, also with the persistent cache.
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +18,12 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub async fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle().await;
+    }
+  }
+
   pub fn use_cache<'a, G>(
     &self,
     module: &'a BoxModule,
@@ -6,6 +6,8 @@ use crate::{cache::storage, Chunk, Compilation, NormalModuleSource, RenderManife
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 
+/// Chunk assets are validated against the in-memory module state, so they are only cached in
+/// memory, also with the persistent cache.
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +18,12 @@ impl CreateChunkAssetsOccasion {
     Self { storage }
   }
 
+  pub async fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle().await;
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     compilation: &Compilation,
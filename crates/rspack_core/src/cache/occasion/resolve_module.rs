@@ -1,17 +1,70 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
 use rspack_error::Error;
 use rspack_identifier::Identifier;
+use rspack_loader_runner::DescriptionData;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  ModuleIdentifier, ResolveArgs, ResolveResult,
+  cache::storage::{self, Persistable},
+  ModuleIdentifier, ResolveArgs, ResolveResult, Resource,
 };
 
 type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
 
+#[derive(Serialize, Deserialize)]
+enum PersistedResolveResult {
+  Resource {
+    path: PathBuf,
+    query: String,
+    fragment: String,
+    description_data: Option<(PathBuf, serde_json::Value)>,
+  },
+  Ignored,
+}
+
+impl Persistable for (Snapshot, ResolveResult) {
+  fn encode(&self) -> Option<Vec<u8>> {
+    let (snapshot, result) = self;
+    let result = match result {
+      ResolveResult::Resource(resource) => PersistedResolveResult::Resource {
+        path: resource.path.clone(),
+        query: resource.query.clone(),
+        fragment: resource.fragment.clone(),
+        description_data: resource
+          .description_data
+          .as_ref()
+          .map(|d| (d.path().to_path_buf(), d.json().clone())),
+      },
+      ResolveResult::Ignored => PersistedResolveResult::Ignored,
+    };
+    serde_json::to_vec(&(snapshot, result)).ok()
+  }
+
+  fn decode(bytes: &[u8]) -> Option<Self> {
+    let (snapshot, result): (Snapshot, PersistedResolveResult) =
+      serde_json::from_slice(bytes).ok()?;
+    let result = match result {
+      PersistedResolveResult::Resource {
+        path,
+        query,
+        fragment,
+        description_data,
+      } => ResolveResult::Resource(Resource {
+        path,
+        query,
+        fragment,
+        description_data: description_data
+          .map(|(path, json)| DescriptionData::new(path, Arc::new(json))),
+      }),
+      PersistedResolveResult::Ignored => ResolveResult::Ignored,
+    };
+    Some((snapshot, result))
+  }
+}
+
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
   storage: Option<Box<Storage>>,
@@ -26,6 +79,12 @@ impl ResolveModuleOccasion {
     }
   }
 
+  pub async fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle().await;
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     args: ResolveArgs<'a>,
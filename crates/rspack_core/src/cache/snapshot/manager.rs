@@ -1,11 +1,13 @@
 use std::{
   hash::BuildHasherDefault,
   path::{Path, PathBuf},
-  time::SystemTime,
+  sync::Arc,
+  time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use dashmap::{DashMap, DashSet};
-use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_error::Result;
+use rspack_fs::AsyncReadableFileSystem;
 use rustc_hash::{FxHashMap as HashMap, FxHasher};
 
 use super::Snapshot;
@@ -19,20 +21,26 @@ use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
 pub struct SnapshotManager {
   /// global snapshot options
   options: SnapshotOptions,
+  /// file system the snapshots are created from and checked against
+  fs: Arc<dyn AsyncReadableFileSystem>,
   /// cache file update time
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache `name@version` of managed package directories, `None` when package.json is missing
+  managed_item_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
 }
 
 impl SnapshotManager {
-  pub fn new(options: SnapshotOptions) -> Self {
+  pub fn new(options: SnapshotOptions, fs: Arc<dyn AsyncReadableFileSystem>) -> Self {
     Self {
       options,
+      fs,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      managed_item_cache: Default::default(),
       modified_files: Default::default(),
     }
   }
@@ -41,33 +49,74 @@ impl SnapshotManager {
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
+    // TODO file_paths deduplication
     let strategy = f(&self.options);
     let mut file_update_times = HashMap::default();
-    file_update_times.reserve(paths.len());
     let mut file_hashes = HashMap::default();
+    let mut managed_items = HashMap::default();
+    if !strategy.timestamp && !strategy.hash {
+      return Ok(Snapshot {
+        file_update_times,
+        file_hashes,
+        managed_items,
+      });
+    }
+
+    // files under immutable paths never change, files under managed paths are
+    // represented by the name and version of the package containing them
+    let mut unmanaged_paths = Vec::with_capacity(paths.len());
+    for &path in paths {
+      let path_str = path.to_string_lossy();
+      if self
+        .options
+        .immutable_paths
+        .iter()
+        .any(|matcher| matcher.match_dir(&path_str).is_some())
+      {
+        continue;
+      }
+      if let Some(item) = self.get_managed_item(&path_str) {
+        let item = PathBuf::from(item);
+        if let Some(info) = self.managed_item_info(&item).await {
+          managed_items.insert(item, info);
+          continue;
+        }
+      }
+      unmanaged_paths.push(path);
+    }
+    let paths = unmanaged_paths;
+
+    file_update_times.reserve(paths.len());
     file_hashes.reserve(paths.len());
     if strategy.timestamp {
-      for &path in paths {
+      for &path in &paths {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
     }
     if strategy.hash {
       let hash_cache = &self.hash_cache;
-      for &path in paths {
+      for &path in &paths {
         let hash = match hash_cache.get(path) {
           Some(hash) => *hash,
           None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await.into_diagnostic()?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await.into_diagnostic()? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
+            let is_dir = self
+              .fs
+              .metadata(path)
+              .await
+              .map(|metadata| metadata.is_directory)
+              .unwrap_or_default();
+            let res = if is_dir {
+              let mut sub_files = self
+                .fs
+                .read_dir(path)
+                .await?
+                .into_iter()
+                .map(|name| path.join(name).to_string_lossy().to_string())
+                .collect::<Vec<_>>();
+              sub_files.sort();
               calc_hash(&sub_files)
             } else {
-              calc_hash(&tokio::fs::read(path).await.into_diagnostic()?)
+              calc_hash(&self.fs.read(path).await?)
             };
             hash_cache.insert(path.to_owned(), res);
             res
@@ -80,15 +129,56 @@ impl SnapshotManager {
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      managed_items,
     })
   }
 
+  /// Returns the package directory when the path is located in a managed path.
+  fn get_managed_item<'a>(&self, path: &'a str) -> Option<&'a str> {
+    self
+      .options
+      .managed_paths
+      .iter()
+      .find_map(|matcher| matcher.match_dir(path))
+      .and_then(|managed_path| get_managed_item(managed_path.len(), path))
+  }
+
+  async fn managed_item_info(&self, item: &Path) -> Option<String> {
+    if let Some(info) = self.managed_item_cache.get(item) {
+      return info.clone();
+    }
+    let info = self
+      .fs
+      .read(&item.join("package.json"))
+      .await
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|json| {
+        let name = json.get("name")?.as_str()?;
+        let version = json.get("version")?.as_str()?;
+        Some(format!("{name}@{version}"))
+      });
+    self
+      .managed_item_cache
+      .insert(item.to_path_buf(), info.clone());
+    info
+  }
+
   pub async fn check_snapshot_valid(&self, snapshot: &Snapshot) -> Result<bool> {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      managed_items,
     } = snapshot;
+    for (item, snapshot_info) in managed_items {
+      if self.modified_files.contains(&item.join("package.json")) {
+        return Ok(false);
+      }
+      if self.managed_item_info(item).await.as_ref() != Some(snapshot_info) {
+        return Ok(false);
+      }
+    }
+
     if !file_update_times.is_empty() {
       // check update time
       let update_time_cache = &self.update_time_cache;
@@ -100,11 +190,7 @@ impl SnapshotManager {
         let update_time = match update_time_cache.get(path) {
           Some(t) => *t,
           None => {
-            let t = tokio::fs::metadata(path)
-              .await
-              .into_diagnostic()?
-              .modified()
-              .into_diagnostic()?;
+            let t = UNIX_EPOCH + Duration::from_millis(self.fs.metadata(path).await?.mtime_ms);
             update_time_cache.insert(path.clone(), t);
             t
           }
@@ -127,7 +213,7 @@ impl SnapshotManager {
         let current_hash = match hash_cache.get(path) {
           Some(h) => *h,
           None => {
-            let res = calc_hash(&tokio::fs::read(path).await.into_diagnostic()?);
+            let res = calc_hash(&self.fs.read(path).await?);
             hash_cache.insert(path.clone(), res);
             res
           }
@@ -144,6 +230,7 @@ impl SnapshotManager {
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.managed_item_cache.clear();
     self.modified_files.clear();
   }
 
@@ -153,3 +240,134 @@ impl SnapshotManager {
     }
   }
 }
+
+/// Port of `getManagedItem` in webpack's FileSystemInfo.
+///
+/// `managed_path_len` is the length of the managed path prefix (including the trailing separator),
+/// returns the package directory like `/node_modules/@scope/pkg` or `None` when the path doesn't
+/// point into a package.
+fn get_managed_item(managed_path_len: usize, path: &str) -> Option<&str> {
+  let bytes = path.as_bytes();
+  let mut i = managed_path_len;
+  let mut slashes = 1;
+  let mut starting_position = true;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' | b'\\' => {
+        slashes -= 1;
+        if slashes == 0 {
+          break;
+        }
+        starting_position = true;
+      }
+      // hidden directories like `.pnpm` or `.bin` are not packages
+      b'.' if starting_position => return None,
+      b'@' => {
+        if !starting_position {
+          return None;
+        }
+        slashes += 1;
+      }
+      _ => starting_position = false,
+    }
+    i += 1;
+  }
+  if i == bytes.len() {
+    slashes -= 1;
+  }
+  // path is incomplete
+  if slashes != 0 {
+    return None;
+  }
+  // nested node_modules of a package are managed items of their own
+  let rest = &path[i..];
+  if rest.len() > 13
+    && rest[1..].starts_with("node_modules")
+    && matches!(rest.as_bytes()[13], b'/' | b'\\')
+  {
+    if let Some(nested) = get_managed_item(i + 14, path) {
+      return Some(nested);
+    }
+  }
+  Some(&path[..i])
+}
+
+#[cfg(test)]
+mod test {
+  use std::{path::Path, sync::Arc};
+
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+
+  use super::{get_managed_item, SnapshotManager};
+  use crate::{SnapshotOptions, SnapshotPathMatcher, SnapshotStrategy};
+
+  #[test]
+  fn test_get_managed_item() {
+    let managed = "/app/node_modules/";
+    let item = |path: &'static str| get_managed_item(managed.len(), path);
+    assert_eq!(
+      item("/app/node_modules/react/index.js"),
+      Some("/app/node_modules/react")
+    );
+    assert_eq!(
+      item("/app/node_modules/@scope/pkg/lib/index.js"),
+      Some("/app/node_modules/@scope/pkg")
+    );
+    assert_eq!(
+      item("/app/node_modules/a/node_modules/b/index.js"),
+      Some("/app/node_modules/a/node_modules/b")
+    );
+    assert_eq!(item("/app/node_modules/.pnpm/a@1.0.0/index.js"), None);
+    assert_eq!(item("/app/node_modules/@scope"), None);
+  }
+
+  #[tokio::test]
+  async fn test_managed_item_snapshot() {
+    let fs = MemoryFileSystem::default();
+    fs.create_dir_all("/app/node_modules/react").unwrap();
+    fs.write(
+      "/app/node_modules/react/package.json",
+      r#"{ "name": "react", "version": "18.2.0" }"#,
+    )
+    .unwrap();
+    fs.write("/app/node_modules/react/index.js", "module.exports = 1")
+      .unwrap();
+    let options = SnapshotOptions {
+      module: SnapshotStrategy {
+        hash: true,
+        timestamp: false,
+      },
+      managed_paths: vec![SnapshotPathMatcher::node_modules()],
+      ..Default::default()
+    };
+    let manager = SnapshotManager::new(options.clone(), Arc::new(fs.clone()));
+
+    let file = Path::new("/app/node_modules/react/index.js");
+    let snapshot = manager
+      .create_snapshot(&[file], |options| &options.module)
+      .await
+      .unwrap();
+    assert!(snapshot.file_hashes.is_empty());
+    assert_eq!(
+      snapshot
+        .managed_items
+        .get(Path::new("/app/node_modules/react"))
+        .map(String::as_str),
+      Some("react@18.2.0")
+    );
+
+    // files of a managed package are not checked, only its version
+    fs.write("/app/node_modules/react/index.js", "module.exports = 2")
+      .unwrap();
+    let manager = SnapshotManager::new(options.clone(), Arc::new(fs.clone()));
+    assert!(manager.check_snapshot_valid(&snapshot).await.unwrap());
+
+    fs.write(
+      "/app/node_modules/react/package.json",
+      r#"{ "name": "react", "version": "18.3.0" }"#,
+    )
+    .unwrap();
+    let manager = SnapshotManager::new(options, Arc::new(fs));
+    assert!(!manager.check_snapshot_valid(&snapshot).await.unwrap());
+  }
+}
@@ -18,6 +18,7 @@ impl<Item> MemoryStorage<Item> {
   }
 }
 
+#[async_trait::async_trait]
 impl<Item> Storage<Item> for MemoryStorage<Item>
 where
   Item: Clone + std::fmt::Debug + Send + Sync,
@@ -2,26 +2,59 @@ use std::fmt::Debug;
 
 use rspack_identifier::Identifier;
 
-use crate::CacheOptions;
+use crate::{CacheOptions, CompilerOptions};
 
 mod memory;
+mod pack;
 use memory::MemoryStorage;
+use pack::PackStorage;
 
+#[async_trait::async_trait]
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
+  /// Whether the items are written to disk and restored by a later process.
+  fn is_persistent(&self) -> bool {
+    false
+  }
+  /// Called when the compiler becomes idle, persistent storages write their data here.
+  async fn begin_idle(&self) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Cache item which can be written to a persistent storage.
+///
+/// Items that contain data without a stable serialized form return `None` from [Persistable::encode],
+/// they are only kept in memory for the lifetime of the compiler.
+pub trait Persistable: Sized {
+  fn encode(&self) -> Option<Vec<u8>>;
+  fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Storage of items which are only kept in memory, also with the persistent cache.
+pub fn new_memory_storage<Item>(options: &CompilerOptions) -> Option<Box<dyn Storage<Item>>>
 where
   Item: Debug + Clone + Send + Sync + 'static,
 {
-  match options {
+  match &options.cache {
+    CacheOptions::Disabled => None,
+    CacheOptions::Memory(_) | CacheOptions::FileSystem(_) => Some(Box::new(MemoryStorage::new())),
+  }
+}
+
+pub fn new_storage<Item>(options: &CompilerOptions, name: &str) -> Option<Box<dyn Storage<Item>>>
+where
+  Item: Persistable + Debug + Clone + Send + Sync + 'static,
+{
+  match &options.cache {
     CacheOptions::Disabled => None,
-    _ => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::Memory(_) => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::FileSystem(fs_options) => Some(Box::new(PackStorage::new(
+      fs_options.location(&options.context),
+      name,
+      fs_options,
+    ))),
   }
 }
@@ -0,0 +1,293 @@
+use std::{
+  fs,
+  hash::{BuildHasherDefault, Hash, Hasher},
+  io,
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicBool, Ordering},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHasher;
+
+use super::{Persistable, Storage};
+use crate::{calc_hash, FileSystemCacheOptions};
+
+const PACK_MAGIC: &[u8; 8] = b"RSPKPACK";
+/// Bump when the layout of the pack file or of any persisted item changes.
+const PACK_FORMAT_VERSION: u32 = 2;
+
+/// A storage keeps all items in memory and writes the persistable ones into a single
+/// pack file under the cache location when the compiler becomes idle.
+///
+/// The pack is dropped wholesale when the cache `version`, `name` or any file of
+/// `build_dependencies` changes, and entries which have not been used for `max_age`
+/// milliseconds are evicted.
+#[derive(Debug)]
+pub struct PackStorage<Item> {
+  path: PathBuf,
+  identity: u64,
+  max_age: u64,
+  data: DashMap<Identifier, Item, BuildHasherDefault<IdentifierHasher>>,
+  last_used: DashMap<Identifier, u64, BuildHasherDefault<IdentifierHasher>>,
+  dirty: AtomicBool,
+}
+
+impl<Item> PackStorage<Item>
+where
+  Item: Persistable,
+{
+  pub fn new(location: PathBuf, name: &str, options: &FileSystemCacheOptions) -> Self {
+    let storage = Self {
+      path: location.join(format!("{name}.pack")),
+      identity: pack_identity(options),
+      max_age: options.max_age as u64,
+      data: Default::default(),
+      last_used: Default::default(),
+      dirty: AtomicBool::new(false),
+    };
+    if let Err(e) = storage.restore() {
+      if e.kind() != io::ErrorKind::NotFound {
+        tracing::warn!(
+          "Failed to restore cache pack {}: {e}",
+          storage.path.display()
+        );
+      }
+    }
+    storage
+  }
+
+  fn is_expired(&self, last_used: u64, now: u64) -> bool {
+    self.max_age != 0 && now.saturating_sub(last_used) > self.max_age
+  }
+
+  fn restore(&self) -> io::Result<()> {
+    let mut reader = PackReader::new(fs::read(&self.path)?);
+    if reader.bytes(PACK_MAGIC.len())? != PACK_MAGIC
+      || reader.u32()? != PACK_FORMAT_VERSION
+      || reader.u64()? != self.identity
+    {
+      // invalidate the whole pack, it will be overwritten by the next store
+      self.dirty.store(true, Ordering::Relaxed);
+      return Ok(());
+    }
+    let now = now();
+    let count = reader.u32()?;
+    for _ in 0..count {
+      let key = reader.string()?;
+      let last_used = reader.u64()?;
+      let len = reader.u32()? as usize;
+      let bytes = reader.bytes(len)?;
+      if self.is_expired(last_used, now) {
+        self.dirty.store(true, Ordering::Relaxed);
+        continue;
+      }
+      if let Some(item) = Item::decode(bytes) {
+        let id = Identifier::from(key);
+        self.data.insert(id, item);
+        self.last_used.insert(id, last_used);
+      }
+    }
+    Ok(())
+  }
+
+  fn encode(&self) -> Vec<u8> {
+    let now = now();
+    let mut entries = Vec::with_capacity(self.data.len());
+    for item in self.data.iter() {
+      let last_used = self.last_used.get(item.key()).map(|t| *t).unwrap_or(now);
+      if self.is_expired(last_used, now) {
+        continue;
+      }
+      if let Some(bytes) = item.value().encode() {
+        entries.push((*item.key(), last_used, bytes));
+      }
+    }
+
+    let mut buf = Vec::new();
+    buf.extend_from_slice(PACK_MAGIC);
+    buf.extend_from_slice(&PACK_FORMAT_VERSION.to_le_bytes());
+    buf.extend_from_slice(&self.identity.to_le_bytes());
+    buf.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+    for (key, last_used, bytes) in entries {
+      buf.extend_from_slice(&(key.len() as u32).to_le_bytes());
+      buf.extend_from_slice(key.as_bytes());
+      buf.extend_from_slice(&last_used.to_le_bytes());
+      buf.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+      buf.extend_from_slice(&bytes);
+    }
+    buf
+  }
+}
+
+#[async_trait::async_trait]
+impl<Item> Storage<Item> for PackStorage<Item>
+where
+  Item: Persistable + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    let item = self.data.get(id).map(|item| item.clone());
+    if item.is_some() {
+      self.last_used.insert(*id, now());
+    }
+    item
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(id, data);
+    self.last_used.insert(id, now());
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    self.data.remove(id);
+    self.last_used.remove(id);
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  fn is_persistent(&self) -> bool {
+    true
+  }
+
+  async fn begin_idle(&self) {
+    if !self.dirty.swap(false, Ordering::Relaxed) {
+      return;
+    }
+    let buf = self.encode();
+    let path = self.path.clone();
+    let result = tokio::task::spawn_blocking(move || write_pack(&path, buf))
+      .await
+      .unwrap_or_else(|e| Err(io::Error::new(io::ErrorKind::Other, e)));
+    if let Err(e) = result {
+      tracing::warn!("Failed to store cache pack {}: {e}", self.path.display());
+    }
+  }
+}
+
+fn write_pack(path: &Path, buf: Vec<u8>) -> io::Result<()> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+  // write to a temporary file first so a crashed process never leaves a truncated pack
+  let temp = path.with_extension("pack.tmp");
+  fs::write(&temp, buf)?;
+  fs::rename(&temp, path)
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+/// Hash of everything that invalidates the whole cache when changed.
+fn pack_identity(options: &FileSystemCacheOptions) -> u64 {
+  let mut hasher = FxHasher::default();
+  PACK_FORMAT_VERSION.hash(&mut hasher);
+  env!("CARGO_PKG_VERSION").hash(&mut hasher);
+  options.name.hash(&mut hasher);
+  options.version.hash(&mut hasher);
+  for dependency in &options.build_dependencies {
+    dependency.hash(&mut hasher);
+    hash_build_dependency(Path::new(dependency), &mut hasher);
+  }
+  hasher.finish()
+}
+
+fn hash_build_dependency(path: &Path, hasher: &mut FxHasher) {
+  if path.is_dir() {
+    let Ok(dir) = fs::read_dir(path) else {
+      return;
+    };
+    let mut entries = dir.flatten().map(|e| e.path()).collect::<Vec<_>>();
+    entries.sort();
+    for entry in entries {
+      entry.hash(hasher);
+      hash_build_dependency(&entry, hasher);
+    }
+  } else {
+    // a missing build dependency hashes differently from an existing one
+    fs::read(path).ok().map(|c| calc_hash(&c)).hash(hasher);
+  }
+}
+
+struct PackReader {
+  buf: Vec<u8>,
+  pos: usize,
+}
+
+impl PackReader {
+  fn new(buf: Vec<u8>) -> Self {
+    Self { buf, pos: 0 }
+  }
+
+  fn bytes(&mut self, len: usize) -> io::Result<&[u8]> {
+    let start = self.pos;
+    let end = start
+      .checked_add(len)
+      .filter(|end| *end <= self.buf.len())
+      .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+    self.pos = end;
+    Ok(&self.buf[start..end])
+  }
+
+  fn u32(&mut self) -> io::Result<u32> {
+    let bytes = self.bytes(4)?;
+    Ok(u32::from_le_bytes(
+      bytes.try_into().expect("should have 4 bytes"),
+    ))
+  }
+
+  fn u64(&mut self) -> io::Result<u64> {
+    let bytes = self.bytes(8)?;
+    Ok(u64::from_le_bytes(
+      bytes.try_into().expect("should have 8 bytes"),
+    ))
+  }
+
+  fn string(&mut self) -> io::Result<String> {
+    let len = self.u32()? as usize;
+    String::from_utf8(self.bytes(len)?.to_vec())
+      .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  impl Persistable for String {
+    fn encode(&self) -> Option<Vec<u8>> {
+      Some(self.as_bytes().to_vec())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+      String::from_utf8(bytes.to_vec()).ok()
+    }
+  }
+
+  fn options(version: &str) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      version: version.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[tokio::test]
+  async fn test_restore_and_invalidate() {
+    let location = std::env::temp_dir().join(format!("rspack-pack-test-{}", std::process::id()));
+    let storage = PackStorage::<String>::new(location.clone(), "test", &options("1"));
+    storage.set("a".into(), "foo".to_string());
+    storage.begin_idle().await;
+
+    let storage = PackStorage::<String>::new(location.clone(), "test", &options("1"));
+    assert_eq!(storage.get(&"a".into()).as_deref(), Some("foo"));
+
+    let storage = PackStorage::<String>::new(location.clone(), "test", &options("2"));
+    assert_eq!(storage.get(&"a".into()), None);
+
+    let _ = fs::remove_dir_all(location);
+  }
+}
@@ -159,6 +159,17 @@ impl ChunkGraph {
     chunk_graph_chunk.entry_modules.keys().cloned().collect()
   }
 
+  pub fn is_entry_module_in_chunk(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    chunk_ukey: &ChunkUkey,
+  ) -> bool {
+    let chunk_graph_chunk = self.get_chunk_graph_chunk(chunk_ukey);
+    chunk_graph_chunk
+      .entry_modules
+      .contains_key(module_identifier)
+  }
+
   pub fn get_chunk_entry_modules_with_chunk_group_iterable(
     &self,
     chunk_ukey: &ChunkUkey,
@@ -9,8 +9,8 @@ use rustc_hash::FxHashSet as HashSet;
 use crate::{
   get_chunk_from_ukey, Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey, FilenameTemplate,
 };
-use crate::{ChunkLoading, ChunkUkey, Compilation};
-use crate::{LibraryOptions, ModuleIdentifier, PublicPath};
+use crate::{ChunkLoading, ChunkUkey, Compilation, WasmLoading};
+use crate::{LibraryOptions, ModuleIdentifier, ModuleLayer, PublicPath};
 
 impl DatabaseItem for ChunkGroup {
   fn ukey(&self) -> rspack_database::Ukey<Self> {
@@ -333,12 +333,14 @@ pub struct EntryOptions {
   pub name: Option<String>,
   pub runtime: Option<EntryRuntime>,
   pub chunk_loading: Option<ChunkLoading>,
+  pub wasm_loading: Option<WasmLoading>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
   pub filename: Option<FilenameTemplate>,
   pub library: Option<LibraryOptions>,
   pub depend_on: Option<Vec<String>>,
+  pub layer: Option<ModuleLayer>,
 }
 
 impl EntryOptions {
@@ -357,12 +359,14 @@ impl EntryOptions {
     merge_field!(name);
     merge_field!(runtime);
     merge_field!(chunk_loading);
+    merge_field!(wasm_loading);
     merge_field!(async_chunks);
     merge_field!(public_path);
     merge_field!(base_uri);
     merge_field!(filename);
     merge_field!(library);
     merge_field!(depend_on);
+    merge_field!(layer);
     Ok(())
   }
 
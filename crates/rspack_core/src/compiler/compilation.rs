@@ -11,6 +11,7 @@ use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_error::{error, Diagnostic, Result, Severity, TWithDiagnosticArray};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_futures::FuturesResults;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::define_hook;
@@ -24,6 +25,7 @@ use super::{
   hmr::CompilationRecords,
   make::{update_module_graph, MakeArtifact, MakeParam},
   module_executor::ModuleExecutor,
+  records::IdRecords,
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
@@ -32,12 +34,12 @@ use crate::{
   to_identifier,
   tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
   BoxDependency, BoxModule, CacheCount, CacheOptions, Chunk, ChunkByUkey, ChunkContentHash,
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkKind, ChunkUkey, CodeGenerationResults,
-  CompilationLogger, CompilationLogging, CompilerOptions, DependencyId, DependencyType, Entry,
-  EntryData, EntryOptions, Entrypoint, ErrorSpan, Filename, ImportVarMap, LocalFilenameFn, Logger,
-  Module, ModuleFactory, ModuleGraph, ModuleGraphPartial, ModuleIdentifier, PathData,
-  ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec, SharedPluginDriver, SourceType,
-  Stats,
+  ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkKind, ChunkLoading, ChunkLoadingType,
+  ChunkUkey, CodeGenerationResults, CompilationLogger, CompilationLogging, CompilerOptions,
+  DependencyId, DependencyType, Entry, EntryData, EntryOptions, Entrypoint, ErrorSpan, Filename,
+  ImportVarMap, LocalFilenameFn, Logger, Module, ModuleFactory, ModuleGraph, ModuleGraphPartial,
+  ModuleIdentifier, PathData, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec,
+  SharedPluginDriver, SourceType, Stats, WasmLoading, WasmLoadingType,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, ExecuteModuleId};
 
@@ -134,6 +136,8 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// Ids recorded by a previous build, `None` when records are disabled.
+  pub id_records: Option<IdRecords>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -169,9 +173,11 @@ pub struct Compilation {
   pub code_generated_modules: IdentifierSet,
   pub cache: Arc<Cache>,
   pub code_splitting_cache: CodeSplittingCache,
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
   pub hash: Option<RspackHashDigest>,
   // lazy compilation visit module
   pub lazy_visit_modules: std::collections::HashSet<String>,
+  pub used_module_ids: HashSet<String>,
   pub used_chunk_ids: HashSet<String>,
   pub include_module_ids: IdentifierSet,
 
@@ -218,12 +224,14 @@ impl Compilation {
     loader_resolver_factory: Arc<ResolverFactory>,
     records: Option<CompilationRecords>,
     cache: Arc<Cache>,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem>,
     module_executor: Option<ModuleExecutor>,
   ) -> Self {
     Self {
       id: CompilationId::new(),
       hot_index: 0,
       records,
+      id_records: None,
       options,
       other_module_graph: None,
       dependency_factories: Default::default(),
@@ -257,8 +265,10 @@ impl Compilation {
       code_generated_modules: Default::default(),
       cache,
       code_splitting_cache: Default::default(),
+      input_filesystem,
       hash: None,
       lazy_visit_modules: Default::default(),
+      used_module_ids: Default::default(),
       used_chunk_ids: Default::default(),
 
       file_dependencies: Default::default(),
@@ -344,7 +354,61 @@ impl Compilation {
     import_var
   }
 
+  fn check_entry_layer(&self, options: &EntryOptions) -> Result<()> {
+    if options.layer.is_some() && !self.options.experiments.layers {
+      return Err(error!(
+        "'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
+      ));
+    }
+    Ok(())
+  }
+
+  /// Custom chunk and wasm loading types of the entry, or of the output when the entry doesn't
+  /// specify one, must be registered by the plugin providing their runtime.
+  fn check_entry_loading_types(&self, options: &EntryOptions) -> Result<()> {
+    let output = &self.options.output;
+    for chunk_loading in [
+      options
+        .chunk_loading
+        .as_ref()
+        .unwrap_or(&output.chunk_loading),
+      &output.worker_chunk_loading,
+    ] {
+      if let ChunkLoading::Enable(ChunkLoadingType::Custom(ty)) = chunk_loading
+        && !self
+          .plugin_driver
+          .registered_chunk_loading_types
+          .contains(&ChunkLoadingType::Custom(ty.clone()))
+      {
+        return Err(error!(
+          "Chunk loading type \"{ty}\" is not enabled. Plugins which provide custom chunk loading types must register them with `ApplyContext::register_chunk_loading_type`"
+        ));
+      }
+    }
+    for wasm_loading in [
+      options
+        .wasm_loading
+        .as_ref()
+        .unwrap_or(&output.wasm_loading),
+      &output.worker_wasm_loading,
+    ] {
+      if let WasmLoading::Enable(WasmLoadingType::Custom(ty)) = wasm_loading
+        && !self
+          .plugin_driver
+          .registered_wasm_loading_types
+          .contains(&WasmLoadingType::Custom(ty.clone()))
+      {
+        return Err(error!(
+          "Wasm loading type \"{ty}\" is not enabled. Plugins which provide custom wasm loading types must register them with `ApplyContext::register_wasm_loading_type`"
+        ));
+      }
+    }
+    Ok(())
+  }
+
   pub fn add_entry(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
+    self.check_entry_layer(&options)?;
+    self.check_entry_loading_types(&options)?;
     let entry_id = *entry.id();
     self.get_module_graph_mut().add_dependency(entry);
     if let Some(name) = options.name.clone() {
@@ -366,6 +430,8 @@ impl Compilation {
   }
 
   pub async fn add_include(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
+    self.check_entry_layer(&options)?;
+    self.check_entry_loading_types(&options)?;
     let entry_id = *entry.id();
     self.get_module_graph_mut().add_dependency(entry);
     if let Some(name) = options.name.clone() {
@@ -907,9 +973,33 @@ impl Compilation {
       .await?;
     logger.time_end(start);
 
+    let start = logger.time("report dependency errors and warnings");
+    self.collect_dependencies_diagnostics();
+    logger.time_end(start);
+
     Ok(())
   }
 
+  // same as `reportDependencyErrorsAndWarnings` of webpack
+  fn collect_dependencies_diagnostics(&mut self) {
+    let module_graph = self.get_module_graph();
+    let diagnostics = module_graph
+      .modules()
+      .values()
+      .flat_map(|module| {
+        let module_identifier = module.identifier();
+        module
+          .get_dependencies()
+          .iter()
+          .filter_map(|dependency_id| module_graph.dependency_by_id(dependency_id))
+          .filter_map(|dependency| dependency.get_diagnostics(&module_graph))
+          .flatten()
+          .map(move |diagnostic| diagnostic.with_module_identifier(Some(module_identifier)))
+      })
+      .collect::<Vec<_>>();
+    self.push_batch_diagnostic(diagnostics);
+  }
+
   #[instrument(name = "compilation:seal", skip_all)]
   pub async fn seal(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     self.other_module_graph = Some(ModuleGraphPartial::default());
@@ -1634,6 +1724,8 @@ pub struct AssetInfo {
   /// An empty string means no version, it will always emit
   pub version: String,
   pub source_filename: Option<String>,
+  /// the subresource integrity of the asset, e.g. `sha384-...`
+  pub integrity: Option<String>,
 }
 
 impl AssetInfo {
@@ -73,6 +73,7 @@ where
         self.loader_resolver_factory.clone(),
         Some(records),
         self.cache.clone(),
+        self.input_filesystem.clone(),
         // reuse module executor
         std::mem::take(&mut self.compilation.module_executor),
       );
@@ -138,7 +139,7 @@ where
       fast_set(&mut self.compilation, new_compilation);
       self.compile(setup_make_params).await?;
 
-      self.cache.begin_idle();
+      self.cache.begin_idle().await;
     }
 
     self.compile_done().await?;
@@ -93,6 +93,7 @@ impl Task<MakeTaskContext> for AddTask {
       compiler_options: context.compiler_options.clone(),
       plugin_driver: context.plugin_driver.clone(),
       cache: context.cache.clone(),
+      fs: context.fs.clone(),
     })])
   }
 }
@@ -1,6 +1,7 @@
 use std::{collections::VecDeque, sync::Arc};
 
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray};
+use rspack_fs::AsyncReadableFileSystem;
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
@@ -18,6 +19,7 @@ pub struct BuildTask {
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
   pub cache: Arc<Cache>,
+  pub fs: Arc<dyn AsyncReadableFileSystem>,
 }
 
 #[async_trait::async_trait]
@@ -31,6 +33,7 @@ impl Task<MakeTaskContext> for BuildTask {
       resolver_factory,
       plugin_driver,
       cache,
+      fs,
       current_profile,
       mut module,
     } = *self;
@@ -61,6 +64,7 @@ impl Task<MakeTaskContext> for BuildTask {
               },
               plugin_driver: plugin_driver.clone(),
               compiler_options: &compiler_options,
+              fs: fs.clone(),
             },
             None,
           )
@@ -10,18 +10,20 @@ use crate::{
   cache::Cache,
   module_graph::ModuleGraphModule,
   utils::task_loop::{Task, TaskResult, TaskType},
-  BoxDependency, CompilerOptions, Context, DependencyId, ExportInfo, ExportsInfo, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleProfile, Resolve,
-  ResolverFactory, SharedPluginDriver, UsageState,
+  BoxDependency, CompilationId, CompilerOptions, Context, DependencyId, ExportInfo, ExportsInfo,
+  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer,
+  ModuleProfile, Resolve, ResolverFactory, SharedPluginDriver, UsageState,
 };
 
 #[derive(Debug)]
 pub struct FactorizeTask {
+  pub compilation_id: CompilationId,
   pub module_factory: Arc<dyn ModuleFactory>,
   pub original_module_identifier: Option<ModuleIdentifier>,
   pub original_module_source: Option<BoxSource>,
   pub original_module_context: Option<Box<Context>>,
   pub issuer: Option<Box<str>>,
+  pub issuer_layer: Option<ModuleLayer>,
   pub dependency: BoxDependency,
   pub dependencies: Vec<DependencyId>,
   pub is_entry: bool,
@@ -45,6 +47,9 @@ impl Task<MakeTaskContext> for FactorizeTask {
     }
     let dependency = self.dependency;
     //    let dep_id = *dependency.id();
+    let critical = dependency
+      .as_context_dependency()
+      .is_some_and(|dependency| dependency.critical().is_some());
 
     let context = if let Some(context) = dependency.get_context() {
       context
@@ -74,6 +79,7 @@ impl Task<MakeTaskContext> for FactorizeTask {
         other_exports_info,
         side_effects_info: side_effects_only_info,
       },
+      critical_removed: false,
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
       missing_dependencies: Default::default(),
@@ -83,18 +89,28 @@ impl Task<MakeTaskContext> for FactorizeTask {
     // Error and result are not mutually exclusive in webpack module factorization.
     // Rspack puts results that need to be shared in both error and ok in [ModuleFactoryCreateData].
     let mut create_data = ModuleFactoryCreateData {
+      compilation_id: self.compilation_id,
       resolve_options: self.resolve_options,
       context,
       dependency,
       issuer: self.issuer,
       issuer_identifier: self.original_module_identifier,
+      issuer_layer: self.issuer_layer,
 
       file_dependencies: Default::default(),
       missing_dependencies: Default::default(),
       context_dependencies: Default::default(),
       diagnostics: Default::default(),
     };
-    match self.module_factory.create(&mut create_data).await {
+    let result = self.module_factory.create(&mut create_data).await;
+    let factorize_result_task = factorize_result_task.with_critical_removed(
+      critical
+        && create_data
+          .dependency
+          .as_context_dependency()
+          .is_some_and(|dependency| dependency.critical().is_none()),
+    );
+    match result {
       Ok(result) => {
         if let Some(current_profile) = &factorize_result_task.current_profile {
           current_profile.mark_factory_end();
@@ -156,6 +172,8 @@ struct FactorizeResultTask {
   pub is_entry: bool,
   pub current_profile: Option<Box<ModuleProfile>>,
   pub exports_info_related: ExportsInfoRelated,
+  /// The critical warning of a context dependency was removed by a hook
+  pub critical_removed: bool,
 
   pub file_dependencies: HashSet<PathBuf>,
   pub context_dependencies: HashSet<PathBuf>,
@@ -164,6 +182,11 @@ struct FactorizeResultTask {
 }
 
 impl FactorizeResultTask {
+  fn with_critical_removed(mut self, critical_removed: bool) -> Self {
+    self.critical_removed = critical_removed;
+    self
+  }
+
   fn with_factory_result(mut self, factory_result: Option<ModuleFactoryResult>) -> Self {
     self.factory_result = factory_result;
     self
@@ -206,6 +229,7 @@ impl Task<MakeTaskContext> for FactorizeResultTask {
       context_dependencies,
       missing_dependencies,
       diagnostics,
+      critical_removed,
       ..
     } = *self;
     if !diagnostics.is_empty() {
@@ -229,6 +253,13 @@ impl Task<MakeTaskContext> for FactorizeResultTask {
     context.missing_dependencies.extend(missing_dependencies);
     let module_graph =
       &mut MakeTaskContext::get_module_graph_mut(&mut context.module_graph_partial);
+    if critical_removed
+      && let Some(dependency) = module_graph
+        .dependency_by_id_mut(&dependencies[0])
+        .and_then(|dependency| dependency.as_context_dependency_mut())
+    {
+      *dependency.critical_mut() = None;
+    }
     let Some(factory_result) = factory_result else {
       let dep = module_graph
         .dependency_by_id(&dependencies[0])
@@ -7,6 +7,7 @@ use std::{hash::BuildHasherDefault, path::PathBuf, sync::Arc};
 
 use indexmap::IndexSet;
 use rspack_error::{Diagnostic, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
@@ -16,18 +17,20 @@ use crate::{
   module_graph::{ModuleGraph, ModuleGraphPartial},
   tree_shaking::visitor::OptimizeAnalyzeResult,
   utils::task_loop::{run_task_loop, Task},
-  BuildDependency, CacheCount, CacheOptions, Compilation, CompilationLogger, CompilerOptions,
-  DependencyType, Logger, Module, ModuleFactory, ModuleIdentifier, ModuleProfile,
+  BuildDependency, CacheCount, CacheOptions, Compilation, CompilationId, CompilationLogger,
+  CompilerOptions, DependencyType, Logger, Module, ModuleFactory, ModuleIdentifier, ModuleProfile,
   NormalModuleSource, ResolverFactory, SharedPluginDriver,
 };
 
 struct MakeTaskContext {
   // compilation info
+  compilation_id: CompilationId,
   plugin_driver: SharedPluginDriver,
   compiler_options: Arc<CompilerOptions>,
   resolver_factory: Arc<ResolverFactory>,
   loader_resolver_factory: Arc<ResolverFactory>,
   cache: Arc<Cache>,
+  fs: Arc<dyn AsyncReadableFileSystem>,
   dependency_factories: HashMap<DependencyType, Arc<dyn ModuleFactory>>,
 
   // TODO move outof context
@@ -66,11 +69,13 @@ impl MakeTaskContext {
     }
 
     Self {
+      compilation_id: compilation.id(),
       plugin_driver: compilation.plugin_driver.clone(),
       compiler_options: compilation.options.clone(),
       resolver_factory: compilation.resolver_factory.clone(),
       loader_resolver_factory: compilation.loader_resolver_factory.clone(),
       cache: compilation.cache.clone(),
+      fs: compilation.input_filesystem.clone(),
       dependency_factories: compilation.dependency_factories.clone(),
 
       // TODO use timer in tasks
@@ -183,12 +188,17 @@ pub fn repair(
           }
         });
       Some(Box::new(factorize::FactorizeTask {
+        compilation_id: compilation.id(),
         module_factory: compilation.get_dependency_factory(dependency),
         original_module_identifier: parent_module_identifier,
         original_module_source,
         issuer: parent_module
           .and_then(|m| m.as_normal_module())
           .and_then(|module| module.name_for_condition()),
+        issuer_layer: dependency
+          .get_layer()
+          .or_else(|| parent_module.and_then(|m| m.get_layer()))
+          .cloned(),
         original_module_context: parent_module.and_then(|m| m.get_context()),
         dependency: dependency.clone(),
         dependencies: vec![id],
@@ -102,6 +102,7 @@ impl Task<MakeTaskContext> for ProcessDependenciesTask {
         })
         .clone();
       res.push(Box::new(FactorizeTask {
+        compilation_id: context.compilation_id,
         module_factory,
         original_module_identifier: Some(module.identifier()),
         original_module_context: module.get_context(),
@@ -109,6 +110,7 @@ impl Task<MakeTaskContext> for ProcessDependenciesTask {
         issuer: module
           .as_normal_module()
           .and_then(|module| module.name_for_condition()),
+        issuer_layer: module.get_layer().cloned(),
         dependency,
         dependencies,
         is_entry: false,
@@ -2,14 +2,18 @@ mod compilation;
 mod hmr;
 mod make;
 mod module_executor;
+mod native_watcher;
+mod records;
+mod watcher;
+mod watching;
 
 use std::collections::hash_map::Entry;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_error::{error, Result};
+use rspack_fs::{AsyncNativeFileSystem, AsyncReadableFileSystem, AsyncWritableFileSystem};
 use rspack_futures::FuturesResults;
 use rspack_hook::define_hook;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
@@ -22,6 +26,10 @@ pub use self::compilation::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
 pub use self::make::MakeParam;
 pub use self::module_executor::{ExecuteModuleId, ModuleExecutor};
+pub use self::native_watcher::NativeWatcher;
+pub use self::records::{ChunkIdRecords, IdRecords, ModuleIdRecords};
+pub use self::watcher::{PollingWatcher, Watcher, WatcherChanges};
+pub use self::watching::{WatchOptions, Watching, WatchingHandle};
 use crate::cache::Cache;
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
@@ -61,6 +69,9 @@ where
 {
   pub options: Arc<CompilerOptions>,
   pub output_filesystem: T,
+  /// File system requests are resolved against, and modules, context directories and snapshots
+  /// are read from.
+  pub input_filesystem: Arc<dyn AsyncReadableFileSystem>,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
@@ -76,17 +87,30 @@ where
   T: AsyncWritableFileSystem + Send + Sync,
 {
   #[instrument(skip_all)]
-  pub fn new(options: CompilerOptions, plugins: Vec<BoxPlugin>, output_filesystem: T) -> Self {
+  pub fn new(
+    options: CompilerOptions,
+    plugins: Vec<BoxPlugin>,
+    output_filesystem: T,
+    // reads from the native file system when `None`
+    input_filesystem: Option<Arc<dyn AsyncReadableFileSystem>>,
+  ) -> Self {
     #[cfg(debug_assertions)]
     {
       if let Ok(mut debug_info) = crate::debug_info::DEBUG_INFO.lock() {
         debug_info.with_context(options.context.to_string());
       }
     }
-    let resolver_factory = Arc::new(ResolverFactory::new(options.resolve.clone()));
-    let loader_resolver_factory = Arc::new(ResolverFactory::new(options.resolve_loader.clone()));
+    let resolver_factory = Arc::new(ResolverFactory::new(
+      options.resolve.clone(),
+      input_filesystem.clone(),
+    ));
+    let loader_resolver_factory = Arc::new(ResolverFactory::new(
+      options.resolve_loader.clone(),
+      input_filesystem.clone(),
+    ));
     let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
-    let cache = Arc::new(Cache::new(options.clone()));
+    let input_filesystem = input_filesystem.unwrap_or_else(|| Arc::new(AsyncNativeFileSystem));
+    let cache = Arc::new(Cache::new(options.clone(), input_filesystem.clone()));
     assert!(!(options.is_new_tree_shaking() && options.builtins.tree_shaking.enable()), "Can't enable builtins.tree_shaking and `experiments.rspack_future.new_treeshaking` at the same time");
     let module_executor = ModuleExecutor::default();
     Self {
@@ -98,9 +122,11 @@ where
         loader_resolver_factory.clone(),
         None,
         cache.clone(),
+        input_filesystem.clone(),
         Some(module_executor),
       ),
       output_filesystem,
+      input_filesystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
@@ -131,6 +157,7 @@ where
         self.loader_resolver_factory.clone(),
         None,
         self.cache.clone(),
+        self.input_filesystem.clone(),
         Some(module_executor),
       ),
     );
@@ -138,13 +165,14 @@ where
     self
       .compile(vec![MakeParam::ForceBuildDeps(Default::default())])
       .await?;
-    self.cache.begin_idle();
+    self.cache.begin_idle().await;
     self.compile_done().await?;
     Ok(())
   }
 
   #[instrument(name = "compile", skip_all)]
   async fn compile(&mut self, mut params: Vec<MakeParam>) -> Result<()> {
+    self.compilation.id_records = self.read_records().await?;
     let mut compilation_params = self.new_compilation_params();
     // FOR BINDING SAFETY:
     // Make sure `thisCompilation` hook was called for each `JsCompilation` update before any access to it.
@@ -315,6 +343,47 @@ where
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
+    Ok(())
+  }
+
+  async fn read_records(&self) -> Result<Option<IdRecords>> {
+    if self.options.records_input_path.is_none() && self.options.records_output_path.is_none() {
+      return Ok(None);
+    }
+    let Some(records_input_path) = &self.options.records_input_path else {
+      return Ok(Some(IdRecords::default()));
+    };
+    let content = match self.input_filesystem.read(records_input_path).await {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+        return Ok(Some(IdRecords::default()))
+      }
+      Err(e) => return Err(e.into()),
+    };
+    let records = serde_json::from_slice(&content)
+      .map_err(|e| error!("Cannot parse records {}: {e}", records_input_path.display()))?;
+    Ok(Some(records))
+  }
+
+  async fn emit_records(&self) -> Result<()> {
+    let (Some(records_output_path), Some(records)) = (
+      &self.options.records_output_path,
+      &self.compilation.id_records,
+    ) else {
+      return Ok(());
+    };
+    if let Some(dir) = records_output_path.parent() {
+      self.output_filesystem.create_dir_all(dir).await?;
+    }
+    let content = serde_json::to_string_pretty(records).map_err(|e| error!(e.to_string()))?;
+    self
+      .output_filesystem
+      .write(records_output_path, content.as_bytes())
+      .await?;
     Ok(())
   }
 
@@ -435,11 +504,13 @@ where
         self.loader_resolver_factory.clone(),
         self.plugin_driver.clone(),
         self.cache.clone(),
+        self.input_filesystem.clone(),
       )),
       context_module_factory: Arc::new(ContextModuleFactory::new(
         self.loader_resolver_factory.clone(),
         self.plugin_driver.clone(),
         self.cache.clone(),
+        self.input_filesystem.clone(),
       )),
     }
   }
@@ -4,6 +4,7 @@ use std::{hash::BuildHasherDefault, iter::once};
 use dashmap::DashMap;
 use rayon::prelude::*;
 use rspack_error::Result;
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_identifier::{Identifiable, IdentifierSet};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
@@ -14,7 +15,7 @@ use crate::{
   ModuleFactory, SourceType,
 };
 use crate::{Compilation, CompilationAsset, MakeParam};
-use crate::{CompilerOptions, Context, ResolverFactory, SharedPluginDriver};
+use crate::{CompilerOptions, Context, ModuleLayer, ResolverFactory, SharedPluginDriver};
 
 static EXECUTE_MODULE_ID: AtomicU32 = AtomicU32::new(0);
 pub type ExecuteModuleId = u32;
@@ -43,11 +44,13 @@ impl ModuleExecutor {
     resolver_factory: Arc<ResolverFactory>,
     loader_resolver_factory: Arc<ResolverFactory>,
     cache: Arc<Cache>,
+    input_filesystem: Arc<dyn AsyncReadableFileSystem>,
     dependency_factories: HashMap<DependencyType, Arc<dyn ModuleFactory>>,
 
     request: String,
     public_path: Option<String>,
     base_uri: Option<String>,
+    layer: Option<ModuleLayer>,
     original_module_context: Option<Context>,
   ) -> Result<ExecuteModuleResult> {
     let mut compilation = Compilation::new(
@@ -57,6 +60,7 @@ impl ModuleExecutor {
       loader_resolver_factory,
       None,
       cache,
+      input_filesystem,
       None,
     );
     compilation.dependency_factories = dependency_factories;
@@ -66,6 +70,7 @@ impl ModuleExecutor {
       let dep = EntryDependency::new(
         request,
         original_module_context.unwrap_or(Context::from("")),
+        layer.clone(),
       );
       let dep_id = *dep.id();
       mg.add_dependency(Box::new(dep));
@@ -124,12 +129,14 @@ impl ModuleExecutor {
         name: Some("build time".into()),
         runtime: Some("runtime".into()),
         chunk_loading: Some(crate::ChunkLoading::Disable),
+        wasm_loading: None,
         async_chunks: None,
         public_path: public_path.map(crate::PublicPath::String),
         base_uri,
         filename: None,
         library: None,
         depend_on: None,
+        layer,
       }),
     });
 
@@ -0,0 +1,259 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::Notify;
+
+use super::watcher::{read_dir_recursive, stat};
+use super::{WatchOptions, Watcher, WatcherChanges};
+
+/// Watches with the native watcher of the platform, through [notify::recommended_watcher].
+///
+/// Watched files are noticed through their parent directories, so that files replaced by a rename
+/// or created later are reported. Context directories are watched recursively.
+#[derive(Debug)]
+pub struct NativeWatcher {
+  options: WatchOptions,
+  watcher: Arc<Mutex<RecommendedWatcher>>,
+  state: Arc<NativeState>,
+}
+
+#[derive(Debug, Default)]
+struct NativeState {
+  inner: Mutex<NativeStateInner>,
+  notify: Notify,
+}
+
+#[derive(Debug, Default)]
+struct NativeStateInner {
+  /// Watched files, including the missing ones
+  files: HashSet<PathBuf>,
+  /// Directories watched recursively
+  contexts: Vec<PathBuf>,
+  /// Paths handed to the native watcher
+  watched: HashMap<PathBuf, RecursiveMode>,
+  pending: WatcherChanges,
+}
+
+impl NativeWatcher {
+  pub fn new(options: WatchOptions) -> notify::Result<Self> {
+    let state = Arc::new(NativeState::default());
+    let watcher = notify::recommended_watcher({
+      let state = state.clone();
+      let options = options.clone();
+      move |event: notify::Result<Event>| state.handle_event(event, &options)
+    })?;
+    Ok(Self {
+      options,
+      watcher: Arc::new(Mutex::new(watcher)),
+      state,
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl Watcher for NativeWatcher {
+  async fn watch(
+    &mut self,
+    files: Vec<PathBuf>,
+    directories: Vec<PathBuf>,
+    missing: Vec<PathBuf>,
+    start_time: SystemTime,
+  ) {
+    let watcher = self.watcher.clone();
+    let state = self.state.clone();
+    let options = self.options.clone();
+    tokio::task::spawn_blocking(move || {
+      let mut watcher = watcher.lock().expect("should lock the native watcher");
+      state.watch(
+        &mut watcher,
+        files,
+        directories,
+        missing,
+        start_time,
+        &options,
+      )
+    })
+    .await
+    .expect("should watch the dependencies");
+  }
+
+  async fn wait(&mut self) -> WatcherChanges {
+    loop {
+      {
+        let mut state = self.state.lock();
+        if !state.pending.is_empty() {
+          return std::mem::take(&mut state.pending);
+        }
+      }
+      // a notification sent in between is kept for the next waiter
+      self.state.notify.notified().await;
+    }
+  }
+}
+
+impl NativeState {
+  fn lock(&self) -> MutexGuard<'_, NativeStateInner> {
+    self.inner.lock().expect("should lock the watcher state")
+  }
+
+  fn watch(
+    &self,
+    watcher: &mut RecommendedWatcher,
+    files: Vec<PathBuf>,
+    directories: Vec<PathBuf>,
+    missing: Vec<PathBuf>,
+    start_time: SystemTime,
+    options: &WatchOptions,
+  ) {
+    use notify::Watcher as _;
+
+    let files = files.into_iter().chain(missing).collect::<HashSet<_>>();
+    let mut watched = HashMap::default();
+    for directory in &directories {
+      if directory.is_dir() {
+        watched.insert(directory.clone(), RecursiveMode::Recursive);
+      } else if let Some(parent) = existing_parent(directory) {
+        watched.entry(parent).or_insert(RecursiveMode::NonRecursive);
+      }
+    }
+    for path in &files {
+      if let Some(parent) = existing_parent(path) {
+        watched.entry(parent).or_insert(RecursiveMode::NonRecursive);
+      }
+    }
+    // directories below a recursively watched one are already covered
+    let recursive = watched
+      .iter()
+      .filter(|(_, mode)| **mode == RecursiveMode::Recursive)
+      .map(|(path, _)| path.clone())
+      .collect::<Vec<_>>();
+    watched.retain(|path, mode| {
+      *mode == RecursiveMode::Recursive || !recursive.iter().any(|root| path.starts_with(root))
+    });
+
+    // the state is not locked while calling the native watcher, which waits for its event
+    // handler
+    let previous = std::mem::take(&mut self.lock().watched);
+    for (path, mode) in &previous {
+      if watched.get(path) != Some(mode) {
+        let _ = watcher.unwatch(path);
+      }
+    }
+    // e.g. the directory was removed in the meantime
+    watched.retain(|path, mode| {
+      previous.get(path) == Some(&*mode) || watcher.watch(path, *mode).is_ok()
+    });
+
+    // checked once watched, changes in between are reported by both
+    let mut changes = WatcherChanges::default();
+    for directory in &directories {
+      let modified = read_dir_recursive(directory, options)
+        .into_iter()
+        .filter(|(_, entry)| entry.is_modified_after(start_time))
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+      if !modified.is_empty() {
+        changes.changed.extend(modified);
+        changes.changed.insert(directory.clone());
+      }
+    }
+    for path in &files {
+      if let Some(entry) = stat(path)
+        && entry.is_modified_after(start_time)
+      {
+        changes.changed.insert(path.clone());
+      }
+    }
+
+    let mut state = self.lock();
+    state.watched = watched;
+    state.files = files;
+    state.contexts = directories;
+    state.pending.extend(changes);
+    if !state.pending.is_empty() {
+      self.notify.notify_one();
+    }
+  }
+
+  fn handle_event(&self, event: notify::Result<Event>, options: &WatchOptions) {
+    let Ok(event) = event else {
+      return;
+    };
+    if matches!(event.kind, EventKind::Access(_)) {
+      return;
+    }
+
+    let mut state = self.lock();
+    let mut changes = WatcherChanges::default();
+    if event.need_rescan() {
+      // events were dropped, anything may have changed
+      for path in &state.files {
+        record(&mut changes, path, stat(path).is_some());
+      }
+      changes.changed.extend(state.contexts.iter().cloned());
+    }
+    for path in &event.paths {
+      if options.is_ignored(path) {
+        continue;
+      }
+      let exists = stat(path).is_some();
+      if !exists {
+        // the native watcher drops the watches of removed directories
+        state
+          .watched
+          .retain(|watched, _| !watched.starts_with(path));
+      }
+      if state.files.contains(path) {
+        record(&mut changes, path, exists);
+      }
+      for context in &state.contexts {
+        if path.starts_with(context) {
+          if path != context {
+            record(&mut changes, path, exists);
+          }
+          // context modules depend on the directory itself
+          changes.changed.insert(context.clone());
+        }
+      }
+      // a created directory may lead to watched paths, which are watched from it after the
+      // rebuild
+      if exists
+        && matches!(event.kind, EventKind::Create(_))
+        && (state
+          .files
+          .iter()
+          .any(|file| file.starts_with(path) && file != path)
+          || state
+            .contexts
+            .iter()
+            .any(|context| context.starts_with(path)))
+      {
+        changes.changed.insert(path.clone());
+      }
+    }
+    state.pending.extend(changes);
+    if !state.pending.is_empty() {
+      self.notify.notify_one();
+    }
+  }
+}
+
+fn record(changes: &mut WatcherChanges, path: &Path, exists: bool) {
+  if exists {
+    changes.changed.insert(path.to_path_buf());
+  } else {
+    changes.removed.insert(path.to_path_buf());
+  }
+}
+
+/// The nearest existing directory above `path`, watching it reports `path` being created.
+fn existing_parent(path: &Path) -> Option<PathBuf> {
+  path
+    .ancestors()
+    .skip(1)
+    .find(|ancestor| ancestor.is_dir())
+    .map(Path::to_path_buf)
+}
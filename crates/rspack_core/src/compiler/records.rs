@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Module and chunk ids of a previous build, persisted through `recordsInputPath`
+/// and `recordsOutputPath` so that ids stay stable across separate builds.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdRecords {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub modules: Option<ModuleIdRecords>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub chunks: Option<ChunkIdRecords>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleIdRecords {
+  /// Module identifier relative to the context -> module id
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, String>,
+  #[serde(default)]
+  pub used_ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkIdRecords {
+  /// Chunk name -> chunk id
+  #[serde(default)]
+  pub by_name: BTreeMap<String, String>,
+  /// `<chunk group name>:<index of chunk in group>` -> chunk id
+  #[serde(default)]
+  pub by_source: BTreeMap<String, String>,
+  #[serde(default)]
+  pub used_ids: Vec<String>,
+}
@@ -0,0 +1,238 @@
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::Mutex;
+
+use super::WatchOptions;
+
+/// Paths changed or removed since they were handed to [Watcher::watch].
+#[derive(Debug, Default)]
+pub struct WatcherChanges {
+  pub changed: HashSet<PathBuf>,
+  pub removed: HashSet<PathBuf>,
+}
+
+impl WatcherChanges {
+  pub fn is_empty(&self) -> bool {
+    self.changed.is_empty() && self.removed.is_empty()
+  }
+
+  pub fn extend(&mut self, other: WatcherChanges) {
+    for path in other.changed {
+      self.removed.remove(&path);
+      self.changed.insert(path);
+    }
+    for path in other.removed {
+      self.changed.remove(&path);
+      self.removed.insert(path);
+    }
+  }
+}
+
+/// Backend reporting changes of the dependencies of a compilation.
+#[async_trait::async_trait]
+pub trait Watcher: Debug + Send {
+  /// Replaces the watched paths.
+  ///
+  /// Paths modified after `start_time` are reported by the next [Watcher::wait], as they changed
+  /// while the compilation was running.
+  async fn watch(
+    &mut self,
+    files: Vec<PathBuf>,
+    directories: Vec<PathBuf>,
+    missing: Vec<PathBuf>,
+    start_time: SystemTime,
+  );
+
+  /// Waits for the next non-empty batch of changes. Must be cancel safe.
+  async fn wait(&mut self) -> WatcherChanges;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Entry {
+  File(Option<SystemTime>),
+  Directory,
+}
+
+impl Entry {
+  pub(super) fn is_modified_after(&self, time: SystemTime) -> bool {
+    matches!(self, Entry::File(Some(mtime)) if *mtime > time)
+  }
+}
+
+/// Watches by comparing modification times every [WatchOptions::poll] milliseconds.
+#[derive(Debug)]
+pub struct PollingWatcher {
+  options: WatchOptions,
+  // the file system is read in blocking tasks, which keep the state when [Watcher::wait] is
+  // cancelled while they run
+  state: Arc<Mutex<PollingState>>,
+}
+
+#[derive(Debug, Default)]
+struct PollingState {
+  files: HashMap<PathBuf, Option<Entry>>,
+  /// Directory -> entries below it, recursively
+  directories: HashMap<PathBuf, HashMap<PathBuf, Entry>>,
+  pending: WatcherChanges,
+}
+
+impl PollingWatcher {
+  pub fn new(options: WatchOptions) -> Self {
+    Self {
+      options,
+      state: Default::default(),
+    }
+  }
+}
+
+impl PollingState {
+  fn watch(
+    &mut self,
+    files: Vec<PathBuf>,
+    directories: Vec<PathBuf>,
+    missing: Vec<PathBuf>,
+    start_time: SystemTime,
+    options: &WatchOptions,
+  ) {
+    self.files.clear();
+    for path in files.into_iter().chain(missing) {
+      let entry = stat(&path);
+      if let Some(entry) = &entry
+        && entry.is_modified_after(start_time)
+      {
+        self.pending.changed.insert(path.clone());
+      }
+      self.files.insert(path, entry);
+    }
+
+    self.directories.clear();
+    for directory in directories {
+      let entries = read_dir_recursive(&directory, options);
+      let modified = entries
+        .iter()
+        .filter(|(_, entry)| entry.is_modified_after(start_time))
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>();
+      if !modified.is_empty() {
+        self.pending.changed.extend(modified);
+        self.pending.changed.insert(directory.clone());
+      }
+      self.directories.insert(directory, entries);
+    }
+  }
+
+  fn scan(&mut self, options: &WatchOptions) {
+    let mut changes = WatcherChanges::default();
+    for (path, entry) in self.files.iter_mut() {
+      let current = stat(path);
+      if current != *entry {
+        if current.is_some() {
+          changes.changed.insert(path.clone());
+        } else {
+          changes.removed.insert(path.clone());
+        }
+        *entry = current;
+      }
+    }
+    for (directory, entries) in self.directories.iter_mut() {
+      let current = read_dir_recursive(directory, options);
+      let mut dirty = false;
+      for (path, entry) in &current {
+        if entries.get(path) != Some(entry) {
+          changes.changed.insert(path.clone());
+          dirty = true;
+        }
+      }
+      for path in entries.keys() {
+        if !current.contains_key(path) {
+          changes.removed.insert(path.clone());
+          dirty = true;
+        }
+      }
+      if dirty {
+        // context modules depend on the directory itself
+        changes.changed.insert(directory.clone());
+        *entries = current;
+      }
+    }
+    self.pending.extend(changes);
+  }
+}
+
+#[async_trait::async_trait]
+impl Watcher for PollingWatcher {
+  async fn watch(
+    &mut self,
+    files: Vec<PathBuf>,
+    directories: Vec<PathBuf>,
+    missing: Vec<PathBuf>,
+    start_time: SystemTime,
+  ) {
+    let state = self.state.clone();
+    let options = self.options.clone();
+    tokio::task::spawn_blocking(move || {
+      state
+        .blocking_lock()
+        .watch(files, directories, missing, start_time, &options)
+    })
+    .await
+    .expect("should watch the dependencies");
+  }
+
+  async fn wait(&mut self) -> WatcherChanges {
+    loop {
+      {
+        let mut state = self.state.lock().await;
+        if !state.pending.is_empty() {
+          return std::mem::take(&mut state.pending);
+        }
+      }
+      tokio::time::sleep(Duration::from_millis(self.options.poll_interval())).await;
+      let state = self.state.clone();
+      let options = self.options.clone();
+      tokio::task::spawn_blocking(move || state.blocking_lock().scan(&options))
+        .await
+        .expect("should scan the dependencies");
+    }
+  }
+}
+
+pub(super) fn stat(path: &Path) -> Option<Entry> {
+  let metadata = fs::metadata(path).ok()?;
+  if metadata.is_dir() {
+    Some(Entry::Directory)
+  } else {
+    Some(Entry::File(metadata.modified().ok()))
+  }
+}
+
+pub(super) fn read_dir_recursive(
+  directory: &Path,
+  options: &WatchOptions,
+) -> HashMap<PathBuf, Entry> {
+  let mut entries = HashMap::default();
+  let mut queue = vec![directory.to_path_buf()];
+  while let Some(directory) = queue.pop() {
+    let Ok(read_dir) = fs::read_dir(&directory) else {
+      continue;
+    };
+    for path in read_dir.flatten().map(|entry| entry.path()) {
+      if options.is_ignored(&path) {
+        continue;
+      }
+      let Some(entry) = stat(&path) else {
+        continue;
+      };
+      if entry == Entry::Directory {
+        queue.push(path.clone());
+      }
+      entries.insert(path, entry);
+    }
+  }
+  entries
+}
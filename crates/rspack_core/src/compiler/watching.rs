@@ -0,0 +1,454 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use rspack_error::Result;
+use rspack_fs::AsyncWritableFileSystem;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use super::{Compiler, NativeWatcher, PollingWatcher, Watcher, WatcherChanges};
+use crate::Stats;
+
+const DEFAULT_AGGREGATE_TIMEOUT: u64 = 20;
+const DEFAULT_POLL_INTERVAL: u64 = 100;
+
+#[derive(Debug, Clone, Default)]
+pub struct WatchOptions {
+  /// Milliseconds without further changes before rebuilding, defaults to 20
+  pub aggregate_timeout: Option<u64>,
+  /// Polls every given milliseconds with the [PollingWatcher] instead of using the
+  /// [NativeWatcher]. The [PollingWatcher] is also used when the [NativeWatcher] can't be created,
+  /// polling every 100 milliseconds by default
+  pub poll: Option<u64>,
+  /// Globs of paths which are not watched, matched against absolute paths and their parent
+  /// directories, e.g. `**/node_modules`
+  pub ignored: Vec<String>,
+}
+
+impl WatchOptions {
+  pub fn aggregate_timeout(&self) -> u64 {
+    self.aggregate_timeout.unwrap_or(DEFAULT_AGGREGATE_TIMEOUT)
+  }
+
+  pub fn poll_interval(&self) -> u64 {
+    self.poll.unwrap_or(DEFAULT_POLL_INTERVAL)
+  }
+
+  pub fn is_ignored(&self, path: &Path) -> bool {
+    if self.ignored.is_empty() {
+      return false;
+    }
+    path.ancestors().any(|path| {
+      let path = path.to_string_lossy().replace('\\', "/");
+      self
+        .ignored
+        .iter()
+        .any(|glob| glob_match::glob_match(glob, &path))
+    })
+  }
+}
+
+#[derive(Debug)]
+enum WatchingCommand {
+  Invalidate,
+  Suspend,
+  Resume,
+  Close,
+}
+
+/// Controls a [Watching] from other tasks.
+#[derive(Debug, Clone)]
+pub struct WatchingHandle {
+  sender: UnboundedSender<WatchingCommand>,
+}
+
+impl WatchingHandle {
+  /// Rebuilds with the changes collected so far, even if there are none.
+  pub fn invalidate(&self) {
+    self.send(WatchingCommand::Invalidate);
+  }
+
+  /// Keeps collecting changes without rebuilding until [WatchingHandle::resume].
+  pub fn suspend(&self) {
+    self.send(WatchingCommand::Suspend);
+  }
+
+  pub fn resume(&self) {
+    self.send(WatchingCommand::Resume);
+  }
+
+  /// Stops watching, [Watching::watch] returns once the running build is done.
+  pub fn close(&self) {
+    self.send(WatchingCommand::Close);
+  }
+
+  fn send(&self, command: WatchingCommand) {
+    // nothing to control once the watching is dropped
+    let _ = self.sender.send(command);
+  }
+}
+
+/// Rebuilds the compiler whenever the dependencies of the last compilation change.
+#[derive(Debug)]
+pub struct Watching<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  pub compiler: Compiler<T>,
+  sender: UnboundedSender<WatchingCommand>,
+  collector: ChangeCollector,
+}
+
+impl<T> Watching<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  pub fn new(compiler: Compiler<T>, options: WatchOptions) -> Self {
+    let watcher = default_watcher(&options);
+    Self::with_watcher(compiler, options, watcher)
+  }
+
+  pub fn with_watcher(
+    compiler: Compiler<T>,
+    options: WatchOptions,
+    watcher: Box<dyn Watcher>,
+  ) -> Self {
+    let (sender, receiver) = unbounded_channel();
+    Self {
+      compiler,
+      sender,
+      collector: ChangeCollector::new(options, watcher, receiver),
+    }
+  }
+
+  pub fn handle(&self) -> WatchingHandle {
+    WatchingHandle {
+      sender: self.sender.clone(),
+    }
+  }
+
+  /// Builds, then rebuilds on changes until closed. `handler` is called after each build.
+  pub async fn watch<F>(&mut self, mut handler: F)
+  where
+    F: FnMut(Result<Stats<'_>>),
+  {
+    let mut start_time = SystemTime::now();
+    let result = self.compiler.build().await;
+    handler(result.map(|_| self.compiler.compilation.get_stats()));
+
+    loop {
+      self.watch_dependencies(start_time).await;
+      let Some(changes) = self.collector.wait_for_changes().await else {
+        return;
+      };
+
+      start_time = SystemTime::now();
+      let result = self
+        .compiler
+        .rebuild(to_strings(changes.changed), to_strings(changes.removed))
+        .await;
+      handler(result.map(|_| self.compiler.compilation.get_stats()));
+    }
+  }
+
+  async fn watch_dependencies(&mut self, start_time: SystemTime) {
+    let compilation = &self.compiler.compilation;
+    let options = &self.collector.options;
+    let collect = |paths: &mut dyn Iterator<Item = &PathBuf>| {
+      paths
+        .filter(|path| !options.is_ignored(path))
+        .cloned()
+        .collect::<Vec<_>>()
+    };
+
+    let files = collect(
+      &mut compilation
+        .file_dependencies
+        .iter()
+        .chain(compilation.build_dependencies.iter()),
+    );
+    let directories = collect(&mut compilation.context_dependencies.iter());
+    let missing = collect(&mut compilation.missing_dependencies.iter());
+    self
+      .collector
+      .watcher
+      .watch(files, directories, missing, start_time)
+      .await;
+  }
+}
+
+/// Collects the changes reported by the watcher, following the commands of the handles.
+#[derive(Debug)]
+struct ChangeCollector {
+  options: WatchOptions,
+  watcher: Box<dyn Watcher>,
+  receiver: UnboundedReceiver<WatchingCommand>,
+  suspended: bool,
+}
+
+impl ChangeCollector {
+  fn new(
+    options: WatchOptions,
+    watcher: Box<dyn Watcher>,
+    receiver: UnboundedReceiver<WatchingCommand>,
+  ) -> Self {
+    Self {
+      options,
+      watcher,
+      receiver,
+      suspended: false,
+    }
+  }
+
+  /// Collects changes until none arrive within the aggregate timeout, `None` once closed.
+  async fn wait_for_changes(&mut self) -> Option<WatcherChanges> {
+    let aggregate_timeout = Duration::from_millis(self.options.aggregate_timeout());
+    let mut changes = WatcherChanges::default();
+    let mut invalidated = false;
+
+    loop {
+      let ready = invalidated || (!changes.is_empty() && !self.suspended);
+      tokio::select! {
+        command = self.receiver.recv() => match command {
+          Some(WatchingCommand::Invalidate) => invalidated = true,
+          Some(WatchingCommand::Suspend) => self.suspended = true,
+          Some(WatchingCommand::Resume) => self.suspended = false,
+          Some(WatchingCommand::Close) | None => return None,
+        },
+        mut batch = self.watcher.wait() => {
+          batch.changed.retain(|path| !self.options.is_ignored(path));
+          batch.removed.retain(|path| !self.options.is_ignored(path));
+          changes.extend(batch);
+        },
+        _ = tokio::time::sleep(aggregate_timeout), if ready => return Some(changes),
+      }
+    }
+  }
+}
+
+/// The native watcher of the platform, unless [WatchOptions::poll] asks for polling or the native
+/// watcher can't be created.
+fn default_watcher(options: &WatchOptions) -> Box<dyn Watcher> {
+  if options.poll.is_none()
+    && let Ok(watcher) = NativeWatcher::new(options.clone())
+  {
+    return Box::new(watcher);
+  }
+  Box::new(PollingWatcher::new(options.clone()))
+}
+
+fn to_strings(paths: impl IntoIterator<Item = PathBuf>) -> HashSet<String> {
+  paths
+    .into_iter()
+    .map(|path| path.to_string_lossy().to_string())
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use std::fs;
+
+  use super::*;
+
+  /// Reports the changes sent by the test.
+  #[derive(Debug)]
+  struct ChannelWatcher {
+    receiver: UnboundedReceiver<WatcherChanges>,
+  }
+
+  #[async_trait::async_trait]
+  impl Watcher for ChannelWatcher {
+    async fn watch(
+      &mut self,
+      _files: Vec<PathBuf>,
+      _directories: Vec<PathBuf>,
+      _missing: Vec<PathBuf>,
+      _start_time: SystemTime,
+    ) {
+    }
+
+    async fn wait(&mut self) -> WatcherChanges {
+      match self.receiver.recv().await {
+        Some(changes) => changes,
+        None => std::future::pending().await,
+      }
+    }
+  }
+
+  fn collector(
+    aggregate_timeout: u64,
+  ) -> (
+    ChangeCollector,
+    WatchingHandle,
+    UnboundedSender<WatcherChanges>,
+  ) {
+    let (sender, receiver) = unbounded_channel();
+    let (changes, changes_receiver) = unbounded_channel();
+    let options = WatchOptions {
+      aggregate_timeout: Some(aggregate_timeout),
+      ..Default::default()
+    };
+    let watcher = Box::new(ChannelWatcher {
+      receiver: changes_receiver,
+    });
+    (
+      ChangeCollector::new(options, watcher, receiver),
+      WatchingHandle { sender },
+      changes,
+    )
+  }
+
+  fn changed(path: &str) -> WatcherChanges {
+    let mut changes = WatcherChanges::default();
+    changes.changed.insert(PathBuf::from(path));
+    changes
+  }
+
+  async fn wait_for_changes(
+    collector: &mut ChangeCollector,
+    millis: u64,
+  ) -> Option<Option<WatcherChanges>> {
+    tokio::time::timeout(Duration::from_millis(millis), collector.wait_for_changes())
+      .await
+      .ok()
+  }
+
+  #[tokio::test]
+  async fn changes_within_the_aggregate_timeout_are_collected_once() {
+    let (mut collector, _handle, changes) = collector(300);
+    changes.send(changed("/a.js")).unwrap();
+    let send = async {
+      tokio::time::sleep(Duration::from_millis(100)).await;
+      changes.send(changed("/b.js")).unwrap();
+    };
+    let (collected, _) = tokio::join!(wait_for_changes(&mut collector, 5000), send);
+    let collected = collected.unwrap().unwrap();
+    assert_eq!(collected.changed.len(), 2);
+    assert!(collected.changed.contains(Path::new("/a.js")));
+    assert!(collected.changed.contains(Path::new("/b.js")));
+    assert!(wait_for_changes(&mut collector, 600).await.is_none());
+  }
+
+  #[tokio::test]
+  async fn suspend_resume_invalidate_and_close() {
+    let (mut collector, handle, changes) = collector(50);
+
+    // changes are collected while suspended, and reported once resumed
+    handle.suspend();
+    changes.send(changed("/a.js")).unwrap();
+    let resumed = std::sync::atomic::AtomicBool::new(false);
+    let resume = async {
+      tokio::time::sleep(Duration::from_millis(300)).await;
+      resumed.store(true, std::sync::atomic::Ordering::SeqCst);
+      handle.resume();
+    };
+    let (collected, _) = tokio::join!(wait_for_changes(&mut collector, 5000), resume);
+    assert!(resumed.load(std::sync::atomic::Ordering::SeqCst));
+    assert!(collected
+      .unwrap()
+      .unwrap()
+      .changed
+      .contains(Path::new("/a.js")));
+
+    // invalidating reports without changes
+    assert!(wait_for_changes(&mut collector, 200).await.is_none());
+    handle.invalidate();
+    let collected = wait_for_changes(&mut collector, 5000)
+      .await
+      .unwrap()
+      .unwrap();
+    assert!(collected.is_empty());
+
+    handle.close();
+    assert!(wait_for_changes(&mut collector, 5000)
+      .await
+      .unwrap()
+      .is_none());
+  }
+
+  /// Waits until the changes reported by `watcher` satisfy `done`.
+  async fn wait_until(watcher: &mut dyn Watcher, done: impl Fn(&WatcherChanges) -> bool) {
+    let mut changes = WatcherChanges::default();
+    tokio::time::timeout(Duration::from_secs(10), async {
+      while !done(&changes) {
+        changes.extend(watcher.wait().await);
+      }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("should report the changes, got {changes:?}"));
+  }
+
+  async fn reports_file_changes(options: WatchOptions, name: &str) {
+    let dir = std::env::temp_dir().join(format!("rspack_watching_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let locale = dir.join("locale");
+    fs::create_dir_all(&locale).expect("should create temp dir");
+    fs::write(dir.join("a.js"), "a").expect("should write file");
+    fs::write(locale.join("en.js"), "en").expect("should write file");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let mut watcher = default_watcher(&options);
+    watcher
+      .watch(
+        vec![dir.join("a.js")],
+        vec![locale.clone()],
+        vec![dir.join("b.js")],
+        SystemTime::now(),
+      )
+      .await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    fs::write(dir.join("a.js"), "a2").expect("should write file");
+    wait_until(&mut *watcher, |changes| {
+      changes.changed.contains(&dir.join("a.js"))
+    })
+    .await;
+
+    fs::write(dir.join("b.js"), "b").expect("should write file");
+    wait_until(&mut *watcher, |changes| {
+      changes.changed.contains(&dir.join("b.js"))
+    })
+    .await;
+
+    // context modules depend on the directory itself
+    fs::write(locale.join("fr.js"), "fr").expect("should write file");
+    wait_until(&mut *watcher, |changes| {
+      changes.changed.contains(&locale.join("fr.js")) && changes.changed.contains(&locale)
+    })
+    .await;
+
+    fs::remove_file(locale.join("en.js")).expect("should remove file");
+    wait_until(&mut *watcher, |changes| {
+      changes.removed.contains(&locale.join("en.js"))
+    })
+    .await;
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn native_watcher_reports_file_changes() {
+    reports_file_changes(WatchOptions::default(), "native").await;
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn polling_watcher_reports_file_changes() {
+    let options = WatchOptions {
+      poll: Some(20),
+      ..Default::default()
+    };
+    reports_file_changes(options, "polling").await;
+  }
+
+  #[test]
+  fn ignored_matches_parent_directories() {
+    let options = WatchOptions {
+      ignored: vec!["**/node_modules".to_string()],
+      ..Default::default()
+    };
+    assert!(options.is_ignored(Path::new("/project/node_modules")));
+    assert!(options.is_ignored(Path::new("/project/node_modules/react/index.js")));
+    assert!(!options.is_ignored(Path::new("/project/src/index.js")));
+    assert!(!WatchOptions::default().is_ignored(Path::new("/project/node_modules")));
+  }
+}
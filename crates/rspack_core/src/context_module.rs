@@ -1,6 +1,6 @@
 use std::{
   borrow::Cow,
-  fs,
+  collections::BTreeMap,
   hash::Hash,
   path::{Path, PathBuf},
   sync::Arc,
@@ -11,7 +11,8 @@ use indoc::formatdoc;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
-use rspack_error::{impl_empty_diagnosable_trait, miette::IntoDiagnostic, Diagnostic, Result};
+use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_macros::impl_source_map_config;
@@ -28,7 +29,7 @@ use crate::{
   ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation, ConcatenationScope,
   ContextElementDependency, DependenciesBlock, Dependency, DependencyCategory, DependencyId,
   DynamicImportMode, ExportsType, FactoryMeta, FakeNamespaceObjectMode, GroupOptions,
-  LibIdentOptions, Module, ModuleType, Resolve, ResolveInnerOptions,
+  LibIdentOptions, Module, ModuleLayer, ModuleType, Resolve, ResolveInnerOptions,
   ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
@@ -159,6 +160,9 @@ pub struct ContextModuleOptions {
   pub resource_fragment: String,
   pub context_options: ContextOptions,
   pub resolve_options: Option<Box<Resolve>>,
+  pub layer: Option<ModuleLayer>,
+  /// User request -> request, used instead of reading the context directory when set
+  pub context_map: Option<BTreeMap<String, String>>,
 }
 
 pub enum FakeMapValue {
@@ -756,15 +760,22 @@ impl Module for ContextModule {
       id.push(' ');
       id.push_str(&regexp.to_pretty_string(true));
     }
+    if let Some(layer) = &self.options.layer {
+      id = format!("({layer})/{id}");
+    }
     Some(Cow::Owned(id))
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.options.layer.as_ref()
+  }
+
   async fn build(
     &mut self,
     build_context: BuildContext<'_>,
     _: Option<&Compilation>,
   ) -> Result<BuildResult> {
-    let (dependencies, blocks) = self.resolve_dependencies()?;
+    let (dependencies, blocks) = self.resolve_dependencies(build_context.fs.as_ref()).await?;
 
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
     self.update_hash(&mut hasher);
@@ -879,21 +890,35 @@ static WEBPACK_CHUNK_NAME_REQUEST_PLACEHOLDER: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"\[request\]").expect("regexp init failed"));
 
 impl ContextModule {
-  fn visit_dirs(
+  #[async_recursion::async_recursion]
+  async fn visit_dirs(
+    fs: &dyn AsyncReadableFileSystem,
     ctx: &str,
     dir: &Path,
     dependencies: &mut Vec<ContextElementDependency>,
     options: &ContextModuleOptions,
-    resolve_options: &ResolveInnerOptions,
+    resolve_options: &ResolveInnerOptions<'_>,
   ) -> Result<()> {
-    if !dir.is_dir() {
+    if !fs
+      .metadata(dir)
+      .await
+      .map(|metadata| metadata.is_directory)
+      .unwrap_or_default()
+    {
       return Ok(());
     }
-    for entry in fs::read_dir(dir).into_diagnostic()? {
-      let path = entry.into_diagnostic()?.path();
-      if path.is_dir() {
+    let mut entries = fs.read_dir(dir).await?;
+    // keep the order of the native file system which lists entries alphabetically on most platforms
+    entries.sort();
+    for entry in entries {
+      let path = dir.join(entry);
+      // e.g. dangling symbolic links
+      let Ok(metadata) = fs.metadata(&path).await else {
+        continue;
+      };
+      if metadata.is_directory {
         if options.context_options.recursive {
-          Self::visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
+          Self::visit_dirs(fs, ctx, &path, dependencies, options, resolve_options).await?;
         }
       } else if path
         .file_name()
@@ -951,7 +976,10 @@ impl ContextModule {
     Ok(())
   }
 
-  fn resolve_dependencies(&self) -> Result<(Vec<BoxDependency>, Vec<AsyncDependenciesBlock>)> {
+  async fn resolve_dependencies(
+    &self,
+    fs: &dyn AsyncReadableFileSystem,
+  ) -> Result<(Vec<BoxDependency>, Vec<AsyncDependenciesBlock>)> {
     tracing::trace!("resolving context module path {}", self.options.resource);
 
     let resolver = &self.resolve_factory.get(ResolveOptionsWithDependencyType {
@@ -961,13 +989,36 @@ impl ContextModule {
     });
 
     let mut context_element_dependencies = vec![];
-    Self::visit_dirs(
-      &self.options.resource,
-      Path::new(&self.options.resource),
-      &mut context_element_dependencies,
-      &self.options,
-      &resolver.options(),
-    )?;
+    if let Some(context_map) = &self.options.context_map {
+      for (user_request, request) in context_map {
+        context_element_dependencies.push(ContextElementDependency {
+          id: DependencyId::new(),
+          request: format!(
+            "{}{}{}{}",
+            self.options.addon,
+            request,
+            self.options.resource_query,
+            self.options.resource_fragment,
+          ),
+          user_request: user_request.to_string(),
+          category: self.options.context_options.category,
+          context: self.options.resource.clone().into(),
+          options: self.options.context_options.clone(),
+          resource_identifier: format!("context{}|{}", &self.options.resource, request),
+          referenced_exports: None,
+        });
+      }
+    } else {
+      Self::visit_dirs(
+        fs,
+        &self.options.resource,
+        Path::new(&self.options.resource),
+        &mut context_element_dependencies,
+        &self.options,
+        &resolver.options(),
+      )
+      .await?;
+    }
     context_element_dependencies.sort_by_cached_key(|d| d.user_request.to_string());
 
     tracing::trace!(
@@ -1108,6 +1159,10 @@ fn create_identifier(options: &ContextModuleOptions) -> Identifier {
     ContextNameSpaceObject::Bool(true) => "|namespace object",
     _ => "",
   };
+  if let Some(layer) = &options.layer {
+    id += "|layer: ";
+    id += layer;
+  }
   id.into()
 }
 
@@ -1173,3 +1228,106 @@ fn alternative_requests(
 
   items
 }
+
+#[cfg(test)]
+mod test {
+  use std::path::Path;
+
+  use rspack_fs::{AsyncReadableFileSystem, MemoryFileSystem, WritableFileSystem};
+  use rspack_regex::RspackRegex;
+
+  use super::{ContextMode, ContextModule, ContextModuleOptions, ContextNameSpaceObject};
+  use crate::{
+    ContextOptions, DependencyCategory, Resolve, ResolveOptionsWithDependencyType, ResolverFactory,
+  };
+
+  async fn visit(fs: &dyn AsyncReadableFileSystem, dir: &Path, recursive: bool) -> Vec<String> {
+    let options = ContextModuleOptions {
+      addon: String::new(),
+      resource: dir.to_string_lossy().to_string(),
+      resource_query: String::new(),
+      resource_fragment: String::new(),
+      context_options: ContextOptions {
+        mode: ContextMode::Sync,
+        recursive,
+        reg_exp: Some(RspackRegex::new(r"\.js$").expect("should be valid")),
+        include: None,
+        exclude: None,
+        category: DependencyCategory::CommonJS,
+        request: ".".to_string(),
+        context: dir.to_string_lossy().to_string(),
+        namespace_object: ContextNameSpaceObject::Unset,
+        group_options: None,
+        replaces: vec![],
+        start: 0,
+        end: 0,
+      },
+      resolve_options: None,
+      layer: None,
+      context_map: None,
+    };
+    let resolver_factory = ResolverFactory::new(
+      Resolve {
+        extensions: Some(vec![".js".to_string()]),
+        ..Default::default()
+      },
+      None,
+    );
+    let resolver = resolver_factory.get(ResolveOptionsWithDependencyType {
+      resolve_options: None,
+      resolve_to_context: true,
+      dependency_category: DependencyCategory::CommonJS,
+    });
+    let mut dependencies = vec![];
+    ContextModule::visit_dirs(
+      fs,
+      &options.resource,
+      dir,
+      &mut dependencies,
+      &options,
+      &resolver.options(),
+    )
+    .await
+    .expect("should visit");
+    dependencies
+      .into_iter()
+      .map(|dependency| dependency.user_request)
+      .collect()
+  }
+
+  #[tokio::test]
+  async fn visit_dirs_of_input_filesystem() {
+    let fs = MemoryFileSystem::default();
+    fs.create_dir_all("/app/locale/nested").unwrap();
+    fs.write("/app/locale/fr.js", "").unwrap();
+    fs.write("/app/locale/en.js", "").unwrap();
+    fs.write("/app/locale/.hidden.js", "").unwrap();
+    fs.write("/app/locale/nested/de.js", "").unwrap();
+
+    let dir = Path::new("/app/locale");
+    assert_eq!(visit(&fs, dir, false).await, vec!["./en.js", "./fr.js"]);
+    assert_eq!(
+      visit(&fs, dir, true).await,
+      vec!["./en.js", "./fr.js", "./nested/de.js"]
+    );
+    assert!(visit(&fs, Path::new("/app/missing"), true).await.is_empty());
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn visit_dirs_skips_dangling_symlinks() {
+    let dir = std::env::temp_dir().join(format!("rspack_context_module_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("should create temp dir");
+    std::fs::write(dir.join("en.js"), "").expect("should write file");
+    std::os::unix::fs::symlink(dir.join("missing.js"), dir.join("fr.js"))
+      .expect("should create symlink");
+
+    assert_eq!(
+      visit(&rspack_fs::AsyncNativeFileSystem, &dir, false).await,
+      vec!["./en.js"]
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
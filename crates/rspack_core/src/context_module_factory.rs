@@ -1,18 +1,19 @@
 use std::sync::Arc;
 
 use rspack_error::{error, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hook::define_hook;
 use tracing::instrument;
 
 use crate::{
-  cache::Cache, resolve, BoxModule, ContextModule, ContextModuleOptions, DependencyCategory,
-  ModuleExt, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier,
-  RawModule, ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
-  ResolverFactory, SharedPluginDriver,
+  cache::Cache, resolve, ContextModule, ContextModuleOptions, DependencyCategory, ModuleExt,
+  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, RawModule,
+  ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory,
+  SharedPluginDriver,
 };
 
 define_hook!(ContextModuleFactoryBeforeResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData) -> bool);
-define_hook!(ContextModuleFactoryAfterResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData) -> bool);
+define_hook!(ContextModuleFactoryAfterResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData, options: &mut ContextModuleOptions) -> bool);
 
 #[derive(Debug, Default)]
 pub struct ContextModuleFactoryHooks {
@@ -20,11 +21,17 @@ pub struct ContextModuleFactoryHooks {
   pub after_resolve: ContextModuleFactoryAfterResolveHook,
 }
 
+enum ResolvedContext {
+  Options(ContextModuleOptions, bool),
+  Ignored(ModuleFactoryResult),
+}
+
 #[derive(Debug)]
 pub struct ContextModuleFactory {
   loader_resolver_factory: Arc<ResolverFactory>,
   plugin_driver: SharedPluginDriver,
   cache: Arc<Cache>,
+  fs: Arc<dyn AsyncReadableFileSystem>,
 }
 
 #[async_trait::async_trait]
@@ -35,13 +42,20 @@ impl ModuleFactory for ContextModuleFactory {
       return Ok(before_resolve_result);
     }
 
-    let factorize_result = self.resolve(data).await?;
+    let (mut options, from_cache) = match self.resolve(data).await? {
+      ResolvedContext::Options(options, from_cache) => (options, from_cache),
+      ResolvedContext::Ignored(factorize_result) => return Ok(factorize_result),
+    };
 
-    if let Some(false) = self.after_resolve(data).await? {
+    if let Some(false) = self.after_resolve(data, &mut options).await? {
       return Ok(ModuleFactoryResult::default());
     }
 
-    Ok(factorize_result)
+    let module = ContextModule::new(options, self.plugin_driver.resolver_factory.clone()).boxed();
+    Ok(ModuleFactoryResult {
+      module: Some(module),
+      from_cache,
+    })
   }
 }
 
@@ -50,11 +64,13 @@ impl ContextModuleFactory {
     loader_resolver_factory: Arc<ResolverFactory>,
     plugin_driver: SharedPluginDriver,
     cache: Arc<Cache>,
+    fs: Arc<dyn AsyncReadableFileSystem>,
   ) -> Self {
     Self {
       loader_resolver_factory,
       plugin_driver,
       cache,
+      fs,
     }
   }
 
@@ -87,7 +103,7 @@ impl ContextModuleFactory {
       })
   }
 
-  async fn resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<ModuleFactoryResult> {
+  async fn resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<ResolvedContext> {
     let plugin_driver = &self.plugin_driver;
     let dependency = data
       .dependency
@@ -168,25 +184,26 @@ impl ContextModuleFactory {
     let (resource_data, from_cache) = match self
       .cache
       .resolve_module_occasion
-      .use_cache(resolve_args, |args| resolve(args, plugin_driver))
+      .use_cache(resolve_args, |args| {
+        resolve(args, plugin_driver, self.fs.as_ref())
+      })
       .await
     {
       Ok(result) => result,
       Err(err) => (Err(err), false),
     };
 
-    let module = match resource_data {
-      Ok(ResolveResult::Resource(resource)) => Box::new(ContextModule::new(
-        ContextModuleOptions {
-          addon: loader_request.to_string(),
-          resource: resource.path.to_string_lossy().to_string(),
-          resource_query: resource.query,
-          resource_fragment: resource.fragment,
-          resolve_options: data.resolve_options.clone(),
-          context_options: dependency.options().clone(),
-        },
-        plugin_driver.resolver_factory.clone(),
-      )) as BoxModule,
+    let options = match resource_data {
+      Ok(ResolveResult::Resource(resource)) => ContextModuleOptions {
+        addon: loader_request.to_string(),
+        resource: resource.path.to_string_lossy().to_string(),
+        resource_query: resource.query,
+        resource_fragment: resource.fragment,
+        resolve_options: data.resolve_options.clone(),
+        context_options: dependency.options().clone(),
+        layer: data.issuer_layer.clone(),
+        context_map: None,
+      },
       Ok(ResolveResult::Ignored) => {
         let ident = format!("{}/{}", data.context, specifier);
         let module_identifier = ModuleIdentifier::from(format!("ignored|{ident}"));
@@ -197,7 +214,9 @@ impl ContextModuleFactory {
           Default::default(),
         )
         .boxed();
-        return Ok(ModuleFactoryResult::new_with_module(raw_module));
+        return Ok(ResolvedContext::Ignored(
+          ModuleFactoryResult::new_with_module(raw_module),
+        ));
       }
       Err(err) => {
         return Err(err);
@@ -208,18 +227,19 @@ impl ContextModuleFactory {
     data.add_missing_dependencies(missing_dependencies);
     // data.add_context_dependencies(context_dependencies);
 
-    Ok(ModuleFactoryResult {
-      module: Some(module),
-      from_cache,
-    })
+    Ok(ResolvedContext::Options(options, from_cache))
   }
 
-  async fn after_resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<bool>> {
+  async fn after_resolve(
+    &self,
+    data: &mut ModuleFactoryCreateData,
+    options: &mut ContextModuleOptions,
+  ) -> Result<Option<bool>> {
     self
       .plugin_driver
       .context_module_factory_hooks
       .after_resolve
-      .call(data)
+      .call(data, options)
       .await
   }
 }
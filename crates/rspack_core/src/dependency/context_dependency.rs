@@ -1,11 +1,19 @@
+use rspack_error::Diagnostic;
+
 use crate::{ContextOptions, Dependency};
 
 pub trait ContextDependency: Dependency {
   fn request(&self) -> &str;
   fn options(&self) -> &ContextOptions;
+  fn options_mut(&mut self) -> &mut ContextOptions;
   fn get_context(&self) -> Option<&str>;
   fn resource_identifier(&self) -> &str;
   fn set_request(&mut self, request: String);
+
+  /// The warning for a request which is an expression, reported unless the context
+  /// is replaced, e.g. by the `ContextReplacementPlugin`
+  fn critical(&self) -> &Option<Diagnostic>;
+  fn critical_mut(&mut self) -> &mut Option<Diagnostic>;
 }
 
 pub trait AsContextDependency {
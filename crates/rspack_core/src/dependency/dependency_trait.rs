@@ -1,6 +1,7 @@
 use std::{any::Any, fmt::Debug};
 
 use dyn_clone::{clone_trait_object, DynClone};
+use rspack_error::Diagnostic;
 use rspack_util::ext::AsAny;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::{common::Span, ecma::atoms::Atom};
@@ -10,7 +11,9 @@ use super::module_dependency::*;
 use super::ExportsSpec;
 use super::{DependencyCategory, DependencyId, DependencyType};
 use crate::AsContextDependency;
-use crate::{ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, UsedByExports};
+use crate::{
+  ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, ModuleLayer, UsedByExports,
+};
 
 pub trait Dependency:
   AsDependencyTemplate
@@ -39,6 +42,11 @@ pub trait Dependency:
     None
   }
 
+  /// Layer of the issuer when the dependency has no parent module, e.g. entries
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     None
   }
@@ -84,6 +92,11 @@ pub trait Dependency:
   fn resource_identifier(&self) -> Option<&str> {
     None
   }
+
+  /// Warnings and errors of the dependency, collected once the exports of all modules are flagged
+  fn get_diagnostics(&self, _module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    None
+  }
 }
 
 impl dyn Dependency + '_ {
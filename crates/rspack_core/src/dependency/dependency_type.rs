@@ -52,6 +52,8 @@ pub enum DependencyType {
   CssImport,
   // css modules compose
   CssCompose,
+  // css modules icss :import
+  CssIcssImport,
   /// css module export
   /// FIXME: remove after we align css module with webpack
   CssModuleExport,
@@ -67,6 +69,18 @@ pub enum DependencyType {
   RequireContext,
   // require.resolve
   RequireResolve,
+  // require.ensure
+  RequireEnsure,
+  // require.ensure item
+  RequireEnsureItem,
+  // require.include
+  RequireInclude,
+  // amd define
+  AmdDefine,
+  // amd require
+  AmdRequire,
+  // amd require item
+  AmdRequireItem,
   /// wasm import
   WasmImport,
   /// wasm export import
@@ -93,6 +107,12 @@ pub enum DependencyType {
   /// Webpack is included
   WebpackIsIncluded,
   LoaderImport,
+  /// lazy compilation proxy to the original module
+  LazyImport,
+  /// dll entry
+  DllEntry,
+  /// delegated source
+  DelegatedSource,
   Custom(Box<str>), // TODO it will increase large layout size
 }
 
@@ -123,6 +143,7 @@ impl DependencyType {
       DependencyType::CssUrl => Cow::Borrowed("css url"),
       DependencyType::CssImport => Cow::Borrowed("css import"),
       DependencyType::CssCompose => Cow::Borrowed("css compose"),
+      DependencyType::CssIcssImport => Cow::Borrowed("css icss import"),
       DependencyType::CssModuleExport => Cow::Borrowed("css export"),
       DependencyType::ContextElement => Cow::Borrowed("context element"),
       // TODO: mode
@@ -131,10 +152,17 @@ impl DependencyType {
       DependencyType::CommonJSRequireContext => Cow::Borrowed("commonjs require context"),
       DependencyType::RequireContext => Cow::Borrowed("require.context"),
       DependencyType::RequireResolve => Cow::Borrowed("require.resolve"),
+      DependencyType::RequireEnsure => Cow::Borrowed("require.ensure"),
+      DependencyType::RequireEnsureItem => Cow::Borrowed("require.ensure item"),
+      DependencyType::RequireInclude => Cow::Borrowed("require.include"),
+      DependencyType::AmdDefine => Cow::Borrowed("amd define"),
+      DependencyType::AmdRequire => Cow::Borrowed("amd require"),
+      DependencyType::AmdRequireItem => Cow::Borrowed("amd require"),
       DependencyType::WasmImport => Cow::Borrowed("wasm import"),
       DependencyType::WasmExportImported => Cow::Borrowed("wasm export imported"),
       DependencyType::StaticExports => Cow::Borrowed("static exports"),
       DependencyType::LoaderImport => Cow::Borrowed("loader import"),
+      DependencyType::LazyImport => Cow::Borrowed("lazy import()"),
       DependencyType::Custom(ty) => Cow::Owned(format!("custom {ty}")),
       DependencyType::ExportInfoApi => Cow::Borrowed("export info api"),
       // TODO: mode
@@ -149,6 +177,8 @@ impl DependencyType {
       DependencyType::ProvideModuleForShared => Cow::Borrowed("provide module for shared"),
       DependencyType::ConsumeSharedFallback => Cow::Borrowed("consume shared fallback"),
       DependencyType::WebpackIsIncluded => Cow::Borrowed("__webpack_is_included__"),
+      DependencyType::DllEntry => Cow::Borrowed("dll entry"),
+      DependencyType::DelegatedSource => Cow::Borrowed("delegated source"),
     }
   }
 }
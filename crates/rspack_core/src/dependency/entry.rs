@@ -1,6 +1,6 @@
 use crate::{
   AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ModuleDependency,
+  DependencyType, ModuleDependency, ModuleLayer,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -8,13 +8,15 @@ pub struct EntryDependency {
   id: DependencyId,
   request: String,
   context: Context,
+  layer: Option<ModuleLayer>,
 }
 
 impl EntryDependency {
-  pub fn new(request: String, context: Context) -> Self {
+  pub fn new(request: String, context: Context, layer: Option<ModuleLayer>) -> Self {
     Self {
       request,
       context,
+      layer,
       id: DependencyId::new(),
     }
   }
@@ -40,6 +42,10 @@ impl Dependency for EntryDependency {
   fn get_context(&self) -> Option<&Context> {
     Some(&self.context)
   }
+
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.layer.as_ref()
+  }
 }
 
 impl ModuleDependency for EntryDependency {
@@ -685,6 +685,39 @@ impl ExportsInfo {
     true
   }
 
+  /// Modules with the same usage key in two runtimes are used in the same way by both runtimes.
+  pub fn get_usage_key(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> UsageKey {
+    let mut key = UsageKey::default();
+    self.extend_usage_key(&mut key, runtime, mg);
+    key
+  }
+
+  fn extend_usage_key(&self, key: &mut UsageKey, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) {
+    if let Some(redirect_to) = self.redirect_to {
+      redirect_to
+        .get_exports_info(mg)
+        .extend_usage_key(key, runtime, mg);
+    } else {
+      key.0.push(
+        self
+          .other_exports_info
+          .get_export_info(mg)
+          .get_used(runtime),
+      );
+    }
+    key.0.push(
+      self
+        ._side_effects_only_info
+        .get_export_info(mg)
+        .get_used(runtime),
+    );
+    for export_info in self.owned_exports() {
+      key
+        .0
+        .push(export_info.get_export_info(mg).get_used(runtime));
+    }
+  }
+
   pub fn get_used(
     &self,
     name: UsedName,
@@ -1813,6 +1846,10 @@ pub enum UsageState {
   Used = 4,
 }
 
+/// See [ExportsInfo::get_usage_key]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct UsageKey(Vec<UsageState>);
+
 #[derive(Debug, PartialEq, Copy, Clone, Hash)]
 pub enum RuntimeUsageStateType {
   OnlyPropertiesUsed,
@@ -1,17 +1,21 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::hash::Hash;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::{any::Any, borrow::Cow, fmt::Debug};
 
 use async_trait::async_trait;
 use json::JsonValue;
 use rspack_error::{Diagnosable, Diagnostic, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
 use rspack_util::source_map::ModuleSourceMapConfig;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::Atom;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
@@ -27,6 +31,7 @@ pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub fs: Arc<dyn AsyncReadableFileSystem>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -51,6 +56,9 @@ pub struct BuildInfo {
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   pub json_data: Option<JsonValue>,
+  /// Local names exported by a css module and their values, used by the
+  /// modules referencing them with ICSS `:import` or `composes`
+  pub css_exports: Option<BTreeMap<String, String>>,
   pub module_concatenation_bailout: Option<String>,
 }
 
@@ -69,12 +77,14 @@ impl Default for BuildInfo {
       all_star_exports: Vec::default(),
       need_create_require: false,
       json_data: None,
+      css_exports: None,
       module_concatenation_bailout: None,
     }
   }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum BuildMetaExportsType {
   #[default]
   Unset,
@@ -92,7 +102,8 @@ pub enum ExportsType {
   Dynamic,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash)]
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum BuildMetaDefaultObject {
   #[default]
   False,
@@ -100,7 +111,8 @@ pub enum BuildMetaDefaultObject {
   RedirectWarn,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash)]
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ModuleArgument {
   #[default]
   Module,
@@ -116,7 +128,8 @@ impl Display for ModuleArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ExportsArgument {
   #[default]
   Exports,
@@ -132,7 +145,8 @@ impl Display for ExportsArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct BuildMeta {
   pub strict_harmony_module: bool,
   pub has_top_level_await: bool,
@@ -141,7 +155,12 @@ pub struct BuildMeta {
   pub default_object: BuildMetaDefaultObject,
   pub module_argument: ModuleArgument,
   pub exports_argument: ExportsArgument,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub side_effect_free: Option<bool>,
+  /// Exports of a sync wasm module which can't be used from javascript,
+  /// mapping from export name to the incompatible type.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub js_incompatible_exports: Option<BTreeMap<String, String>>,
 }
 
 // webpack build info
@@ -164,6 +183,9 @@ pub struct FactoryMeta {
 }
 
 pub type ModuleIdentifier = Identifier;
+
+/// Name of the layer a module is built in, see `experiments.layers`
+pub type ModuleLayer = String;
 #[async_trait]
 pub trait Module:
   Debug
@@ -332,6 +354,12 @@ pub trait Module:
     None
   }
 
+  /// The layer this module belongs to, modules of the same resource in different
+  /// layers are built separately.
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn chunk_condition(&self, _chunk_key: &ChunkUkey, _compilation: &Compilation) -> Option<bool> {
     None
   }
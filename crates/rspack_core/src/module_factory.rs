@@ -4,15 +4,19 @@ use rspack_error::{Diagnostic, Result};
 use rustc_hash::FxHashSet as HashSet;
 use sugar_path::SugarPath;
 
-use crate::{BoxDependency, BoxModule, Context, ModuleIdentifier, Resolve};
+use crate::{
+  BoxDependency, BoxModule, CompilationId, Context, ModuleIdentifier, ModuleLayer, Resolve,
+};
 
 #[derive(Debug)]
 pub struct ModuleFactoryCreateData {
+  pub compilation_id: CompilationId,
   pub resolve_options: Option<Box<Resolve>>,
   pub context: Context,
   pub dependency: BoxDependency,
   pub issuer: Option<Box<str>>,
   pub issuer_identifier: Option<ModuleIdentifier>,
+  pub issuer_layer: Option<ModuleLayer>,
 
   pub file_dependencies: HashSet<PathBuf>,
   pub context_dependencies: HashSet<PathBuf>,
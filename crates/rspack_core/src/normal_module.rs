@@ -11,11 +11,16 @@ use std::{
 use bitflags::bitflags;
 use dashmap::DashMap;
 use derivative::Derivative;
-use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, MietteExt, Result, Severity};
+use rspack_error::{
+  error, Diagnosable, Diagnostic, DiagnosticExt, IntoTWithDiagnosticArray, MietteExt, Result,
+  Severity,
+};
 use rspack_hash::RspackHash;
 use rspack_hook::define_hook;
 use rspack_identifier::Identifiable;
-use rspack_loader_runner::{run_loaders, AdditionalData, Content, LoaderContext, ResourceData};
+use rspack_loader_runner::{
+  run_loaders, AdditionalData, Content, LoaderContext, LoaderResult, ResourceData,
+};
 use rspack_macros::impl_source_map_config;
 use rspack_sources::{
   BoxSource, CachedSource, OriginalSource, RawSource, Source, SourceExt, SourceMap,
@@ -32,9 +37,9 @@ use crate::{
   BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation,
   CompilerContext, ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
   DependencyTemplate, FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RuntimeGlobals,
-  RuntimeSpec, SourceType,
+  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleLayer, ModuleType, ParseContext,
+  ParseResult, ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin,
+  RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -109,6 +114,8 @@ pub struct NormalModule {
   raw_request: String,
   /// The resolved module type of a module
   module_type: ModuleType,
+  /// The layer of the module
+  layer: Option<ModuleLayer>,
   /// Affiliated parser and generator to the module type
   parser_and_generator: Box<dyn ParserAndGenerator>,
   /// Resource matched with inline match resource, (`!=!` syntax)
@@ -125,6 +132,11 @@ pub struct NormalModule {
   original_source: Option<BoxSource>,
   /// Built source of this module (passed with loaders)
   source: NormalModuleSource,
+  /// Result of the loaders, restored by the persistent cache before a build or kept by a build for
+  /// the persistent cache, see [NormalModule::restore_loader_result]
+  loader_result: Option<LoaderResult>,
+  /// Whether the next build keeps its loader result, see [NormalModule::persist_loader_result]
+  persist_loader_result: bool,
 
   /// Resolve options derived from [Rule.resolve]
   resolve_options: Option<Box<Resolve>>,
@@ -170,8 +182,10 @@ impl NormalModuleSource {
 static DEBUG_ID: AtomicUsize = AtomicUsize::new(1);
 
 impl NormalModule {
-  fn create_id(module_type: &ModuleType, request: &str) -> String {
-    if *module_type == ModuleType::Js {
+  fn create_id(module_type: &ModuleType, request: &str, layer: Option<&ModuleLayer>) -> String {
+    if let Some(layer) = layer {
+      format!("{module_type}|{request}|{layer}")
+    } else if *module_type == ModuleType::Js {
       request.to_string()
     } else {
       format!("{module_type}|{request}")
@@ -184,6 +198,7 @@ impl NormalModule {
     user_request: String,
     raw_request: String,
     module_type: impl Into<ModuleType>,
+    layer: Option<ModuleLayer>,
     parser_and_generator: Box<dyn ParserAndGenerator>,
     parser_options: Option<ParserOptions>,
     generator_options: Option<GeneratorOptions>,
@@ -194,7 +209,7 @@ impl NormalModule {
     contains_inline_loader: bool,
   ) -> Self {
     let module_type = module_type.into();
-    let id = Self::create_id(&module_type, &request);
+    let id = Self::create_id(&module_type, &request, layer.as_ref());
     Self {
       blocks: Vec::new(),
       dependencies: Vec::new(),
@@ -204,6 +219,7 @@ impl NormalModule {
       user_request,
       raw_request,
       module_type,
+      layer,
       parser_and_generator,
       parser_options,
       generator_options,
@@ -214,6 +230,8 @@ impl NormalModule {
       contains_inline_loader,
       original_source: None,
       source: NormalModuleSource::Unbuild,
+      loader_result: None,
+      persist_loader_result: false,
       debug_id: DEBUG_ID.fetch_add(1, Ordering::Relaxed),
 
       cached_source_sizes: DashMap::default(),
@@ -233,6 +251,32 @@ impl NormalModule {
     self.id
   }
 
+  /// Distinguishes the module from modules of the same request which a plugin renders differently,
+  /// e.g. css imported with different `@import` conditions.
+  pub fn extend_identifier(&mut self, suffix: &str) {
+    let mut id = Self::create_id(&self.module_type, &self.request, self.layer.as_ref());
+    id.push_str(suffix);
+    self.id = ModuleIdentifier::from(id);
+  }
+
+  /// Makes the next build parse `loader_result` instead of running the loaders.
+  pub fn restore_loader_result(&mut self, loader_result: LoaderResult) {
+    self.loader_result = Some(loader_result);
+  }
+
+  /// Makes the next build keep its loader result for the persistent cache. The loaders are asked
+  /// for content then, builtin:swc-loader would only give its ast otherwise.
+  pub fn persist_loader_result(&mut self) {
+    self.persist_loader_result = true;
+  }
+
+  /// Takes the loader result of the last build. It is only kept when asked for by
+  /// [NormalModule::persist_loader_result], and only when the loaders reported no diagnostics and
+  /// no additional data, as the module can then be built from the result alone.
+  pub fn take_loader_result(&mut self) -> Option<LoaderResult> {
+    self.loader_result.take()
+  }
+
   pub fn match_resource(&self) -> Option<&ResourceData> {
     self.match_resource.as_ref()
   }
@@ -388,31 +432,39 @@ impl Module for NormalModule {
       false
     };
 
-    build_context
-      .plugin_driver
-      .normal_module_hooks
-      .before_loaders
-      .call(self)?;
-
     let plugin = RspackLoaderRunnerPlugin {
       plugin_driver: build_context.plugin_driver.clone(),
       current_loader: Default::default(),
     };
 
+    let persist_loader_result = std::mem::take(&mut self.persist_loader_result);
     let mut additional_data = AdditionalData::default();
 
-    if no_parse {
+    // the persistent cache stores the content, builtin:swc-loader would only give its ast
+    if no_parse || persist_loader_result {
       additional_data.insert(&LoadersShouldAlwaysGiveContent {});
     }
 
-    let loader_result = run_loaders(
-      &self.loaders,
-      &mut self.resource_data,
-      &[&plugin],
-      build_context.compiler_context,
-      additional_data,
-    )
-    .await;
+    // also called for a restored loader result, plugins may track the modules being built
+    build_context
+      .plugin_driver
+      .normal_module_hooks
+      .before_loaders
+      .call(self)?;
+    let loader_result = match self.loader_result.take() {
+      Some(loader_result) => Ok(loader_result.with_empty_diagnostic()),
+      None => {
+        run_loaders(
+          &self.loaders,
+          &mut self.resource_data,
+          &[&plugin],
+          build_context.compiler_context,
+          build_context.fs.clone(),
+          additional_data,
+        )
+        .await
+      }
+    };
     let (mut loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
       Err(e) => {
@@ -449,6 +501,18 @@ impl Module for NormalModule {
       .additional_data
       .call(&mut loader_result.additional_data)
       .await?;
+    if persist_loader_result
+      && ds.is_empty()
+      && loader_result.additional_data.len()
+        == loader_result
+          .additional_data
+          .contains::<&LoadersShouldAlwaysGiveContent>() as usize
+    {
+      self.loader_result = Some(LoaderResult {
+        additional_data: Default::default(),
+        ..loader_result.clone()
+      });
+    }
     self.add_diagnostics(ds);
 
     let content = if self.module_type().is_binary() {
@@ -637,7 +701,11 @@ impl Module for NormalModule {
 
   fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/NormalModule.js#L362
-    Some(Cow::Owned(contextify(options.context, self.user_request())))
+    let mut ident = contextify(options.context, self.user_request());
+    if let Some(layer) = &self.layer {
+      ident = format!("({layer})/{ident}");
+    }
+    Some(Cow::Owned(ident))
   }
 
   fn get_resolve_options(&self) -> Option<Box<Resolve>> {
@@ -668,6 +736,10 @@ impl Module for NormalModule {
     Some(self.context.clone())
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.layer.as_ref()
+  }
+
   // Port from https://github.com/webpack/webpack/blob/main/lib/NormalModule.js#L1120
   fn get_side_effects_connection_state(
     &self,
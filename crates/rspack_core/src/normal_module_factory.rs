@@ -3,6 +3,7 @@ use std::{borrow::Cow, path::Path, sync::Arc};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_error::{error, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_hook::define_hook;
 use rspack_loader_runner::{get_scheme, Loader, Scheme};
 use rspack_util::MergeFrom;
@@ -16,7 +17,7 @@ use crate::{
   tree_shaking::visitor::{get_side_effects_from_package_json, SideEffects},
   BoxLoader, BoxModule, CompilerContext, CompilerOptions, Context, DependencyCategory, FactoryMeta,
   FuncUseCtx, GeneratorOptions, ModuleExt, ModuleFactory, ModuleFactoryCreateData,
-  ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
+  ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
   ModuleRuleUseLoader, ModuleType, NormalModule, ParserOptions, RawModule, Resolve, ResolveArgs,
   ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory, ResourceData,
   ResourceParsedData, SharedPluginDriver,
@@ -25,6 +26,7 @@ use crate::{
 define_hook!(NormalModuleFactoryBeforeResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData) -> bool);
 define_hook!(NormalModuleFactoryFactorize: AsyncSeriesBail(data: &mut ModuleFactoryCreateData) -> BoxModule);
 define_hook!(NormalModuleFactoryResolveForScheme: AsyncSeriesBail(data: &mut ModuleFactoryCreateData, resource_data: &mut ResourceData) -> bool);
+define_hook!(NormalModuleFactoryResolveInScheme: AsyncSeriesBail(data: &ModuleFactoryCreateData, resource_data: &mut ResourceData) -> bool);
 define_hook!(NormalModuleFactoryAfterResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData, create_data: &mut NormalModuleCreateData) -> bool);
 define_hook!(NormalModuleFactoryCreateModule: AsyncSeriesBail(data: &mut ModuleFactoryCreateData, create_data: &mut NormalModuleCreateData) -> BoxModule);
 define_hook!(NormalModuleFactoryModule: AsyncSeries(data: &mut ModuleFactoryCreateData, create_data: &mut NormalModuleCreateData, module: &mut BoxModule));
@@ -35,6 +37,7 @@ pub struct NormalModuleFactoryHooks {
   pub before_resolve: NormalModuleFactoryBeforeResolveHook,
   pub factorize: NormalModuleFactoryFactorizeHook,
   pub resolve_for_scheme: NormalModuleFactoryResolveForSchemeHook,
+  pub resolve_in_scheme: NormalModuleFactoryResolveInSchemeHook,
   pub after_resolve: NormalModuleFactoryAfterResolveHook,
   pub create_module: NormalModuleFactoryCreateModuleHook,
   pub module: NormalModuleFactoryModuleHook,
@@ -51,6 +54,7 @@ pub struct NormalModuleFactory {
   loader_resolver_factory: Arc<ResolverFactory>,
   plugin_driver: SharedPluginDriver,
   cache: Arc<Cache>,
+  fs: Arc<dyn AsyncReadableFileSystem>,
 }
 
 #[async_trait::async_trait]
@@ -84,12 +88,14 @@ impl NormalModuleFactory {
     loader_resolver_factory: Arc<ResolverFactory>,
     plugin_driver: SharedPluginDriver,
     cache: Arc<Cache>,
+    fs: Arc<dyn AsyncReadableFileSystem>,
   ) -> Self {
     Self {
       options,
       loader_resolver_factory,
       plugin_driver,
       cache,
+      fs,
     }
   }
 
@@ -122,6 +128,21 @@ impl NormalModuleFactory {
       })
   }
 
+  async fn resolve_in_scheme(
+    &self,
+    data: &ModuleFactoryCreateData,
+    request: String,
+  ) -> Result<Option<ResourceData>> {
+    let mut resource_data = ResourceData::new(request, "".into());
+    let handled = self
+      .plugin_driver
+      .normal_module_factory_hooks
+      .resolve_in_scheme
+      .call(data, &mut resource_data)
+      .await?;
+    Ok(handled.unwrap_or_default().then_some(resource_data))
+  }
+
   pub async fn factorize_normal_module(
     &self,
     data: &mut ModuleFactoryCreateData,
@@ -162,9 +183,7 @@ impl NormalModuleFactory {
         .call(data, &mut resource_data)
         .await?;
       (resource_data, false)
-    }
-    // TODO: resource within scheme, call resolveInScheme hook
-    else {
+    } else {
       {
         request_without_match_resource = {
           let match_resource_match = MATCH_RESOURCE_REGEX.captures(request_without_match_resource);
@@ -276,6 +295,13 @@ impl NormalModuleFactory {
           .query_optional(query)
           .fragment_optional(fragment);
         (resource_data, false)
+      } else if context_scheme != Scheme::None
+        && let Some(resource_data) = self
+          .resolve_in_scheme(data, request_without_match_resource.to_string())
+          .await?
+      {
+        // resource within scheme, e.g. a relative request of a http(s) module
+        (resource_data, false)
       } else {
         let optional = dependency.get_optional();
 
@@ -304,7 +330,9 @@ impl NormalModuleFactory {
         let (resource_data, from_cache) = match self
           .cache
           .resolve_module_occasion
-          .use_cache(resolve_args, |args| resolve(args, plugin_driver))
+          .use_cache(resolve_args, |args| {
+            resolve(args, plugin_driver, self.fs.as_ref())
+          })
           .await
         {
           Ok(result) => result,
@@ -370,6 +398,7 @@ impl NormalModuleFactory {
           },
           data.dependency.category(),
           data.issuer.as_deref(),
+          data.issuer_layer.as_deref(),
         )
         .await?
     };
@@ -503,6 +532,14 @@ impl NormalModuleFactory {
         resolved_generator_options,
       );
     let resolved_side_effects = self.calculate_side_effects(&resolved_module_rules);
+    let resolved_layer = self
+      .calculate_layer(&resolved_module_rules)
+      .or_else(|| data.issuer_layer.clone());
+    if resolved_layer.is_some() && !self.options.experiments.layers {
+      return Err(error!(
+        "'Rule.layer' is only allowed when 'experiments.layers' is enabled"
+      ));
+    }
     let resolved_parser_and_generator = self
       .plugin_driver
       .registered_parser_and_generator_builder
@@ -560,6 +597,7 @@ impl NormalModuleFactory {
         create_data.user_request.clone(),
         create_data.raw_request.clone(),
         resolved_module_type,
+        resolved_layer,
         resolved_parser_and_generator,
         resolved_parser_options,
         resolved_generator_options,
@@ -580,7 +618,9 @@ impl NormalModuleFactory {
       .await?;
 
     data.add_file_dependencies(file_dependencies);
-    data.add_file_dependency(file_dependency);
+    if file_dependency.is_absolute() {
+      data.add_file_dependency(file_dependency);
+    }
     data.add_missing_dependencies(missing_dependencies);
 
     // Compat for old tree shaking
@@ -615,12 +655,14 @@ impl NormalModuleFactory {
     resource_data: &ResourceData,
     dependency: &DependencyCategory,
     issuer: Option<&'a str>,
+    issuer_layer: Option<&'a str>,
   ) -> Result<Vec<&'a ModuleRule>> {
     let mut rules = Vec::new();
     module_rules_matcher(
       &self.options.module.rules,
       resource_data,
       issuer,
+      issuer_layer,
       dependency,
       &mut rules,
     )
@@ -638,6 +680,13 @@ impl NormalModuleFactory {
     resolved
   }
 
+  fn calculate_layer(&self, module_rules: &[&ModuleRule]) -> Option<ModuleLayer> {
+    module_rules
+      .iter()
+      .rev()
+      .find_map(|rule| rule.layer.clone())
+  }
+
   fn calculate_side_effects(&self, module_rules: &[&ModuleRule]) -> Option<bool> {
     let mut side_effect_res = None;
     // side_effects from module rule has higher priority
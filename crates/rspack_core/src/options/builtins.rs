@@ -26,7 +26,15 @@ impl Plugin for DefinePlugin {
     _ctx: PluginContext<&mut ApplyContext>,
     options: &mut CompilerOptions,
   ) -> Result<()> {
-    options.builtins.define.extend(self.options.clone());
+    // the plugin applied first wins, as the first tapped hook does in webpack, so that user
+    // definitions take precedence over `optimization.nodeEnv`
+    for (key, code) in &self.options {
+      options
+        .builtins
+        .define
+        .entry(key.clone())
+        .or_insert_with(|| code.clone());
+    }
     Ok(())
   }
 }
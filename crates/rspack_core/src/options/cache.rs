@@ -1,3 +1,7 @@
+use std::path::{Path, PathBuf};
+
+use crate::Context;
+
 #[derive(Debug, Clone, Default)]
 pub struct MemoryCacheOptions {
   /// Define the lifespan of unused cache entries in the memory cache.
@@ -36,6 +40,28 @@ pub struct FileSystemCacheOptions {
   pub version: String,
 }
 
+impl FileSystemCacheOptions {
+  /// Resolve the directory where cache packs are stored.
+  ///
+  /// Falls back to `cache_directory/name`, and `node_modules/.cache/rspack` under the context
+  /// when `cache_directory` is not set either.
+  pub fn location(&self, context: &Context) -> PathBuf {
+    if !self.cache_location.is_empty() {
+      return PathBuf::from(&self.cache_location);
+    }
+    let directory = if self.cache_directory.is_empty() {
+      Path::new(context.as_str()).join("node_modules/.cache/rspack")
+    } else {
+      PathBuf::from(&self.cache_directory)
+    };
+    if self.name.is_empty() {
+      directory.join("default")
+    } else {
+      directory.join(&self.name)
+    }
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum CacheOptions {
   #[default]
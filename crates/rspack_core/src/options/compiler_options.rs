@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
   Builtins, CacheOptions, Context, DevServerOptions, Experiments, IncrementalRebuildMakeState,
   Mode, ModuleOptions, NodeOption, Optimization, OutputOptions, Resolve, SnapshotOptions,
@@ -20,9 +22,15 @@ pub struct CompilerOptions {
   pub cache: CacheOptions,
   pub experiments: Experiments,
   pub node: Option<NodeOption>,
+  /// JSON of the options exposed as `define.amd`, `None` disables AMD support.
+  pub amd: Option<String>,
   pub optimization: Optimization,
   pub profile: bool,
   pub bail: bool,
+  /// JSON file the ids of a previous build are read from.
+  pub records_input_path: Option<PathBuf>,
+  /// JSON file the ids of this build are written to after emit.
+  pub records_output_path: Option<PathBuf>,
   pub builtins: Builtins,
 }
 
@@ -28,6 +28,7 @@ pub struct RspackFuture {
 
 #[derive(Debug, Default)]
 pub struct Experiments {
+  pub layers: bool,
   pub incremental_rebuild: IncrementalRebuild,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
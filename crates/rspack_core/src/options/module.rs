@@ -7,7 +7,7 @@ use async_recursion::async_recursion;
 use bitflags::bitflags;
 use derivative::Derivative;
 use futures::future::BoxFuture;
-use rspack_error::Result;
+use rspack_error::{error, Result, Severity};
 use rspack_macros::MergeFrom;
 use rspack_regex::RspackRegex;
 use rspack_util::{try_all, try_any, MergeFrom};
@@ -133,6 +133,57 @@ impl From<&str> for JavascriptParserOrder {
   }
 }
 
+/// How imports and re-exports of exports which are not provided are reported
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, MergeFrom)]
+pub enum ExportPresenceMode {
+  None,
+  Warn,
+  /// Errors in strict harmony modules, e.g. `javascript/esm`, warns otherwise
+  #[default]
+  Auto,
+  Error,
+}
+
+impl TryFrom<&str> for ExportPresenceMode {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "false" => Ok(Self::None),
+      "warn" => Ok(Self::Warn),
+      "auto" => Ok(Self::Auto),
+      "error" => Ok(Self::Error),
+      _ => Err(error!(
+        "Unsupported export presence mode: '{value}', expected false, \"warn\", \"auto\" or \"error\""
+      )),
+    }
+  }
+}
+
+impl ExportPresenceMode {
+  /// Falls back to `exportsPresence`, then to `strictExportPresence` when `specified` is `None`
+  pub fn from_options(specified: Option<Self>, options: &JavascriptParserOptions) -> Self {
+    specified
+      .or(options.exports_presence)
+      .unwrap_or(if options.strict_export_presence {
+        Self::Error
+      } else {
+        Self::Auto
+      })
+  }
+
+  /// Severity of a missing export in a module, `None` when it is not reported
+  pub fn get_effective_severity(&self, strict_harmony_module: bool) -> Option<Severity> {
+    match self {
+      Self::None => None,
+      Self::Warn => Some(Severity::Warn),
+      Self::Auto if strict_harmony_module => Some(Severity::Error),
+      Self::Auto => Some(Severity::Warn),
+      Self::Error => Some(Severity::Error),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default, MergeFrom)]
 pub struct JavascriptParserOptions {
   pub dynamic_import_mode: DynamicImportMode,
@@ -141,6 +192,10 @@ pub struct JavascriptParserOptions {
   pub url: JavascriptParserUrl,
   pub expr_context_critical: bool,
   pub wrapped_context_critical: bool,
+  pub exports_presence: Option<ExportPresenceMode>,
+  pub import_exports_presence: Option<ExportPresenceMode>,
+  pub reexport_exports_presence: Option<ExportPresenceMode>,
+  pub strict_export_presence: bool,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -521,10 +576,14 @@ pub struct ModuleRule {
   pub resource_fragment: Option<RuleSetCondition>,
   pub dependency: Option<RuleSetCondition>,
   pub issuer: Option<RuleSetCondition>,
+  /// A condition matcher against the layer of the issuer.
+  pub issuer_layer: Option<RuleSetCondition>,
   pub scheme: Option<RuleSetCondition>,
   pub mimetype: Option<RuleSetCondition>,
   pub description_data: Option<DescriptionData>,
   pub side_effects: Option<bool>,
+  /// The layer to build the matched module in.
+  pub layer: Option<String>,
   /// The `ModuleType` to use for the matched resource.
   pub r#type: Option<ModuleType>,
   #[derivative(Debug(format_with = "fmt_use"))]
@@ -96,7 +96,10 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// Chunk loading provided by a plugin, which claims the type with
+  /// [crate::ApplyContext::register_chunk_loading_type] and adds its own
+  /// runtime module for chunks using this type.
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -107,12 +110,12 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      _ => Self::Custom(value.to_string()),
     }
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum WasmLoading {
   Enable(WasmLoadingType),
   Disable,
@@ -127,20 +130,24 @@ impl From<&str> for WasmLoading {
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum WasmLoadingType {
+  /// Also used for `fetch-streaming`, the runtime prefers `WebAssembly.instantiateStreaming`
+  /// whenever it is available.
   Fetch,
   AsyncNode,
   AsyncNodeModule,
+  /// Wasm loading provided by a plugin, see [ChunkLoadingType::Custom].
+  Custom(String),
 }
 
 impl From<&str> for WasmLoadingType {
   fn from(value: &str) -> Self {
     match value {
-      "fetch" => Self::Fetch,
+      "fetch" | "fetch-streaming" => Self::Fetch,
       "async-node" => Self::AsyncNode,
       "async-node-module" => Self::AsyncNodeModule,
-      _ => todo!(),
+      _ => Self::Custom(value.to_string()),
     }
   }
 }
@@ -1,9 +1,47 @@
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+/// Matches paths for `managed_paths` and `immutable_paths`.
+///
+/// A string is the path of the directory, a regex should capture the directory
+/// in its first group (or match it as a whole).
+#[derive(Debug, Clone)]
+pub enum SnapshotPathMatcher {
+  String(String),
+  Regex(RspackRegex),
+}
+
+impl SnapshotPathMatcher {
+  /// The default of `managed_paths`, matches every `node_modules` directory.
+  pub fn node_modules() -> Self {
+    Self::Regex(RspackRegex::new(r"^(.+?[\\/]node_modules[\\/])").expect("should be a valid regex"))
+  }
+
+  /// Returns the matched directory with a trailing separator.
+  pub fn match_dir<'a>(&self, path: &'a str) -> Option<&'a str> {
+    match self {
+      Self::String(dir) => {
+        let dir = dir.trim_end_matches(['/', '\\']);
+        (path.starts_with(dir) && path[dir.len()..].starts_with(['/', '\\']))
+          .then(|| &path[..dir.len() + 1])
+      }
+      Self::Regex(regex) => {
+        let mut groups = regex.exec(path)?;
+        let range = match groups.get_mut(1) {
+          Some(group) => group.take(),
+          None => groups.swap_remove(0),
+        }?;
+        (range.start == 0).then(|| &path[range])
+      }
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
   // Snapshots for resolving of build dependencies when using the persistent cache.
@@ -14,8 +52,8 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// An array of paths that are managed by a package manager and contain a version or a hash in their paths.
+  pub immutable_paths: Vec<SnapshotPathMatcher>,
+  /// An array of paths that are managed by a package manager.
+  pub managed_paths: Vec<SnapshotPathMatcher>,
 }
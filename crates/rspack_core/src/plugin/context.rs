@@ -1,8 +1,10 @@
 use rspack_util::fx_hash::FxDashMap;
+use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  CompilationHooks, CompilerHooks, ContextModuleFactoryHooks, GeneratorOptions, ModuleType,
-  NormalModuleFactoryHooks, NormalModuleHooks, ParserAndGenerator, ParserOptions,
+  ChunkLoadingType, CompilationHooks, CompilerHooks, ContextModuleFactoryHooks, GeneratorOptions,
+  ModuleType, NormalModuleFactoryHooks, NormalModuleHooks, ParserAndGenerator, ParserOptions,
+  WasmLoadingType,
 };
 
 #[derive(Debug, Default)]
@@ -38,6 +40,8 @@ pub type BoxedParserAndGeneratorBuilder = Box<
 pub struct ApplyContext<'c> {
   pub(crate) registered_parser_and_generator_builder:
     &'c mut FxDashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  pub(crate) registered_chunk_loading_types: &'c mut HashSet<ChunkLoadingType>,
+  pub(crate) registered_wasm_loading_types: &'c mut HashSet<WasmLoadingType>,
   pub compiler_hooks: &'c mut CompilerHooks,
   pub compilation_hooks: &'c mut CompilationHooks,
   pub normal_module_factory_hooks: &'c mut NormalModuleFactoryHooks,
@@ -55,4 +59,17 @@ impl<'c> ApplyContext<'c> {
       .registered_parser_and_generator_builder
       .insert(module_type, parser_and_generator_builder);
   }
+
+  /// Claims a [ChunkLoadingType::Custom], the plugin is responsible for adding the chunk loading
+  /// runtime. Entries using a custom type nobody registered fail to build.
+  pub fn register_chunk_loading_type(&mut self, chunk_loading_type: ChunkLoadingType) {
+    self
+      .registered_chunk_loading_types
+      .insert(chunk_loading_type);
+  }
+
+  /// Claims a [WasmLoadingType::Custom], see [ApplyContext::register_chunk_loading_type].
+  pub fn register_wasm_loading_type(&mut self, wasm_loading_type: WasmLoadingType) {
+    self.registered_wasm_loading_types.insert(wasm_loading_type);
+  }
 }
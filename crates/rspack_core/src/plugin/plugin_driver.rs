@@ -3,11 +3,12 @@ use std::sync::{Arc, Mutex};
 use derivative::Derivative;
 use rspack_error::Diagnostic;
 use rspack_util::fx_hash::FxDashMap;
+use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  ApplyContext, BoxedParserAndGeneratorBuilder, CompilationHooks, CompilerHooks, CompilerOptions,
-  ContextModuleFactoryHooks, ModuleType, NormalModuleFactoryHooks, NormalModuleHooks, Plugin,
-  PluginContext, ResolverFactory,
+  ApplyContext, BoxedParserAndGeneratorBuilder, ChunkLoadingType, CompilationHooks, CompilerHooks,
+  CompilerOptions, ContextModuleFactoryHooks, ModuleType, NormalModuleFactoryHooks,
+  NormalModuleHooks, Plugin, PluginContext, ResolverFactory, WasmLoadingType,
 };
 
 #[derive(Derivative)]
@@ -19,6 +20,10 @@ pub struct PluginDriver {
   #[derivative(Debug = "ignore")]
   pub registered_parser_and_generator_builder:
     FxDashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  /// Custom chunk loading types claimed by plugins
+  pub registered_chunk_loading_types: HashSet<ChunkLoadingType>,
+  /// Custom wasm loading types claimed by plugins
+  pub registered_wasm_loading_types: HashSet<WasmLoadingType>,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
   pub diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
   pub compiler_hooks: CompilerHooks,
@@ -40,8 +45,12 @@ impl PluginDriver {
     let mut context_module_factory_hooks = Default::default();
     let mut normal_module_hooks = Default::default();
     let mut registered_parser_and_generator_builder = FxDashMap::default();
+    let mut registered_chunk_loading_types = HashSet::default();
+    let mut registered_wasm_loading_types = HashSet::default();
     let mut apply_context = ApplyContext {
       registered_parser_and_generator_builder: &mut registered_parser_and_generator_builder,
+      registered_chunk_loading_types: &mut registered_chunk_loading_types,
+      registered_wasm_loading_types: &mut registered_wasm_loading_types,
       compiler_hooks: &mut compiler_hooks,
       compilation_hooks: &mut compilation_hooks,
       normal_module_factory_hooks: &mut normal_module_factory_hooks,
@@ -65,6 +74,8 @@ impl PluginDriver {
         plugins,
         resolver_factory,
         registered_parser_and_generator_builder,
+        registered_chunk_loading_types,
+        registered_wasm_loading_types,
         diagnostics: Arc::new(Mutex::new(vec![])),
         compiler_hooks,
         compilation_hooks,
@@ -1,9 +1,10 @@
 use std::{hash::BuildHasherDefault, sync::Arc};
 
 use dashmap::DashMap;
+use rspack_fs::AsyncReadableFileSystem;
 use rustc_hash::FxHasher;
 
-use super::resolver_impl::Resolver;
+use super::resolver_impl::{Resolver, ResolverFileSystem};
 use crate::{DependencyCategory, Resolve};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -25,7 +26,7 @@ pub struct ResolverFactory {
 
 impl Default for ResolverFactory {
   fn default() -> Self {
-    Self::new(Resolve::default())
+    Self::new(Resolve::default(), None)
   }
 }
 
@@ -34,10 +35,15 @@ impl ResolverFactory {
     self.resolver.clear_cache();
   }
 
-  pub fn new(options: Resolve) -> Self {
+  /// The resolvers read `input_filesystem`, or the native file system when it's `None`.
+  pub fn new(options: Resolve, input_filesystem: Option<Arc<dyn AsyncReadableFileSystem>>) -> Self {
+    let fs = match input_filesystem {
+      Some(fs) => ResolverFileSystem::Input(fs),
+      None => ResolverFileSystem::Native,
+    };
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(options),
+      resolver: Resolver::new(options, fs),
       resolvers: Default::default(),
     }
   }
@@ -57,3 +63,56 @@ impl ResolverFactory {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use std::path::{Path, PathBuf};
+  use std::sync::Arc;
+
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+
+  use super::{ResolveOptionsWithDependencyType, ResolverFactory};
+  use crate::{DependencyCategory, Resolve, ResolveResult};
+
+  #[test]
+  fn resolve_from_input_filesystem() {
+    let fs = MemoryFileSystem::default();
+    fs.create_dir_all("/app/src/locale").unwrap();
+    fs.create_dir_all("/app/node_modules/pkg").unwrap();
+    fs.write("/app/src/a.js", "").unwrap();
+    fs.write("/app/src/locale/index.js", "").unwrap();
+    fs.write(
+      "/app/node_modules/pkg/package.json",
+      r#"{ "main": "lib.js" }"#,
+    )
+    .unwrap();
+    fs.write("/app/node_modules/pkg/lib.js", "").unwrap();
+
+    let factory = ResolverFactory::new(
+      Resolve {
+        extensions: Some(vec![".js".to_string()]),
+        ..Default::default()
+      },
+      Some(Arc::new(fs)),
+    );
+    let resolver = factory.get(ResolveOptionsWithDependencyType {
+      resolve_options: None,
+      resolve_to_context: false,
+      dependency_category: DependencyCategory::Esm,
+    });
+    let resolve = |request: &str| match resolver.resolve(Path::new("/app/src"), request) {
+      Ok(ResolveResult::Resource(resource)) => Some(resource.path),
+      _ => None,
+    };
+    assert_eq!(resolve("./a"), Some(PathBuf::from("/app/src/a.js")));
+    assert_eq!(
+      resolve("./locale"),
+      Some(PathBuf::from("/app/src/locale/index.js"))
+    );
+    assert_eq!(
+      resolve("pkg"),
+      Some(PathBuf::from("/app/node_modules/pkg/lib.js"))
+    );
+    assert_eq!(resolve("./missing"), None);
+  }
+}
@@ -1,12 +1,12 @@
 mod factory;
 mod resolver_impl;
 use std::borrow::Borrow;
-use std::fs;
 use std::{fmt, path::PathBuf};
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_error::{Error, MietteExt};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet;
 use sugar_path::SugarPath;
@@ -84,9 +84,10 @@ impl Resource {
   }
 }
 
-pub fn resolve_for_error_hints(
+pub async fn resolve_for_error_hints(
   args: ResolveArgs<'_>,
   plugin_driver: &SharedPluginDriver,
+  fs: &dyn AsyncReadableFileSystem,
 ) -> Option<String> {
   let dep = ResolveOptionsWithDependencyType {
     resolve_options: args.resolve_options.clone(),
@@ -194,10 +195,10 @@ which tries to resolve these kind of requests in the current directory too.",
     let mut is_resolving_dir = false; // whether the request is to resolve a directory or not
 
     let file_name = normalized_path.file_name();
-    let parent_path = match fs::metadata(&normalized_path) {
+    let parent_path = match fs.metadata(&normalized_path).await {
       Ok(metadata) => {
         // if the path is not directory, we need to resolve the parent directory
-        if !metadata.is_dir() {
+        if !metadata.is_directory {
           normalized_path.parent()
         } else {
           is_resolving_dir = true;
@@ -213,7 +214,7 @@ which tries to resolve these kind of requests in the current directory too.",
         parent_path.expect("fail to get the parent path of the current resolved module");
 
       // read the files in the parent directory
-      let files = fs::read_dir(parent_path);
+      let files = fs.read_dir(parent_path).await;
       match files {
         Ok(files) => {
           let mut requested_names = vec![file_name
@@ -236,19 +237,18 @@ which tries to resolve these kind of requests in the current directory too.",
           let suggestions = files
             .into_iter()
             .filter_map(|file| {
-              file.ok().and_then(|file| {
-                file.path().file_stem().and_then(|file_stem| {
-                  if requested_names.contains(&file_stem.to_string_lossy().to_string()) {
-                    let mut suggestion = file.path().relative(&args.context);
-
-                    if !suggestion.to_string_lossy().starts_with('.') {
-                      suggestion = PathBuf::from(format!("./{}", suggestion.to_string_lossy()));
-                    }
-                    Some(suggestion)
-                  } else {
-                    None
+              let file = parent_path.join(file);
+              file.file_stem().and_then(|file_stem| {
+                if requested_names.contains(&file_stem.to_string_lossy().to_string()) {
+                  let mut suggestion = file.relative(&args.context);
+
+                  if !suggestion.to_string_lossy().starts_with('.') {
+                    suggestion = PathBuf::from(format!("./{}", suggestion.to_string_lossy()));
                   }
-                })
+                  Some(suggestion)
+                } else {
+                  None
+                }
               })
             })
             .collect::<Vec<_>>();
@@ -289,6 +289,7 @@ if its extension was not listed in the `resolve.extensions`. Here're some possib
 pub async fn resolve(
   args: ResolveArgs<'_>,
   plugin_driver: &SharedPluginDriver,
+  fs: &dyn AsyncReadableFileSystem,
 ) -> Result<ResolveResult, Error> {
   let dep = ResolveOptionsWithDependencyType {
     resolve_options: args.resolve_options.clone(),
@@ -308,7 +309,7 @@ pub async fn resolve(
     .extend(context.missing_dependencies);
 
   if result.is_err()
-    && let Some(hint) = resolve_for_error_hints(args, plugin_driver)
+    && let Some(hint) = resolve_for_error_hints(args, plugin_driver, fs).await
   {
     result = result.map_err(|err| err.with_help(hint))
   };
@@ -1,5 +1,5 @@
 use std::{
-  fmt,
+  fmt, io,
   path::{Path, PathBuf},
   sync::Arc,
 };
@@ -8,6 +8,7 @@ use rspack_error::{
   miette::{diagnostic, Diagnostic},
   DiagnosticExt, Severity, TraceableError,
 };
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -72,22 +73,81 @@ impl<'a> ResolveInnerOptions<'a> {
   }
 }
 
+/// The file system read by the resolver.
+///
+/// The resolver is synchronous, the futures of an input file system are blocked on, which is fine
+/// for file systems answering without waiting, e.g. [rspack_fs::MemoryFileSystem].
+#[derive(Debug, Clone)]
+pub enum ResolverFileSystem {
+  Native,
+  Input(Arc<dyn AsyncReadableFileSystem>),
+}
+
+impl ResolverFileSystem {
+  fn block_on<T>(future: futures::future::BoxFuture<'_, rspack_fs::Result<T>>) -> io::Result<T> {
+    futures::executor::block_on(future).map_err(|rspack_fs::Error::Io(error)| error)
+  }
+}
+
+impl oxc_resolver::FileSystem for ResolverFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    match self {
+      Self::Native => std::fs::read_to_string(path),
+      Self::Input(fs) => String::from_utf8(Self::block_on(fs.read(path))?)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+    }
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    match self {
+      Self::Native => std::fs::metadata(path).map(to_oxc_metadata),
+      Self::Input(fs) => {
+        let metadata = Self::block_on(fs.metadata(path))?;
+        Ok(oxc_resolver::FileMetadata::new(
+          metadata.is_file,
+          metadata.is_directory,
+          metadata.is_symlink,
+        ))
+      }
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    match self {
+      Self::Native => std::fs::symlink_metadata(path).map(to_oxc_metadata),
+      // symbolic links are resolved by `canonicalize` of the input file system
+      Self::Input(_) => self.metadata(path),
+    }
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    match self {
+      Self::Native => dunce::canonicalize(path),
+      Self::Input(fs) => Self::block_on(fs.canonicalize(path)),
+    }
+  }
+}
+
+fn to_oxc_metadata(metadata: std::fs::Metadata) -> oxc_resolver::FileMetadata {
+  oxc_resolver::FileMetadata::new(metadata.is_file(), metadata.is_dir(), metadata.is_symlink())
+}
+
 /// Proxy to [oxc_resolver::Resolver]
 ///
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<ResolverFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(options: Resolve) -> Self {
-    Self::new_oxc_resolver(options)
+  pub fn new(options: Resolve, fs: ResolverFileSystem) -> Self {
+    Self::new_oxc_resolver(options, fs)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
+  fn new_oxc_resolver(options: Resolve, fs: ResolverFileSystem) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
+    let resolver = oxc_resolver::ResolverGeneric::new_with_file_system(fs, options);
     Self::OxcResolver(resolver)
   }
 
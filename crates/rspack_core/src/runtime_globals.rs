@@ -5,7 +5,7 @@ use swc_core::ecma::atoms::Atom;
 
 bitflags! {
   #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-  pub struct RuntimeGlobals: u64 {
+  pub struct RuntimeGlobals: u128 {
     const REQUIRE_SCOPE = 1 << 0;
 
     const EXPORT_STAR = 1 << 1;
@@ -246,6 +246,31 @@ bitflags! {
     const RSPACK_VERSION = 1 << 62;
 
     const HAS_CSS_MODULES = 1 << 63;
+
+    /**
+     * the AMD define function
+     */
+    const AMD_DEFINE = 1 << 64;
+
+    /**
+     * the AMD options
+     */
+    const AMD_OPTIONS = 1 << 65;
+
+    /**
+     * the handler for errors thrown from async callbacks
+     */
+    const UNCAUGHT_ERROR_HANDLER = 1 << 66;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 67;
+
+    /**
+     * the subresource integrity of async chunk files, keyed by file type and chunk id
+     */
+    const SRI_HASHES = 1 << 68;
   }
 }
 
@@ -327,9 +352,14 @@ impl RuntimeGlobals {
       R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
       R::PRELOAD_CHUNK => "__webpack_require__.G",
       R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
+      R::AMD_DEFINE => "__webpack_require__.amdD",
+      R::AMD_OPTIONS => "__webpack_require__.amdO",
+      R::UNCAUGHT_ERROR_HANDLER => "__webpack_require__.oe",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       // rspack only
       R::RSPACK_VERSION => "__webpack_require__.rv",
       R::HAS_CSS_MODULES => "has css modules",
+      R::SRI_HASHES => "__webpack_require__.sriHashes",
       _ => unreachable!(),
     }
   }
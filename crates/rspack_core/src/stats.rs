@@ -563,6 +563,7 @@ impl Stats<'_> {
       module_type: *module.module_type(),
       identifier,
       name_for_condition: module.name_for_condition().map(|n| n.to_string()),
+      layer: module.get_layer().cloned(),
       name: module
         .readable_identifier(&self.compilation.options.context)
         .into(),
@@ -615,6 +616,7 @@ impl Stats<'_> {
       module_type: *module.module_type(),
       identifier: module.identifier(),
       name_for_condition: module.name_for_condition().map(|n| n.to_string()),
+      layer: module.get_layer().cloned(),
       name: module.name().to_string(),
       id: Some(String::new()),
       chunks,
@@ -749,6 +751,7 @@ pub struct StatsModule<'a> {
   pub identifier: ModuleIdentifier,
   pub name: String,
   pub name_for_condition: Option<String>,
+  pub layer: Option<String>,
   pub id: Option<String>,
   pub chunks: Vec<Option<String>>, // has id after the call of chunkIds hook
   pub size: f64,
@@ -1,6 +1,31 @@
+use crate::{CrossOriginLoading, RuntimeGlobals};
+
 pub fn to_normal_comment(str: &str) -> String {
   if str.is_empty() {
     return String::new();
   }
   format!("/* {} */", str.replace("*/", "* /"))
 }
+
+/// Renders the statements that set `integrity` and `crossOrigin` on the
+/// `element` loading the `file_type` file of `chunkId`, reading the digests
+/// from [RuntimeGlobals::SRI_HASHES].
+pub fn render_sri_integrity(
+  element: &str,
+  file_type: &str,
+  cross_origin_loading: &CrossOriginLoading,
+) -> String {
+  let cross_origin = match cross_origin_loading {
+    CrossOriginLoading::Enable(cross_origin) => cross_origin.as_str(),
+    CrossOriginLoading::Disable => "anonymous",
+  };
+  let sri_hashes = RuntimeGlobals::SRI_HASHES;
+  format!(
+    r#"
+    if ({sri_hashes}["{file_type}"][chunkId]) {{
+      {element}.integrity = {sri_hashes}["{file_type}"][chunkId];
+      {element}.crossOrigin = "{cross_origin}";
+    }}
+    "#
+  )
+}
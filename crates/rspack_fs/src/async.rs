@@ -1,8 +1,11 @@
-use std::path::Path;
+use std::{
+  fmt::Debug,
+  path::{Path, PathBuf},
+};
 
 use futures::future::BoxFuture;
 
-use crate::Result;
+use crate::{FileMetadata, Result};
 
 pub trait AsyncWritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -31,11 +34,27 @@ pub trait AsyncWritableFileSystem {
   fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>>;
 }
 
-pub trait AsyncReadableFileSystem {
+pub trait AsyncReadableFileSystem: Debug + Send + Sync {
   /// Read the entire contents of a file into a bytes vector.
   ///
   /// Error: This function will return an error if path does not already exist.
-  fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>>;
+  fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>>;
+
+  /// Returns the names of the entries within a directory, in no particular order.
+  ///
+  /// Error: This function will return an error if path does not exist or is not a directory.
+  fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>>;
+
+  /// Queries the metadata of a file or directory, symbolic links are followed.
+  ///
+  /// Error: This function will return an error if path does not exist.
+  fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>>;
+
+  /// Returns the canonical, absolute form of a path with all intermediate components normalized
+  /// and symbolic links resolved.
+  ///
+  /// Error: This function will return an error if path does not exist.
+  fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>>;
 }
 
 /// Async readable and writable file system representation.
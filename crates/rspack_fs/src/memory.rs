@@ -0,0 +1,481 @@
+use std::{
+  collections::HashMap,
+  io::ErrorKind,
+  path::{Component, Path, PathBuf},
+  sync::{Arc, Mutex, MutexGuard},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{
+  cfg_async,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, FileMetadata, Result,
+};
+
+#[derive(Debug)]
+enum Entry {
+  File { content: Vec<u8>, mtime_ms: u64 },
+  Directory { mtime_ms: u64 },
+}
+
+impl Entry {
+  fn metadata(&self) -> FileMetadata {
+    match self {
+      Entry::File { content, mtime_ms } => FileMetadata {
+        is_file: true,
+        size: content.len() as u64,
+        mtime_ms: *mtime_ms,
+        ..Default::default()
+      },
+      Entry::Directory { mtime_ms } => FileMetadata {
+        is_directory: true,
+        mtime_ms: *mtime_ms,
+        ..Default::default()
+      },
+    }
+  }
+}
+
+/// A file system keeping everything in memory.
+///
+/// Clones share the same storage, so a clone can be handed to the compiler as its output (or input)
+/// file system while the original is kept around to inspect the emitted files.
+/// The root directory always exists, relative paths are not supported.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+  entries: Arc<Mutex<HashMap<PathBuf, Entry>>>,
+}
+
+impl MemoryFileSystem {
+  fn entries(&self) -> MutexGuard<'_, HashMap<PathBuf, Entry>> {
+    self.entries.lock().expect("should be able to lock")
+  }
+
+  fn is_dir_in(entries: &HashMap<PathBuf, Entry>, path: &Path) -> bool {
+    path.parent().is_none() || matches!(entries.get(path), Some(Entry::Directory { .. }))
+  }
+
+  fn create_dir_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir)?;
+    let mut entries = self.entries();
+    if dir.parent().is_none() || entries.contains_key(&dir) {
+      return Err(io_error(
+        ErrorKind::AlreadyExists,
+        "directory already exists",
+        &dir,
+      ));
+    }
+    let parent = dir.parent().expect("should have a parent");
+    if !Self::is_dir_in(&entries, parent) {
+      return Err(io_error(ErrorKind::NotFound, "no such directory", parent));
+    }
+    entries.insert(dir, Entry::Directory { mtime_ms: now_ms() });
+    Ok(())
+  }
+
+  fn create_dir_all_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir)?;
+    let mut entries = self.entries();
+    for ancestor in dir.ancestors().collect::<Vec<_>>().into_iter().rev() {
+      if ancestor.parent().is_none() {
+        continue;
+      }
+      match entries.get(ancestor) {
+        Some(Entry::Directory { .. }) => {}
+        Some(Entry::File { .. }) => {
+          return Err(io_error(
+            ErrorKind::AlreadyExists,
+            "not a directory",
+            ancestor,
+          ));
+        }
+        None => {
+          entries.insert(
+            ancestor.to_path_buf(),
+            Entry::Directory { mtime_ms: now_ms() },
+          );
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn write_impl(&self, file: &Path, data: &[u8]) -> Result<()> {
+    let file = normalize(file)?;
+    let mut entries = self.entries();
+    let Some(parent) = file.parent() else {
+      return Err(io_error(ErrorKind::Other, "is a directory", &file));
+    };
+    if !Self::is_dir_in(&entries, parent) {
+      return Err(io_error(ErrorKind::NotFound, "no such directory", parent));
+    }
+    if let Some(Entry::Directory { .. }) = entries.get(&file) {
+      return Err(io_error(ErrorKind::Other, "is a directory", &file));
+    }
+    entries.insert(
+      file,
+      Entry::File {
+        content: data.to_vec(),
+        mtime_ms: now_ms(),
+      },
+    );
+    Ok(())
+  }
+
+  fn remove_file_impl(&self, file: &Path) -> Result<()> {
+    let file = normalize(file)?;
+    let mut entries = self.entries();
+    match entries.get(&file) {
+      Some(Entry::File { .. }) => {
+        entries.remove(&file);
+        Ok(())
+      }
+      Some(Entry::Directory { .. }) => Err(io_error(ErrorKind::Other, "is a directory", &file)),
+      None => Err(io_error(ErrorKind::NotFound, "no such file", &file)),
+    }
+  }
+
+  fn remove_dir_all_impl(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir)?;
+    let mut entries = self.entries();
+    match entries.get(&dir) {
+      Some(Entry::File { .. }) => {
+        return Err(io_error(ErrorKind::Other, "not a directory", &dir));
+      }
+      None if dir.parent().is_some() => {
+        return Err(io_error(ErrorKind::NotFound, "no such directory", &dir));
+      }
+      _ => {}
+    }
+    entries.retain(|path, _| !path.starts_with(&dir));
+    Ok(())
+  }
+
+  fn read_impl(&self, file: &Path) -> Result<Vec<u8>> {
+    let file = normalize(file)?;
+    match self.entries().get(&file) {
+      Some(Entry::File { content, .. }) => Ok(content.clone()),
+      Some(Entry::Directory { .. }) => Err(io_error(ErrorKind::Other, "is a directory", &file)),
+      None => Err(io_error(ErrorKind::NotFound, "no such file", &file)),
+    }
+  }
+
+  fn read_dir_impl(&self, dir: &Path) -> Result<Vec<String>> {
+    let dir = normalize(dir)?;
+    let entries = self.entries();
+    if !Self::is_dir_in(&entries, &dir) {
+      return Err(io_error(ErrorKind::NotFound, "no such directory", &dir));
+    }
+    Ok(
+      entries
+        .keys()
+        .filter(|path| path.parent() == Some(dir.as_path()))
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect(),
+    )
+  }
+
+  fn metadata_impl(&self, path: &Path) -> Result<FileMetadata> {
+    let path = normalize(path)?;
+    if path.parent().is_none() {
+      return Ok(Entry::Directory { mtime_ms: 0 }.metadata());
+    }
+    self
+      .entries()
+      .get(&path)
+      .map(Entry::metadata)
+      .ok_or_else(|| io_error(ErrorKind::NotFound, "no such file or directory", &path))
+  }
+
+  fn canonicalize_impl(&self, path: &Path) -> Result<PathBuf> {
+    self.metadata_impl(path)?;
+    normalize(path)
+  }
+}
+
+/// Resolves `.` and `..` lexically, there are no symbolic links in memory.
+fn normalize(path: &Path) -> Result<PathBuf> {
+  if !path.is_absolute() {
+    return Err(io_error(
+      ErrorKind::InvalidInput,
+      "expected an absolute path",
+      path,
+    ));
+  }
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      component => normalized.push(component),
+    }
+  }
+  Ok(normalized)
+}
+
+fn io_error(kind: ErrorKind, message: &str, path: &Path) -> Error {
+  Error::Io(std::io::Error::new(
+    kind,
+    format!("{message}, '{}'", path.display()),
+  ))
+}
+
+fn now_ms() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.create_dir_impl(dir.as_ref())
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.create_dir_all_impl(dir.as_ref())
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    self.write_impl(file.as_ref(), data.as_ref())
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
+    self.read_impl(file.as_ref())
+  }
+
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>> {
+    self.read_dir_impl(dir.as_ref())
+  }
+
+  fn metadata<P: AsRef<Path>>(&self, path: P) -> Result<FileMetadata> {
+    self.metadata_impl(path.as_ref())
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.create_dir_impl(dir.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.create_dir_all_impl(dir.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+      let res = self.write_impl(file.as_ref(), data.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.remove_file_impl(file.as_ref());
+      Box::pin(async move { res })
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let res = self.remove_dir_all_impl(dir.as_ref());
+      Box::pin(async move { res })
+    }
+  }
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let res = self.read_impl(file);
+      Box::pin(async move { res })
+    }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let res = self.read_dir_impl(dir);
+      Box::pin(async move { res })
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let res = self.metadata_impl(path);
+      Box::pin(async move { res })
+    }
+
+    fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let res = self.canonicalize_impl(path);
+      Box::pin(async move { res })
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::{Path, PathBuf};
+
+  use super::MemoryFileSystem;
+  use crate::{ReadableFileSystem, WritableFileSystem};
+
+  #[test]
+  fn test_memory_file_system() {
+    let fs = MemoryFileSystem::default();
+    assert!(fs.write("/dist/main.js", "").is_err());
+    fs.create_dir_all("/dist/static").unwrap();
+    fs.write("/dist/main.js", "main").unwrap();
+    fs.write("/dist/static/logo.svg", "<svg />").unwrap();
+
+    // clones share the same storage
+    let cloned = fs.clone();
+    assert_eq!(cloned.read("/dist/static/../main.js").unwrap(), b"main");
+    let mut names = cloned.read_dir("/dist").unwrap();
+    names.sort();
+    assert_eq!(names, vec!["main.js", "static"]);
+    assert!(cloned.metadata("/dist/static").unwrap().is_directory);
+    assert_eq!(cloned.metadata("/dist/main.js").unwrap().size, 4);
+
+    assert!(fs.create_dir("/dist").is_err());
+    assert!(fs.create_dir("/missing/dir").is_err());
+    assert!(fs.read("/dist").is_err());
+  }
+
+  #[test]
+  fn test_emit_into_memory() {
+    let dir = std::env::temp_dir().join("rspack_fs_memory_emit");
+    let dist = dir.join("dist");
+    let fs = MemoryFileSystem::default();
+    // as the compiler emits assets, the directory of each asset is created first
+    for (name, content) in [("main.js", "main"), ("chunks/lazy.js", "lazy")] {
+      let file = dist.join(name);
+      fs.create_dir_all(file.parent().unwrap()).unwrap();
+      fs.write(&file, content).unwrap();
+    }
+
+    let mut names = fs.read_dir(&dist).unwrap();
+    names.sort();
+    assert_eq!(names, vec!["chunks", "main.js"]);
+    assert_eq!(fs.read_dir(dist.join("chunks")).unwrap(), vec!["lazy.js"]);
+    let metadata = fs.metadata(dist.join("chunks/lazy.js")).unwrap();
+    assert!(metadata.is_file);
+    assert_eq!(metadata.size, 4);
+    // nothing is written to the native file system
+    assert!(!dist.exists());
+  }
+
+  #[test]
+  fn test_canonicalize() {
+    let fs = MemoryFileSystem::default();
+    fs.create_dir_all("/dist/static").unwrap();
+    assert_eq!(
+      fs.canonicalize_impl(Path::new("/dist/./static/../static"))
+        .unwrap(),
+      PathBuf::from("/dist/static")
+    );
+    assert_eq!(
+      fs.canonicalize_impl(Path::new("/")).unwrap(),
+      PathBuf::from("/")
+    );
+    assert!(fs.canonicalize_impl(Path::new("/missing")).is_err());
+    assert!(fs.canonicalize_impl(Path::new("dist")).is_err());
+  }
+
+  #[cfg(feature = "async")]
+  #[test]
+  fn test_async_memory_file_system() {
+    use futures::executor::block_on;
+
+    use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+    let fs = MemoryFileSystem::default();
+    block_on(async {
+      AsyncWritableFileSystem::create_dir_all(&fs, "/dist/static")
+        .await
+        .unwrap();
+      AsyncWritableFileSystem::create_dir(&fs, "/dist/chunks")
+        .await
+        .unwrap();
+      AsyncWritableFileSystem::write(&fs, "/dist/main.js", "main")
+        .await
+        .unwrap();
+      AsyncWritableFileSystem::write(&fs, "/dist/static/logo.svg", "<svg />")
+        .await
+        .unwrap();
+      assert!(AsyncWritableFileSystem::write(&fs, "/missing/main.js", "")
+        .await
+        .is_err());
+
+      assert_eq!(
+        AsyncReadableFileSystem::read(&fs, Path::new("/dist/main.js"))
+          .await
+          .unwrap(),
+        b"main"
+      );
+      let mut names = AsyncReadableFileSystem::read_dir(&fs, Path::new("/dist"))
+        .await
+        .unwrap();
+      names.sort();
+      assert_eq!(names, vec!["chunks", "main.js", "static"]);
+      let metadata = AsyncReadableFileSystem::metadata(&fs, Path::new("/dist/static/logo.svg"))
+        .await
+        .unwrap();
+      assert!(metadata.is_file);
+      assert_eq!(metadata.size, 7);
+      assert_eq!(
+        AsyncReadableFileSystem::canonicalize(&fs, Path::new("/dist/static/../main.js"))
+          .await
+          .unwrap(),
+        PathBuf::from("/dist/main.js")
+      );
+
+      // a file can't be removed as a directory and the other way around
+      assert!(AsyncWritableFileSystem::remove_file(&fs, "/dist/static")
+        .await
+        .is_err());
+      let error = AsyncWritableFileSystem::remove_dir_all(&fs, "/dist/main.js")
+        .await
+        .unwrap_err();
+      assert!(error.to_string().contains("not a directory"), "{error}");
+      assert!(
+        AsyncWritableFileSystem::remove_file(&fs, "/dist/missing.js")
+          .await
+          .is_err()
+      );
+      assert!(AsyncWritableFileSystem::remove_dir_all(&fs, "/missing")
+        .await
+        .is_err());
+
+      AsyncWritableFileSystem::remove_file(&fs, "/dist/main.js")
+        .await
+        .unwrap();
+      assert!(
+        AsyncReadableFileSystem::read(&fs, Path::new("/dist/main.js"))
+          .await
+          .is_err()
+      );
+      AsyncWritableFileSystem::remove_dir_all(&fs, "/dist/static")
+        .await
+        .unwrap();
+      assert!(
+        AsyncReadableFileSystem::metadata(&fs, Path::new("/dist/static/logo.svg"))
+          .await
+          .is_err()
+      );
+      assert_eq!(
+        AsyncReadableFileSystem::read_dir(&fs, Path::new("/dist"))
+          .await
+          .unwrap(),
+        vec!["chunks"]
+      );
+      AsyncWritableFileSystem::remove_dir_all(&fs, "/dist")
+        .await
+        .unwrap();
+      assert!(AsyncReadableFileSystem::read_dir(&fs, Path::new("/"))
+        .await
+        .unwrap()
+        .is_empty());
+    });
+  }
+}
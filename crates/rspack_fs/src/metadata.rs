@@ -0,0 +1,34 @@
+use std::time::UNIX_EPOCH;
+
+use crate::{Error, Result};
+
+/// Metadata of a file or directory, independent of the file system it comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileMetadata {
+  pub is_file: bool,
+  pub is_directory: bool,
+  pub is_symlink: bool,
+  /// Size of the file in bytes
+  pub size: u64,
+  /// Last modification time in milliseconds since the unix epoch
+  pub mtime_ms: u64,
+}
+
+impl TryFrom<std::fs::Metadata> for FileMetadata {
+  type Error = Error;
+
+  fn try_from(metadata: std::fs::Metadata) -> Result<Self> {
+    let mtime_ms = metadata
+      .modified()?
+      .duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_millis() as u64)
+      .unwrap_or_default();
+    Ok(Self {
+      is_file: metadata.is_file(),
+      is_directory: metadata.is_dir(),
+      is_symlink: metadata.is_symlink(),
+      size: metadata.len(),
+      mtime_ms,
+    })
+  }
+}
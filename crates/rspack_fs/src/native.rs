@@ -3,7 +3,7 @@ use std::{fs, path::Path};
 use super::{
   cfg_async,
   sync::{ReadableFileSystem, WritableFileSystem},
-  Error, Result,
+  Error, FileMetadata, Result,
 };
 
 pub struct NativeFileSystem;
@@ -26,12 +26,26 @@ impl ReadableFileSystem for NativeFileSystem {
   fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
     fs::read(file.as_ref()).map_err(Error::from)
   }
+
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>> {
+    fs::read_dir(dir.as_ref())?
+      .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+      .collect()
+  }
+
+  fn metadata<P: AsRef<Path>>(&self, path: P) -> Result<FileMetadata> {
+    FileMetadata::try_from(fs::metadata(path.as_ref())?)
+  }
 }
 
 cfg_async! {
+  use std::path::PathBuf;
+
   use futures::future::BoxFuture;
 
   use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  #[derive(Debug, Default)]
   pub struct AsyncNativeFileSystem;
 
   impl AsyncWritableFileSystem for AsyncNativeFileSystem {
@@ -72,10 +86,35 @@ cfg_async! {
   }
 
   impl AsyncReadableFileSystem for AsyncNativeFileSystem {
-    fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>> {
-      let file = file.as_ref().to_string_lossy().to_string();
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let file = file.to_path_buf();
       let fut = async move { tokio::fs::read(file).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn read_dir(&self, dir: &Path) -> BoxFuture<'_, Result<Vec<String>>> {
+      let dir = dir.to_path_buf();
+      let fut = async move {
+        let mut entries = tokio::fs::read_dir(dir).await?;
+        let mut names = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+          names.push(entry.file_name().to_string_lossy().to_string());
+        }
+        Ok(names)
+      };
+      Box::pin(fut)
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let path = path.to_path_buf();
+      let fut = async move { FileMetadata::try_from(tokio::fs::metadata(path).await?) };
+      Box::pin(fut)
+    }
+
+    fn canonicalize(&self, path: &Path) -> BoxFuture<'_, Result<PathBuf>> {
+      let path = path.to_path_buf();
+      let fut = async move { tokio::fs::canonicalize(path).await.map_err(Error::from) };
+      Box::pin(fut)
+    }
   }
 }
@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use super::Result;
+use super::{FileMetadata, Result};
 
 pub trait WritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -28,6 +28,16 @@ pub trait ReadableFileSystem {
   ///
   /// Error: This function will return an error if path does not already exist.
   fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>>;
+
+  /// Returns the names of the entries within a directory, in no particular order.
+  ///
+  /// Error: This function will return an error if path does not exist or is not a directory.
+  fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>>;
+
+  /// Queries the metadata of a file or directory, symbolic links are followed.
+  ///
+  /// Error: This function will return an error if path does not exist.
+  fn metadata<P: AsRef<Path>>(&self, path: P) -> Result<FileMetadata>;
 }
 
 /// Readable and writable file system representation.
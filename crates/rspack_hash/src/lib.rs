@@ -3,23 +3,32 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
+use rspack_error::error;
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash128,
   MD4,
+  Sha256,
+  Sha512,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash128" => Ok(HashFunction::Xxhash128),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha512" => Ok(HashFunction::Sha512),
+      _ => Err(error!("Unsupported hash function: '{value}'")),
     }
   }
 }
@@ -27,13 +36,25 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  Base64Url,
+  Base32,
+  Base58,
+  Base62,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base32" => Ok(HashDigest::Base32),
+      "base58" => Ok(HashDigest::Base58),
+      "base62" => Ok(HashDigest::Base62),
+      _ => Err(error!("Unsupported hash digest: '{value}'")),
     }
   }
 }
@@ -56,14 +77,20 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<xxh3::Xxh3>),
+  Xxhash128(Box<xxh3::Xxh3>),
   MD4(md4::Md4),
+  Sha256(sha2::Sha256),
+  Sha512(sha2::Sha512),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash128(_) => write!(f, "RspackHash(Xxhash128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
     }
   }
 }
@@ -72,7 +99,10 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(xxh3::Xxh3::new())),
+      HashFunction::Xxhash128 => Self::Xxhash128(Box::new(xxh3::Xxh3::new())),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::Sha256 => Self::Sha256(sha2::Sha256::new()),
+      HashFunction::Sha512 => Self::Sha512(sha2::Sha512::new()),
     }
   }
 
@@ -85,7 +115,10 @@ impl RspackHash {
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_le_bytes().to_vec(),
+      RspackHash::Xxhash128(hasher) => hasher.digest128().to_le_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -95,30 +128,54 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      RspackHash::Xxhash128(hasher) => hasher.finish(),
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha512(hasher) => msb_u64(&hasher.clone().finalize()),
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash128(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
     }
   }
 }
 
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
+// Same alphabets as `loader-utils`, so hashes stay compatible with webpack.
+const BASE32_ALPHABET: &[u8] = b"123456789abcdefghjkmnpqrstuvwxyz";
+const BASE58_ALPHABET: &[u8] = b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+const BASE62_ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Encode `input` as a big-endian number in the base of `alphabet`.
+fn encode_base_n(input: &[u8], alphabet: &[u8]) -> String {
+  let base = alphabet.len() as u32;
+  let mut number = input.to_vec();
+  let mut output = Vec::new();
+  while number.iter().any(|byte| *byte != 0) {
+    let mut remainder = 0u32;
+    for byte in number.iter_mut() {
+      let acc = (remainder << 8) | *byte as u32;
+      *byte = (acc / base) as u8;
+      remainder = acc % base;
+    }
+    output.push(alphabet[remainder as usize]);
+  }
+  output.reverse();
+  String::from_utf8(output).expect("alphabet should be ascii")
+}
+
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
   encoded: SmolStr,
@@ -128,6 +185,11 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base32 => encode_base_n(&inner, BASE32_ALPHABET).into(),
+      HashDigest::Base58 => encode_base_n(&inner, BASE58_ALPHABET).into(),
+      HashDigest::Base62 => encode_base_n(&inner, BASE62_ALPHABET).into(),
     };
     Self { encoded }
   }
@@ -142,6 +204,14 @@ impl RspackHashDigest {
   }
 }
 
+impl From<&str> for RspackHashDigest {
+  fn from(value: &str) -> Self {
+    Self {
+      encoded: value.into(),
+    }
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -153,3 +223,25 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn encode_base_n_works() {
+    assert_eq!(encode_base_n(&[0xff], BASE62_ALPHABET), "47");
+    assert_eq!(encode_base_n(&[0x01, 0x00], BASE58_ALPHABET), "5q");
+    assert_eq!(encode_base_n(&[0, 0], BASE32_ALPHABET), "");
+  }
+
+  #[test]
+  fn unknown_values_are_errors() {
+    assert!(HashFunction::try_from("md5").is_err());
+    assert!(HashDigest::try_from("latin1").is_err());
+    assert!(matches!(
+      HashDigest::try_from("base62"),
+      Ok(HashDigest::Base62)
+    ));
+  }
+}
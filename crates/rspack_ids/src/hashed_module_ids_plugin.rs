@@ -0,0 +1,103 @@
+use std::hash::Hasher;
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilationModuleIds, CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{
+  compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
+  get_used_module_ids_and_modules,
+};
+
+#[derive(Debug)]
+pub struct HashedModuleIdsPluginOptions {
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashDigest::Base64,
+      hash_digest_length: 4,
+    }
+  }
+}
+
+/// Uses the hash of the module path as module id.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationModuleIds for HashedModuleIdsPlugin)]
+fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+  let mut chunk_graph = std::mem::take(&mut compilation.chunk_graph);
+  let module_graph = compilation.get_module_graph();
+  let context = self
+    .options
+    .context
+    .as_deref()
+    .unwrap_or(compilation.options.context.as_str());
+
+  let mut modules_in_natural_order = modules
+    .into_iter()
+    .filter_map(|i| module_graph.module_by_identifier(&i))
+    .collect::<Vec<_>>();
+  modules_in_natural_order
+    .sort_unstable_by(|a, b| compare_modules_by_pre_order_index_or_identifier(&module_graph, a, b));
+
+  for module in modules_in_natural_order {
+    let ident = get_full_module_name(module, context);
+    let mut hasher = RspackHash::new(&self.options.hash_function);
+    hasher.write(ident.as_bytes());
+    let hash_id = hasher.digest(&self.options.hash_digest);
+    let hash_id = hash_id.encoded();
+    let mut len = self.options.hash_digest_length;
+    while len < hash_id.len() && used_ids.contains(&hash_id[..len]) {
+      len += 1;
+    }
+    let module_id = hash_id[..len.min(hash_id.len())].to_string();
+    chunk_graph.set_module_id(module.identifier(), module_id.clone());
+    used_ids.insert(module_id);
+  }
+
+  drop(module_graph);
+  compilation.chunk_graph = chunk_graph;
+  Ok(())
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedModuleIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    Ok(())
+  }
+}
@@ -8,3 +8,15 @@ mod named_chunk_ids_plugin;
 pub use named_chunk_ids_plugin::*;
 mod deterministic_chunk_ids_plugin;
 pub use deterministic_chunk_ids_plugin::DeterministicChunkIdsPlugin;
+mod natural_module_ids_plugin;
+pub use natural_module_ids_plugin::*;
+mod natural_chunk_ids_plugin;
+pub use natural_chunk_ids_plugin::*;
+mod occurrence_module_ids_plugin;
+pub use occurrence_module_ids_plugin::*;
+mod occurrence_chunk_ids_plugin;
+pub use occurrence_chunk_ids_plugin::*;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::*;
+mod record_ids_plugin;
+pub use record_ids_plugin::*;
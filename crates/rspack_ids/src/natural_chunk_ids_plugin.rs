@@ -0,0 +1,48 @@
+use rspack_core::{
+  ApplyContext, Compilation, CompilationChunkIds, CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct NaturalChunkIdsPlugin;
+
+#[plugin_hook(CompilationChunkIds for NaturalChunkIdsPlugin)]
+fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let chunk_graph = &compilation.chunk_graph;
+  let module_graph = compilation.get_module_graph();
+
+  let mut chunks_in_natural_order = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+  chunks_in_natural_order
+    .sort_unstable_by(|a, b| compare_chunks_natural(chunk_graph, &module_graph, a, b));
+  let chunks_in_natural_order = chunks_in_natural_order
+    .into_iter()
+    .map(|chunk| chunk.ukey)
+    .collect::<Vec<_>>();
+  drop(module_graph);
+
+  assign_ascending_chunk_ids(&chunks_in_natural_order, compilation);
+  Ok(())
+}
+
+impl Plugin for NaturalChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NaturalChunkIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(chunk_ids::new(self));
+    Ok(())
+  }
+}
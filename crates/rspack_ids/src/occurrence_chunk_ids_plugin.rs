@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilationChunkIds, CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+#[derive(Debug, Default)]
+pub struct OccurrenceChunkIdsPluginOptions {
+  /// Prioritize chunks loaded from initial chunks
+  pub prioritize_initial: bool,
+}
+
+/// Assigns shorter ids to chunks which are used more often.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct OccurrenceChunkIdsPlugin {
+  options: OccurrenceChunkIdsPluginOptions,
+}
+
+impl OccurrenceChunkIdsPlugin {
+  pub fn new(options: OccurrenceChunkIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationChunkIds for OccurrenceChunkIdsPlugin)]
+fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let chunk_graph = &compilation.chunk_graph;
+  let module_graph = compilation.get_module_graph();
+  let chunk_group_by_ukey = &compilation.chunk_group_by_ukey;
+
+  let occurs_in_initial_chunks_map = compilation
+    .chunk_by_ukey
+    .values()
+    .map(|chunk| {
+      let occurs = chunk
+        .groups
+        .iter()
+        .map(|group| chunk_group_by_ukey.expect_get(group))
+        .flat_map(|group| group.parents_iterable())
+        .filter(|parent| chunk_group_by_ukey.expect_get(parent).is_initial())
+        .count();
+      (chunk.ukey, occurs)
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut chunks_in_occurrence_order = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+  chunks_in_occurrence_order.sort_unstable_by(|a, b| {
+    if self.options.prioritize_initial {
+      let a_entry_occurs = occurs_in_initial_chunks_map[&a.ukey];
+      let b_entry_occurs = occurs_in_initial_chunks_map[&b.ukey];
+      match b_entry_occurs.cmp(&a_entry_occurs) {
+        Ordering::Equal => {}
+        ordering => return ordering,
+      }
+    }
+    match b.groups.len().cmp(&a.groups.len()) {
+      Ordering::Equal => compare_chunks_natural(chunk_graph, &module_graph, a, b),
+      ordering => ordering,
+    }
+  });
+  let chunks_in_occurrence_order = chunks_in_occurrence_order
+    .into_iter()
+    .map(|chunk| chunk.ukey)
+    .collect::<Vec<_>>();
+  drop(module_graph);
+
+  assign_ascending_chunk_ids(&chunks_in_occurrence_order, compilation);
+  Ok(())
+}
+
+impl Plugin for OccurrenceChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceChunkIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(chunk_ids::new(self));
+    Ok(())
+  }
+}
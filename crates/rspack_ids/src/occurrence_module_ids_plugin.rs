@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+
+use rspack_core::{
+  ApplyContext, ChunkGraph, Compilation, CompilationModuleIds, CompilerOptions, ModuleGraph,
+  ModuleIdentifier, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{
+  assign_ascending_module_ids, compare_modules_by_pre_order_index_or_identifier,
+  get_used_module_ids_and_modules,
+};
+
+#[derive(Debug, Default)]
+pub struct OccurrenceModuleIdsPluginOptions {
+  /// Prioritize modules used in initial chunks
+  pub prioritize_initial: bool,
+}
+
+/// Assigns shorter ids to modules which are used more often.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct OccurrenceModuleIdsPlugin {
+  options: OccurrenceModuleIdsPluginOptions,
+}
+
+impl OccurrenceModuleIdsPlugin {
+  pub fn new(options: OccurrenceModuleIdsPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+fn count_occurs_in_entry(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+  initial_chunk_chunk_map: &HashMap<ModuleIdentifier, usize>,
+) -> usize {
+  module_graph
+    .get_incoming_connections_by_origin_module(module)
+    .into_iter()
+    .filter_map(|(origin_module, connections)| {
+      let origin_module = origin_module?;
+      connections
+        .iter()
+        .any(|c| c.is_target_active(module_graph, None))
+        .then(|| {
+          initial_chunk_chunk_map
+            .get(&origin_module)
+            .copied()
+            .unwrap_or_default()
+        })
+    })
+    .sum()
+}
+
+fn count_occurs(
+  module_graph: &ModuleGraph,
+  chunk_graph: &ChunkGraph,
+  module: &ModuleIdentifier,
+) -> usize {
+  module_graph
+    .get_incoming_connections_by_origin_module(module)
+    .into_iter()
+    .filter_map(|(origin_module, connections)| {
+      let origin_module = origin_module?;
+      let chunk_modules = chunk_graph.get_number_of_module_chunks(origin_module);
+      let active_connections = connections
+        .iter()
+        .filter(|c| c.is_target_active(module_graph, None))
+        .count();
+      Some(active_connections * chunk_modules)
+    })
+    .sum()
+}
+
+#[plugin_hook(CompilationModuleIds for OccurrenceModuleIdsPlugin)]
+fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+  let (used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+  let mut chunk_graph = std::mem::take(&mut compilation.chunk_graph);
+  let module_graph = compilation.get_module_graph();
+
+  let mut initial_chunk_chunk_map = HashMap::default();
+  let mut entry_count_map = HashMap::default();
+  for module in &modules {
+    let mut initial = 0;
+    let mut entry = 0;
+    for chunk_ukey in chunk_graph.get_module_chunks(*module) {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        initial += 1;
+      }
+      if chunk_graph.is_entry_module_in_chunk(module, chunk_ukey) {
+        entry += 1;
+      }
+    }
+    initial_chunk_chunk_map.insert(*module, initial);
+    entry_count_map.insert(*module, entry);
+  }
+
+  let occurs_in_initial_chunks_map = if self.options.prioritize_initial {
+    modules
+      .iter()
+      .map(|module| {
+        let result = count_occurs_in_entry(&module_graph, module, &initial_chunk_chunk_map)
+          + initial_chunk_chunk_map[module]
+          + entry_count_map[module];
+        (*module, result)
+      })
+      .collect::<HashMap<_, _>>()
+  } else {
+    HashMap::default()
+  };
+
+  let occurs_in_all_chunks_map = modules
+    .iter()
+    .map(|module| {
+      let result = count_occurs(&module_graph, &chunk_graph, module)
+        + chunk_graph.get_number_of_module_chunks(*module)
+        + entry_count_map[module];
+      (*module, result)
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut modules_in_occurrence_order = modules
+    .iter()
+    .filter_map(|i| module_graph.module_by_identifier(i))
+    .collect::<Vec<_>>();
+  modules_in_occurrence_order.sort_unstable_by(|a, b| {
+    if self.options.prioritize_initial {
+      let a_entry_occurs = occurs_in_initial_chunks_map[&a.identifier()];
+      let b_entry_occurs = occurs_in_initial_chunks_map[&b.identifier()];
+      match b_entry_occurs.cmp(&a_entry_occurs) {
+        Ordering::Equal => {}
+        ordering => return ordering,
+      }
+    }
+    let a_occurs = occurs_in_all_chunks_map[&a.identifier()];
+    let b_occurs = occurs_in_all_chunks_map[&b.identifier()];
+    match b_occurs.cmp(&a_occurs) {
+      Ordering::Equal => compare_modules_by_pre_order_index_or_identifier(&module_graph, a, b),
+      ordering => ordering,
+    }
+  });
+
+  assign_ascending_module_ids(&used_ids, modules_in_occurrence_order, &mut chunk_graph);
+  drop(module_graph);
+  compilation.chunk_graph = chunk_graph;
+  Ok(())
+}
+
+impl Plugin for OccurrenceModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceModuleIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(module_ids::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeSet;
+
+use rspack_core::{
+  ApplyContext, Chunk, ChunkGroupByUkey, ChunkIdRecords, Compilation, CompilationChunkIds,
+  CompilationModuleIds, CompilerOptions, ModuleIdRecords, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::identifier::make_paths_relative;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Reuses the ids stored in [rspack_core::IdRecords] for modules and chunks of
+/// a previous build, and stores the ids of this build for the next one.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct RecordIdsPlugin;
+
+fn get_chunk_sources(chunk: &Chunk, chunk_group_by_ukey: &ChunkGroupByUkey) -> Vec<String> {
+  let mut sources = chunk
+    .groups
+    .iter()
+    .map(|group| chunk_group_by_ukey.expect_get(group))
+    .filter_map(|group| {
+      let name = group.name()?;
+      let index = group.chunks.iter().position(|c| *c == chunk.ukey)?;
+      Some(format!("{name}:{index}"))
+    })
+    .collect::<Vec<_>>();
+  sources.sort_unstable();
+  sources
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = i32::MIN)]
+fn revive_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(records) = compilation
+    .id_records
+    .as_ref()
+    .and_then(|records| records.modules.as_ref())
+  else {
+    return Ok(());
+  };
+  let context = compilation.options.context.as_ref();
+  let mut used_ids = records.used_ids.iter().cloned().collect::<BTreeSet<_>>();
+  let module_graph = compilation.get_module_graph();
+  let mut revived = vec![];
+  let mut revived_ids = BTreeSet::new();
+  for module_identifier in module_graph.modules().keys() {
+    if compilation
+      .chunk_graph
+      .get_module_id(*module_identifier)
+      .is_some()
+    {
+      continue;
+    }
+    let identifier = make_paths_relative(context, module_identifier);
+    let Some(id) = records.by_identifier.get(&identifier) else {
+      continue;
+    };
+    if !revived_ids.insert(id.clone()) {
+      continue;
+    }
+    used_ids.insert(id.clone());
+    revived.push((*module_identifier, id.clone()));
+  }
+  drop(module_graph);
+
+  for (module_identifier, id) in revived {
+    compilation.chunk_graph.set_module_id(module_identifier, id);
+  }
+  compilation.used_module_ids.extend(used_ids);
+  Ok(())
+}
+
+#[plugin_hook(CompilationModuleIds for RecordIdsPlugin, stage = i32::MAX)]
+fn record_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  if compilation.id_records.is_none() {
+    return Ok(());
+  }
+  let context = compilation.options.context.as_ref();
+  let mut records = ModuleIdRecords::default();
+  let module_graph = compilation.get_module_graph();
+  let mut used_ids = BTreeSet::new();
+  for module_identifier in module_graph.modules().keys() {
+    let Some(id) = compilation.chunk_graph.get_module_id(*module_identifier) else {
+      continue;
+    };
+    records
+      .by_identifier
+      .insert(make_paths_relative(context, module_identifier), id.clone());
+    used_ids.insert(id.clone());
+  }
+  drop(module_graph);
+  records.used_ids = used_ids.into_iter().collect();
+
+  if let Some(id_records) = &mut compilation.id_records {
+    id_records.modules = Some(records);
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = i32::MIN)]
+fn revive_chunks(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(records) = compilation
+    .id_records
+    .as_ref()
+    .and_then(|records| records.chunks.as_ref())
+  else {
+    return Ok(());
+  };
+  let mut used_ids = records.used_ids.iter().cloned().collect::<BTreeSet<_>>();
+  let mut revived = HashMap::default();
+  let mut revived_ids = BTreeSet::new();
+  for chunk in compilation.chunk_by_ukey.values() {
+    if chunk.id.is_some() {
+      continue;
+    }
+    let Some(name) = &chunk.name else {
+      continue;
+    };
+    let Some(id) = records.by_name.get(name) else {
+      continue;
+    };
+    if !revived_ids.insert(id.clone()) {
+      continue;
+    }
+    used_ids.insert(id.clone());
+    revived.insert(chunk.ukey, id.clone());
+  }
+  for chunk in compilation.chunk_by_ukey.values() {
+    if chunk.id.is_some() || revived.contains_key(&chunk.ukey) {
+      continue;
+    }
+    let sources = get_chunk_sources(chunk, &compilation.chunk_group_by_ukey);
+    let Some(id) = sources
+      .iter()
+      .find_map(|source| records.by_source.get(source))
+    else {
+      continue;
+    };
+    if !revived_ids.insert(id.clone()) {
+      continue;
+    }
+    used_ids.insert(id.clone());
+    revived.insert(chunk.ukey, id.clone());
+  }
+
+  for (chunk_ukey, id) in revived {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+    chunk.id = Some(id.clone());
+    chunk.ids = vec![id];
+  }
+  compilation.used_chunk_ids.extend(used_ids);
+  Ok(())
+}
+
+#[plugin_hook(CompilationChunkIds for RecordIdsPlugin, stage = i32::MAX)]
+fn record_chunks(&self, compilation: &mut Compilation) -> Result<()> {
+  if compilation.id_records.is_none() {
+    return Ok(());
+  }
+  let mut records = ChunkIdRecords::default();
+  let mut used_ids = BTreeSet::new();
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = &chunk.id else {
+      continue;
+    };
+    if let Some(name) = &chunk.name {
+      records.by_name.insert(name.clone(), id.clone());
+    }
+    for source in get_chunk_sources(chunk, &compilation.chunk_group_by_ukey) {
+      records.by_source.insert(source, id.clone());
+    }
+    used_ids.insert(id.clone());
+  }
+  records.used_ids = used_ids.into_iter().collect();
+
+  if let Some(id_records) = &mut compilation.id_records {
+    id_records.chunks = Some(records);
+  }
+  Ok(())
+}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RecordIdsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(revive_modules::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .module_ids
+      .tap(record_modules::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(revive_chunks::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .chunk_ids
+      .tap(record_chunks::new(self));
+    Ok(())
+  }
+}
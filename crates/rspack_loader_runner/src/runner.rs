@@ -8,6 +8,7 @@ use anymap::CloneAny;
 use derivative::Derivative;
 use once_cell::sync::OnceCell;
 use rspack_error::{error, Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_sources::SourceMap;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -197,6 +198,9 @@ pub struct LoaderContext<'c, C> {
 
   pub asset_filenames: HashSet<String>,
 
+  /// The input file system the resource is read from
+  pub fs: Arc<dyn AsyncReadableFileSystem>,
+
   // Only used for cross-crate accessing.
   // This field should not be accessed in builtin loaders.
   pub __loader_index: usize,
@@ -290,7 +294,9 @@ async fn process_resource<C: Send>(loader_context: &mut LoaderContext<'_, C>) ->
       .to_string_lossy()
       .is_empty()
   {
-    let result = tokio::fs::read(&loader_context.__resource_data.resource_path)
+    let result = loader_context
+      .fs
+      .read(&loader_context.__resource_data.resource_path)
       .await
       .map_err(|e| {
         let r = loader_context
@@ -325,6 +331,7 @@ async fn create_loader_context<'c, C: 'c>(
   resource_data: &'c mut ResourceData,
   plugins: &'c [&dyn LoaderRunnerPlugin<Context = C>],
   context: C,
+  fs: Arc<dyn AsyncReadableFileSystem>,
   additional_data: AdditionalData,
 ) -> Result<LoaderContext<'c, C>> {
   let mut file_dependencies: HashSet<PathBuf> = Default::default();
@@ -340,6 +347,7 @@ async fn create_loader_context<'c, C: 'c>(
     missing_dependencies: Default::default(),
     build_dependencies: Default::default(),
     asset_filenames: Default::default(),
+    fs,
     content: None,
     context,
     source_map: None,
@@ -422,7 +430,7 @@ async fn iterate_pitching_loaders<C: Send>(
   Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LoaderResult {
   pub cacheable: bool,
   pub file_dependencies: HashSet<PathBuf>,
@@ -469,6 +477,7 @@ pub async fn run_loaders<C: 'static + Send>(
   resource_data: &mut ResourceData,
   plugins: &[&dyn LoaderRunnerPlugin<Context = C>],
   context: C,
+  fs: Arc<dyn AsyncReadableFileSystem>,
   additional_data: AdditionalData,
 ) -> Result<TWithDiagnosticArray<LoaderResult>> {
   let loaders = loaders
@@ -481,6 +490,7 @@ pub async fn run_loaders<C: 'static + Send>(
     resource_data,
     plugins,
     context,
+    fs,
     additional_data,
   )
   .await?;
@@ -498,6 +508,7 @@ mod test {
 
   use once_cell::sync::OnceCell;
   use rspack_error::Result;
+  use rspack_fs::AsyncNativeFileSystem;
   use rspack_identifier::{Identifiable, Identifier};
 
   use super::{run_loaders, Loader, LoaderContext, ResourceData};
@@ -595,6 +606,7 @@ mod test {
       &mut rs,
       &[&TestContentPlugin],
       (),
+      Arc::new(AsyncNativeFileSystem),
       Default::default(),
     )
     .await
@@ -774,6 +786,7 @@ mod test {
       &mut rs,
       &[&TestContentPlugin],
       (),
+      Arc::new(AsyncNativeFileSystem),
       Default::default(),
     )
     .await
@@ -790,6 +803,7 @@ mod test {
       &mut rs,
       &[&TestContentPlugin],
       (),
+      Arc::new(AsyncNativeFileSystem),
       Default::default(),
     )
     .await
@@ -861,9 +875,65 @@ mod test {
       &mut rs,
       &[&TestContentPlugin],
       (),
+      Arc::new(AsyncNativeFileSystem),
+      Default::default(),
+    )
+    .await
+    .unwrap();
+  }
+
+  #[tokio::test]
+  async fn should_read_resource_from_input_filesystem() {
+    use std::{
+      future::Future,
+      path::{Path, PathBuf},
+      pin::Pin,
+    };
+
+    use rspack_fs::{AsyncReadableFileSystem, FileMetadata};
+
+    type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = rspack_fs::Result<T>> + Send + 'a>>;
+
+    #[derive(Debug)]
+    struct VirtualFileSystem;
+
+    impl AsyncReadableFileSystem for VirtualFileSystem {
+      fn read(&self, file: &Path) -> BoxFuture<'_, Vec<u8>> {
+        let content = if file == Path::new("/rspack/virtual.js") {
+          Ok(b"virtual".to_vec())
+        } else {
+          Err(std::io::Error::from(std::io::ErrorKind::NotFound).into())
+        };
+        Box::pin(async move { content })
+      }
+
+      fn read_dir(&self, _dir: &Path) -> BoxFuture<'_, Vec<String>> {
+        unreachable!()
+      }
+
+      fn metadata(&self, _path: &Path) -> BoxFuture<'_, FileMetadata> {
+        unreachable!()
+      }
+
+      fn canonicalize(&self, _path: &Path) -> BoxFuture<'_, PathBuf> {
+        unreachable!()
+      }
+    }
+
+    let mut rs = ResourceData::new(
+      "/rspack/virtual.js".to_owned(),
+      PathBuf::from("/rspack/virtual.js"),
+    );
+    let result = run_loaders(
+      &[],
+      &mut rs,
+      &[],
+      (),
+      Arc::new(VirtualFileSystem),
       Default::default(),
     )
     .await
     .unwrap();
+    assert_eq!(result.inner.content.into_bytes(), b"virtual");
   }
 }
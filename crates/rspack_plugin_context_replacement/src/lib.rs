@@ -0,0 +1,115 @@
+use std::{collections::BTreeMap, path::Path};
+
+use rspack_core::{
+  ApplyContext, CompilerOptions, ContextModuleFactoryAfterResolve,
+  ContextModuleFactoryBeforeResolve, ContextModuleOptions, ModuleFactoryCreateData, Plugin,
+  PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+
+#[derive(Debug)]
+pub struct ContextReplacementPluginOptions {
+  pub resource_reg_exp: RspackRegex,
+  /// New directory of the context, absolute or relative to the original one
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  pub new_content_reg_exp: Option<RspackRegex>,
+  /// User request -> request, replaces the files found in the context directory
+  pub new_content_create_context_map: Option<BTreeMap<String, String>>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ContextReplacementPlugin {
+  options: ContextReplacementPluginOptions,
+}
+
+impl ContextReplacementPlugin {
+  pub fn new(options: ContextReplacementPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(ContextModuleFactoryBeforeResolve for ContextReplacementPlugin)]
+async fn cmf_before_resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<bool>> {
+  let Some(dependency) = data.dependency.as_context_dependency_mut() else {
+    return Ok(None);
+  };
+  if !self.options.resource_reg_exp.test(dependency.request()) {
+    return Ok(None);
+  }
+  if let Some(new_content_resource) = &self.options.new_content_resource {
+    dependency.set_request(new_content_resource.clone());
+  }
+  let options = dependency.options_mut();
+  if let Some(new_content_recursive) = self.options.new_content_recursive {
+    options.recursive = new_content_recursive;
+  }
+  if let Some(new_content_reg_exp) = &self.options.new_content_reg_exp {
+    options.reg_exp = Some(new_content_reg_exp.clone());
+  }
+  Ok(None)
+}
+
+#[plugin_hook(ContextModuleFactoryAfterResolve for ContextReplacementPlugin)]
+async fn cmf_after_resolve(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  options: &mut ContextModuleOptions,
+) -> Result<Option<bool>> {
+  if !self.options.resource_reg_exp.test(&options.resource) {
+    return Ok(None);
+  }
+  if let Some(new_content_resource) = &self.options.new_content_resource {
+    options.resource = if Path::new(new_content_resource).is_absolute() {
+      new_content_resource.clone()
+    } else {
+      Path::new(&options.resource)
+        .join(new_content_resource)
+        .normalize()
+        .to_string_lossy()
+        .to_string()
+    };
+  }
+  if let Some(new_content_recursive) = self.options.new_content_recursive {
+    options.context_options.recursive = new_content_recursive;
+  }
+  if let Some(new_content_reg_exp) = &self.options.new_content_reg_exp {
+    options.context_options.reg_exp = Some(new_content_reg_exp.clone());
+  }
+  if let Some(context_map) = &self.options.new_content_create_context_map {
+    options.context_map = Some(context_map.clone());
+  }
+  // the replaced context is intended, so the request being an expression is no longer critical
+  if let Some(dependency) = data.dependency.as_context_dependency_mut() {
+    *dependency.critical_mut() = None;
+  }
+  Ok(None)
+}
+
+impl Plugin for ContextReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ContextReplacementPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .context_module_factory_hooks
+      .before_resolve
+      .tap(cmf_before_resolve::new(self));
+    ctx
+      .context
+      .context_module_factory_hooks
+      .after_resolve
+      .tap(cmf_after_resolve::new(self));
+    Ok(())
+  }
+}
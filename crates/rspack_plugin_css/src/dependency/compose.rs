@@ -1,21 +1,25 @@
 use rspack_core::{
   AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ErrorSpan, ModuleDependency,
+  DependencyType, ErrorSpan, ModuleDependency, ModuleGraph,
 };
+use rspack_error::Diagnostic;
 
 #[derive(Debug, Clone)]
 pub struct CssComposeDependency {
   id: DependencyId,
   request: String,
   span: ErrorSpan,
+  /// Class names composed from the requested module
+  names: Vec<String>,
 }
 
 impl CssComposeDependency {
-  pub fn new(request: String, span: ErrorSpan) -> Self {
+  pub fn new(request: String, span: ErrorSpan, names: Vec<String>) -> Self {
     Self {
       id: DependencyId::new(),
       request,
       span,
+      names,
     }
   }
 }
@@ -40,6 +44,29 @@ impl Dependency for CssComposeDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "CssComposeDependency"
   }
+
+  /// Reports the composed class names which don't exist in the requested module.
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let exports = module_graph
+      .get_module_by_dependency_id(&self.id)
+      .and_then(|module| module.build_info())
+      .and_then(|build_info| build_info.css_exports.as_ref())?;
+    let diagnostics = self
+      .names
+      .iter()
+      .filter(|name| !exports.contains_key(*name))
+      .map(|name| {
+        Diagnostic::warn(
+          "CSS Modules Compose Warning".into(),
+          format!(
+            "Class '{name}' composed from \"{}\" was not found",
+            self.request
+          ),
+        )
+      })
+      .collect::<Vec<_>>();
+    Some(diagnostics)
+  }
 }
 
 impl ModuleDependency for CssComposeDependency {
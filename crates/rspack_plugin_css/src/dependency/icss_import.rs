@@ -0,0 +1,113 @@
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ModuleDependency, ModuleGraph, TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::Diagnostic;
+
+/// `:import("./x.css") { alias: name; }`, the rule is removed from the output
+/// and the usages of `alias` are replaced by [super::CssIcssSymbolDependency].
+#[derive(Debug, Clone)]
+pub struct CssIcssImportDependency {
+  id: DependencyId,
+  request: String,
+  span: ErrorSpan,
+  start: u32,
+  end: u32,
+  /// Pairs of local alias and the name exported by the imported module
+  symbols: Vec<(String, String)>,
+}
+
+impl CssIcssImportDependency {
+  pub fn new(
+    request: String,
+    span: ErrorSpan,
+    start: u32,
+    end: u32,
+    symbols: Vec<(String, String)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+      start,
+      end,
+      symbols,
+    }
+  }
+
+  pub fn symbols(&self) -> &[(String, String)] {
+    &self.symbols
+  }
+}
+
+impl Dependency for CssIcssImportDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "CssIcssImportDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssImport
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssIcssImport
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(self.span)
+  }
+
+  /// Reports the imported names which aren't exported by the imported module.
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let exports = module_graph
+      .get_module_by_dependency_id(&self.id)
+      .and_then(|module| module.build_info())
+      .and_then(|build_info| build_info.css_exports.as_ref())?;
+    let diagnostics = self
+      .symbols
+      .iter()
+      .filter(|(_, name)| !exports.contains_key(name))
+      .map(|(_, name)| {
+        Diagnostic::warn(
+          "CSS Modules Import Warning".into(),
+          format!("'{name}' imported from \"{}\" was not found", self.request),
+        )
+      })
+      .collect::<Vec<_>>();
+    Some(diagnostics)
+  }
+}
+
+impl ModuleDependency for CssIcssImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl DependencyTemplate for CssIcssImportDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(self.start, self.end, "", None);
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for CssIcssImportDependency {}
@@ -0,0 +1,77 @@
+use rspack_core::{
+  AsContextDependency, AsModuleDependency, Dependency, DependencyId, DependencyTemplate,
+  ModuleDependency, TemplateContext, TemplateReplaceSource,
+};
+
+use super::CssIcssImportDependency;
+
+/// A usage of an alias declared by `:import`, replaced by the value the
+/// imported module exports.
+#[derive(Debug, Clone)]
+pub struct CssIcssSymbolDependency {
+  id: DependencyId,
+  request: String,
+  name: String,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssSymbolDependency {
+  pub fn new(request: String, name: String, start: u32, end: u32) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      name,
+      start,
+      end,
+    }
+  }
+}
+
+impl Dependency for CssIcssSymbolDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "CssIcssSymbolDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+}
+
+impl DependencyTemplate for CssIcssSymbolDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+    let module_graph = compilation.get_module_graph();
+    let value = module
+      .get_dependencies()
+      .iter()
+      .find(|id| {
+        module_graph
+          .dependency_by_id(id)
+          .and_then(|dep| dep.downcast_ref::<CssIcssImportDependency>())
+          .is_some_and(|dep| dep.request() == self.request)
+      })
+      .and_then(|id| module_graph.get_module_by_dependency_id(id))
+      .and_then(|module| module.build_info())
+      .and_then(|build_info| build_info.css_exports.as_ref())
+      .and_then(|exports| exports.get(&self.name));
+    if let Some(value) = value {
+      source.replace(self.start, self.end, value, None);
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for CssIcssSymbolDependency {}
+impl AsModuleDependency for CssIcssSymbolDependency {}
@@ -3,6 +3,21 @@ use rspack_core::{
   DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
 };
 
+/// Conditions of a css `@import`, e.g. `@import url(a.css) layer(base) supports(display: grid) screen;`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssImportConditions {
+  /// `Some("")` for an anonymous `layer`
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
@@ -10,17 +25,48 @@ pub struct CssImportDependency {
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
+  conditions: CssImportConditions,
+  resource_identifier: String,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+    conditions: CssImportConditions,
+  ) -> Self {
+    let resource_identifier = Self::create_resource_identifier(&request, &conditions);
     Self {
       id: DependencyId::new(),
       request,
       span,
       start,
       end,
+      conditions,
+      resource_identifier,
+    }
+  }
+
+  /// The same request imported with different conditions creates different modules, so the
+  /// dependencies must not be factorized together.
+  fn create_resource_identifier(request: &str, conditions: &CssImportConditions) -> String {
+    let mut resource_identifier = format!("css-import|{request}");
+    if let Some(layer) = &conditions.layer {
+      resource_identifier.push_str(&format!("|layer{layer}"));
+    }
+    if let Some(supports) = &conditions.supports {
+      resource_identifier.push_str(&format!("|supports{supports}"));
     }
+    if let Some(media) = &conditions.media {
+      resource_identifier.push_str(&format!("|media{media}"));
+    }
+    resource_identifier
+  }
+
+  pub fn conditions(&self) -> &CssImportConditions {
+    &self.conditions
   }
 }
 
@@ -44,6 +90,10 @@ impl Dependency for CssImportDependency {
   fn span(&self) -> Option<ErrorSpan> {
     self.span
   }
+
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
 }
 
 impl ModuleDependency for CssImportDependency {
@@ -56,6 +106,7 @@ impl ModuleDependency for CssImportDependency {
   }
 
   fn set_request(&mut self, request: String) {
+    self.resource_identifier = Self::create_resource_identifier(&request, &self.conditions);
     self.request = request;
   }
 }
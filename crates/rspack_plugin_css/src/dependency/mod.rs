@@ -1,9 +1,13 @@
 mod compose;
 mod css_module_export;
+mod icss_import;
+mod icss_symbol;
 mod import;
 mod url;
 
 pub use compose::*;
 pub use css_module_export::*;
+pub use icss_import::*;
+pub use icss_symbol::*;
 pub use import::*;
 pub use url::*;
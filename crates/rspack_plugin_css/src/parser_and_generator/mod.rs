@@ -19,9 +19,8 @@ use rspack_core::{
 use rspack_core::{ModuleInitFragments, RuntimeGlobals};
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_util::source_map::SourceMapKind;
-use rustc_hash::FxHashSet;
 use swc_core::{
-  css::{parser::parser::ParserConfig, visit::VisitWith},
+  css::{modules::CssClassName, parser::parser::ParserConfig, visit::VisitWith},
   ecma::atoms::Atom,
 };
 
@@ -172,15 +171,36 @@ impl ParserAndGenerator for CssParserAndGenerator {
       Default::default(),
     )?;
 
+    let mut icss_imports = vec![];
     if let Some(exports) = &mut exports {
       let mut exports_analyzer = ExportsAnalyzer::new(&source_code);
       new_stylesheet_ast.visit_with(&mut exports_analyzer);
       presentational_dependencies = Some(exports_analyzer.presentation_deps);
+      icss_imports = exports_analyzer.icss_imports;
 
       for (key, value) in exports_analyzer.exports {
         exports.insert(key, vec![value]);
       }
 
+      build_info.css_exports = Some(
+        exports
+          .iter()
+          .map(|(name, elements)| {
+            let value = elements
+              .iter()
+              .filter_map(|element| match element {
+                CssClassName::Local { name } | CssClassName::Global { name } => {
+                  Some(name.value.as_ref())
+                }
+                CssClassName::Import { .. } => None,
+              })
+              .collect::<Vec<_>>()
+              .join(" ");
+            (name.to_string(), value)
+          })
+          .collect(),
+      );
+
       exports.sort_keys();
       let normalized_exports = IndexMap::from_iter(
         exports
@@ -210,6 +230,11 @@ impl ParserAndGenerator for CssParserAndGenerator {
       &source_code,
       module_user_request,
     );
+    dependencies.extend(
+      icss_imports
+        .into_iter()
+        .map(|dep| Box::new(dep) as BoxDependency),
+    );
     for (k, v) in exports_pairs {
       dependencies.push(Box::new(CssModuleExportDependency::new(
         k[1..k.len() - 1].to_owned(),
@@ -220,21 +245,19 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let dependencies = if let Some(locals) = &self.exports
       && !locals.is_empty()
     {
-      let mut dep_set = FxHashSet::default();
-      let mut compose_deps = locals
-        .iter()
-        .flat_map(|(_, value)| value)
-        .filter_map(|CssExport(_, span, from)| {
-          if let Some(from) = from {
-            if dep_set.contains(&from) {
-              None
-            } else {
-              dep_set.insert(from);
-              Some(Box::new(CssComposeDependency::new(from.to_owned(), *span)) as BoxDependency)
-            }
-          } else {
-            None
-          }
+      let mut composes: IndexMap<&str, (ErrorSpan, Vec<String>)> = IndexMap::default();
+      for CssExport(name, span, from) in locals.iter().flat_map(|(_, value)| value) {
+        if let Some(from) = from {
+          let (_, names) = composes
+            .entry(from.as_str())
+            .or_insert_with(|| (*span, vec![]));
+          names.push(serde_json::from_str(name).expect("should be a json string"));
+        }
+      }
+      let mut compose_deps = composes
+        .into_iter()
+        .map(|(from, (span, names))| {
+          Box::new(CssComposeDependency::new(from.to_owned(), span, names)) as BoxDependency
         })
         .collect::<Vec<_>>();
 
@@ -4,18 +4,18 @@ use std::hash::Hash;
 
 use async_trait::async_trait;
 use rayon::prelude::*;
-use rspack_core::rspack_sources::ReplaceSource;
+use rspack_core::rspack_sources::{BoxSource, ReplaceSource};
 use rspack_core::{
   get_css_chunk_filename_template,
   rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
-  Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
-  SourceType,
+  BoxModule, Chunk, ChunkKind, Module, ModuleFactoryCreateData, ModuleType, NormalModuleCreateData,
+  NormalModuleFactoryModule, ParserAndGenerator, PathData, Plugin, RenderManifestEntry, SourceType,
 };
 use rspack_core::{
   ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationContentHash,
-  CompilationParams, CompilationRenderManifest, CompilationRuntimeRequirementInTree,
-  CompilerCompilation, CompilerOptions, DependencyType, LibIdentOptions, PublicPath,
-  RuntimeGlobals,
+  CompilationFinishModules, CompilationParams, CompilationRenderManifest,
+  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, DependencyType,
+  LibIdentOptions, PublicPath, RuntimeGlobals,
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
@@ -23,6 +23,7 @@ use rspack_hook::plugin_hook;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 use rustc_hash::FxHashMap;
 
+use crate::dependency::{CssImportConditions, CssImportDependency};
 use crate::parser_and_generator::CssParserAndGenerator;
 use crate::runtime::CssLoadingRuntimeModule;
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
@@ -34,6 +35,7 @@ struct CssModuleDebugInfo<'a> {
 
 impl CssPlugin {
   fn render_chunk_to_source(
+    &self,
     compilation: &Compilation,
     chunk: &Chunk,
     ordered_css_modules: &[&dyn Module],
@@ -65,7 +67,11 @@ impl CssPlugin {
         |mut acc, (debug_info, cur_source)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
+          acc.add(self.render_css_import_inheritance(
+            compilation,
+            debug_info.module,
+            cur_source.clone(),
+          ));
           acc.add(RawSource::from("\n"));
           acc.add(end);
           acc
@@ -79,6 +85,48 @@ impl CssPlugin {
     Ok(source)
   }
 
+  /// Wraps the source in the `@media`, `@supports` and `@layer` blocks of the
+  /// `@import`s leading to the module, innermost first.
+  fn render_css_import_inheritance(
+    &self,
+    compilation: &Compilation,
+    module: &dyn Module,
+    source: BoxSource,
+  ) -> BoxSource {
+    let Some(inheritance) = self
+      .css_import_inheritance
+      .get(&compilation.id())
+      .and_then(|inheritance| inheritance.get(&module.identifier()).map(|i| i.clone()))
+    else {
+      return source;
+    };
+    let mut source = source;
+    let wrap = |source: BoxSource, start: String| {
+      ConcatSource::new([
+        RawSource::from(start).boxed(),
+        source,
+        RawSource::from("\n}").boxed(),
+      ])
+      .boxed()
+    };
+    for conditions in inheritance.iter() {
+      if let Some(media) = &conditions.media {
+        source = wrap(source, format!("@media {media} {{\n"));
+      }
+      if let Some(supports) = &conditions.supports {
+        source = wrap(source, format!("@supports ({supports}) {{\n"));
+      }
+      if let Some(layer) = &conditions.layer {
+        if layer.is_empty() {
+          source = wrap(source, "@layer {\n".to_string());
+        } else {
+          source = wrap(source, format!("@layer {layer} {{\n"));
+        }
+      }
+    }
+    source
+  }
+
   fn render_module_debug_info(
     compilation: &Compilation,
     debug_info: &CssModuleDebugInfo,
@@ -115,12 +163,82 @@ impl CssPlugin {
   }
 }
 
+#[plugin_hook(NormalModuleFactoryModule for CssPlugin)]
+async fn nmf_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  _create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  let Some(dep) = data
+    .dependency
+    .as_any()
+    .downcast_ref::<CssImportDependency>()
+  else {
+    return Ok(());
+  };
+  let Some(normal_module) = module.as_normal_module_mut() else {
+    return Ok(());
+  };
+
+  let compilation_inheritance = self
+    .css_import_inheritance
+    .entry(data.compilation_id)
+    .or_default()
+    .downgrade();
+  let mut inheritance = vec![dep.conditions().clone()];
+  if let Some(issuer) = &data.issuer_identifier
+    && let Some(issuer_inheritance) = compilation_inheritance.get(issuer)
+  {
+    inheritance.extend(issuer_inheritance.iter().cloned());
+  }
+  if inheritance.iter().all(CssImportConditions::is_empty) {
+    return Ok(());
+  }
+
+  // modules imported with different conditions are rendered differently
+  let mut suffix = String::new();
+  for (index, conditions) in inheritance.iter().enumerate() {
+    suffix.push_str(&format!(
+      "|inheritance_{index}|{}|{}|{}",
+      conditions.layer.as_deref().unwrap_or_default(),
+      conditions.supports.as_deref().unwrap_or_default(),
+      conditions.media.as_deref().unwrap_or_default(),
+    ));
+  }
+  normal_module.extend_identifier(&suffix);
+  compilation_inheritance.insert(normal_module.id(), inheritance);
+  Ok(())
+}
+
+#[plugin_hook(CompilationFinishModules for CssPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  if let Some(inheritance) = self.css_import_inheritance.get(&compilation.id()) {
+    inheritance.retain(|module, _| module_graph.module_by_identifier(module).is_some());
+  }
+  Ok(())
+}
+
 #[plugin_hook(CompilerCompilation for CssPlugin)]
 async fn compilation(
   &self,
   compilation: &mut Compilation,
   params: &mut CompilationParams,
 ) -> Result<()> {
+  let previous = self
+    .css_import_inheritance
+    .iter()
+    .map(|entry| *entry.key())
+    .max();
+  let inheritance = previous
+    .and_then(|previous| self.css_import_inheritance.remove(&previous))
+    .map(|(_, inheritance)| inheritance)
+    .unwrap_or_default();
+  self
+    .css_import_inheritance
+    .insert(compilation.id(), inheritance);
+
   compilation.set_dependency_factory(DependencyType::CssUrl, params.normal_module_factory.clone());
   compilation.set_dependency_factory(
     DependencyType::CssImport,
@@ -130,6 +248,10 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::CssIcssImport,
+    params.normal_module_factory.clone(),
+  );
   Ok(())
 }
 
@@ -223,7 +345,7 @@ async fn render_manifest(
     return Ok(());
   }
 
-  let source = Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
+  let source = self.render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
 
   let filename_template = get_css_chunk_filename_template(
     chunk,
@@ -325,6 +447,16 @@ impl Plugin for CssPlugin {
       .compilation_hooks
       .render_manifest
       .tap(render_manifest::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(nmf_module::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
 
     ctx.context.register_parser_and_generator_builder(
       ModuleType::Css,
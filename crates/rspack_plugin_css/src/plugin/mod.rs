@@ -2,14 +2,24 @@
 mod impl_plugin_for_css_plugin;
 use std::cmp::{self, Reverse};
 
-use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, SourceType};
+use dashmap::DashMap;
+use rspack_core::{Chunk, ChunkGraph, Compilation, CompilationId, Module, ModuleGraph, SourceType};
 use rspack_core::{ChunkUkey, ModuleIdentifier};
 use rspack_hook::plugin;
 use rspack_identifier::IdentifierSet;
 
+use crate::dependency::CssImportConditions;
+
 #[plugin]
 #[derive(Debug, Default)]
-pub struct CssPlugin;
+pub struct CssPlugin {
+  /// `@import` conditions inherited by the css modules of each compilation, own conditions first.
+  /// A compilation takes over the entries of the previous one, since unchanged modules are not
+  /// created again on rebuild, modules which are no longer in the module graph are removed once
+  /// the modules are finished.
+  css_import_inheritance:
+    DashMap<CompilationId, DashMap<ModuleIdentifier, Vec<CssImportConditions>>>,
+}
 
 #[derive(Debug)]
 pub struct CssOrderConflicts {
@@ -1,5 +1,5 @@
 use rspack_core::{
-  compile_boolean_matcher, impl_runtime_module,
+  compile_boolean_matcher, impl_runtime_module, render_sri_integrity,
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
   BooleanMatcher, ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
   RuntimeModuleStage,
@@ -98,12 +98,21 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       } else {
         "".to_string()
       };
+      let integrity = if runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+        render_sri_integrity(
+          "link",
+          "css",
+          &compilation.options.output.cross_origin_loading,
+        )
+      } else {
+        "".to_string()
+      };
 
       source.add(RawSource::from(
         include_str!("./css_loading.js")
           .replace(
             "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-            &cross_origin_content,
+            &format!("{cross_origin_content}{integrity}"),
           )
           .replace("__UNIQUE_NAME__", unique_name),
       ));
@@ -5,18 +5,22 @@ use regex::Regex;
 use rspack_core::{BoxDependency, ConstDependency, DependencyTemplate, ModuleDependency, SpanExt};
 use rspack_error::miette::{Diagnostic, Severity};
 use rspack_error::{DiagnosticExt, TraceableError};
+use rustc_hash::FxHashMap;
 use swc_core::atoms::Atom;
 use swc_core::common::{BytePos, FileName, Span, Spanned, SyntaxContext};
 use swc_core::css::ast::{
-  AtRule, AtRuleName, ComplexSelectorChildren, ComponentValue, DeclarationName, Function, Ident,
-  ImportHref, ImportPrelude, PseudoClassSelector, QualifiedRulePrelude, Stylesheet,
-  SubclassSelector, Token, TokenAndSpan, Url, UrlValue,
+  AtRule, AtRuleName, ComplexSelectorChildren, ComponentValue, Declaration, DeclarationName,
+  Function, Ident, ImportHref, ImportLayerName, ImportPrelude, PseudoClassSelector, QualifiedRule,
+  QualifiedRulePrelude, Stylesheet, SubclassSelector, Token, TokenAndSpan, Url, UrlValue,
 };
 use swc_core::css::modules::CssClassName;
 use swc_core::css::visit::{Visit, VisitWith};
 
 use crate::{
-  dependency::{CssImportDependency, CssUrlDependency},
+  dependency::{
+    CssIcssImportDependency, CssIcssSymbolDependency, CssImportConditions, CssImportDependency,
+    CssUrlDependency,
+  },
   utils::normalize_url,
 };
 
@@ -116,6 +120,47 @@ impl Analyzer<'_> {
     self.deps.push(dep.clone());
     self.code_generation_dependencies.push(dep);
   }
+
+  fn source_of(&self, span: Span) -> &str {
+    &self.source_code[span.real_lo() as usize..span.real_hi() as usize]
+  }
+
+  /// The arguments of a function like `layer(base)` or `supports(display: grid)`.
+  fn function_arguments_of(&self, f: &Function) -> String {
+    let source = self.source_of(f.span);
+    let start = source.find('(').map_or(0, |i| i + 1);
+    let end = source.rfind(')').unwrap_or(source.len()).max(start);
+    source[start..end].trim().to_string()
+  }
+
+  fn analyze_import_conditions(&self, n: &ImportPrelude) -> CssImportConditions {
+    let layer = n.layer_name.as_ref().map(|box layer| match layer {
+      ImportLayerName::Ident(_) => String::new(),
+      ImportLayerName::Function(f) => self.function_arguments_of(f),
+    });
+    let (supports, media) = n
+      .import_conditions
+      .as_ref()
+      .map(|box conditions| {
+        (
+          conditions
+            .supports
+            .as_ref()
+            .map(|box f| self.function_arguments_of(f)),
+          conditions
+            .media
+            .as_ref()
+            .map(|box media| self.source_of(media.span).trim().to_string())
+            .filter(|media| !media.is_empty()),
+        )
+      })
+      .unwrap_or_default();
+    CssImportConditions {
+      layer,
+      supports,
+      media,
+    }
+  }
 }
 
 impl Visit for Analyzer<'_> {
@@ -148,11 +193,13 @@ impl Visit for Analyzer<'_> {
         self.filename,
         span,
       );
+      let conditions = self.analyze_import_conditions(n);
       self.deps.push(Box::new(CssImportDependency::new(
         specifier,
         Some(span.into()),
         span.real_lo(),
         span.real_hi(),
+        conditions,
       )));
     }
   }
@@ -235,6 +282,10 @@ pub(crate) struct ExportsAnalyzer<'source> {
   source_code: &'source str,
   pub exports: Vec<(Atom, CssClassName)>,
   pub presentation_deps: Vec<Box<dyn DependencyTemplate>>,
+  pub icss_imports: Vec<CssIcssImportDependency>,
+  /// Identifiers in declaration values, which may be aliases of `:import`
+  value_idents: Vec<(Atom, Span)>,
+  in_declaration_value: bool,
 }
 
 impl<'source> ExportsAnalyzer<'source> {
@@ -243,64 +294,203 @@ impl<'source> ExportsAnalyzer<'source> {
       source_code,
       exports: Default::default(),
       presentation_deps: Default::default(),
+      icss_imports: Default::default(),
+      value_idents: Default::default(),
+      in_declaration_value: false,
     }
   }
-}
 
-impl<'source> Visit for ExportsAnalyzer<'source> {
-  fn visit_qualified_rule(&mut self, n: &swc_core::css::ast::QualifiedRule) {
-    // parse :export { foo: bar; }
-    // TODO: is this a fulfilled detection ?
-    if let QualifiedRulePrelude::SelectorList(selector_list) = &n.prelude
+  fn source_of(&self, span: Span) -> &'source str {
+    &self.source_code[span.real_lo() as usize..span.real_hi() as usize]
+  }
+
+  /// Returns the pseudo class of `:export` or `:import(...)` like selectors.
+  fn single_pseudo_class(prelude: &QualifiedRulePrelude) -> Option<&PseudoClassSelector> {
+    if let QualifiedRulePrelude::SelectorList(selector_list) = prelude
       && selector_list.children.len() == 1
       && selector_list.children[0].children.len() == 1
       && let ComplexSelectorChildren::CompoundSelector(selector) =
         &selector_list.children[0].children[0]
       && selector.subclass_selectors.len() == 1
-      && let SubclassSelector::PseudoClass(PseudoClassSelector { name, .. }) =
-        &selector.subclass_selectors[0]
-      && &name.value == "export"
+      && let SubclassSelector::PseudoClass(pseudo_class) = &selector.subclass_selectors[0]
     {
-      for decl in &n.block.value {
-        if let ComponentValue::Declaration(decl) = decl {
-          let key = match &decl.name {
-            DeclarationName::Ident(ident) => &ident.value,
-            DeclarationName::DashedIdent(dashed_ident) => &dashed_ident.value,
-          };
-
-          let start = decl
-            .value
-            .first()
-            .expect("export value should have value")
-            .span()
-            .real_lo();
-          let end = decl
-            .value
-            .last()
-            .expect("export value should have value")
-            .span()
-            .real_hi();
-
-          let value = &self.source_code[start as usize..end as usize];
-          self.exports.push((
-            key.clone(),
-            CssClassName::Global {
-              name: Ident {
-                value: value.into(),
-                span: Span::new(BytePos(start), BytePos(end), SyntaxContext::empty()),
-                raw: None,
-              },
-            },
-          ));
-        }
+      Some(pseudo_class)
+    } else {
+      None
+    }
+  }
+
+  fn declarations(n: &QualifiedRule) -> impl Iterator<Item = &Declaration> {
+    n.block.value.iter().filter_map(|decl| match decl {
+      ComponentValue::Declaration(decl) => Some(&**decl),
+      _ => None,
+    })
+  }
+
+  fn declaration_name(decl: &Declaration) -> &Atom {
+    match &decl.name {
+      DeclarationName::Ident(ident) => &ident.value,
+      DeclarationName::DashedIdent(dashed_ident) => &dashed_ident.value,
+    }
+  }
+
+  fn declaration_value_span(decl: &Declaration) -> (u32, u32) {
+    let start = decl
+      .value
+      .first()
+      .expect("export value should have value")
+      .span()
+      .real_lo();
+    let end = decl
+      .value
+      .last()
+      .expect("export value should have value")
+      .span()
+      .real_hi();
+    (start, end)
+  }
+
+  // parse :export { foo: bar; }
+  fn analyze_export(&mut self, n: &QualifiedRule) {
+    for decl in Self::declarations(n) {
+      let key = Self::declaration_name(decl);
+      let (start, end) = Self::declaration_value_span(decl);
+      let value = &self.source_code[start as usize..end as usize];
+      self.exports.push((
+        key.clone(),
+        CssClassName::Global {
+          name: Ident {
+            value: value.into(),
+            span: Span::new(BytePos(start), BytePos(end), SyntaxContext::empty()),
+            raw: None,
+          },
+        },
+      ));
+    }
+  }
+
+  // parse :import("./x.css") { alias: name; }
+  fn analyze_import(&mut self, n: &QualifiedRule, pseudo_class: &PseudoClassSelector) {
+    let selector = self.source_of(pseudo_class.span);
+    let start = selector.find('(').map_or(selector.len(), |i| i + 1);
+    let end = selector.rfind(')').unwrap_or(selector.len()).max(start);
+    let request = selector[start..end]
+      .trim()
+      .trim_matches(|c| c == '"' || c == '\'');
+    if request.is_empty() {
+      return;
+    }
+
+    let symbols = Self::declarations(n)
+      .filter(|decl| !decl.value.is_empty())
+      .map(|decl| {
+        let (start, end) = Self::declaration_value_span(decl);
+        (
+          Self::declaration_name(decl).to_string(),
+          self.source_code[start as usize..end as usize]
+            .trim()
+            .to_string(),
+        )
+      })
+      .collect();
+    self.icss_imports.push(CssIcssImportDependency::new(
+      request.to_string(),
+      n.span.into(),
+      n.span.real_lo(),
+      n.span.real_hi(),
+      symbols,
+    ));
+  }
+
+  /// Replaces the aliases declared by `:import`s in declaration values and
+  /// `:export` values.
+  fn resolve_icss_symbols(&mut self) {
+    let symbols = self
+      .icss_imports
+      .iter()
+      .flat_map(|dep| {
+        dep
+          .symbols()
+          .iter()
+          .map(|(alias, name)| (alias.as_str(), (dep.request(), name.as_str())))
+      })
+      .collect::<FxHashMap<_, _>>();
+    if symbols.is_empty() {
+      return;
+    }
+
+    for (_, class_name) in &mut self.exports {
+      if let CssClassName::Global { name } = class_name
+        && let Some((request, symbol)) = symbols.get(&*name.value)
+      {
+        *class_name = CssClassName::Import {
+          name: Ident {
+            value: (*symbol).into(),
+            span: name.span,
+            raw: None,
+          },
+          from: (*request).into(),
+        };
       }
+    }
 
-      self.presentation_deps.push(Box::new(ConstDependency::new(
-        n.span.real_lo(),
-        n.span.real_hi(),
-        "".into(),
-        None,
-      )));
+    for (ident, span) in &self.value_idents {
+      if let Some((request, symbol)) = symbols.get(&**ident) {
+        self
+          .presentation_deps
+          .push(Box::new(CssIcssSymbolDependency::new(
+            request.to_string(),
+            symbol.to_string(),
+            span.real_lo(),
+            span.real_hi(),
+          )));
+      }
+    }
+  }
+}
+
+impl<'source> Visit for ExportsAnalyzer<'source> {
+  fn visit_stylesheet(&mut self, n: &Stylesheet) {
+    n.visit_children_with(self);
+    self.resolve_icss_symbols();
+  }
+
+  fn visit_qualified_rule(&mut self, n: &QualifiedRule) {
+    // TODO: is this a fulfilled detection ?
+    match Self::single_pseudo_class(&n.prelude) {
+      Some(pseudo_class) if &pseudo_class.name.value == "export" => {
+        self.analyze_export(n);
+        self.presentation_deps.push(Box::new(ConstDependency::new(
+          n.span.real_lo(),
+          n.span.real_hi(),
+          "".into(),
+          None,
+        )));
+      }
+      Some(pseudo_class) if &pseudo_class.name.value == "import" => {
+        self.analyze_import(n, pseudo_class);
+      }
+      _ => n.visit_children_with(self),
+    }
+  }
+
+  fn visit_declaration(&mut self, n: &Declaration) {
+    self.in_declaration_value = true;
+    n.value.visit_with(self);
+    self.in_declaration_value = false;
+  }
+
+  fn visit_ident(&mut self, n: &Ident) {
+    if self.in_declaration_value {
+      self.value_idents.push((n.value.clone(), n.span));
+    }
+  }
+
+  fn visit_token_and_span(&mut self, n: &TokenAndSpan) {
+    if self.in_declaration_value
+      && let Token::Ident { value, .. } = &n.token
+    {
+      self.value_idents.push((value.clone(), n.span));
     }
   }
 }
@@ -0,0 +1,57 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+#[derive(Debug, Clone)]
+pub struct DllEntryDependency {
+  id: DependencyId,
+  resource_identifier: String,
+  pub context: Context,
+  pub entries: Vec<String>,
+  pub name: String,
+}
+
+impl DllEntryDependency {
+  pub fn new(context: Context, entries: Vec<String>, name: String) -> Self {
+    let resource_identifier = format!("dll {}", &name);
+    Self {
+      id: DependencyId::new(),
+      resource_identifier,
+      context,
+      entries,
+      name,
+    }
+  }
+}
+
+impl Dependency for DllEntryDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "DllEntryDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::DllEntry
+  }
+
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+}
+
+impl ModuleDependency for DllEntryDependency {
+  fn request(&self) -> &str {
+    &self.resource_identifier
+  }
+}
+
+impl AsContextDependency for DllEntryDependency {}
+impl AsDependencyTemplate for DllEntryDependency {}
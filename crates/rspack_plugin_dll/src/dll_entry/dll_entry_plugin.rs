@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, Compilation, CompilationParams, CompilerCompilation, CompilerMake, CompilerOptions,
+  Context, Dependency, DependencyType, EntryOptions, MakeParam, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use super::{dll_entry_dependency::DllEntryDependency, dll_module_factory::DllModuleFactory};
+
+#[derive(Debug, Clone)]
+pub struct DllEntryPluginOptions {
+  pub name: String,
+  pub context: Context,
+  pub entries: Vec<String>,
+}
+
+/// Replaces an entry with a [super::dll_module::DllModule] that bundles all
+/// requests of the entry into a dll.
+#[plugin]
+#[derive(Debug)]
+pub struct DllEntryPlugin {
+  options: DllEntryPluginOptions,
+}
+
+impl DllEntryPlugin {
+  pub fn new(options: DllEntryPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerCompilation for DllEntryPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(DependencyType::DllEntry, Arc::new(DllModuleFactory));
+  compilation.set_dependency_factory(DependencyType::Entry, params.normal_module_factory.clone());
+  Ok(())
+}
+
+#[plugin_hook(CompilerMake for DllEntryPlugin)]
+async fn make(&self, compilation: &mut Compilation, params: &mut Vec<MakeParam>) -> Result<()> {
+  if let Some(state) = compilation.options.get_incremental_rebuild_make_state()
+    && !state.is_first()
+  {
+    return Ok(());
+  }
+  let dependency = DllEntryDependency::new(
+    self.options.context.clone(),
+    self.options.entries.clone(),
+    self.options.name.clone(),
+  );
+  let dependency_id = *dependency.id();
+  compilation.add_entry(
+    Box::new(dependency),
+    EntryOptions {
+      name: Some(self.options.name.clone()),
+      ..Default::default()
+    },
+  )?;
+
+  params.push(MakeParam::new_force_build_dep_param(dependency_id, None));
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for DllEntryPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DllEntryPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx.context.compiler_hooks.make.tap(make::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use async_trait::async_trait;
+use rspack_core::{
+  impl_module_meta_info, impl_source_map_config,
+  rspack_sources::{RawSource, Source, SourceExt},
+  AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult,
+  CodeGenerationResult, Compilation, ConcatenationScope, Context, DependenciesBlock, DependencyId,
+  EntryDependency, FactoryMeta, Module, ModuleIdentifier, ModuleType, RuntimeGlobals, RuntimeSpec,
+  SourceType,
+};
+use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_util::source_map::SourceMapKind;
+
+/// The entry module of a dll bundle, which requires every entry of the dll and
+/// exposes `__webpack_require__` so the dll can be consumed by other builds.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct DllModule {
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+  identifier: ModuleIdentifier,
+  context: Context,
+  entries: Vec<String>,
+  factory_meta: Option<FactoryMeta>,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+}
+
+impl DllModule {
+  pub fn new(context: Context, entries: Vec<String>, name: String) -> Self {
+    Self {
+      blocks: Default::default(),
+      dependencies: Default::default(),
+      identifier: ModuleIdentifier::from(format!("dll {name}")),
+      context,
+      entries,
+      factory_meta: None,
+      build_info: None,
+      build_meta: None,
+      source_map_kind: SourceMapKind::None,
+    }
+  }
+}
+
+impl Identifiable for DllModule {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+impl DependenciesBlock for DllModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait]
+impl Module for DllModule {
+  impl_module_meta_info!();
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    12.0
+  }
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::JsDynamic
+  }
+
+  fn get_diagnostics(&self) -> Vec<Diagnostic> {
+    vec![]
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    self.identifier.as_str().into()
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let dependencies = self
+      .entries
+      .iter()
+      .map(|entry| {
+        Box::new(EntryDependency::new(
+          entry.clone(),
+          self.context.clone(),
+          None,
+        )) as BoxDependency
+      })
+      .collect();
+
+    Ok(BuildResult {
+      build_info: BuildInfo {
+        hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+        ..Default::default()
+      },
+      dependencies,
+      ..Default::default()
+    })
+  }
+
+  fn code_generation(
+    &self,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut codegen = CodeGenerationResult::default();
+    codegen.runtime_requirements.insert(RuntimeGlobals::MODULE);
+    codegen.runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    codegen = codegen.with_javascript(
+      RawSource::from(format!("module.exports = {};", RuntimeGlobals::REQUIRE)).boxed(),
+    );
+    Ok(codegen)
+  }
+}
+
+impl_empty_diagnosable_trait!(DllModule);
+
+impl Hash for DllModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__DllModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for DllModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for DllModule {}
@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use async_trait::async_trait;
+use rspack_core::{
+  impl_module_meta_info, impl_source_map_config, module_raw,
+  rspack_sources::{RawSource, Source, SourceExt},
+  AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult,
+  CodeGenerationResult, Compilation, ConcatenationScope, Context, DependenciesBlock, DependencyId,
+  FactoryMeta, LibIdentOptions, Module, ModuleIdentifier, ModuleType, RuntimeGlobals, RuntimeSpec,
+  SourceType, StaticExportsDependency, StaticExportsSpec,
+};
+use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_util::{json_stringify, source_map::SourceMapKind};
+
+use super::delegated_source_dependency::DelegatedSourceDependency;
+use crate::DllManifestContentItem;
+
+/// A module which is provided by a dll, it requires the module with the id
+/// from the manifest through the dll's `__webpack_require__`.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct DelegatedModule {
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+  identifier: ModuleIdentifier,
+  source_request: String,
+  request: Option<String>,
+  delegation_type: String,
+  user_request: String,
+  original_request: Option<String>,
+  delegate_data: DllManifestContentItem,
+  factory_meta: Option<FactoryMeta>,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+}
+
+impl DelegatedModule {
+  pub fn new(
+    source_request: String,
+    data: DllManifestContentItem,
+    delegation_type: String,
+    user_request: String,
+    original_request: Option<String>,
+  ) -> Self {
+    let request = data.id.clone();
+    Self {
+      blocks: Default::default(),
+      dependencies: Default::default(),
+      identifier: ModuleIdentifier::from(format!(
+        "delegated {} from {}",
+        json_stringify(&request),
+        source_request
+      )),
+      source_request,
+      request,
+      delegation_type,
+      user_request,
+      original_request,
+      delegate_data: data,
+      factory_meta: None,
+      build_info: None,
+      build_meta: None,
+      source_map_kind: SourceMapKind::None,
+    }
+  }
+}
+
+impl Identifiable for DelegatedModule {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+impl DependenciesBlock for DelegatedModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait]
+impl Module for DelegatedModule {
+  impl_module_meta_info!();
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    42.0
+  }
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::JsDynamic
+  }
+
+  fn get_diagnostics(&self) -> Vec<Diagnostic> {
+    vec![]
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    format!(
+      "delegated {} from {}",
+      self.user_request, self.source_request
+    )
+    .into()
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    self.original_request.as_deref().map(Cow::Borrowed)
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let exports = match &self.delegate_data.exports {
+      Some(exports) => {
+        StaticExportsSpec::Array(exports.iter().map(|e| e.as_str().into()).collect())
+      }
+      None => StaticExportsSpec::True,
+    };
+    let dependencies: Vec<BoxDependency> = vec![
+      Box::new(DelegatedSourceDependency::new(self.source_request.clone())),
+      Box::new(StaticExportsDependency::new(exports, false)),
+    ];
+
+    Ok(BuildResult {
+      build_info: BuildInfo {
+        hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+        ..Default::default()
+      },
+      build_meta: self.delegate_data.build_meta.clone().unwrap_or_default(),
+      dependencies,
+      ..Default::default()
+    })
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut codegen = CodeGenerationResult::default();
+    codegen.runtime_requirements.insert(RuntimeGlobals::MODULE);
+    codegen.runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    let source_dependency = self
+      .dependencies
+      .first()
+      .expect("should have DelegatedSourceDependency");
+    let mut source = format!(
+      "module.exports = ({})",
+      module_raw(
+        compilation,
+        &mut codegen.runtime_requirements,
+        source_dependency,
+        &self.source_request,
+        false,
+      )
+    );
+    match self.delegation_type.as_str() {
+      "require" => source += &format!("({})", json_stringify(&self.request)),
+      "object" => source += &format!("[{}]", json_stringify(&self.request)),
+      _ => {}
+    }
+    source += ";";
+    codegen = codegen.with_javascript(RawSource::from(source).boxed());
+    Ok(codegen)
+  }
+}
+
+impl_empty_diagnosable_trait!(DelegatedModule);
+
+impl Hash for DelegatedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__DelegatedModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for DelegatedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for DelegatedModule {}
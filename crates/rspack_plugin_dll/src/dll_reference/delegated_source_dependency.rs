@@ -0,0 +1,50 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+#[derive(Debug, Clone)]
+pub struct DelegatedSourceDependency {
+  id: DependencyId,
+  request: String,
+}
+
+impl DelegatedSourceDependency {
+  pub fn new(request: String) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+    }
+  }
+}
+
+impl Dependency for DelegatedSourceDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "DelegatedSourceDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::DelegatedSource
+  }
+
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.request)
+  }
+}
+
+impl ModuleDependency for DelegatedSourceDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+}
+
+impl AsContextDependency for DelegatedSourceDependency {}
+impl AsDependencyTemplate for DelegatedSourceDependency {}
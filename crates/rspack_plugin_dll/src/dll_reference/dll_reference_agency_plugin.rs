@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationParams, CompilerCompilation, CompilerOptions,
+  Context, DependencyType, LibIdentOptions, ModuleFactoryCreateData, NormalModuleCreateData,
+  NormalModuleFactoryFactorize, NormalModuleFactoryModule, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use super::delegated_module::DelegatedModule;
+use crate::DllManifestContent;
+
+#[derive(Debug, Clone)]
+pub struct DllReferenceAgencyPluginOptions {
+  /// Context of the module identifiers in the manifest.
+  pub context: Context,
+  /// Name of the dll, the dll is referenced through the `dll-reference <name>` request.
+  pub name: String,
+  /// Extensions tried for requests in the scope of the dll.
+  pub extensions: Vec<String>,
+  /// Prefix of the requests which are resolved from the dll content.
+  pub scope: Option<String>,
+  /// How the modules of the dll are accessed, `require` or `object`.
+  pub r#type: String,
+  /// Lib identifiers of the modules in the dll -> module id and meta.
+  pub content: DllManifestContent,
+}
+
+/// Delegates requests found in a dll manifest to the dll, the dll itself is
+/// expected to be provided as an external with the `dll-reference <name>`
+/// request.
+#[plugin]
+#[derive(Debug)]
+pub struct DllReferenceAgencyPlugin {
+  options: DllReferenceAgencyPluginOptions,
+  source: String,
+}
+
+impl DllReferenceAgencyPlugin {
+  pub fn new(options: DllReferenceAgencyPluginOptions) -> Self {
+    let source = format!("dll-reference {}", options.name);
+    Self::new_inner(options, source)
+  }
+}
+
+#[plugin_hook(CompilerCompilation for DllReferenceAgencyPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::DelegatedSource,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryFactorize for DllReferenceAgencyPlugin)]
+async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<BoxModule>> {
+  let Some(scope) = &self.options.scope else {
+    return Ok(None);
+  };
+  let Some(dependency) = data.dependency.as_module_dependency() else {
+    return Ok(None);
+  };
+  let request = dependency.request();
+  let Some(inner_request) = request
+    .strip_prefix(scope.as_str())
+    .filter(|rest| rest.starts_with('/'))
+  else {
+    return Ok(None);
+  };
+  let inner_request = format!(".{inner_request}");
+  let delegated = |inner_request: String, user_request: String| -> Option<BoxModule> {
+    let data = self.options.content.get(&inner_request)?;
+    Some(Box::new(DelegatedModule::new(
+      self.source.clone(),
+      data.clone(),
+      self.options.r#type.clone(),
+      inner_request,
+      Some(user_request),
+    )))
+  };
+  if let Some(module) = delegated(inner_request.clone(), request.to_string()) {
+    return Ok(Some(module));
+  }
+  for extension in &self.options.extensions {
+    if let Some(module) = delegated(
+      format!("{inner_request}{extension}"),
+      format!("{request}{extension}"),
+    ) {
+      return Ok(Some(module));
+    }
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryModule for DllReferenceAgencyPlugin)]
+async fn nmf_module(
+  &self,
+  _data: &mut ModuleFactoryCreateData,
+  _create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  if self.options.scope.is_some() {
+    return Ok(());
+  }
+  let Some(request) = module.lib_ident(LibIdentOptions {
+    context: &self.options.context,
+  }) else {
+    return Ok(());
+  };
+  if let Some(data) = self.options.content.get(request.as_ref()) {
+    let request = request.into_owned();
+    *module = Box::new(DelegatedModule::new(
+      self.source.clone(),
+      data.clone(),
+      self.options.r#type.clone(),
+      request.clone(),
+      Some(request),
+    ));
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for DllReferenceAgencyPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DllReferenceAgencyPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .factorize
+      .tap(factorize::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(nmf_module::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,3 @@
+pub mod delegated_module;
+pub mod delegated_source_dependency;
+pub mod dll_reference_agency_plugin;
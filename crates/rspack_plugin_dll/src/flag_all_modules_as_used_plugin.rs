@@ -0,0 +1,51 @@
+use rspack_core::{
+  get_entry_runtime, merge_runtime, ApplyContext, Compilation, CompilationOptimizeDependencies,
+  CompilerOptions, Plugin, PluginContext, RuntimeSpec,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+/// Flags the exports of all modules as used in an unknown way, so that every
+/// export of a dll stays available to its consumers.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FlagAllModulesAsUsedPlugin;
+
+#[plugin_hook(CompilationOptimizeDependencies for FlagAllModulesAsUsedPlugin)]
+fn optimize_dependencies(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
+  let mut runtime: Option<RuntimeSpec> = None;
+  for (name, entry) in compilation.entries.iter() {
+    let entry_runtime = get_entry_runtime(name, &entry.options, &compilation.entries);
+    runtime = Some(match runtime {
+      Some(runtime) => merge_runtime(&runtime, &entry_runtime),
+      None => entry_runtime,
+    });
+  }
+
+  let mut module_graph = compilation.get_module_graph_mut();
+  let module_identifiers = module_graph.modules().keys().copied().collect::<Vec<_>>();
+  for module_identifier in module_identifiers {
+    let exports_info_id = module_graph.get_exports_info(&module_identifier).id;
+    exports_info_id.set_used_in_unknown_way(&mut module_graph, runtime.as_ref());
+  }
+  Ok(None)
+}
+
+impl Plugin for FlagAllModulesAsUsedPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.FlagAllModulesAsUsedPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .optimize_dependencies
+      .tap(optimize_dependencies::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,67 @@
+#![feature(let_chains)]
+
+use std::collections::BTreeMap;
+
+use rspack_core::BuildMeta;
+use serde::{Deserialize, Deserializer, Serialize};
+
+mod dll_entry;
+mod dll_reference;
+mod flag_all_modules_as_used_plugin;
+mod lib_manifest_plugin;
+
+pub use dll_entry::dll_entry_plugin::{DllEntryPlugin, DllEntryPluginOptions};
+pub use dll_reference::dll_reference_agency_plugin::{
+  DllReferenceAgencyPlugin, DllReferenceAgencyPluginOptions,
+};
+pub use flag_all_modules_as_used_plugin::FlagAllModulesAsUsedPlugin;
+pub use lib_manifest_plugin::{LibManifestPlugin, LibManifestPluginOptions};
+
+/// Lib identifier of a module in the dll -> module id and meta.
+pub type DllManifestContent = BTreeMap<String, DllManifestContentItem>;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DllManifestContentItem {
+  #[serde(
+    default,
+    deserialize_with = "deserialize_module_id",
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub id: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub build_meta: Option<BuildMeta>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub exports: Option<Vec<String>>,
+}
+
+/// The manifest emitted by [LibManifestPlugin] and consumed by
+/// [DllReferenceAgencyPlugin].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DllManifest {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  pub content: DllManifestContent,
+}
+
+/// Module ids of manifests emitted by webpack may be numbers.
+fn deserialize_module_id<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum ModuleId {
+    String(String),
+    Number(f64),
+  }
+
+  Ok(
+    Option::<ModuleId>::deserialize(deserializer)?.map(|id| match id {
+      ModuleId::String(id) => id,
+      ModuleId::Number(id) => id.to_string(),
+    }),
+  )
+}
@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, Compilation, CompilationAsset, CompilerEmit, CompilerOptions, Context,
+  DependencyType, FilenameTemplate, LibIdentOptions, PathData, Plugin, PluginContext,
+  ProvidedExports, SourceType,
+};
+use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::infallible::ResultInfallibleExt as _;
+
+use crate::{DllManifest, DllManifestContent, DllManifestContentItem};
+
+#[derive(Debug, Clone)]
+pub struct LibManifestPluginOptions {
+  /// Context of the module identifiers in the manifest, defaults to the
+  /// compiler context.
+  pub context: Option<Context>,
+  /// Only expose the modules required by the entries of the dll.
+  pub entry_only: bool,
+  /// Pretty print the manifest.
+  pub format: bool,
+  /// Name of the exposed dll function, supports filename placeholders.
+  pub name: Option<String>,
+  /// Filename of the manifest, supports filename placeholders. Relative
+  /// paths are resolved against the output path.
+  pub path: String,
+  /// Library type of the dll.
+  pub r#type: Option<String>,
+}
+
+/// Emits a manifest for every initial chunk, mapping the lib identifiers of
+/// the modules in the chunk to their module ids.
+#[plugin]
+#[derive(Debug)]
+pub struct LibManifestPlugin {
+  options: LibManifestPluginOptions,
+}
+
+impl LibManifestPlugin {
+  pub fn new(options: LibManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerEmit for LibManifestPlugin)]
+async fn emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let context = self
+    .options
+    .context
+    .as_ref()
+    .unwrap_or(&compilation.options.context);
+  let module_graph = compilation.get_module_graph();
+  let mut manifests = vec![];
+
+  for (chunk_ukey, chunk) in compilation.chunk_by_ukey.iter() {
+    if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+      continue;
+    }
+    let target_path = compilation
+      .get_path(
+        &FilenameTemplate::from(self.options.path.clone()),
+        PathData::default().chunk(chunk),
+      )
+      .always_ok();
+    let name = self.options.name.as_ref().map(|name| {
+      compilation
+        .get_path(
+          &FilenameTemplate::from(name.clone()),
+          PathData::default().chunk(chunk).content_hash_optional(
+            chunk
+              .content_hash
+              .get(&SourceType::JavaScript)
+              .map(|i| i.rendered(compilation.options.output.hash_digest_length)),
+          ),
+        )
+        .always_ok()
+    });
+
+    let mut content = DllManifestContent::default();
+    for module in compilation
+      .chunk_graph
+      .get_ordered_chunk_modules(chunk_ukey, &module_graph)
+    {
+      let module_identifier = module.identifier();
+      if self.options.entry_only
+        && !module_graph
+          .get_incoming_connections(&module_identifier)
+          .iter()
+          .any(|connection| {
+            module_graph
+              .dependency_by_id(&connection.dependency_id)
+              .is_some_and(|dep| dep.dependency_type() == &DependencyType::Entry)
+          })
+      {
+        continue;
+      }
+      let Some(ident) = module.lib_ident(LibIdentOptions { context }) else {
+        continue;
+      };
+      let exports = match module_graph.get_provided_exports(module_identifier) {
+        ProvidedExports::Vec(exports) => Some(exports.iter().map(|e| e.to_string()).collect()),
+        _ => None,
+      };
+      content.insert(
+        ident.into_owned(),
+        DllManifestContentItem {
+          id: compilation
+            .chunk_graph
+            .get_module_id(module_identifier)
+            .clone(),
+          build_meta: module.build_meta().cloned(),
+          exports,
+        },
+      );
+    }
+
+    let manifest = DllManifest {
+      name,
+      r#type: self.options.r#type.clone(),
+      content,
+    };
+    let manifest_json = if self.options.format {
+      serde_json::to_string_pretty(&manifest)
+    } else {
+      serde_json::to_string(&manifest)
+    }
+    .into_diagnostic()?;
+    manifests.push((target_path, manifest_json));
+  }
+  drop(module_graph);
+
+  for (filename, manifest_json) in manifests {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawSource::from(manifest_json).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for LibManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.LibManifestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.compiler_hooks.emit.tap(emit::new(self));
+    Ok(())
+  }
+}
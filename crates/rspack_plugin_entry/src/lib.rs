@@ -44,6 +44,7 @@ async fn make(&self, compilation: &mut Compilation, params: &mut Vec<MakeParam>)
   let dependency: BoxDependency = Box::new(EntryDependency::new(
     this.entry_request.clone(),
     this.context.clone(),
+    this.options.layer.clone(),
   ));
   let dependency_id = *dependency.id();
   compilation.add_entry(dependency, this.options.clone())?;
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use rspack_core::{
-  impl_runtime_module, rspack_sources::RawSource, ChunkUkey, Compilation, CrossOriginLoading,
-  RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+  impl_runtime_module, render_sri_integrity, rspack_sources::RawSource, ChunkUkey, Compilation,
+  CrossOriginLoading, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
 use rspack_error::Result;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -96,21 +96,35 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       runtime.replace("__SET_LINKTYPE__", "")
     };
 
-    let runtime = if let CrossOriginLoading::Enable(cross_origin_loading) =
+    let cross_origin_loading = if let CrossOriginLoading::Enable(cross_origin_loading) =
       &compilation.options.output.cross_origin_loading
     {
-      runtime.replace(
-        "__CROSS_ORIGIN_LOADING__",
-        &format!(
-          "if (linkTag.href.indexOf(window.location.origin + '/') !== 0) {{
+      format!(
+        "if (linkTag.href.indexOf(window.location.origin + '/') !== 0) {{
   linkTag.crossOrigin = \"{}\";
 }}",
-          cross_origin_loading
-        ),
+        cross_origin_loading
+      )
+    } else {
+      String::new()
+    };
+    let integrity = if compilation
+      .chunk_graph
+      .get_chunk_runtime_requirements(&self.chunk)
+      .contains(RuntimeGlobals::SRI_HASHES)
+    {
+      render_sri_integrity(
+        "linkTag",
+        "css",
+        &compilation.options.output.cross_origin_loading,
       )
     } else {
-      runtime.replace("__CROSS_ORIGIN_LOADING__", "")
+      String::new()
     };
+    let runtime = runtime.replace(
+      "__CROSS_ORIGIN_LOADING__",
+      &format!("{cross_origin_loading}{integrity}"),
+    );
 
     let runtime = match &self.insert {
       InsertType::Fn(f) => runtime.replace("__INSERT__", &format!("({f})(linkTag);")),
@@ -0,0 +1,103 @@
+use rspack_core::{
+  AsDependency, DependencyId, DependencyTemplate, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+
+#[derive(Debug, Clone)]
+pub struct AMDDefineDependency {
+  range: (u32, u32),
+  array_range: Option<(u32, u32)>,
+  function_range: Option<(u32, u32)>,
+  object_range: Option<(u32, u32)>,
+}
+
+impl AMDDefineDependency {
+  pub fn new(
+    range: (u32, u32),
+    array_range: Option<(u32, u32)>,
+    function_range: Option<(u32, u32)>,
+    object_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      range,
+      array_range,
+      function_range,
+      object_range,
+    }
+  }
+
+  /// Returns the variable definitions, the replacement text with `#` standing
+  /// for each retained range, and the runtime requirements of the replacement.
+  fn definition(&self) -> (&'static str, &'static str, RuntimeGlobals) {
+    match (
+      self.array_range.is_some(),
+      self.object_range.is_some(),
+      self.function_range.is_some(),
+    ) {
+      (false, false, true) => (
+        "var __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_RESULT__ = (#).call(exports, __webpack_require__, exports, module),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::REQUIRE | RuntimeGlobals::EXPORTS | RuntimeGlobals::MODULE,
+      ),
+      (false, true, false) => ("", "!(module.exports = #)", RuntimeGlobals::MODULE),
+      (false, true, true) => (
+        "var __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.call(exports, __webpack_require__, exports, module)) :\n\t\t__WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::REQUIRE | RuntimeGlobals::EXPORTS | RuntimeGlobals::MODULE,
+      ),
+      (true, false, true) => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_RESULT__ = (#).apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::EXPORTS | RuntimeGlobals::MODULE,
+      ),
+      (true, true, false) => ("", "!(#, module.exports = #)", RuntimeGlobals::MODULE),
+      (true, true, true) => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__)) : __WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::EXPORTS | RuntimeGlobals::MODULE,
+      ),
+      // the parser always records a factory or an object
+      (_, false, false) => ("", "!(#)", RuntimeGlobals::empty()),
+    }
+  }
+}
+
+impl DependencyTemplate for AMDDefineDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let (definition, content, requirements) = self.definition();
+    code_generatable_context
+      .runtime_requirements
+      .insert(requirements);
+
+    let mut texts = content.split('#');
+
+    if !definition.is_empty() {
+      source.insert(0, definition, None);
+    }
+    let mut current = self.range.0;
+    if let Some((start, end)) = self.array_range {
+      source.replace(current, start, texts.next().unwrap_or_default(), None);
+      current = end;
+    }
+    if let Some((start, end)) = self.object_range.or(self.function_range) {
+      source.replace(current, start, texts.next().unwrap_or_default(), None);
+      current = end;
+    }
+    source.replace(
+      current,
+      self.range.1,
+      texts.next().unwrap_or_default(),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    None
+  }
+}
+
+impl AsDependency for AMDDefineDependency {}
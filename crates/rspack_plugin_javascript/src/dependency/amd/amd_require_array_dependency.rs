@@ -0,0 +1,69 @@
+use itertools::Itertools;
+use rspack_core::{
+  module_raw, AsDependency, DependencyId, DependencyTemplate, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+#[derive(Debug, Clone)]
+pub enum AMDRequireArrayItem {
+  Require,
+  Exports,
+  Module,
+  Dependency { id: DependencyId, request: Atom },
+}
+
+#[derive(Debug, Clone)]
+pub struct AMDRequireArrayDependency {
+  start: u32,
+  end: u32,
+  items: Vec<AMDRequireArrayItem>,
+}
+
+impl AMDRequireArrayDependency {
+  pub fn new(start: u32, end: u32, items: Vec<AMDRequireArrayItem>) -> Self {
+    Self { start, end, items }
+  }
+}
+
+impl DependencyTemplate for AMDRequireArrayDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let content = self
+      .items
+      .iter()
+      .map(|item| match item {
+        AMDRequireArrayItem::Require => {
+          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+          RuntimeGlobals::REQUIRE.name().to_string()
+        }
+        AMDRequireArrayItem::Exports => {
+          runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+          "exports".to_string()
+        }
+        AMDRequireArrayItem::Module => {
+          runtime_requirements.insert(RuntimeGlobals::MODULE);
+          "module".to_string()
+        }
+        AMDRequireArrayItem::Dependency { id, request } => {
+          module_raw(compilation, runtime_requirements, id, request, false)
+        }
+      })
+      .join(", ");
+    source.replace(self.start, self.end, &format!("[{content}]"), None);
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    None
+  }
+}
+
+impl AsDependency for AMDRequireArrayDependency {}
@@ -0,0 +1,152 @@
+use rspack_core::{
+  block_promise, AsContextDependency, AsModuleDependency, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+
+#[derive(Debug, Clone)]
+pub struct AMDRequireDependency {
+  id: DependencyId,
+  outer_range: (u32, u32),
+  array_range: Option<(u32, u32)>,
+  function_range: Option<(u32, u32)>,
+  error_callback_range: Option<(u32, u32)>,
+  pub function_bind_this: bool,
+  pub error_callback_bind_this: bool,
+}
+
+impl AMDRequireDependency {
+  pub fn new(
+    outer_range: (u32, u32),
+    array_range: Option<(u32, u32)>,
+    function_range: Option<(u32, u32)>,
+    error_callback_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      outer_range,
+      array_range,
+      function_range,
+      error_callback_range,
+      function_bind_this: false,
+      error_callback_bind_this: false,
+    }
+  }
+}
+
+impl Dependency for AMDRequireDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequire
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.outer_range.0, self.outer_range.1))
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "AMDRequireDependency"
+  }
+}
+
+impl AsModuleDependency for AMDRequireDependency {}
+impl AsContextDependency for AMDRequireDependency {}
+
+impl DependencyTemplate for AMDRequireDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let module_graph = compilation.get_module_graph();
+    let block = module_graph.get_parent_block(&self.id);
+    let promise = block_promise(block, runtime_requirements, compilation);
+
+    let (outer_start, outer_end) = self.outer_range;
+    let bind_this = |bind_this: bool| if bind_this { ".bind(this)" } else { "" };
+
+    match (
+      self.array_range,
+      self.function_range,
+      self.error_callback_range,
+    ) {
+      (Some((array_start, array_end)), None, _) => {
+        runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+        source.replace(
+          outer_start,
+          array_start,
+          &format!("{promise}.then(function() {{"),
+          None,
+        );
+        source.replace(
+          array_end,
+          outer_end,
+          &format!(
+            ";}})['catch']({})",
+            RuntimeGlobals::UNCAUGHT_ERROR_HANDLER.name()
+          ),
+          None,
+        );
+      }
+      (Some((array_start, array_end)), Some((function_start, function_end)), error_callback) => {
+        source.replace(
+          outer_start,
+          array_start,
+          &format!("{promise}.then(function() {{ "),
+          None,
+        );
+        source.insert(array_start, "var __WEBPACK_AMD_REQUIRE_ARRAY__ = ", None);
+        source.replace(array_end, function_start, "; (", None);
+        source.insert(
+          function_end,
+          ").apply(null, __WEBPACK_AMD_REQUIRE_ARRAY__);",
+          None,
+        );
+        if let Some((error_callback_start, error_callback_end)) = error_callback {
+          source.replace(
+            function_end,
+            error_callback_start,
+            &format!("}}{})['catch'](", bind_this(self.function_bind_this)),
+            None,
+          );
+          source.replace(
+            error_callback_end,
+            outer_end,
+            &format!("{})", bind_this(self.error_callback_bind_this)),
+            None,
+          );
+        } else {
+          runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+          source.replace(
+            function_end,
+            outer_end,
+            &format!(
+              "}}{})['catch']({})",
+              bind_this(self.function_bind_this),
+              RuntimeGlobals::UNCAUGHT_ERROR_HANDLER.name()
+            ),
+            None,
+          );
+        }
+      }
+      // the parser only creates this dependency for `require([...], ...)`
+      (None, _, _) => {}
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
@@ -0,0 +1,109 @@
+use rspack_core::{
+  module_raw, AsContextDependency, Dependency, DependencyCategory, DependencyId,
+  DependencyTemplate, DependencyType, ErrorSpan, ModuleDependency, TemplateContext,
+  TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+#[derive(Debug, Clone)]
+pub struct AMDRequireItemDependency {
+  id: DependencyId,
+  request: Atom,
+  /// Replaced with the module when the item is written as an expression,
+  /// items of a literal array are rendered by `AMDRequireArrayDependency` instead.
+  range: Option<(u32, u32)>,
+  span: Option<ErrorSpan>,
+  optional: bool,
+}
+
+impl AMDRequireItemDependency {
+  pub fn new(
+    request: Atom,
+    range: Option<(u32, u32)>,
+    span: Option<ErrorSpan>,
+    optional: bool,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      range,
+      span,
+      optional,
+    }
+  }
+}
+
+impl Dependency for AMDRequireItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireItem
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "AMDRequireItemDependency"
+  }
+}
+
+impl ModuleDependency for AMDRequireItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn get_optional(&self) -> bool {
+    self.optional
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
+
+impl DependencyTemplate for AMDRequireItemDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let Some((start, end)) = self.range else {
+      return;
+    };
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    source.replace(
+      start,
+      end,
+      &module_raw(
+        compilation,
+        runtime_requirements,
+        &self.id,
+        &self.request,
+        false,
+      ),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for AMDRequireItemDependency {}
@@ -4,7 +4,10 @@ mod common_js_full_require_dependency;
 mod common_js_require_dependency;
 mod common_js_self_reference_dependency;
 mod module_decorator_dependency;
+mod require_ensure_dependency;
+mod require_ensure_item_dependency;
 mod require_header_dependency;
+mod require_include_dependency;
 mod require_resolve_dependency;
 
 pub use common_js_export_require_dependency::CommonJsExportRequireDependency;
@@ -14,5 +17,8 @@ pub use common_js_full_require_dependency::CommonJsFullRequireDependency;
 pub use common_js_require_dependency::CommonJsRequireDependency;
 pub use common_js_self_reference_dependency::CommonJsSelfReferenceDependency;
 pub use module_decorator_dependency::ModuleDecoratorDependency;
+pub use require_ensure_dependency::RequireEnsureDependency;
+pub use require_ensure_item_dependency::RequireEnsureItemDependency;
 pub use require_header_dependency::RequireHeaderDependency;
+pub use require_include_dependency::RequireIncludeDependency;
 pub use require_resolve_dependency::RequireResolveDependency;
@@ -0,0 +1,106 @@
+use rspack_core::{
+  block_promise, AsContextDependency, AsModuleDependency, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+
+#[derive(Debug, Clone)]
+pub struct RequireEnsureDependency {
+  id: DependencyId,
+  range: (u32, u32),
+  content_range: (u32, u32),
+  error_handler_range: Option<(u32, u32)>,
+}
+
+impl RequireEnsureDependency {
+  pub fn new(
+    range: (u32, u32),
+    content_range: (u32, u32),
+    error_handler_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      range,
+      content_range,
+      error_handler_range,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsure
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.range.0, self.range.1))
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireEnsureDependency"
+  }
+}
+
+impl AsModuleDependency for RequireEnsureDependency {}
+impl AsContextDependency for RequireEnsureDependency {}
+
+impl DependencyTemplate for RequireEnsureDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let module_graph = compilation.get_module_graph();
+    let block = module_graph.get_parent_block(&self.id);
+    let promise = block_promise(block, runtime_requirements, compilation);
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+
+    source.replace(
+      self.range.0,
+      self.content_range.0,
+      &format!("{promise}.then(("),
+      None,
+    );
+    if let Some((error_handler_start, error_handler_end)) = self.error_handler_range {
+      source.replace(
+        self.content_range.1,
+        error_handler_start,
+        &format!(
+          ").bind(null, {}))['catch'](",
+          RuntimeGlobals::REQUIRE.name()
+        ),
+        None,
+      );
+      source.replace(error_handler_end, self.range.1, ")", None);
+    } else {
+      runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+      source.replace(
+        self.content_range.1,
+        self.range.1,
+        &format!(
+          ").bind(null, {}))['catch']({})",
+          RuntimeGlobals::REQUIRE.name(),
+          RuntimeGlobals::UNCAUGHT_ERROR_HANDLER.name()
+        ),
+        None,
+      );
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
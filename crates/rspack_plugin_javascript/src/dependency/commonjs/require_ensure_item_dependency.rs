@@ -0,0 +1,73 @@
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+#[derive(Debug, Clone)]
+pub struct RequireEnsureItemDependency {
+  id: DependencyId,
+  request: Atom,
+  span: Option<ErrorSpan>,
+}
+
+impl RequireEnsureItemDependency {
+  pub fn new(request: Atom, span: Option<ErrorSpan>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsureItem
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireEnsureItemDependency"
+  }
+}
+
+impl ModuleDependency for RequireEnsureItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
+
+impl DependencyTemplate for RequireEnsureItemDependency {
+  fn apply(
+    &self,
+    _source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for RequireEnsureItemDependency {}
@@ -0,0 +1,85 @@
+use rspack_core::{
+  AsContextDependency, Dependency, DependencyCategory, DependencyId, DependencyTemplate,
+  DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+#[derive(Debug, Clone)]
+pub struct RequireIncludeDependency {
+  id: DependencyId,
+  start: u32,
+  end: u32,
+  request: Atom,
+}
+
+impl RequireIncludeDependency {
+  pub fn new(start: u32, end: u32, request: Atom) -> Self {
+    Self {
+      id: DependencyId::new(),
+      start,
+      end,
+      request,
+    }
+  }
+}
+
+impl Dependency for RequireIncludeDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireInclude
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.start, self.end))
+  }
+
+  fn dependency_debug_name(&self) -> &'static str {
+    "RequireIncludeDependency"
+  }
+}
+
+impl ModuleDependency for RequireIncludeDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+}
+
+impl DependencyTemplate for RequireIncludeDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(self.start, self.end, "undefined", None);
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for RequireIncludeDependency {}
@@ -1,7 +1,8 @@
 use rspack_core::{AsModuleDependency, ContextDependency};
 use rspack_core::{ContextOptions, Dependency, TemplateReplaceSource};
 use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
-use rspack_core::{DependencyType, ErrorSpan, TemplateContext};
+use rspack_core::{DependencyType, ErrorSpan, ModuleGraph, TemplateContext};
+use rspack_error::Diagnostic;
 
 use super::{
   context_dependency_template_as_require_call, create_resource_identifier_for_context_dependency,
@@ -16,6 +17,7 @@ pub struct CommonJsRequireContextDependency {
   options: ContextOptions,
   span: Option<ErrorSpan>,
   resource_identifier: String,
+  critical: Option<Diagnostic>,
 }
 
 impl CommonJsRequireContextDependency {
@@ -35,6 +37,7 @@ impl CommonJsRequireContextDependency {
       span,
       id: DependencyId::new(),
       resource_identifier,
+      critical: None,
     }
   }
 }
@@ -59,6 +62,10 @@ impl Dependency for CommonJsRequireContextDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "CommonJsRequireContextDependency"
   }
+
+  fn get_diagnostics(&self, _module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    self.critical.clone().map(|critical| vec![critical])
+  }
 }
 
 impl ContextDependency for CommonJsRequireContextDependency {
@@ -70,6 +77,10 @@ impl ContextDependency for CommonJsRequireContextDependency {
     &self.options
   }
 
+  fn options_mut(&mut self) -> &mut ContextOptions {
+    &mut self.options
+  }
+
   fn get_context(&self) -> Option<&str> {
     None
   }
@@ -81,6 +92,14 @@ impl ContextDependency for CommonJsRequireContextDependency {
   fn set_request(&mut self, request: String) {
     self.options.request = request;
   }
+
+  fn critical(&self) -> &Option<Diagnostic> {
+    &self.critical
+  }
+
+  fn critical_mut(&mut self) -> &mut Option<Diagnostic> {
+    &mut self.critical
+  }
 }
 
 impl DependencyTemplate for CommonJsRequireContextDependency {
@@ -1,7 +1,8 @@
 use rspack_core::{module_id_expr, AsModuleDependency, ContextDependency};
 use rspack_core::{ContextOptions, Dependency, DependencyCategory, DependencyId};
 use rspack_core::{DependencyTemplate, DependencyType, ErrorSpan, RuntimeGlobals};
-use rspack_core::{TemplateContext, TemplateReplaceSource};
+use rspack_core::{ModuleGraph, TemplateContext, TemplateReplaceSource};
+use rspack_error::Diagnostic;
 
 use super::create_resource_identifier_for_context_dependency;
 
@@ -13,6 +14,7 @@ pub struct ImportMetaContextDependency {
   options: ContextOptions,
   span: Option<ErrorSpan>,
   resource_identifier: String,
+  critical: Option<Diagnostic>,
 }
 
 impl ImportMetaContextDependency {
@@ -25,6 +27,7 @@ impl ImportMetaContextDependency {
       span,
       id: DependencyId::new(),
       resource_identifier,
+      critical: None,
     }
   }
 }
@@ -49,6 +52,10 @@ impl Dependency for ImportMetaContextDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "ImportMetaContextDependency"
   }
+
+  fn get_diagnostics(&self, _module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    self.critical.clone().map(|critical| vec![critical])
+  }
 }
 
 impl ContextDependency for ImportMetaContextDependency {
@@ -60,6 +67,10 @@ impl ContextDependency for ImportMetaContextDependency {
     &self.options
   }
 
+  fn options_mut(&mut self) -> &mut ContextOptions {
+    &mut self.options
+  }
+
   fn get_context(&self) -> Option<&str> {
     None
   }
@@ -71,6 +82,14 @@ impl ContextDependency for ImportMetaContextDependency {
   fn set_request(&mut self, request: String) {
     self.options.request = request;
   }
+
+  fn critical(&self) -> &Option<Diagnostic> {
+    &self.critical
+  }
+
+  fn critical_mut(&mut self) -> &mut Option<Diagnostic> {
+    &mut self.critical
+  }
 }
 
 impl DependencyTemplate for ImportMetaContextDependency {
@@ -7,16 +7,20 @@ use rspack_core::{
   get_exports_type, process_export_info, property_access, property_name, string_of_used_name,
   AsContextDependency, ConnectionState, Dependency, DependencyCategory, DependencyCondition,
   DependencyId, DependencyTemplate, DependencyType, ExportInfoId, ExportInfoProvided,
-  ExportNameOrSpec, ExportSpec, ExportsInfoId, ExportsOfExportsSpec, ExportsSpec, ExportsType,
-  ExtendedReferencedExport, HarmonyExportInitFragment, InitFragmentExt, InitFragmentKey,
-  InitFragmentStage, ModuleDependency, ModuleGraph, ModuleIdentifier, NormalInitFragment,
-  RuntimeGlobals, RuntimeSpec, Template, TemplateContext, TemplateReplaceSource, UsageState,
-  UsedName,
+  ExportNameOrSpec, ExportPresenceMode, ExportSpec, ExportsInfoId, ExportsOfExportsSpec,
+  ExportsSpec, ExportsType, ExtendedReferencedExport, HarmonyExportInitFragment, InitFragmentExt,
+  InitFragmentKey, InitFragmentStage, ModuleDependency, ModuleGraph, ModuleIdentifier,
+  NormalInitFragment, RuntimeGlobals, RuntimeSpec, Template, TemplateContext,
+  TemplateReplaceSource, UsageState, UsedName,
 };
+use rspack_error::Diagnostic;
 use rustc_hash::{FxHashSet as HashSet, FxHasher};
 use swc_core::ecma::atoms::Atom;
 
-use super::{create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply};
+use super::{
+  create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply,
+  harmony_import_dependency_get_linking_error,
+};
 
 // Create _webpack_require__.d(__webpack_exports__, {}).
 // case1: `import { a } from 'a'; export { a }`
@@ -37,9 +41,11 @@ pub struct HarmonyExportImportedSpecifierDependency {
   // pub all_star_exports: Option<Vec<DependencyId>>,
   pub other_star_exports: Option<Vec<DependencyId>>,
   pub export_all: bool,
+  export_presence_mode: ExportPresenceMode,
 }
 
 impl HarmonyExportImportedSpecifierDependency {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     request: Atom,
     source_order: i32,
@@ -48,6 +54,7 @@ impl HarmonyExportImportedSpecifierDependency {
     name: Option<Atom>,
     export_all: bool,
     other_star_exports: Option<Vec<DependencyId>>,
+    export_presence_mode: ExportPresenceMode,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
@@ -60,6 +67,7 @@ impl HarmonyExportImportedSpecifierDependency {
       resource_identifier,
       export_all,
       other_star_exports,
+      export_presence_mode,
     }
   }
 
@@ -1155,6 +1163,23 @@ impl Dependency for HarmonyExportImportedSpecifierDependency {
     "HarmonyExportImportedSpecifierDependency"
   }
 
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let module = module_graph.get_parent_module(&self.id)?;
+    let module = module_graph.module_by_identifier(module)?;
+    let severity = self
+      .export_presence_mode
+      .get_effective_severity(module.get_strict_harmony_module())?;
+    let name = self.name.as_ref()?;
+    let diagnostic = harmony_import_dependency_get_linking_error(
+      self,
+      &self.get_ids(module_graph),
+      module_graph,
+      format!("(reexported as '{name}')"),
+      severity,
+    )?;
+    Some(vec![diagnostic])
+  }
+
   fn resource_identifier(&self) -> Option<&str> {
     Some(&self.resource_identifier)
   }
@@ -1,16 +1,18 @@
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 use rspack_core::{
-  filter_runtime, import_statement, merge_runtime, AsContextDependency,
+  filter_runtime, get_exports_type, import_statement, merge_runtime, AsContextDependency,
   AwaitDependenciesInitFragment, ConditionalInitFragment, ConnectionState, Dependency,
   DependencyCategory, DependencyCondition, DependencyId, DependencyTemplate, DependencyType,
-  ErrorSpan, ExtendedReferencedExport, InitFragmentExt, InitFragmentKey, InitFragmentStage,
-  ModuleDependency, ModuleIdentifier, NormalInitFragment, RuntimeCondition, RuntimeGlobals,
-  TemplateContext, TemplateReplaceSource,
+  ErrorSpan, ExportInfoProvided, ExportsType, ExtendedReferencedExport, InitFragmentExt,
+  InitFragmentKey, InitFragmentStage, ModuleDependency, ModuleIdentifier, NormalInitFragment,
+  ProvidedExports, RuntimeCondition, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
 };
 use rspack_core::{ModuleGraph, RuntimeSpec};
+use rspack_error::{miette, Diagnostic, DiagnosticKind, Severity, TraceableError};
 use rustc_hash::{FxHashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
@@ -238,6 +240,99 @@ pub fn harmony_import_dependency_apply<T: ModuleDependency>(
   }
 }
 
+/// Reports `ids` which are imported from the module of `module_dependency` but not provided,
+/// `additional_message` names the local binding, e.g. `(imported as 'foo')`
+pub fn harmony_import_dependency_get_linking_error<T: ModuleDependency>(
+  module_dependency: &T,
+  ids: &[Atom],
+  module_graph: &ModuleGraph,
+  additional_message: String,
+  severity: Severity,
+) -> Option<Diagnostic> {
+  let imported_module = module_graph.get_module_by_dependency_id(module_dependency.id())?;
+  if imported_module
+    .get_diagnostics()
+    .iter()
+    .any(|diagnostic| matches!(diagnostic.severity(), Severity::Error))
+  {
+    return None;
+  }
+  let parent_module = module_graph.get_parent_module(module_dependency.id())?;
+  let exports_type = get_exports_type(module_graph, module_dependency.id(), parent_module);
+  let first = ids.first()?;
+
+  let message = match exports_type {
+    ExportsType::Namespace | ExportsType::DefaultWithNamed => {
+      if matches!(exports_type, ExportsType::DefaultWithNamed) && first == "default" {
+        return None;
+      }
+      let mut exports_info = Some(module_graph.get_exports_info(&imported_module.identifier()).id);
+      let mut message = None;
+      for (pos, id) in ids.iter().enumerate() {
+        let Some(exports_info_id) = exports_info else {
+          break;
+        };
+        let export_info = exports_info_id.get_read_only_export_info(id, module_graph);
+        if matches!(export_info.provided, Some(ExportInfoProvided::False)) {
+          let more_info = match exports_info_id
+            .get_exports_info(module_graph)
+            .get_provided_exports(module_graph)
+          {
+            ProvidedExports::Vec(exports) if exports.is_empty() => {
+              " (module has no exports)".to_string()
+            }
+            ProvidedExports::Vec(exports) => {
+              format!(" (possible exports: {})", exports.iter().join(", "))
+            }
+            _ => " (possible exports unknown)".to_string(),
+          };
+          message = Some(format!(
+            "export {} {additional_message} was not found in '{}'{more_info}",
+            format_ids(&ids[..=pos]),
+            module_dependency.user_request()
+          ));
+          break;
+        }
+        exports_info = export_info.exports_info;
+      }
+      message?
+    }
+    ExportsType::DefaultOnly if first != "default" => format!(
+      "Can't import the named export {} {additional_message} from default-exporting module (only default export is available)",
+      format_ids(ids)
+    ),
+    _ => return None,
+  };
+
+  let title = "HarmonyLinkingError".to_string();
+  let source = module_graph
+    .module_by_identifier(parent_module)
+    .and_then(|module| module.original_source());
+  let diagnostic = match (source, module_dependency.span()) {
+    (Some(source), Some(span)) => Diagnostic::from(Box::new(
+      TraceableError::from_file(
+        source.source().into_owned(),
+        span.start as usize,
+        span.end as usize,
+        title,
+        message,
+      )
+      .with_kind(DiagnosticKind::JavaScript)
+      .with_severity(severity),
+    )
+      as Box<dyn miette::Diagnostic + Send + Sync>),
+    _ => match severity {
+      Severity::Error => Diagnostic::error(title, message),
+      Severity::Warn => Diagnostic::warn(title, message),
+    },
+  };
+  Some(diagnostic)
+}
+
+fn format_ids(ids: &[Atom]) -> String {
+  ids.iter().map(|id| format!("'{id}'")).join(".")
+}
+
 impl Dependency for HarmonyImportSideEffectDependency {
   fn dependency_debug_name(&self) -> &'static str {
     "HarmonyImportSideEffectDependency"
@@ -2,16 +2,18 @@ use rspack_core::{
   create_exports_object_referenced, export_from_import, get_dependency_used_by_exports_condition,
   get_exports_type, tree_shaking::symbol::DEFAULT_JS_WORD, AsContextDependency, Compilation,
   ConnectionState, Dependency, DependencyCategory, DependencyCondition, DependencyId,
-  DependencyTemplate, DependencyType, ExportsType, ExtendedReferencedExport, ModuleDependency,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ReferencedExport, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource, UsedByExports,
+  DependencyTemplate, DependencyType, ExportPresenceMode, ExportsType, ExtendedReferencedExport,
+  ModuleDependency, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ReferencedExport,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource, UsedByExports,
 };
 use rspack_core::{property_access, ModuleReferenceOptions};
+use rspack_error::Diagnostic;
 use rustc_hash::FxHashSet as HashSet;
 use swc_core::{common::Span, ecma::atoms::Atom};
 
 use super::{
-  create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply, Specifier,
+  create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply,
+  harmony_import_dependency_get_linking_error, Specifier,
 };
 
 #[derive(Debug, Clone)]
@@ -31,6 +33,7 @@ pub struct HarmonyImportSpecifierDependency {
   referenced_properties_in_destructuring: Option<HashSet<Atom>>,
   resource_identifier: String,
   span_for_on_usage_search: Span,
+  export_presence_mode: ExportPresenceMode,
 }
 
 impl HarmonyImportSpecifierDependency {
@@ -47,6 +50,7 @@ impl HarmonyImportSpecifierDependency {
     specifier: Specifier,
     referenced_properties_in_destructuring: Option<HashSet<Atom>>,
     span_for_on_usage_search: Span,
+    export_presence_mode: ExportPresenceMode,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_esm_dependency(&request);
     Self {
@@ -65,6 +69,7 @@ impl HarmonyImportSpecifierDependency {
       referenced_properties_in_destructuring,
       resource_identifier,
       span_for_on_usage_search,
+      export_presence_mode,
     }
   }
 
@@ -300,6 +305,25 @@ impl Dependency for HarmonyImportSpecifierDependency {
   fn resource_identifier(&self) -> Option<&str> {
     Some(&self.resource_identifier)
   }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let module = module_graph.get_parent_module(&self.id)?;
+    let module = module_graph.module_by_identifier(module)?;
+    let severity = self
+      .export_presence_mode
+      .get_effective_severity(module.get_strict_harmony_module())?;
+    let name = match &self.specifier {
+      Specifier::Namespace(local) | Specifier::Default(local) | Specifier::Named(local, _) => local,
+    };
+    let diagnostic = harmony_import_dependency_get_linking_error(
+      self,
+      &self.get_ids(module_graph),
+      module_graph,
+      format!("(imported as '{name}')"),
+      severity,
+    )?;
+    Some(vec![diagnostic])
+  }
 }
 
 impl ModuleDependency for HarmonyImportSpecifierDependency {
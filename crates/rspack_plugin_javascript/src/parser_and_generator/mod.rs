@@ -151,7 +151,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         .0
       };
 
-    run_before_pass(&mut ast, compiler_options)?;
+    run_before_pass(&mut ast)?;
 
     let output: crate::TransformOutput = crate::ast::stringify(
       &ast,
@@ -0,0 +1,96 @@
+use rspack_core::SpanExt;
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, Lit};
+
+use super::{process_array, EXPORTS, MODULE, REQUIRE};
+use crate::dependency::AMDDefineDependency;
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::visitors::{FunctionExpression, JavascriptParser};
+
+const DEFINE: &str = "define";
+
+fn is_named_module(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Str(_)))
+}
+
+pub struct AMDDefineDependencyParserPlugin;
+
+impl AMDDefineDependencyParserPlugin {
+  fn process_call_define(&self, parser: &mut JavascriptParser, expr: &CallExpr) -> Option<bool> {
+    if expr.args.iter().any(|arg| arg.spread.is_some()) {
+      return None;
+    }
+    // FIXME: named modules are not registered as local modules, so
+    // `define("name", ...)` exports the module like an anonymous `define`
+    let (array, factory) = match expr.args.as_slice() {
+      [factory] => (None, &*factory.expr),
+      [name, factory] if is_named_module(&name.expr) => (None, &*factory.expr),
+      [array, factory] => (Some(&*array.expr), &*factory.expr),
+      [_, array, factory] => (Some(&*array.expr), &*factory.expr),
+      _ => return None,
+    };
+    let function = FunctionExpression::from_expr(factory);
+    let (function_range, object_range) = {
+      let range = Some((factory.span().real_lo(), factory.span().real_hi()));
+      if function.is_some() {
+        (range, None)
+      } else if factory.is_object() {
+        (None, range)
+      } else {
+        (range, range)
+      }
+    };
+
+    parser.bailout();
+
+    let identifiers = if let Some(array) = array {
+      let (dependencies, identifiers) = process_array(parser, array)?;
+      parser.dependencies.extend(dependencies);
+      identifiers
+    } else {
+      vec![Some(REQUIRE), Some(EXPORTS), Some(MODULE)]
+    };
+
+    if let Some(function) = function {
+      // parameters bound by `.bind(this, ...)` don't receive the dependencies
+      let offset = function
+        .bind_args
+        .map_or(0, |args| args.len().saturating_sub(1));
+      let mut params = vec![];
+      let mut renames = vec![];
+      for (index, param) in function.params.iter().enumerate() {
+        if index >= offset
+          && let Some(name) = FunctionExpression::param_name(param)
+          && let Some(Some(identifier)) = identifiers.get(index - offset)
+        {
+          renames.push((name.to_string(), *identifier));
+        } else {
+          params.push(*param);
+        }
+      }
+      function.walk(parser, params, renames);
+    } else {
+      parser.walk_expression(factory);
+    }
+
+    parser
+      .presentational_dependencies
+      .push(Box::new(AMDDefineDependency::new(
+        (expr.span.real_lo(), expr.span.real_hi()),
+        array.map(|array| (array.span().real_lo(), array.span().real_hi())),
+        function_range,
+        object_range,
+      )));
+    Some(true)
+  }
+}
+
+impl JavascriptParserPlugin for AMDDefineDependencyParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name == DEFINE {
+      self.process_call_define(parser, expr)
+    } else {
+      None
+    }
+  }
+}
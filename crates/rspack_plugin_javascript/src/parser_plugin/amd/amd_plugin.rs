@@ -0,0 +1,195 @@
+use rspack_core::{ConstDependency, RuntimeGlobals, SpanExt};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, Ident, MemberExpr, UnaryExpr};
+
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+use crate::visitors::JavascriptParser;
+
+const DEFINE: &str = "define";
+const DEFINE_AMD: &str = "define.amd";
+const REQUIRE_AMD: &str = "require.amd";
+const WEBPACK_AMD_OPTIONS: &str = "__webpack_amd_options__";
+const REQUIRE_CONFIG: &str = "require.config";
+const REQUIREJS_CONFIG: &str = "requirejs.config";
+const REQUIRE_VERSION: &str = "require.version";
+const REQUIREJS_ON_ERROR: &str = "requirejs.onError";
+
+fn is_amd_options(for_name: &str) -> bool {
+  matches!(for_name, DEFINE_AMD | REQUIRE_AMD | WEBPACK_AMD_OPTIONS)
+}
+
+fn to_constant_dependency(
+  parser: &mut JavascriptParser,
+  start: u32,
+  end: u32,
+  content: &str,
+  runtime_requirements: Option<RuntimeGlobals>,
+) -> Option<bool> {
+  parser
+    .presentational_dependencies
+    .push(Box::new(ConstDependency::new(
+      start,
+      end,
+      content.into(),
+      runtime_requirements,
+    )));
+  Some(true)
+}
+
+/// Handles the free variables of AMD and requirejs, `define(...)` and
+/// `require([...], ...)` are handled by `AMDDefineDependencyParserPlugin` and
+/// `AMDRequireDependenciesBlockParserPlugin`.
+pub struct AMDPlugin;
+
+impl JavascriptParserPlugin for AMDPlugin {
+  fn evaluate_typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    let sym = ident.sym.as_str();
+    if !parser.is_unresolved_ident(sym) {
+      return None;
+    }
+    match sym {
+      DEFINE => Some(eval::evaluate_to_string("function".to_string(), start, end)),
+      WEBPACK_AMD_OPTIONS => Some(eval::evaluate_to_string("object".to_string(), start, end)),
+      _ => None,
+    }
+  }
+
+  fn evaluate_identifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    let root = match ident {
+      DEFINE_AMD => DEFINE,
+      REQUIRE_AMD => "require",
+      WEBPACK_AMD_OPTIONS => WEBPACK_AMD_OPTIONS,
+      _ => return None,
+    };
+    Some(eval::evaluate_to_identifier(
+      ident.to_string(),
+      root.to_string(),
+      Some(true),
+      start,
+      end,
+    ))
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    let content = if for_name == DEFINE {
+      "'function'"
+    } else if is_amd_options(for_name) {
+      "'object'"
+    } else {
+      return None;
+    };
+    to_constant_dependency(
+      parser,
+      expr.span.real_lo(),
+      expr.span.real_hi(),
+      content,
+      None,
+    )
+  }
+
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    let runtime_global = match for_name {
+      DEFINE => RuntimeGlobals::AMD_DEFINE,
+      WEBPACK_AMD_OPTIONS => RuntimeGlobals::AMD_OPTIONS,
+      _ => return None,
+    };
+    to_constant_dependency(
+      parser,
+      ident.span.real_lo(),
+      ident.span.real_hi(),
+      runtime_global.name(),
+      Some(runtime_global),
+    )
+  }
+
+  fn member(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if is_amd_options(for_name) {
+      to_constant_dependency(
+        parser,
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        RuntimeGlobals::AMD_OPTIONS.name(),
+        Some(RuntimeGlobals::AMD_OPTIONS),
+      )
+    } else if for_name == REQUIRE_VERSION {
+      to_constant_dependency(
+        parser,
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        "'0.0.0'",
+        None,
+      )
+    } else if for_name == REQUIREJS_ON_ERROR {
+      to_constant_dependency(
+        parser,
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        RuntimeGlobals::UNCAUGHT_ERROR_HANDLER.name(),
+        Some(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER),
+      )
+    } else {
+      None
+    }
+  }
+
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name == REQUIRE_CONFIG || for_name == REQUIREJS_CONFIG {
+      to_constant_dependency(
+        parser,
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        "undefined",
+        None,
+      )
+    } else {
+      None
+    }
+  }
+
+  fn can_rename(&self, _parser: &mut JavascriptParser, str: &str) -> Option<bool> {
+    (str == DEFINE).then_some(true)
+  }
+
+  fn rename(&self, parser: &mut JavascriptParser, expr: &Expr, str: &str) -> Option<bool> {
+    if str != DEFINE {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span().real_lo(),
+        expr.span().real_hi(),
+        RuntimeGlobals::AMD_DEFINE.name().into(),
+        Some(RuntimeGlobals::AMD_DEFINE),
+      )));
+    Some(false)
+  }
+}
@@ -0,0 +1,93 @@
+use rspack_core::{AsyncDependenciesBlock, DependencyLocation, SpanExt};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr};
+
+use super::{process_array, EXPORTS, MODULE, REQUIRE};
+use crate::dependency::AMDRequireDependency;
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::visitors::{FunctionExpression, JavascriptParser};
+
+pub struct AMDRequireDependenciesBlockParserPlugin;
+
+impl AMDRequireDependenciesBlockParserPlugin {
+  /// Walks a callback of `require([...], callback, errorCallback)`, returns
+  /// whether the callback should be bound to `this`.
+  fn process_function_argument(&self, parser: &mut JavascriptParser, expr: &Expr) -> bool {
+    let Some(function) = FunctionExpression::from_expr(expr) else {
+      parser.walk_expression(expr);
+      return true;
+    };
+    let params = function
+      .params
+      .iter()
+      .filter(|param| {
+        !FunctionExpression::param_name(param)
+          .is_some_and(|name| matches!(name, REQUIRE | EXPORTS | MODULE))
+      })
+      .copied()
+      .collect();
+    function.walk(parser, params, vec![]);
+    function.bind_args.is_some()
+  }
+
+  fn process_call_require(&self, parser: &mut JavascriptParser, expr: &CallExpr) -> Option<bool> {
+    if expr.args.iter().any(|arg| arg.spread.is_some()) {
+      return None;
+    }
+    let (array, callback, error_callback) = match expr.args.as_slice() {
+      [array] => (&*array.expr, None, None),
+      [array, callback] => (&*array.expr, Some(&*callback.expr), None),
+      [array, callback, error_callback] => (
+        &*array.expr,
+        Some(&*callback.expr),
+        Some(&*error_callback.expr),
+      ),
+      _ => return None,
+    };
+    if !array.is_array() {
+      return None;
+    }
+
+    let range = |expr: &Expr| (expr.span().real_lo(), expr.span().real_hi());
+    let mut dep = AMDRequireDependency::new(
+      (expr.span.real_lo(), expr.span.real_hi()),
+      Some(range(array)),
+      callback.map(range),
+      error_callback.map(range),
+    );
+
+    let (mut dependencies, _) = process_array(parser, array)?;
+    // dependencies of the callbacks are loaded together with the array
+    let start = parser.dependencies.len();
+    if let Some(callback) = callback {
+      dep.function_bind_this = self.process_function_argument(parser, callback);
+    }
+    if let Some(error_callback) = error_callback {
+      dep.error_callback_bind_this = self.process_function_argument(parser, error_callback);
+    }
+    dependencies.extend(parser.dependencies.drain(start..));
+    dependencies.push(Box::new(dep));
+
+    let block = AsyncDependenciesBlock::new(
+      *parser.module_identifier,
+      Some(DependencyLocation::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+      )),
+      None,
+      dependencies,
+    );
+    parser.blocks.push(block);
+    Some(true)
+  }
+}
+
+impl JavascriptParserPlugin for AMDRequireDependenciesBlockParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name == REQUIRE {
+      self.process_call_require(parser, expr)
+    } else {
+      None
+    }
+  }
+}
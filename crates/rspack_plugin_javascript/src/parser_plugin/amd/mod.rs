@@ -0,0 +1,126 @@
+mod amd_define_dependency_parser_plugin;
+mod amd_plugin;
+mod amd_require_dependencies_block_parser_plugin;
+
+use rspack_core::{BoxDependency, ConstDependency, Dependency, ErrorSpan, RuntimeGlobals, SpanExt};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::Expr;
+
+pub(crate) use self::amd_define_dependency_parser_plugin::AMDDefineDependencyParserPlugin;
+pub(crate) use self::amd_plugin::AMDPlugin;
+pub(crate) use self::amd_require_dependencies_block_parser_plugin::AMDRequireDependenciesBlockParserPlugin;
+use crate::dependency::{AMDRequireArrayDependency, AMDRequireArrayItem, AMDRequireItemDependency};
+use crate::visitors::{expression_not_supported, JavascriptParser};
+
+const REQUIRE: &str = "require";
+const EXPORTS: &str = "exports";
+const MODULE: &str = "module";
+
+/// Processes the dependency array of `define` and `require`, returns the
+/// created module dependencies and the positions of `require`, `exports` and
+/// `module`, or `None` when `expr` is not an array literal.
+fn process_array(
+  parser: &mut JavascriptParser,
+  expr: &Expr,
+) -> Option<(Vec<BoxDependency>, Vec<Option<&'static str>>)> {
+  let array = expr.as_array()?;
+  if array
+    .elems
+    .iter()
+    .any(|elem| elem.as_ref().map_or(true, |elem| elem.spread.is_some()))
+  {
+    return None;
+  }
+  let items = array
+    .elems
+    .iter()
+    .flatten()
+    .map(|elem| (&*elem.expr, parser.evaluate_expression(&elem.expr)))
+    .collect::<Vec<_>>();
+  let identifiers = items
+    .iter()
+    .map(|(_, item)| {
+      if !item.is_string() {
+        return None;
+      }
+      match item.string().as_str() {
+        REQUIRE => Some(REQUIRE),
+        EXPORTS => Some(EXPORTS),
+        MODULE => Some(MODULE),
+        _ => None,
+      }
+    })
+    .collect();
+
+  let mut dependencies: Vec<BoxDependency> = vec![];
+  if items.iter().all(|(_, item)| item.is_string()) {
+    let array_items = items
+      .iter()
+      .map(|(elem, item)| match item.string().as_str() {
+        REQUIRE => AMDRequireArrayItem::Require,
+        EXPORTS => AMDRequireArrayItem::Exports,
+        MODULE => AMDRequireArrayItem::Module,
+        request => {
+          let dep = AMDRequireItemDependency::new(
+            request.into(),
+            None,
+            Some(ErrorSpan::from(elem.span())),
+            parser.in_try,
+          );
+          let id = *dep.id();
+          dependencies.push(Box::new(dep));
+          AMDRequireArrayItem::Dependency {
+            id,
+            request: request.into(),
+          }
+        }
+      })
+      .collect();
+    parser
+      .presentational_dependencies
+      .push(Box::new(AMDRequireArrayDependency::new(
+        expr.span().real_lo(),
+        expr.span().real_hi(),
+        array_items,
+      )));
+    return Some((dependencies, identifiers));
+  }
+
+  for (elem, item) in items {
+    if !item.is_string() {
+      // FIXME: webpack creates a context dependency here
+      let (warning, dep) = expression_not_supported(
+        parser.source_file,
+        "Non-string request in AMD dependency array",
+        elem,
+      );
+      parser.warning_diagnostics.push(warning);
+      parser.presentational_dependencies.push(dep);
+      continue;
+    }
+    let (start, end) = (elem.span().real_lo(), elem.span().real_hi());
+    let (content, runtime_requirement) = match item.string().as_str() {
+      REQUIRE => (RuntimeGlobals::REQUIRE.name(), RuntimeGlobals::REQUIRE),
+      EXPORTS => (EXPORTS, RuntimeGlobals::EXPORTS),
+      MODULE => (MODULE, RuntimeGlobals::MODULE),
+      request => {
+        dependencies.push(Box::new(AMDRequireItemDependency::new(
+          request.into(),
+          Some((start, end)),
+          Some(ErrorSpan::from(elem.span())),
+          parser.in_try,
+        )));
+        continue;
+      }
+    };
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        start,
+        end,
+        content.into(),
+        Some(runtime_requirement),
+      )));
+  }
+  Some((dependencies, identifiers))
+}
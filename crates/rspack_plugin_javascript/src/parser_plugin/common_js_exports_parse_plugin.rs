@@ -163,7 +163,7 @@ impl<'parser> JavascriptParser<'parser> {
   }
 
   // can't scan `__esModule` value
-  fn bailout(&mut self) {
+  pub(crate) fn bailout(&mut self) {
     if matches!(self.parser_exports_state, Some(true)) {
       self.build_meta.exports_type = BuildMetaExportsType::Unset;
       self.build_meta.default_object = BuildMetaDefaultObject::False;
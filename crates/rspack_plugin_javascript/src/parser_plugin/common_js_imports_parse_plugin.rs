@@ -2,8 +2,7 @@ use itertools::Itertools;
 use rspack_core::{
   context_reg_exp, ConstDependency, ContextMode, DependencyCategory, ErrorSpan, SpanExt,
 };
-use rspack_core::{ContextNameSpaceObject, ContextOptions};
-use rspack_error::Severity;
+use rspack_core::{ContextDependency, ContextNameSpaceObject, ContextOptions};
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{CallExpr, Expr, Ident, Lit, MemberExpr};
 
@@ -13,7 +12,7 @@ use crate::dependency::{CommonJsFullRequireDependency, CommonJsRequireContextDep
 use crate::dependency::{CommonJsRequireDependency, RequireResolveDependency};
 use crate::utils::eval::{self, BasicEvaluatedExpression};
 use crate::visitors::{
-  create_context_dependency, create_traceable_error, expr_matcher, expr_name, JavascriptParser,
+  create_context_dependency, create_critical_warning, expr_matcher, expr_name, JavascriptParser,
 };
 use crate::visitors::{extract_require_call_info, is_require_call_start};
 
@@ -41,7 +40,10 @@ fn create_commonjs_require_context_dependency(
     start: callee_start,
     end: callee_end,
   };
-  CommonJsRequireContextDependency::new(callee_start, callee_end, args_end, options, span)
+  let mut dependency =
+    CommonJsRequireContextDependency::new(callee_start, callee_end, args_end, options, span);
+  *dependency.critical_mut() = result.critical;
+  dependency
 }
 
 pub struct CommonJsImportsParserPlugin;
@@ -200,7 +202,7 @@ impl CommonJsImportsParserPlugin {
     parser: &mut JavascriptParser,
     ident: &Ident,
   ) -> Option<bool> {
-    let dep = CommonJsRequireContextDependency::new(
+    let mut dep = CommonJsRequireContextDependency::new(
       ident.span().real_lo(),
       ident.span().real_hi(),
       ident.span().real_hi(),
@@ -221,15 +223,10 @@ impl CommonJsImportsParserPlugin {
       },
       Some(ident.span().into()),
     );
-    parser.warning_diagnostics.push(Box::new(
-      create_traceable_error(
-        "Critical dependency".into(),
-        "require function is used in a way in which dependencies cannot be statically extracted"
-          .to_string(),
-        parser.source_file,
-        ident.span().into(),
-      )
-      .with_severity(Severity::Warn),
+    *dep.critical_mut() = Some(create_critical_warning(
+      parser,
+      "require function is used in a way in which dependencies cannot be statically extracted",
+      ident.span().into(),
     ));
     parser.dependencies.push(Box::new(dep));
     Some(true)
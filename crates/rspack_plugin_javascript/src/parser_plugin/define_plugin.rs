@@ -0,0 +1,225 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use rspack_core::{ConstDependency, RuntimeGlobals, SpanExt};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::common::{FileName, SourceMap, Spanned};
+use swc_core::ecma::ast::{AssignExpr, Expr, Ident, Lit, MemberExpr, UnaryExpr, UnaryOp};
+use swc_core::ecma::parser::parse_file_as_expr;
+
+use super::JavascriptParserPlugin;
+use crate::utils::eval::BasicEvaluatedExpression;
+use crate::visitors::JavascriptParser;
+
+const TYPEOF_PREFIX: &str = "typeof ";
+
+/// Replaces the keys of `builtins.define` with their code, and evaluates the code so that
+/// defined values take part in dead branch elimination and `typeof` evaluation.
+#[derive(Default)]
+pub struct DefineParserPlugin {
+  cm: Arc<SourceMap>,
+  parsed: RefCell<FxHashMap<String, Option<Box<Expr>>>>,
+  // keys being evaluated, to bail out of recursive definitions
+  evaluating: RefCell<FxHashSet<String>>,
+}
+
+impl DefineParserPlugin {
+  fn parse(&self, code: &str) -> Option<Box<Expr>> {
+    self
+      .parsed
+      .borrow_mut()
+      .entry(code.to_string())
+      .or_insert_with(|| {
+        let fm = self.cm.new_source_file(FileName::Anon, code.to_string());
+        parse_file_as_expr(
+          &fm,
+          Default::default(),
+          Default::default(),
+          None,
+          &mut vec![],
+        )
+        .ok()
+      })
+      .clone()
+  }
+
+  fn evaluate(
+    &self,
+    parser: &mut JavascriptParser,
+    key: &str,
+    expr: Expr,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    if !self.evaluating.borrow_mut().insert(key.to_string()) {
+      return None;
+    }
+    let mut evaluated = parser.evaluate_expression(&expr);
+    self.evaluating.borrow_mut().remove(key);
+    evaluated.set_range(start, end);
+    Some(evaluated)
+  }
+
+  fn evaluate_typeof_code(
+    &self,
+    parser: &mut JavascriptParser,
+    for_name: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    let compiler_options = parser.compiler_options;
+    let define = &compiler_options.builtins.define;
+    if let Some(code) = define.get(&format!("{TYPEOF_PREFIX}{for_name}")) {
+      let expr = self.parse(code)?;
+      return self.evaluate(parser, for_name, *expr, start, end);
+    }
+    let arg = self.parse(define.get(for_name)?)?;
+    let expr = Expr::Unary(UnaryExpr {
+      span: arg.span(),
+      op: UnaryOp::TypeOf,
+      arg,
+    });
+    self.evaluate(parser, for_name, expr, start, end)
+  }
+
+  fn replace(&self, parser: &mut JavascriptParser, for_name: &str, start: u32, end: u32) -> bool {
+    let compiler_options = parser.compiler_options;
+    let Some(code) = compiler_options.builtins.define.get(for_name) else {
+      return false;
+    };
+    let code = match self.parse(code) {
+      Some(expr) if !needs_parens(&expr) => code.to_string(),
+      _ => format!("({code})"),
+    };
+    let content = if parser.in_short_hand {
+      format!("{for_name}: {code}")
+    } else {
+      code
+    };
+    let runtime_requirements = get_runtime_requirements(&content);
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        start,
+        end,
+        content.into(),
+        runtime_requirements,
+      )));
+    true
+  }
+}
+
+/// Whether the code has to be wrapped to keep its precedence where the key was.
+fn needs_parens(expr: &Expr) -> bool {
+  !matches!(
+    expr,
+    Expr::Ident(_)
+      | Expr::This(_)
+      | Expr::Member(_)
+      | Expr::Call(_)
+      | Expr::Paren(_)
+      | Expr::Array(_)
+      | Expr::Tpl(_)
+      | Expr::Lit(Lit::Str(_) | Lit::Bool(_) | Lit::Null(_) | Lit::BigInt(_) | Lit::Regex(_))
+  )
+}
+
+fn get_runtime_requirements(code: &str) -> Option<RuntimeGlobals> {
+  let require = RuntimeGlobals::REQUIRE.name();
+  let index = code.find(require)?;
+  if code[index + require.len()..].trim_start().starts_with('.') {
+    Some(RuntimeGlobals::REQUIRE_SCOPE)
+  } else {
+    Some(RuntimeGlobals::REQUIRE)
+  }
+}
+
+impl JavascriptParserPlugin for DefineParserPlugin {
+  fn evaluate_identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    let compiler_options = parser.compiler_options;
+    let code = compiler_options.builtins.define.get(ident)?;
+    let expr = self.parse(code)?;
+    self.evaluate(parser, ident, *expr, start, end)
+  }
+
+  fn evaluate_typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    if !parser.is_unresolved_ident(ident.sym.as_str()) {
+      return None;
+    }
+    self.evaluate_typeof_code(parser, ident.sym.as_str(), start, end)
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    let evaluated =
+      self.evaluate_typeof_code(parser, for_name, expr.span.real_lo(), expr.span.hi().0)?;
+    if !evaluated.is_string() {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        serde_json::to_string(evaluated.string())
+          .expect("should serialize")
+          .into(),
+        None,
+      )));
+    Some(true)
+  }
+
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    self
+      .replace(parser, for_name, ident.span.real_lo(), ident.span.real_hi())
+      .then_some(true)
+  }
+
+  fn member(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    self
+      .replace(parser, for_name, expr.span.real_lo(), expr.span.real_hi())
+      .then_some(true)
+  }
+
+  fn assign(&self, parser: &mut JavascriptParser, expr: &AssignExpr) -> Option<bool> {
+    // the key is assigned to, keep it as is instead of replacing it into an invalid assignment target
+    let ident = expr.left.as_ident()?;
+    if !parser.is_unresolved_ident(ident.sym.as_str())
+      || !parser
+        .compiler_options
+        .builtins
+        .define
+        .contains_key(ident.sym.as_str())
+    {
+      return None;
+    }
+    parser.walk_expression(&expr.right);
+    Some(true)
+  }
+}
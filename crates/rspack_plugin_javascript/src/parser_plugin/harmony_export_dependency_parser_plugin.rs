@@ -1,5 +1,7 @@
 use rspack_core::tree_shaking::symbol::DEFAULT_JS_WORD;
-use rspack_core::{ConstDependency, DependencyLocation, DependencyType, SpanExt, DEFAULT_EXPORT};
+use rspack_core::{
+  ConstDependency, DependencyLocation, DependencyType, ExportPresenceMode, SpanExt, DEFAULT_EXPORT,
+};
 use swc_core::atoms::Atom;
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{
@@ -40,6 +42,10 @@ fn handle_esm_export_harmony_import_side_effects_dep(
             Some(n.clone()),
             false,
             None,
+            ExportPresenceMode::from_options(
+              parser.javascript_options.reexport_exports_presence,
+              parser.javascript_options,
+            ),
           )));
         parser.build_info.harmony_named_exports.insert(n.clone());
       }
@@ -60,6 +66,10 @@ fn handle_esm_export_harmony_import_side_effects_dep(
             Some(name.clone()),
             false,
             None,
+            ExportPresenceMode::from_options(
+              parser.javascript_options.reexport_exports_presence,
+              parser.javascript_options,
+            ),
           )));
         parser.build_info.harmony_named_exports.insert(name);
       }
@@ -162,6 +172,10 @@ impl JavascriptParserPlugin for HarmonyExportDependencyParserPlugin {
       None,
       true,
       list,
+      ExportPresenceMode::from_options(
+        parser.javascript_options.reexport_exports_presence,
+        parser.javascript_options,
+      ),
     );
 
     parser
@@ -399,6 +413,10 @@ impl JavascriptParserPlugin for HarmonyExportDependencyParserPlugin {
                     Some(export.clone()),
                     false,
                     None,
+                    ExportPresenceMode::from_options(
+                      parser.javascript_options.reexport_exports_presence,
+                      parser.javascript_options,
+                    ),
                   )));
               } else {
                 parser
@@ -1,5 +1,7 @@
 use rspack_core::tree_shaking::symbol::DEFAULT_JS_WORD;
-use rspack_core::{extract_member_expression_chain, ConstDependency, DependencyType, SpanExt};
+use rspack_core::{
+  extract_member_expression_chain, ConstDependency, DependencyType, ExportPresenceMode, SpanExt,
+};
 use swc_core::atoms::Atom;
 use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{
@@ -175,6 +177,10 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
             reference.specifier.clone(),
             None,
             ident.span,
+            ExportPresenceMode::from_options(
+              parser.javascript_options.import_exports_presence,
+              parser.javascript_options,
+            ),
           )));
       }
       Some(true)
@@ -196,6 +202,10 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
           reference.specifier.clone(),
           parser.properties_in_destructuring.remove(&ident.sym),
           ident.span,
+          ExportPresenceMode::from_options(
+            parser.javascript_options.import_exports_presence,
+            parser.javascript_options,
+          ),
         )));
       Some(true)
     } else {
@@ -251,6 +261,10 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
             reference.specifier.clone(),
             None,
             callee.span(),
+            ExportPresenceMode::from_options(
+              parser.javascript_options.import_exports_presence,
+              parser.javascript_options,
+            ),
           )));
         parser.walk_expr_or_spread(&expr.args);
         return Some(true);
@@ -298,6 +312,10 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
             reference.specifier.clone(),
             None,
             member_expr.span,
+            ExportPresenceMode::from_options(
+              parser.javascript_options.import_exports_presence,
+              parser.javascript_options,
+            ),
           )));
         return Some(true);
       }
@@ -374,6 +392,10 @@ impl JavascriptParserPlugin for HarmonyImportDependencyParserPlugin {
           reference.specifier.clone(),
           None,
           opt_chain_expr.span,
+          ExportPresenceMode::from_options(
+            parser.javascript_options.import_exports_presence,
+            parser.javascript_options,
+          ),
         )));
       return Some(true);
     }
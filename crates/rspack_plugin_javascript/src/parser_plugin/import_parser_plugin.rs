@@ -1,8 +1,8 @@
-use rspack_core::ChunkGroupOptions;
 use rspack_core::{
   context_reg_exp, AsyncDependenciesBlock, DependencyLocation, DynamicImportMode, ErrorSpan,
   GroupOptions,
 };
+use rspack_core::{ChunkGroupOptions, ContextDependency};
 use rspack_core::{ContextNameSpaceObject, ContextOptions, DependencyCategory, SpanExt};
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::{CallExpr, Callee};
@@ -110,38 +110,39 @@ impl JavascriptParserPlugin for ImportParserPlugin {
         query,
         fragment,
         replaces,
+        critical,
       } = create_context_dependency(&param, parser);
-      parser
-        .dependencies
-        .push(Box::new(ImportContextDependency::new(
-          import_call.span.real_lo(),
-          import_call.span.real_hi(),
-          node.span.real_hi(),
-          ContextOptions {
-            mode: mode.into(),
-            recursive: true,
-            reg_exp: context_reg_exp(&reg, ""),
-            include: None,
-            exclude: None,
-            category: DependencyCategory::Esm,
-            request: format!("{}{}{}", context.clone(), query, fragment),
-            context,
-            namespace_object: if parser.build_meta.strict_harmony_module {
-              ContextNameSpaceObject::Strict
-            } else {
-              ContextNameSpaceObject::Bool(true)
-            },
-            group_options: Some(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
-              chunk_name,
-              chunk_preload,
-              chunk_prefetch,
-            ))),
-            replaces,
-            start: node.span().real_lo(),
-            end: node.span().real_hi(),
+      let mut dependency = ImportContextDependency::new(
+        import_call.span.real_lo(),
+        import_call.span.real_hi(),
+        node.span.real_hi(),
+        ContextOptions {
+          mode: mode.into(),
+          recursive: true,
+          reg_exp: context_reg_exp(&reg, ""),
+          include: None,
+          exclude: None,
+          category: DependencyCategory::Esm,
+          request: format!("{}{}{}", context.clone(), query, fragment),
+          context,
+          namespace_object: if parser.build_meta.strict_harmony_module {
+            ContextNameSpaceObject::Strict
+          } else {
+            ContextNameSpaceObject::Bool(true)
           },
-          Some(node.span.into()),
-        )));
+          group_options: Some(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
+            chunk_name,
+            chunk_preload,
+            chunk_prefetch,
+          ))),
+          replaces,
+          start: node.span().real_lo(),
+          end: node.span().real_hi(),
+        },
+        Some(node.span.into()),
+      );
+      *dependency.critical_mut() = critical;
+      parser.dependencies.push(Box::new(dependency));
       // FIXME: align `parser.walk_expression` to webpack, which put into `context_dependency_helper`
       parser.walk_expression(&dyn_imported.expr);
       Some(true)
@@ -1,3 +1,4 @@
+mod amd;
 mod api_plugin;
 mod check_var_decl;
 mod common_js_exports_parse_plugin;
@@ -5,6 +6,7 @@ mod common_js_imports_parse_plugin;
 mod common_js_plugin;
 mod compatibility_plugin;
 mod r#const;
+mod define_plugin;
 mod drive;
 mod exports_info_api_plugin;
 mod harmony_detection_parser_plugin;
@@ -19,6 +21,8 @@ mod initialize_evaluating;
 mod node_stuff_plugin;
 mod provide;
 mod require_context_dependency_parser_plugin;
+mod require_ensure_dependencies_block_parser_plugin;
+mod require_include_dependency_parser_plugin;
 mod r#trait;
 mod url_plugin;
 mod webpack_included_plugin;
@@ -26,12 +30,16 @@ mod worker_plugin;
 /// TODO: should move to rspack_plugin_javascript once we drop old treeshaking
 mod worker_syntax_plugin;
 
+pub(crate) use self::amd::{
+  AMDDefineDependencyParserPlugin, AMDPlugin, AMDRequireDependenciesBlockParserPlugin,
+};
 pub(crate) use self::api_plugin::APIPlugin;
 pub(crate) use self::check_var_decl::CheckVarDeclaratorIdent;
 pub(crate) use self::common_js_exports_parse_plugin::CommonJsExportsParserPlugin;
 pub(crate) use self::common_js_imports_parse_plugin::CommonJsImportsParserPlugin;
 pub(crate) use self::common_js_plugin::CommonJsPlugin;
 pub(crate) use self::compatibility_plugin::CompatibilityPlugin;
+pub(crate) use self::define_plugin::DefineParserPlugin;
 pub(crate) use self::drive::JavaScriptParserPluginDrive;
 pub(crate) use self::exports_info_api_plugin::ExportsInfoApiPlugin;
 pub(crate) use self::harmony_detection_parser_plugin::HarmonyDetectionParserPlugin;
@@ -48,6 +56,8 @@ pub(crate) use self::provide::ProviderPlugin;
 pub(crate) use self::r#const::{is_logic_op, ConstPlugin};
 pub(crate) use self::r#trait::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
 pub(crate) use self::require_context_dependency_parser_plugin::RequireContextDependencyParserPlugin;
+pub(crate) use self::require_ensure_dependencies_block_parser_plugin::RequireEnsureDependenciesBlockParserPlugin;
+pub(crate) use self::require_include_dependency_parser_plugin::RequireIncludeDependencyParserPlugin;
 pub(crate) use self::url_plugin::URLPlugin;
 pub(crate) use self::webpack_included_plugin::WebpackIsIncludedPlugin;
 pub(crate) use self::worker_plugin::WorkerPlugin;
@@ -0,0 +1,139 @@
+use rspack_core::{
+  AsyncDependenciesBlock, BoxDependency, ChunkGroupOptions, ConstDependency, DependencyLocation,
+  ErrorSpan, GroupOptions, SpanExt,
+};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, UnaryExpr};
+
+use super::JavascriptParserPlugin;
+use crate::dependency::{RequireEnsureDependency, RequireEnsureItemDependency};
+use crate::visitors::{expr_name, FunctionExpression, JavascriptParser};
+
+pub struct RequireEnsureDependenciesBlockParserPlugin;
+
+impl RequireEnsureDependenciesBlockParserPlugin {
+  /// Walks a callback of `require.ensure`, its `require` parameter refers to
+  /// the free `require` so that requires inside the callback are detected.
+  fn walk_callback(parser: &mut JavascriptParser, expr: &Expr) {
+    if let Some(function) = FunctionExpression::from_expr(expr) {
+      let params = function
+        .params
+        .iter()
+        .filter(|param| FunctionExpression::param_name(param) != Some(expr_name::REQUIRE))
+        .copied()
+        .collect();
+      function.walk(parser, params, vec![]);
+    } else {
+      parser.walk_expression(expr);
+    }
+  }
+}
+
+impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::REQUIRE_ENSURE
+      || !(2..=4).contains(&expr.args.len())
+      || expr.args.iter().any(|arg| arg.spread.is_some())
+    {
+      return None;
+    }
+
+    // require.ensure(dependencies, callback, errorCallback?, chunkName?)
+    // require.ensure(dependencies, callback, chunkName?)
+    let mut chunk_name = None;
+    if let Some(arg) = expr.args.get(3) {
+      let param = parser.evaluate_expression(&arg.expr);
+      if !param.is_string() {
+        return None;
+      }
+      chunk_name = Some(param.string().to_string());
+    }
+    let mut error_callback = expr.args.get(2).map(|arg| &*arg.expr);
+    if let Some(arg) = error_callback
+      && chunk_name.is_none()
+      && FunctionExpression::from_expr(arg).is_none()
+    {
+      let param = parser.evaluate_expression(arg);
+      if !param.is_string() {
+        return None;
+      }
+      chunk_name = Some(param.string().to_string());
+      error_callback = None;
+    }
+
+    let dependencies_expr = &expr.args[0].expr;
+    let param = parser.evaluate_expression(dependencies_expr);
+    let items = if param.is_array() {
+      param.items().iter().collect::<Vec<_>>()
+    } else {
+      vec![&param]
+    };
+    if items.iter().any(|item| !item.is_string()) {
+      return None;
+    }
+    let mut dependencies: Vec<BoxDependency> = items
+      .into_iter()
+      .map(|item| {
+        let (start, end) = item.range();
+        Box::new(RequireEnsureItemDependency::new(
+          item.string().as_str().into(),
+          Some(ErrorSpan::new(start, end - 1)),
+        )) as BoxDependency
+      })
+      .collect();
+
+    let callback = &*expr.args[1].expr;
+    let range = |expr: &Expr| (expr.span().real_lo(), expr.span().real_hi());
+    let dep = RequireEnsureDependency::new(
+      (expr.span.real_lo(), expr.span.real_hi()),
+      range(callback),
+      error_callback.map(range),
+    );
+
+    // dependencies of the callback are loaded together with the ensured ones
+    let start = parser.dependencies.len();
+    Self::walk_callback(parser, callback);
+    dependencies.extend(parser.dependencies.drain(start..));
+    dependencies.push(Box::new(dep));
+
+    let mut block = AsyncDependenciesBlock::new(
+      *parser.module_identifier,
+      Some(DependencyLocation::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+      )),
+      None,
+      dependencies,
+    );
+    block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
+      chunk_name, None, None,
+    )));
+    parser.blocks.push(block);
+
+    if let Some(error_callback) = error_callback {
+      Self::walk_callback(parser, error_callback);
+    }
+    Some(true)
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name == expr_name::REQUIRE_ENSURE {
+      parser
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span.real_lo(),
+          expr.span.real_hi(),
+          "'function'".into(),
+          None,
+        )));
+      Some(true)
+    } else {
+      None
+    }
+  }
+}
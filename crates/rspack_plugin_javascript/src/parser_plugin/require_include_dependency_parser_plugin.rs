@@ -0,0 +1,62 @@
+use rspack_core::{ConstDependency, SpanExt};
+use rspack_error::miette::Severity;
+use swc_core::ecma::ast::{CallExpr, UnaryExpr};
+
+use super::JavascriptParserPlugin;
+use crate::dependency::RequireIncludeDependency;
+use crate::visitors::{create_traceable_error, expr_name, JavascriptParser};
+
+pub struct RequireIncludeDependencyParserPlugin;
+
+impl JavascriptParserPlugin for RequireIncludeDependencyParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::REQUIRE_INCLUDE
+      || expr.args.len() != 1
+      || expr.args[0].spread.is_some()
+    {
+      return None;
+    }
+    let param = parser.evaluate_expression(&expr.args[0].expr);
+    if !param.is_string() {
+      return None;
+    }
+    parser.warning_diagnostics.push(Box::new(
+      create_traceable_error(
+        "Deprecated feature".into(),
+        "require.include() is deprecated and will be removed soon.".into(),
+        parser.source_file,
+        expr.span.into(),
+      )
+      .with_severity(Severity::Warning),
+    ));
+    parser
+      .dependencies
+      .push(Box::new(RequireIncludeDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        param.string().as_str().into(),
+      )));
+    Some(true)
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name == expr_name::REQUIRE_INCLUDE {
+      parser
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span.real_lo(),
+          expr.span.real_hi(),
+          "'function'".into(),
+          None,
+        )));
+      Some(true)
+    } else {
+      None
+    }
+  }
+}
@@ -95,12 +95,14 @@ impl JavascriptParser<'_> {
       name,
       runtime: Some(runtime),
       chunk_loading: Some(output_options.worker_chunk_loading.clone()),
+      wasm_loading: Some(output_options.worker_wasm_loading.clone()),
       async_chunks: None,
       public_path: None,
       base_uri: None,
       filename: None,
       library: None,
       depend_on: None,
+      layer: None,
     })));
 
     self.blocks.push(block);
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilationParams, CompilerOptions, CompilerThisCompilation, Plugin,
+  PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+#[derive(Debug)]
+pub struct EnvironmentPluginOptions {
+  pub keys: Vec<String>,
+  /// Key -> code used when the environment variable is not set
+  pub defaults: HashMap<String, String>,
+}
+
+/// Defines `process.env.<key>` with the value of the environment variable of each key.
+#[plugin]
+#[derive(Debug)]
+pub struct EnvironmentPlugin {
+  definitions: Vec<(String, String)>,
+  undefined_keys: Vec<String>,
+}
+
+impl EnvironmentPlugin {
+  pub fn new(options: EnvironmentPluginOptions) -> Self {
+    let mut definitions = Vec::with_capacity(options.keys.len());
+    let mut undefined_keys = vec![];
+    for key in options.keys {
+      let code = match std::env::var(&key) {
+        Ok(value) => Some(serde_json::to_string(&value).expect("should serialize")),
+        Err(_) => options.defaults.get(&key).cloned(),
+      };
+      let code = code.unwrap_or_else(|| {
+        undefined_keys.push(key.clone());
+        "undefined".to_string()
+      });
+      definitions.push((format!("process.env.{key}"), code));
+    }
+    Self::new_inner(definitions, undefined_keys)
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for EnvironmentPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  for key in &self.undefined_keys {
+    compilation.push_diagnostic(Diagnostic::error(
+      "EnvVariableNotDefinedError".to_string(),
+      format!(
+        "EnvironmentPlugin - {key} environment variable is undefined.\n\n\
+        You can pass an object with default values to suppress this warning.\n\
+        See https://webpack.js.org/plugins/environment-plugin for example."
+      ),
+    ));
+  }
+  Ok(())
+}
+
+impl Plugin for EnvironmentPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.EnvironmentPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    options
+      .builtins
+      .define
+      .extend(self.definitions.iter().cloned());
+    if !self.undefined_keys.is_empty() {
+      ctx
+        .context
+        .compiler_hooks
+        .this_compilation
+        .tap(this_compilation::new(self));
+    }
+    Ok(())
+  }
+}
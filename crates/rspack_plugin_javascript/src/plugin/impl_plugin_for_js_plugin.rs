@@ -67,6 +67,21 @@ async fn compilation(
     DependencyType::RequireResolve,
     params.normal_module_factory.clone(),
   );
+  // AMDPlugin
+  compilation.set_dependency_factory(
+    DependencyType::AmdRequireItem,
+    params.normal_module_factory.clone(),
+  );
+  // RequireEnsurePlugin
+  compilation.set_dependency_factory(
+    DependencyType::RequireEnsureItem,
+    params.normal_module_factory.clone(),
+  );
+  // RequireIncludePlugin
+  compilation.set_dependency_factory(
+    DependencyType::RequireInclude,
+    params.normal_module_factory.clone(),
+  );
   // RequireContextPlugin
   compilation.set_dependency_factory(
     DependencyType::RequireContext,
@@ -1,5 +1,6 @@
 pub mod api_plugin;
 mod drive;
+mod environment_plugin;
 mod flag_dependency_exports_plugin;
 mod flag_dependency_usage_plugin;
 pub mod impl_plugin_for_js_plugin;
@@ -12,6 +13,7 @@ mod side_effects_flag_plugin;
 use std::hash::Hash;
 
 pub use drive::*;
+pub use environment_plugin::*;
 pub use flag_dependency_exports_plugin::*;
 pub use flag_dependency_usage_plugin::*;
 pub use mangle_exports_plugin::*;
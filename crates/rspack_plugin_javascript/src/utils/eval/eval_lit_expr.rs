@@ -31,6 +31,12 @@ pub fn eval_lit_expr(expr: &Lit) -> Option<BasicEvaluatedExpression> {
       Some(res)
     }
     Lit::Num(num) => Some(eval_number(num)),
+    Lit::Bool(boolean) => {
+      let mut res =
+        BasicEvaluatedExpression::with_range(boolean.span.real_lo(), boolean.span.hi().0);
+      res.set_bool(boolean.value);
+      Some(res)
+    }
     // TODO:
     _ => None,
   }
@@ -25,20 +25,29 @@ fn eval_typeof(
 
   // TODO: if let `MetaProperty`, `MemberExpression` ...
   let arg = parser.evaluate_expression(&expr.arg);
-  if arg.is_unknown() {
+  let res = if arg.is_unknown() {
     None
   } else if arg.is_string() {
-    let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi.0);
-    res.set_string("string".to_string());
-    Some(res)
+    Some("string")
   } else if arg.is_undefined() {
-    let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi.0);
-    res.set_string("undefined".to_string());
-    Some(res)
+    Some("undefined")
+  } else if arg.is_number() {
+    Some("number")
+  } else if arg.is_bigint() {
+    Some("bigint")
+  } else if arg.is_bool() {
+    Some("boolean")
+  } else if arg.is_regexp() || arg.is_null() || arg.is_array() {
+    Some("object")
   } else {
     // TODO: `arg.is_wrapped()`...
     None
-  }
+  };
+  res.map(|res| {
+    let mut eval = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi.0);
+    eval.set_string(res.to_string());
+    eval
+  })
 }
 
 pub fn eval_unary_expression(
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use itertools::Itertools;
 use rspack_core::parse_resource;
-use rspack_error::Severity;
+use rspack_error::{miette, Diagnostic, Severity};
 use rspack_util::json_stringify;
 
 use super::context_helper::{quote_meta, split_context_from_prefix};
@@ -79,18 +79,15 @@ pub fn create_context_dependency(
       }
     }
 
-    if parser.javascript_options.wrapped_context_critical {
+    let critical = parser.javascript_options.wrapped_context_critical.then(|| {
       let range = param.range();
-      parser.warning_diagnostics.push(Box::new(
-        create_traceable_error(
-          "Critical dependency".into(),
-          "a part of the request of a dependency is an expression".to_string(),
-          parser.source_file,
-          rspack_core::ErrorSpan::new(range.0, range.1),
-        )
-        .with_severity(Severity::Warn),
-      ));
-    }
+      let span = rspack_core::ErrorSpan::new(range.0, range.1);
+      create_critical_warning(
+        parser,
+        "a part of the request of a dependency is an expression",
+        span,
+      )
+    });
 
     ContextModuleScanResult {
       context,
@@ -98,6 +95,7 @@ pub fn create_context_dependency(
       query,
       fragment,
       replaces,
+      critical,
     }
   } else if param.is_wrapped()
     && let prefix_is_string = param
@@ -147,18 +145,15 @@ pub fn create_context_dependency(
       replaces.push((json_stringify(&postfix), postfix_range.0, postfix_range.1))
     }
 
-    if parser.javascript_options.wrapped_context_critical {
+    let critical = parser.javascript_options.wrapped_context_critical.then(|| {
       let range = param.range();
-      parser.warning_diagnostics.push(Box::new(
-        create_traceable_error(
-          "Critical dependency".into(),
-          "a part of the request of a dependency is an expression".to_string(),
-          parser.source_file,
-          rspack_core::ErrorSpan::new(range.0, range.1),
-        )
-        .with_severity(Severity::Warn),
-      ));
-    }
+      let span = rspack_core::ErrorSpan::new(range.0, range.1);
+      create_critical_warning(
+        parser,
+        "a part of the request of a dependency is an expression",
+        span,
+      )
+    });
 
     ContextModuleScanResult {
       context,
@@ -166,27 +161,38 @@ pub fn create_context_dependency(
       query,
       fragment,
       replaces,
+      critical,
     }
     // TODO: handle `param.wrappedInnerExpressions`
   } else {
-    if parser.javascript_options.expr_context_critical {
+    let critical = parser.javascript_options.expr_context_critical.then(|| {
       let range = param.range();
-      parser.warning_diagnostics.push(Box::new(
-        create_traceable_error(
-          "Critical dependency".into(),
-          "the request of a dependency is an expression".to_string(),
-          parser.source_file,
-          rspack_core::ErrorSpan::new(range.0, range.1),
-        )
-        .with_severity(Severity::Warn),
-      ));
-    }
+      let span = rspack_core::ErrorSpan::new(range.0, range.1);
+      create_critical_warning(parser, "the request of a dependency is an expression", span)
+    });
     ContextModuleScanResult {
       context: String::from("."),
       reg: String::new(),
       query: String::new(),
       fragment: String::new(),
       replaces: Vec::new(),
+      critical,
     }
   }
 }
+
+/// The warning of a context dependency, see [rspack_core::ContextDependency::critical]
+pub fn create_critical_warning(
+  parser: &crate::visitors::JavascriptParser,
+  message: &str,
+  span: rspack_core::ErrorSpan,
+) -> Diagnostic {
+  let error = create_traceable_error(
+    "Critical dependency".into(),
+    message.to_string(),
+    parser.source_file,
+    span,
+  )
+  .with_severity(Severity::Warn);
+  Diagnostic::from(Box::new(error) as Box<dyn miette::Diagnostic + Send + Sync>)
+}
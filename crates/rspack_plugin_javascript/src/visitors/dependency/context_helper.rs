@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{parse_resource, SpanExt};
+use rspack_error::Diagnostic;
 use rspack_util::json_stringify;
 use swc_core::ecma::ast::{
   BinExpr, BinaryOp, CallExpr, Callee, Expr, Lit, MemberProp, TaggedTpl, Tpl,
@@ -16,6 +17,8 @@ pub struct ContextModuleScanResult {
   pub query: String,
   pub fragment: String,
   pub replaces: Vec<(String, u32, u32)>,
+  /// Warning for a request which is (partly) an expression
+  pub critical: Option<Diagnostic>,
 }
 
 pub(super) fn split_context_from_prefix(prefix: String) -> (String, String) {
@@ -102,6 +105,7 @@ fn scan_context_module_tpl(tpl: &Tpl, kind: TemplateStringKind) -> ContextModule
     query,
     fragment,
     replaces: Vec::new(),
+    critical: None,
   }
 }
 
@@ -158,6 +162,7 @@ fn scan_context_module_bin(bin: &BinExpr) -> Option<ContextModuleScanResult> {
     query,
     fragment,
     replaces,
+    critical: None,
   })
 }
 
@@ -231,6 +236,7 @@ fn scan_context_module_concat_call(expr: &CallExpr) -> Option<ContextModuleScanR
     query,
     fragment,
     replaces: Vec::new(),
+    critical: None,
   })
 }
 
@@ -257,6 +263,7 @@ fn scan_context_module_tagged_tpl(tpl: &TaggedTpl) -> ContextModuleScanResult {
       query: String::new(),
       fragment: String::new(),
       replaces: Vec::new(),
+      critical: None,
     },
   }
 }
@@ -0,0 +1,85 @@
+use std::borrow::Cow;
+
+use swc_core::ecma::ast::{BlockStmt, BlockStmtOrExpr, Expr, ExprOrSpread, Pat};
+
+use super::JavascriptParser;
+
+pub enum FunctionBody<'a> {
+  Block(&'a BlockStmt),
+  Expr(&'a Expr),
+}
+
+/// A `function () {}` or arrow function passed as a callback, e.g. to
+/// `define` or `require.ensure`, optionally written as `function () {}.bind(...)`.
+pub struct FunctionExpression<'a> {
+  pub params: Vec<&'a Pat>,
+  pub body: Option<FunctionBody<'a>>,
+  pub bind_args: Option<&'a [ExprOrSpread]>,
+}
+
+impl<'a> FunctionExpression<'a> {
+  pub fn from_expr(expr: &'a Expr) -> Option<Self> {
+    match expr {
+      Expr::Fn(f) => Some(Self {
+        params: f.function.params.iter().map(|param| &param.pat).collect(),
+        body: f.function.body.as_ref().map(FunctionBody::Block),
+        bind_args: None,
+      }),
+      Expr::Arrow(f) => Some(Self {
+        params: f.params.iter().collect(),
+        body: Some(match &*f.body {
+          BlockStmtOrExpr::BlockStmt(block) => FunctionBody::Block(block),
+          BlockStmtOrExpr::Expr(expr) => FunctionBody::Expr(expr),
+        }),
+        bind_args: None,
+      }),
+      Expr::Call(call)
+        if let Some(callee) = call.callee.as_expr()
+          && let Some(member) = callee.as_member()
+          && member.obj.is_fn_expr()
+          && member
+            .prop
+            .as_ident()
+            .is_some_and(|prop| &prop.sym == "bind") =>
+      {
+        let mut function = Self::from_expr(&member.obj)?;
+        function.bind_args = Some(&call.args);
+        Some(function)
+      }
+      _ => None,
+    }
+  }
+
+  pub fn param_name(param: &Pat) -> Option<&str> {
+    param.as_ident().map(|ident| ident.id.sym.as_str())
+  }
+
+  /// Walks the function in a new scope, `renames` maps parameter names to the
+  /// free variables they stand for instead of defining them.
+  pub fn walk(
+    &self,
+    parser: &mut JavascriptParser,
+    params: Vec<&Pat>,
+    renames: Vec<(String, &'static str)>,
+  ) {
+    let in_try = parser.in_try;
+    parser.in_function_scope(true, params.into_iter().map(Cow::Borrowed), |parser| {
+      for (name, variable) in renames {
+        parser.set_variable(name, variable.to_string());
+      }
+      parser.in_try = in_try;
+      match self.body {
+        Some(FunctionBody::Block(block)) => {
+          parser.detect_mode(&block.stmts);
+          parser.pre_walk_block_statement(block);
+          parser.walk_block_statement(block);
+        }
+        Some(FunctionBody::Expr(expr)) => parser.walk_expression(expr),
+        None => {}
+      }
+    });
+    if let Some(args) = self.bind_args {
+      parser.walk_expressions(args.iter().map(|arg| &*arg.expr));
+    }
+  }
+}
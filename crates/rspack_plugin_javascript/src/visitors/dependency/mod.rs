@@ -1,5 +1,6 @@
 mod context_dependency_helper;
 mod context_helper;
+mod function_expression;
 mod parser;
 mod util;
 
@@ -15,8 +16,9 @@ use swc_core::common::comments::Comments;
 use swc_core::common::{SourceFile, Span};
 use swc_core::ecma::atoms::Atom;
 
-pub use self::context_dependency_helper::create_context_dependency;
+pub use self::context_dependency_helper::{create_context_dependency, create_critical_warning};
 pub use self::context_helper::{scanner_context_module, ContextModuleScanResult};
+pub use self::function_expression::{FunctionBody, FunctionExpression};
 pub use self::parser::{CallExpressionInfo, CallHooksName, ExportedVariableInfo};
 pub use self::parser::{JavascriptParser, MemberExpressionInfo, TagInfoData, TopLevelScope};
 pub use self::util::*;
@@ -222,6 +222,9 @@ impl<'parser> JavascriptParser<'parser> {
 
     let mut plugins: Vec<parser_plugin::BoxJavascriptParserPlugin> = Vec::with_capacity(32);
     plugins.push(Box::new(parser_plugin::InitializeEvaluating));
+    if !compiler_options.builtins.define.is_empty() {
+      plugins.push(Box::<parser_plugin::DefineParserPlugin>::default());
+    }
     plugins.push(Box::new(parser_plugin::CheckVarDeclaratorIdent));
     plugins.push(Box::new(parser_plugin::ConstPlugin));
     plugins.push(Box::new(
@@ -234,6 +237,19 @@ impl<'parser> JavascriptParser<'parser> {
     plugins.push(Box::new(parser_plugin::CompatibilityPlugin));
 
     if module_type.is_js_auto() || module_type.is_js_dynamic() {
+      if compiler_options.amd.is_some() {
+        plugins.push(Box::new(
+          parser_plugin::AMDRequireDependenciesBlockParserPlugin,
+        ));
+        plugins.push(Box::new(parser_plugin::AMDDefineDependencyParserPlugin));
+        plugins.push(Box::new(parser_plugin::AMDPlugin));
+      }
+      plugins.push(Box::new(
+        parser_plugin::RequireEnsureDependenciesBlockParserPlugin,
+      ));
+      plugins.push(Box::new(
+        parser_plugin::RequireIncludeDependencyParserPlugin,
+      ));
       plugins.push(Box::new(parser_plugin::CommonJsImportsParserPlugin));
       plugins.push(Box::new(parser_plugin::CommonJsPlugin));
       plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin));
@@ -373,7 +389,7 @@ impl<'parser> JavascriptParser<'parser> {
     self.definitions_db.set(definitions, name, info);
   }
 
-  fn set_variable(&mut self, name: String, variable: String) {
+  pub(crate) fn set_variable(&mut self, name: String, variable: String) {
     let id = self.definitions;
     if name == variable {
       self.definitions_db.delete(id, &name);
@@ -650,7 +666,7 @@ impl<'parser> JavascriptParser<'parser> {
     current_scope.is_strict = value;
   }
 
-  fn detect_mode(&mut self, stmts: &[Stmt]) {
+  pub(crate) fn detect_mode(&mut self, stmts: &[Stmt]) {
     let Some(Lit::Str(str)) = stmts
       .first()
       .and_then(|stmt| stmt.as_expr())
@@ -74,7 +74,7 @@ impl<'parser> JavascriptParser<'parser> {
     self.in_tagged_template_tag = old_in_tagged_template_tag;
   }
 
-  fn in_function_scope<'a, I, F>(&mut self, has_this: bool, params: I, f: F)
+  pub(crate) fn in_function_scope<'a, I, F>(&mut self, has_this: bool, params: I, f: F)
   where
     F: FnOnce(&mut Self),
     I: Iterator<Item = Cow<'a, Pat>>,
@@ -976,7 +976,7 @@ impl<'parser> JavascriptParser<'parser> {
     self.top_level_scope = was_top_level_scope;
   }
 
-  fn walk_expressions<'a, I>(&mut self, expressions: I)
+  pub(crate) fn walk_expressions<'a, I>(&mut self, expressions: I)
   where
     I: Iterator<Item = &'a Expr>,
   {
@@ -1003,7 +1003,7 @@ impl<'parser> JavascriptParser<'parser> {
     self.walk_expression(&stmt.test);
   }
 
-  fn walk_block_statement(&mut self, stmt: &BlockStmt) {
+  pub(crate) fn walk_block_statement(&mut self, stmt: &BlockStmt) {
     self.in_block_scope(|this| {
       this.block_pre_walk_statements(&stmt.stmts);
       this.walk_statements(&stmt.stmts);
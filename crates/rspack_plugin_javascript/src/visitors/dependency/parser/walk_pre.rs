@@ -153,7 +153,7 @@ impl<'parser> JavascriptParser<'parser> {
     self.pre_walk_statement(&stmt.body)
   }
 
-  pub(super) fn pre_walk_block_statement(&mut self, stmt: &BlockStmt) {
+  pub(crate) fn pre_walk_block_statement(&mut self, stmt: &BlockStmt) {
     self.pre_walk_statements(&stmt.stmts);
   }
 
@@ -195,11 +195,9 @@ pub(crate) mod expr_matcher {
     is_object_define_property: "Object.defineProperty",
     // unsupported
     is_require_extensions: "require.extensions",
-    is_require_ensure: "require.ensure",
     is_require_config: "require.config",
     is_require_version: "require.version",
     is_require_amd: "require.amd",
-    is_require_include: "require.include",
     is_require_onerror: "require.onError",
     is_require_main_require: "require.main.require",
     is_module_parent_require: "module.parent.require",
@@ -214,6 +212,8 @@ pub mod expr_name {
   pub const REQUIRE: &str = "require";
   pub const REQUIRE_RESOLVE: &str = "require.resolve";
   pub const REQUIRE_RESOLVE_WEAK: &str = "require.resolveWeak";
+  pub const REQUIRE_ENSURE: &str = "require.ensure";
+  pub const REQUIRE_INCLUDE: &str = "require.include";
   pub const IMPORT_META: &str = "import.meta";
   pub const IMPORT_META_URL: &str = "import.meta.url";
   pub const IMPORT_META_WEBPACK_HOT: &str = "import.meta.webpackHot";
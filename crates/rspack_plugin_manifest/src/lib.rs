@@ -0,0 +1,287 @@
+#![feature(let_chains)]
+
+use std::{collections::BTreeMap, path::Path};
+
+use derivative::Derivative;
+use futures::future::BoxFuture;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, Chunk, Compilation, CompilationAsset, CompilationProcessAssets,
+  CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashSet as HashSet;
+use serde::Serialize;
+
+pub type ManifestFilterFn =
+  Box<dyn for<'a> Fn(&'a ManifestFile) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
+
+pub type ManifestSerializeFn =
+  Box<dyn for<'a> Fn(&'a Manifest) -> BoxFuture<'a, Result<String>> + Sync + Send>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct ManifestPluginOptions {
+  /// Filename of the emitted manifest, relative to the output path.
+  pub filename: String,
+  /// Prefix of the paths in the manifest, defaults to `output.publicPath`.
+  pub public_path: Option<String>,
+  /// Only files for which this returns `true` are listed in `files`.
+  #[derivative(Debug = "ignore")]
+  pub filter: Option<ManifestFilterFn>,
+  /// Turns the manifest into the content of the asset, defaults to pretty
+  /// printed JSON.
+  #[derivative(Debug = "ignore")]
+  pub serialize: Option<ManifestSerializeFn>,
+}
+
+/// A file emitted by the compilation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestFile {
+  /// Logical name of the file, e.g. `main.js` for the javascript file of the
+  /// `main` chunk, or the source filename for asset modules.
+  pub name: String,
+  /// Output filename prefixed with the public path.
+  pub path: String,
+  pub chunk_name: Option<String>,
+  /// Whether the file belongs to a chunk that can be loaded initially.
+  pub is_initial: bool,
+  /// Whether the file is one of the files of a chunk.
+  pub is_chunk: bool,
+  /// Whether the file is an auxiliary file of a chunk, e.g. a source map.
+  pub is_auxiliary: bool,
+}
+
+/// Files needed by an entrypoint, keyed by file extension.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ManifestEntrypoint {
+  /// Files of the chunks loaded when the entrypoint starts.
+  pub initial: BTreeMap<String, Vec<String>>,
+  /// Files of the chunks the entrypoint may load on demand.
+  pub r#async: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct Manifest {
+  /// Logical name -> paths, all the files sharing a name are listed, e.g. a chunk `async` and an
+  /// asset module `async.js`
+  pub files: BTreeMap<String, Vec<String>>,
+  pub entrypoints: BTreeMap<String, ManifestEntrypoint>,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ManifestPlugin {
+  options: ManifestPluginOptions,
+}
+
+impl ManifestPlugin {
+  pub fn new(options: ManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn get_public_path(&self, compilation: &Compilation) -> String {
+    match &self.options.public_path {
+      Some(public_path) => public_path.clone(),
+      None => compilation
+        .options
+        .output
+        .public_path
+        .render(compilation, &self.options.filename),
+    }
+  }
+}
+
+fn get_extension(filename: &str) -> String {
+  let filename = filename.split(['?', '#']).next().unwrap_or(filename);
+  Path::new(filename)
+    .extension()
+    .map(|ext| ext.to_string_lossy().to_string())
+    .unwrap_or_default()
+}
+
+fn get_chunk_name(chunk: &Chunk) -> Option<String> {
+  chunk.name.clone().or_else(|| chunk.id.clone())
+}
+
+fn is_hot_update(compilation: &Compilation, filename: &str) -> bool {
+  compilation
+    .assets()
+    .get(filename)
+    .map_or(false, |asset| asset.info.hot_module_replacement)
+}
+
+#[plugin_hook(CompilationProcessAssets for ManifestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let public_path = self.get_public_path(compilation);
+  let mut files = vec![];
+  let mut visited = HashSet::default();
+
+  let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+  chunks.sort_by_key(|chunk| get_chunk_name(chunk));
+  for chunk in chunks {
+    let chunk_name = get_chunk_name(chunk);
+    let is_initial = chunk.can_be_initial(&compilation.chunk_group_by_ukey);
+    let mut chunk_files = chunk.files.iter().collect::<Vec<_>>();
+    chunk_files.sort();
+    for file in chunk_files {
+      if is_hot_update(compilation, file) || !visited.insert(file.clone()) {
+        continue;
+      }
+      let name = match &chunk_name {
+        Some(chunk_name) => format!("{chunk_name}.{}", get_extension(file)),
+        None => file.clone(),
+      };
+      if let Some(source_map) = compilation
+        .assets()
+        .get(file)
+        .and_then(|asset| asset.info.related.source_map.as_ref())
+        && visited.insert(source_map.clone())
+      {
+        files.push(ManifestFile {
+          name: format!("{name}.map"),
+          path: format!("{public_path}{source_map}"),
+          chunk_name: chunk_name.clone(),
+          is_initial,
+          is_chunk: false,
+          is_auxiliary: true,
+        });
+      }
+      files.push(ManifestFile {
+        name,
+        path: format!("{public_path}{file}"),
+        chunk_name: chunk_name.clone(),
+        is_initial,
+        is_chunk: true,
+        is_auxiliary: false,
+      });
+    }
+    let mut auxiliary_files = chunk.auxiliary_files.iter().collect::<Vec<_>>();
+    auxiliary_files.sort();
+    for file in auxiliary_files {
+      if !visited.insert(file.clone()) {
+        continue;
+      }
+      let name = compilation
+        .assets()
+        .get(file)
+        .and_then(|asset| asset.info.source_filename.clone())
+        .unwrap_or_else(|| file.clone());
+      files.push(ManifestFile {
+        name,
+        path: format!("{public_path}{file}"),
+        chunk_name: chunk_name.clone(),
+        is_initial,
+        is_chunk: false,
+        is_auxiliary: true,
+      });
+    }
+  }
+
+  let mut assets = compilation.assets().iter().collect::<Vec<_>>();
+  assets.sort_by(|a, b| a.0.cmp(b.0));
+  for (filename, asset) in assets {
+    if asset.info.hot_module_replacement
+      || filename == &self.options.filename
+      || visited.contains(filename)
+    {
+      continue;
+    }
+    files.push(ManifestFile {
+      name: asset
+        .info
+        .source_filename
+        .clone()
+        .unwrap_or_else(|| filename.clone()),
+      path: format!("{public_path}{filename}"),
+      chunk_name: None,
+      is_initial: false,
+      is_chunk: false,
+      is_auxiliary: false,
+    });
+  }
+
+  let mut manifest = Manifest::default();
+  for file in files {
+    if let Some(filter) = &self.options.filter
+      && !filter(&file).await?
+    {
+      continue;
+    }
+    manifest.files.entry(file.name).or_default().push(file.path);
+  }
+
+  for (name, entrypoint_ukey) in compilation.entrypoints() {
+    let entrypoint = compilation.chunk_group_by_ukey.expect_get(entrypoint_ukey);
+    let mut manifest_entrypoint = ManifestEntrypoint::default();
+    let mut async_chunks = HashSet::default();
+    for chunk_ukey in &entrypoint.chunks {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      async_chunks.extend(chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey));
+    }
+    for file in entrypoint.get_files(&compilation.chunk_by_ukey) {
+      if is_hot_update(compilation, &file) {
+        continue;
+      }
+      manifest_entrypoint
+        .initial
+        .entry(get_extension(&file))
+        .or_default()
+        .push(format!("{public_path}{file}"));
+    }
+    let mut async_files = async_chunks
+      .into_iter()
+      .flat_map(|chunk_ukey| {
+        compilation
+          .chunk_by_ukey
+          .expect_get(&chunk_ukey)
+          .files
+          .iter()
+      })
+      .filter(|file| !is_hot_update(compilation, file))
+      .collect::<Vec<_>>();
+    async_files.sort();
+    async_files.dedup();
+    for file in async_files {
+      manifest_entrypoint
+        .r#async
+        .entry(get_extension(file))
+        .or_default()
+        .push(format!("{public_path}{file}"));
+    }
+    manifest
+      .entrypoints
+      .insert(name.clone(), manifest_entrypoint);
+  }
+
+  let content = match &self.options.serialize {
+    Some(serialize) => serialize(&manifest).await?,
+    None => serde_json::to_string_pretty(&manifest).into_diagnostic()?,
+  };
+  compilation.emit_asset(
+    self.options.filename.clone(),
+    CompilationAsset::new(Some(RawSource::from(content).boxed()), AssetInfo::default()),
+  );
+  Ok(())
+}
+
+impl Plugin for ManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ManifestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,145 @@
+use std::path::{Path, PathBuf};
+
+use derivative::Derivative;
+use futures::future::BoxFuture;
+use rspack_core::{
+  ApplyContext, CompilerOptions, ModuleFactoryCreateData, NormalModuleCreateData,
+  NormalModuleFactoryAfterResolve, NormalModuleFactoryBeforeResolve, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+
+/// The data passed to and returned from [NewResource::Fn]. `resource` is only
+/// present after the request is resolved.
+#[derive(Debug, Clone)]
+pub struct NormalModuleReplacementData {
+  pub request: String,
+  pub context: String,
+  pub resource: Option<String>,
+}
+
+pub type NewResourceFn = Box<
+  dyn Fn(NormalModuleReplacementData) -> BoxFuture<'static, Result<NormalModuleReplacementData>>
+    + Sync
+    + Send,
+>;
+
+pub enum NewResource {
+  String(String),
+  Fn(NewResourceFn),
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct NormalModuleReplacementPluginOptions {
+  pub resource_reg_exp: RspackRegex,
+  #[derivative(Debug = "ignore")]
+  pub new_resource: NewResource,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct NormalModuleReplacementPlugin {
+  options: NormalModuleReplacementPluginOptions,
+}
+
+impl NormalModuleReplacementPlugin {
+  pub fn new(options: NormalModuleReplacementPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryBeforeResolve for NormalModuleReplacementPlugin)]
+async fn nmf_before_resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<bool>> {
+  let context = data.context.to_string();
+  let Some(dependency) = data.dependency.as_module_dependency_mut() else {
+    return Ok(None);
+  };
+  if !self.options.resource_reg_exp.test(dependency.request()) {
+    return Ok(None);
+  }
+  match &self.options.new_resource {
+    NewResource::String(new_resource) => dependency.set_request(new_resource.clone()),
+    NewResource::Fn(new_resource) => {
+      let result = new_resource(NormalModuleReplacementData {
+        request: dependency.request().to_string(),
+        context,
+        resource: None,
+      })
+      .await?;
+      dependency.set_request(result.request);
+      data.context = result.context.into();
+    }
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryAfterResolve for NormalModuleReplacementPlugin)]
+async fn nmf_after_resolve(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+) -> Result<Option<bool>> {
+  let resource_data = &mut create_data.resource_resolve_data;
+  if !self.options.resource_reg_exp.test(&resource_data.resource) {
+    return Ok(None);
+  }
+  let new_resource_path = match &self.options.new_resource {
+    NewResource::String(new_resource) => {
+      if Path::new(new_resource).is_absolute() {
+        PathBuf::from(new_resource)
+      } else {
+        resource_data
+          .resource_path
+          .parent()
+          .unwrap_or_else(|| Path::new(""))
+          .join(new_resource)
+          .normalize()
+      }
+    }
+    NewResource::Fn(new_resource) => {
+      let result = new_resource(NormalModuleReplacementData {
+        request: create_data.request.clone(),
+        context: data.context.to_string(),
+        resource: Some(resource_data.resource_path.to_string_lossy().to_string()),
+      })
+      .await?;
+      match result.resource {
+        Some(resource) => PathBuf::from(resource),
+        None => return Ok(None),
+      }
+    }
+  };
+  let origin_resource_path = resource_data.resource_path.to_string_lossy().to_string();
+  resource_data.resource = resource_data
+    .resource
+    .replace(&origin_resource_path, &new_resource_path.to_string_lossy());
+  resource_data.resource_path = new_resource_path;
+  Ok(None)
+}
+
+impl Plugin for NormalModuleReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NormalModuleReplacementPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .before_resolve
+      .tap(nmf_before_resolve::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .after_resolve
+      .tap(nmf_after_resolve::new(self));
+    Ok(())
+  }
+}
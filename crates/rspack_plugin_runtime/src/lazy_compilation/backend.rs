@@ -0,0 +1,22 @@
+use std::fmt::Debug;
+
+use rspack_core::ModuleIdentifier;
+use rspack_error::Result;
+
+#[derive(Debug, Clone)]
+pub struct ModuleInfo {
+  /// Whether the module has been requested by the client
+  pub active: bool,
+  /// Key of the module that the client sends back to activate it
+  pub data: String,
+  /// Request of the client module which keeps the proxy alive
+  pub client: String,
+}
+
+/// The backend serves the activation requests of lazy compiled modules, it
+/// is expected to trigger a rebuild of the proxy module (at `path`) once the
+/// module becomes active.
+#[async_trait::async_trait]
+pub trait Backend: Debug + Send + Sync {
+  async fn module(&self, original_module: ModuleIdentifier, path: String) -> Result<ModuleInfo>;
+}
@@ -0,0 +1,58 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+/// Dependency from the active proxy to the original module, it is resolved
+/// again with the context and resolve options of the replaced dependency.
+#[derive(Debug, Clone)]
+pub struct LazyCompilationDependency {
+  id: DependencyId,
+  request: String,
+  category: DependencyCategory,
+}
+
+impl LazyCompilationDependency {
+  pub fn new(request: String, category: DependencyCategory) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      category,
+    }
+  }
+}
+
+impl Dependency for LazyCompilationDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "LazyCompilationDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &self.category
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::LazyImport
+  }
+}
+
+impl ModuleDependency for LazyCompilationDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsContextDependency for LazyCompilationDependency {}
+impl AsDependencyTemplate for LazyCompilationDependency {}
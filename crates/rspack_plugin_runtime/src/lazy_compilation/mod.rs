@@ -0,0 +1,164 @@
+mod backend;
+mod dependency;
+mod module;
+
+use std::fmt;
+
+pub use backend::{Backend, ModuleInfo};
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationParams, CompilerCompilation, CompilerOptions,
+  DependencyType, ModuleFactoryCreateData, NormalModuleCreateData, NormalModuleFactoryModule,
+  Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::Identifiable;
+use rspack_regex::RspackRegex;
+
+pub use self::module::LazyCompilationProxyModule;
+
+static HOT_CLIENT_REQUEST: Lazy<regex::Regex> = Lazy::new(|| {
+  regex::Regex::new(
+    r"webpack[/\\]hot[/\\]|webpack-dev-server[/\\]client|webpack-hot-middleware[/\\]client|@rspack[/\\]dev-server|@rspack[/\\]core[/\\]hot",
+  )
+  .expect("should be valid regex")
+});
+
+pub type LazyCompilationTestFn =
+  Box<dyn Fn(String) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
+
+pub enum LazyCompilationTest {
+  Regex(RspackRegex),
+  Fn(LazyCompilationTestFn),
+}
+
+impl fmt::Debug for LazyCompilationTest {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Regex(regex) => regex.fmt(f),
+      Self::Fn(_) => "Fn(...)".fmt(f),
+    }
+  }
+}
+
+impl LazyCompilationTest {
+  async fn test(&self, name: &str) -> Result<bool> {
+    match self {
+      Self::Regex(regex) => Ok(regex.test(name)),
+      Self::Fn(func) => func(name.to_string()).await,
+    }
+  }
+}
+
+/// Replaces dynamically imported modules and/or entries with proxies that
+/// only compile the original module after the backend reports a request.
+#[plugin]
+#[derive(Debug)]
+pub struct LazyCompilationPlugin {
+  backend: Box<dyn Backend>,
+  entries: bool,
+  imports: bool,
+  test: Option<LazyCompilationTest>,
+}
+
+impl LazyCompilationPlugin {
+  pub fn new(
+    backend: Box<dyn Backend>,
+    entries: bool,
+    imports: bool,
+    test: Option<LazyCompilationTest>,
+  ) -> Self {
+    Self::new_inner(backend, entries, imports, test)
+  }
+
+  async fn check_test(&self, module: &BoxModule) -> Result<bool> {
+    let Some(test) = &self.test else {
+      return Ok(true);
+    };
+    match module.name_for_condition() {
+      Some(name) => test.test(&name).await,
+      None => Ok(false),
+    }
+  }
+}
+
+#[plugin_hook(CompilerCompilation for LazyCompilationPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::LazyImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryModule for LazyCompilationPlugin)]
+async fn normal_module_factory_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  let dep_type = data.dependency.dependency_type();
+  let is_import = matches!(dep_type, DependencyType::DynamicImport);
+  let is_entry = matches!(dep_type, DependencyType::Entry);
+  if !(is_import && self.imports || is_entry && self.entries) {
+    return Ok(());
+  }
+  if HOT_CLIENT_REQUEST.is_match(&create_data.request) || !self.check_test(module).await? {
+    return Ok(());
+  }
+  let Some(request) = data.request() else {
+    return Ok(());
+  };
+  let request = request.to_string();
+
+  let original_module = module.identifier();
+  let proxy_identifier = LazyCompilationProxyModule::proxy_identifier(&original_module);
+  let info = self
+    .backend
+    .module(original_module, proxy_identifier.to_string())
+    .await?;
+
+  *module = Box::new(LazyCompilationProxyModule::new(
+    original_module,
+    request,
+    create_data.resource_resolve_data.resource.clone(),
+    data.context.clone(),
+    data.resolve_options.clone(),
+    *data.dependency.category(),
+    info.active,
+    info.data,
+    info.client,
+  ));
+  Ok(())
+}
+
+impl Plugin for LazyCompilationPlugin {
+  fn name(&self) -> &'static str {
+    "LazyCompilationPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(normal_module_factory_module::new(self));
+    Ok(())
+  }
+}
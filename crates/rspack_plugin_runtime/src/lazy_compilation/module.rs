@@ -0,0 +1,310 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::path::PathBuf;
+
+use rspack_core::{
+  contextify, impl_module_meta_info, impl_source_map_config, module_namespace_promise, module_raw,
+  rspack_sources::{RawSource, Source, SourceExt},
+  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo,
+  BuildMeta, BuildResult, CodeGenerationResult, Compilation, ConcatenationScope, Context,
+  DependenciesBlock, DependencyCategory, DependencyId, FactoryMeta, LibIdentOptions, Module,
+  ModuleIdentifier, ModuleInitFragments, ModuleType, Resolve, RuntimeGlobals, RuntimeSpec,
+  SourceType, TemplateContext,
+};
+use rspack_error::{impl_empty_diagnosable_trait, Result};
+use rspack_hash::RspackHash;
+use rspack_identifier::Identifiable;
+use rspack_plugin_javascript::dependency::CommonJsRequireDependency;
+use rustc_hash::FxHashSet as HashSet;
+
+use super::dependency::LazyCompilationDependency;
+
+/// Stands in for a module that is not compiled until it is requested.
+///
+/// The proxy requires the backend client and, once active, imports the
+/// original module in an async block. Activation rebuilds the proxy, which is
+/// then swapped by HMR.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct LazyCompilationProxyModule {
+  dependencies: Vec<DependencyId>,
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  module_identifier: ModuleIdentifier,
+  original_module: ModuleIdentifier,
+  factory_meta: Option<FactoryMeta>,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+
+  /// Request, context and resolve options of the replaced dependency
+  request: String,
+  resource: String,
+  context: Context,
+  resolve_options: Option<Box<Resolve>>,
+  dependency_category: DependencyCategory,
+
+  active: bool,
+  data: String,
+  client: String,
+}
+
+impl LazyCompilationProxyModule {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    original_module: ModuleIdentifier,
+    request: String,
+    resource: String,
+    context: Context,
+    resolve_options: Option<Box<Resolve>>,
+    dependency_category: DependencyCategory,
+    active: bool,
+    data: String,
+    client: String,
+  ) -> Self {
+    Self {
+      dependencies: vec![],
+      blocks: vec![],
+      module_identifier: Self::proxy_identifier(&original_module),
+      original_module,
+      factory_meta: None,
+      build_info: None,
+      build_meta: None,
+      request,
+      resource,
+      context,
+      resolve_options,
+      dependency_category,
+      active,
+      data,
+      client,
+      source_map_kind: Default::default(),
+    }
+  }
+
+  pub fn proxy_identifier(original_module: &ModuleIdentifier) -> ModuleIdentifier {
+    format!("lazy-compilation-proxy|{original_module}").into()
+  }
+}
+
+impl DependenciesBlock for LazyCompilationProxyModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for LazyCompilationProxyModule {
+  impl_module_meta_info!();
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn get_diagnostics(&self) -> Vec<rspack_error::Diagnostic> {
+    vec![]
+  }
+
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "lazy-compilation-proxy {}",
+      context.shorten(&self.original_module)
+    ))
+  }
+
+  fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "{}!lazy-compilation-proxy",
+      contextify(options.context, &self.resource)
+    )))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    200.0
+  }
+
+  fn get_context(&self) -> Option<Box<Context>> {
+    Some(Box::new(self.context.clone()))
+  }
+
+  fn get_resolve_options(&self) -> Option<Box<Resolve>> {
+    self.resolve_options.clone()
+  }
+
+  fn is_available(&self, modified_file: &HashSet<PathBuf>) -> bool {
+    // the backend reports an activation as a modification of the proxy
+    !modified_file.contains(&PathBuf::from(self.module_identifier.as_str()))
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+    let hash = hasher.digest(&build_context.compiler_options.output.hash_digest);
+
+    let client_dep = CommonJsRequireDependency::new(self.client.clone(), None, 0, 0, false);
+    let dependencies: Vec<BoxDependency> = vec![Box::new(client_dep)];
+    let mut blocks = vec![];
+    if self.active {
+      let dep = LazyCompilationDependency::new(self.request.clone(), self.dependency_category);
+      blocks.push(AsyncDependenciesBlock::new(
+        self.module_identifier,
+        None,
+        None,
+        vec![Box::new(dep)],
+      ));
+    }
+
+    Ok(BuildResult {
+      build_info: BuildInfo {
+        hash: Some(hash),
+        ..Default::default()
+      },
+      build_meta: Default::default(),
+      dependencies,
+      blocks,
+      analyze_result: Default::default(),
+      optimization_bailouts: vec![],
+    })
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut runtime_requirements = RuntimeGlobals::MODULE;
+    let client_dep_id = self
+      .dependencies
+      .first()
+      .expect("should have client dependency");
+    let client = format!(
+      "var client = {};\nvar data = {};\n",
+      module_raw(
+        compilation,
+        &mut runtime_requirements,
+        client_dep_id,
+        &self.client,
+        false
+      ),
+      serde_json::to_string(&self.data).expect("should stringify data")
+    );
+    let keep_active = format!(
+      "var dispose = client.keepAlive({{ data: data, active: {}, module: module, onError: onError }});\n",
+      self.active
+    );
+
+    let source = if let Some(block_id) = self.blocks.first() {
+      let module_graph = compilation.get_module_graph();
+      let block = module_graph
+        .block_by_id(block_id)
+        .expect("should have block");
+      let dep_id = block
+        .get_dependencies()
+        .first()
+        .expect("should have lazy compilation dependency");
+      let module_id = module_graph
+        .module_identifier_by_dependency_id(dep_id)
+        .and_then(|module| compilation.chunk_graph.get_module_id(*module).as_ref())
+        .map(|id| serde_json::to_string(id).expect("should stringify module id"))
+        .unwrap_or_else(|| "undefined".to_string());
+      let mut init_fragments = ModuleInitFragments::default();
+      let mut template_context = TemplateContext {
+        compilation,
+        module: self,
+        runtime_requirements: &mut runtime_requirements,
+        init_fragments: &mut init_fragments,
+        runtime,
+        concatenation_scope: None,
+      };
+      let promise = module_namespace_promise(
+        &mut template_context,
+        dep_id,
+        Some(block_id),
+        &self.request,
+        "import()",
+        false,
+      );
+      format!(
+        r#"{client}module.exports = {promise};
+if (module.hot) {{
+  module.hot.accept();
+  module.hot.accept({module_id}, function() {{ module.hot.invalidate(); }});
+  module.hot.dispose(function(data) {{ delete data.resolveSelf; dispose(data); }});
+  if (module.hot.data && module.hot.data.resolveSelf) module.hot.data.resolveSelf(module.exports);
+}}
+function onError() {{ /* ignore */ }}
+{keep_active}"#
+      )
+    } else {
+      format!(
+        r#"{client}var resolveSelf, onError;
+module.exports = new Promise(function(resolve, reject) {{ resolveSelf = resolve; onError = reject; }});
+if (module.hot) {{
+  module.hot.accept();
+  if (module.hot.data && module.hot.data.resolveSelf) module.hot.data.resolveSelf(module.exports);
+  module.hot.dispose(function(data) {{ data.resolveSelf = resolveSelf; dispose(data); }});
+}}
+{keep_active}"#
+      )
+    };
+
+    let mut cgr = CodeGenerationResult {
+      runtime_requirements,
+      ..Default::default()
+    };
+    cgr.add(SourceType::JavaScript, RawSource::from(source).boxed());
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+}
+
+impl Identifiable for LazyCompilationProxyModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.module_identifier
+  }
+}
+
+impl_empty_diagnosable_trait!(LazyCompilationProxyModule);
+
+impl Hash for LazyCompilationProxyModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__LazyCompilationProxyModule".hash(state);
+    self.identifier().hash(state);
+    self.active.hash(state);
+  }
+}
+
+impl PartialEq for LazyCompilationProxyModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for LazyCompilationProxyModule {}
@@ -2,7 +2,10 @@
 mod helpers;
 pub use helpers::*;
 mod lazy_compilation;
-pub use lazy_compilation::LazyCompilationPlugin;
+pub use lazy_compilation::{
+  Backend as LazyCompilationBackend, LazyCompilationPlugin, LazyCompilationProxyModule,
+  LazyCompilationTest, LazyCompilationTestFn, ModuleInfo as LazyCompilationModuleInfo,
+};
 mod common_js_chunk_format;
 pub use common_js_chunk_format::CommonJsChunkFormatPlugin;
 mod runtime_plugin;
@@ -64,5 +67,8 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin::default().boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin::default().boxed()),
+    // Registered with `ApplyContext::register_chunk_loading_type` by the plugin
+    // providing its runtime, entries using it fail to build otherwise.
+    ChunkLoadingType::Custom(_) => {}
   }
 }
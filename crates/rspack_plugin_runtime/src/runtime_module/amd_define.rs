@@ -0,0 +1,33 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_util::source_map::SourceMapKind;
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct AmdDefineRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for AmdDefineRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/amd_define"),
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for AmdDefineRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(RawSource::from(include_str!("runtime/amd_define.js")).boxed())
+  }
+}
@@ -0,0 +1,42 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_util::source_map::SourceMapKind;
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct AmdOptionsRuntimeModule {
+  id: Identifier,
+  options: String,
+}
+
+impl AmdOptionsRuntimeModule {
+  pub fn new(options: String) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/amd_options"),
+      options,
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for AmdOptionsRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(
+      RawSource::from(format!(
+        "{} = {};",
+        RuntimeGlobals::AMD_OPTIONS,
+        self.options
+      ))
+      .boxed(),
+    )
+  }
+}
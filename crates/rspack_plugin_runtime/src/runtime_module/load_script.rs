@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  impl_runtime_module, render_sri_integrity,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
+  ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
@@ -12,6 +12,7 @@ pub struct LoadScriptRuntimeModule {
   id: Identifier,
   unique_name: String,
   with_create_script_url: bool,
+  chunk: Option<ChunkUkey>,
 }
 
 impl LoadScriptRuntimeModule {
@@ -20,6 +21,7 @@ impl LoadScriptRuntimeModule {
       id: Identifier::from("webpack/runtime/load_script"),
       unique_name,
       with_create_script_url,
+      chunk: None,
       source_map_kind: SourceMapKind::None,
       custom_source: None,
     }
@@ -31,6 +33,10 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     self.id
   }
 
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
   fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
     let url = if self.with_create_script_url {
       format!("{}(url)", RuntimeGlobals::CREATE_SCRIPT_URL)
@@ -54,6 +60,25 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       }
     };
 
+    let with_sri = self.chunk.map_or(false, |chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_runtime_requirements(&chunk)
+        .contains(RuntimeGlobals::SRI_HASHES)
+    });
+    let integrity = if with_sri {
+      format!(
+        "if (chunkId !== undefined) {{ {} }}",
+        render_sri_integrity(
+          "script",
+          "js",
+          &compilation.options.output.cross_origin_loading
+        )
+      )
+    } else {
+      String::new()
+    };
+
     let script_type = if compilation.options.output.script_type.eq("false") {
       String::new()
     } else {
@@ -76,7 +101,7 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       include_str!("runtime/load_script.js")
         .replace(
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
-          &cross_origin_loading,
+          &format!("{cross_origin_loading}{integrity}"),
         )
         .replace("$URL$", &url)
         .replace("$SCRIPT_TYPE$", &script_type)
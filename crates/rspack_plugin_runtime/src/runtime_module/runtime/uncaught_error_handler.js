@@ -0,0 +1,4 @@
+__webpack_require__.oe = function (err) {
+	console.error(err);
+	throw err;
+};
@@ -0,0 +1,33 @@
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_util::source_map::SourceMapKind;
+
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct UncaughtErrorHandlerRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for UncaughtErrorHandlerRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/uncaught_error_handler"),
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for UncaughtErrorHandlerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(RawSource::from(include_str!("runtime/uncaught_error_handler.js")).boxed())
+  }
+}
@@ -16,16 +16,17 @@ use rspack_plugin_javascript::{
 };
 
 use crate::runtime_module::{
-  chunk_has_css, is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule,
-  BaseUriRuntimeModule, ChunkNameRuntimeModule, ChunkPrefetchPreloadFunctionRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule,
-  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NonceRuntimeModule,
-  NormalRuntimeModule, OnChunkLoadedRuntimeModule, PublicPathRuntimeModule,
-  RelativeUrlRuntimeModule, RuntimeIdRuntimeModule, SystemContextRuntimeModule,
+  chunk_has_css, is_enabled_for_chunk, AmdDefineRuntimeModule, AmdOptionsRuntimeModule,
+  AsyncRuntimeModule, AutoPublicPathRuntimeModule, BaseUriRuntimeModule, ChunkNameRuntimeModule,
+  ChunkPrefetchPreloadFunctionRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
+  NodeModuleDecoratorRuntimeModule, NonceRuntimeModule, NormalRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
+  RuntimeIdRuntimeModule, SystemContextRuntimeModule, UncaughtErrorHandlerRuntimeModule,
 };
 
 static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
@@ -51,15 +52,17 @@ static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
     RuntimeGlobals::BASE_URI,
     RuntimeGlobals::RELATIVE_URL,
     RuntimeGlobals::SCRIPT_NONCE,
-    // RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
+    RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
-    // RuntimeGlobals::WASM_INSTANCES,
+    RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
     RuntimeGlobals::LOAD_SCRIPT,
     RuntimeGlobals::SYSTEM_CONTEXT,
     RuntimeGlobals::ON_CHUNKS_LOADED,
+    RuntimeGlobals::AMD_DEFINE,
+    RuntimeGlobals::AMD_OPTIONS,
   ]
 });
 
@@ -484,6 +487,21 @@ fn runtime_requirements_in_tree(
       RuntimeGlobals::RUNTIME_ID => {
         compilation.add_runtime_module(chunk_ukey, RuntimeIdRuntimeModule::default().boxed())?;
       }
+      RuntimeGlobals::AMD_DEFINE => {
+        compilation.add_runtime_module(chunk_ukey, AmdDefineRuntimeModule::default().boxed())?;
+      }
+      RuntimeGlobals::AMD_OPTIONS => {
+        if let Some(options) = compilation.options.amd.clone() {
+          compilation
+            .add_runtime_module(chunk_ukey, AmdOptionsRuntimeModule::new(options).boxed())?;
+        }
+      }
+      RuntimeGlobals::UNCAUGHT_ERROR_HANDLER => {
+        compilation.add_runtime_module(
+          chunk_ukey,
+          UncaughtErrorHandlerRuntimeModule::default().boxed(),
+        )?;
+      }
       RuntimeGlobals::PREFETCH_CHUNK => {
         compilation.add_runtime_module(
           chunk_ukey,
@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use rspack_error::{error, error_bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const LOCKFILE_VERSION: u64 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockfileEntry {
+  /// The url after following redirects
+  pub resolved: String,
+  /// Subresource integrity of the content, e.g. `sha512-...`
+  pub integrity: String,
+  pub content_type: String,
+}
+
+/// `rspack.lock` maps every requested url to its [LockfileEntry].
+#[derive(Debug, Default)]
+pub struct Lockfile {
+  entries: BTreeMap<String, LockfileEntry>,
+}
+
+impl Lockfile {
+  pub fn parse(content: &str) -> Result<Self> {
+    let Value::Object(mut map) =
+      serde_json::from_str(content).map_err(|e| error!("Invalid lockfile: {e}"))?
+    else {
+      error_bail!("Invalid lockfile: expected an object");
+    };
+    let version = map.remove("version").and_then(|v| v.as_u64());
+    if version != Some(LOCKFILE_VERSION) {
+      error_bail!("Unsupported lockfile version {version:?}");
+    }
+    let entries = map
+      .into_iter()
+      .map(|(url, entry)| {
+        let entry = serde_json::from_value(entry)
+          .map_err(|e| error!("Invalid lockfile entry for {url}: {e}"))?;
+        Ok((url, entry))
+      })
+      .collect::<Result<_>>()?;
+    Ok(Self { entries })
+  }
+
+  pub fn get(&self, url: &str) -> Option<&LockfileEntry> {
+    self.entries.get(url)
+  }
+
+  pub fn insert(&mut self, url: String, entry: LockfileEntry) {
+    self.entries.insert(url, entry);
+  }
+
+  pub fn to_json_string(&self) -> String {
+    let mut map = Map::new();
+    map.insert("version".into(), LOCKFILE_VERSION.into());
+    for (url, entry) in &self.entries {
+      map.insert(
+        url.clone(),
+        serde_json::to_value(entry).expect("should serialize lockfile entry"),
+      );
+    }
+    let mut content =
+      serde_json::to_string_pretty(&Value::Object(map)).expect("should serialize lockfile");
+    content.push('\n');
+    content
+  }
+}
@@ -0,0 +1,499 @@
+mod lockfile;
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use derivative::Derivative;
+use futures::future::BoxFuture;
+pub use lockfile::{Lockfile, LockfileEntry};
+use once_cell::sync::OnceCell;
+use rspack_core::{
+  get_scheme, ApplyContext, CompilerOptions, Content, ModuleFactoryCreateData,
+  NormalModuleFactoryResolveForScheme, NormalModuleFactoryResolveInScheme,
+  NormalModuleReadResource, Plugin, PluginContext, ResourceData,
+};
+use rspack_error::{error, error_bail, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use sha2::{Digest, Sha512};
+use tokio::sync::Mutex;
+use url::Url;
+
+const MAX_REDIRECTS: usize = 5;
+
+#[derive(Debug)]
+pub enum AllowedUri {
+  /// Allows every url starting with the string
+  Prefix(String),
+  Regex(RspackRegex),
+}
+
+impl AllowedUri {
+  fn is_match(&self, url: &str) -> bool {
+    match self {
+      Self::Prefix(prefix) => url.starts_with(prefix.as_str()),
+      Self::Regex(regex) => regex.test(url),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+  pub status: u16,
+  /// Header names are lower cased
+  pub headers: HashMap<String, String>,
+  pub body: Vec<u8>,
+}
+
+pub type HttpClientFn = Box<
+  dyn Fn(String, HashMap<String, String>) -> BoxFuture<'static, Result<HttpResponse>> + Sync + Send,
+>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct HttpUriPluginOptions {
+  pub allowed_uris: Vec<AllowedUri>,
+  /// Defaults to `<context>/rspack.lock`
+  pub lockfile_location: Option<PathBuf>,
+  /// Defaults to `<lockfile_location>.data`
+  pub cache_location: Option<PathBuf>,
+  /// Only use the lockfile and the cache, never fetch from network
+  pub frozen: bool,
+  /// Refetch every url and update the lockfile
+  pub upgrade: bool,
+  /// Fetches the url with the headers, provided by the host (e.g. `fetch` from JavaScript side)
+  #[derivative(Debug = "ignore")]
+  pub http_client: HttpClientFn,
+}
+
+#[derive(Debug)]
+struct Locations {
+  lockfile: PathBuf,
+  cache: PathBuf,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct HttpUriPlugin {
+  options: HttpUriPluginOptions,
+  locations: OnceCell<Locations>,
+  /// Loaded lazily at the first http(s) request
+  lockfile: Mutex<Option<Lockfile>>,
+}
+
+impl HttpUriPlugin {
+  pub fn new(options: HttpUriPluginOptions) -> Self {
+    Self::new_inner(options, Default::default(), Default::default())
+  }
+
+  fn locations(&self) -> &Locations {
+    self
+      .locations
+      .get()
+      .expect("HttpUriPlugin should be applied before use")
+  }
+
+  fn is_allowed(&self, url: &str) -> bool {
+    self
+      .options
+      .allowed_uris
+      .iter()
+      .any(|allowed| allowed.is_match(url))
+  }
+
+  /// Returns the lockfile entry and the content of `url`, fetching and caching
+  /// it when it is unknown to the lockfile.
+  async fn get_info(&self, url: &str) -> Result<(LockfileEntry, Vec<u8>)> {
+    let locked = self
+      .with_lockfile(|lockfile| lockfile.get(url).cloned())
+      .await?;
+    if let Some(entry) = &locked
+      && (self.options.frozen || !self.options.upgrade)
+    {
+      if let Some(content) = self.read_cache(entry).await {
+        return Ok((entry.clone(), content));
+      }
+      if self.options.frozen {
+        error_bail!(
+          "{url} is not in the cache at {} and the lockfile is frozen",
+          self.locations().cache.display()
+        );
+      }
+    } else if self.options.frozen {
+      error_bail!(
+        "{url} has no lockfile entry in {} and the lockfile is frozen",
+        self.locations().lockfile.display()
+      );
+    }
+
+    let (entry, content) = self.fetch(url).await?;
+    if let Some(locked) = &locked
+      && !self.options.upgrade
+      && locked.integrity != entry.integrity
+    {
+      error_bail!(
+        "{url} has changed since it was locked: expected integrity {}, got {}. Enable upgrade to update the lockfile",
+        locked.integrity,
+        entry.integrity
+      );
+    }
+    self.write_cache(&entry, &content).await?;
+    if locked.as_ref() != Some(&entry) {
+      self
+        .with_lockfile(|lockfile| lockfile.insert(url.to_string(), entry.clone()))
+        .await?;
+      self.write_lockfile().await?;
+    }
+    Ok((entry, content))
+  }
+
+  async fn with_lockfile<R>(&self, f: impl FnOnce(&mut Lockfile) -> R) -> Result<R> {
+    let mut lockfile = self.lockfile.lock().await;
+    if lockfile.is_none() {
+      let path = &self.locations().lockfile;
+      *lockfile = Some(match tokio::fs::read_to_string(path).await {
+        Ok(content) => Lockfile::parse(&content)
+          .map_err(|e| error!("Failed to parse {}: {e}", path.display()))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Lockfile::default(),
+        Err(e) => error_bail!("Failed to read {}: {e}", path.display()),
+      });
+    }
+    Ok(f(lockfile.as_mut().expect("should have lockfile")))
+  }
+
+  async fn write_lockfile(&self) -> Result<()> {
+    let content = self
+      .with_lockfile(|lockfile| lockfile.to_json_string())
+      .await?;
+    let path = &self.locations().lockfile;
+    tokio::fs::write(path, content)
+      .await
+      .map_err(|e| error!("Failed to write {}: {e}", path.display()))
+  }
+
+  async fn read_cache(&self, entry: &LockfileEntry) -> Option<Vec<u8>> {
+    let hex = integrity_hex(&entry.integrity)?;
+    let path = self.cache_path(&entry.resolved, &hex);
+    let content = tokio::fs::read(path).await.ok()?;
+    // a corrupted cache entry is treated as missing
+    (digest(&content).0 == entry.integrity).then_some(content)
+  }
+
+  async fn write_cache(&self, entry: &LockfileEntry, content: &[u8]) -> Result<()> {
+    let (_, hex) = digest(content);
+    let path = self.cache_path(&entry.resolved, &hex);
+    if let Some(dir) = path.parent() {
+      tokio::fs::create_dir_all(dir)
+        .await
+        .map_err(|e| error!("Failed to create {}: {e}", dir.display()))?;
+    }
+    tokio::fs::write(&path, content)
+      .await
+      .map_err(|e| error!("Failed to write {}: {e}", path.display()))
+  }
+
+  /// Cache entries are addressed by the content digest, prefixed with a
+  /// readable form of the url.
+  fn cache_path(&self, url: &str, hex: &str) -> PathBuf {
+    let readable = match Url::parse(url) {
+      Ok(url) => format!(
+        "{}{}",
+        url.host_str().unwrap_or_default(),
+        url.path().trim_end_matches('/')
+      ),
+      Err(_) => String::new(),
+    };
+    let readable = readable
+      .split('/')
+      .map(|segment| {
+        segment
+          .chars()
+          .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+              c
+            } else {
+              '_'
+            }
+          })
+          .take(64)
+          .collect::<String>()
+      })
+      .filter(|segment| !segment.is_empty() && segment != "." && segment != "..")
+      .collect::<Vec<_>>()
+      .join("/");
+    self
+      .locations()
+      .cache
+      .join(format!("{readable}_{}", &hex[..20]))
+  }
+
+  async fn fetch(&self, url: &str) -> Result<(LockfileEntry, Vec<u8>)> {
+    let mut current = url.to_string();
+    for _ in 0..=MAX_REDIRECTS {
+      if !self.is_allowed(&current) {
+        error_bail!(
+          "{current} doesn't match the allowedUris policy. These URIs are allowed: {}",
+          self.allowed_uris_message()
+        );
+      }
+      let headers = HashMap::from([("accept-encoding".to_string(), "identity".to_string())]);
+      let response = (self.options.http_client)(current.clone(), headers).await?;
+      if matches!(response.status, 301 | 302 | 303 | 307 | 308)
+        && let Some(location) = response.headers.get("location")
+      {
+        current = Url::parse(&current)
+          .and_then(|base| base.join(location))
+          .map_err(|e| error!("Invalid redirect from {current} to {location}: {e}"))?
+          .to_string();
+        continue;
+      }
+      if response.status != 200 {
+        error_bail!("{current} request status code = {}", response.status);
+      }
+      let (integrity, _) = digest(&response.body);
+      let entry = LockfileEntry {
+        resolved: current,
+        integrity,
+        content_type: response
+          .headers
+          .get("content-type")
+          .cloned()
+          .unwrap_or_default(),
+      };
+      return Ok((entry, response.body));
+    }
+    error_bail!("{url} redirected too many times")
+  }
+
+  fn allowed_uris_message(&self) -> String {
+    self
+      .options
+      .allowed_uris
+      .iter()
+      .map(|allowed| match allowed {
+        AllowedUri::Prefix(prefix) => prefix.clone(),
+        AllowedUri::Regex(regex) => regex.to_string(),
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+
+  async fn resolve_resource(&self, url: &str, resource_data: &mut ResourceData) -> Result<()> {
+    let (entry, _) = self.get_info(url).await?;
+    let parsed = Url::parse(url).map_err(|e| error!("Invalid url {url}: {e}"))?;
+    let query = parsed.query().map(|q| format!("?{q}"));
+    let fragment = parsed.fragment().map(|f| format!("#{f}"));
+    let path = &parsed[..url::Position::AfterPath];
+    let mut new_resource_data = ResourceData::new(url.to_string(), PathBuf::from(path))
+      .query_optional(query)
+      .fragment_optional(fragment);
+    let mimetype = entry
+      .content_type
+      .split(';')
+      .next()
+      .unwrap_or_default()
+      .trim();
+    if !mimetype.is_empty() {
+      new_resource_data.set_mimetype(mimetype.to_string());
+    }
+    *resource_data = new_resource_data;
+    Ok(())
+  }
+}
+
+/// Returns the sri integrity and the hex digest of `content`.
+fn digest(content: &[u8]) -> (String, String) {
+  let hash = Sha512::digest(content);
+  let integrity = format!("sha512-{}", rspack_base64::encode_to_string(hash));
+  (integrity, to_hex(&hash))
+}
+
+fn integrity_hex(integrity: &str) -> Option<String> {
+  let hash = rspack_base64::decode_to_vec(integrity.strip_prefix("sha512-")?).ok()?;
+  Some(to_hex(&hash))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+  bytes.iter().fold(String::new(), |mut hex, b| {
+    let _ = write!(hex, "{b:02x}");
+    hex
+  })
+}
+
+#[plugin_hook(NormalModuleFactoryResolveForScheme for HttpUriPlugin)]
+async fn resolve_for_scheme(
+  &self,
+  _data: &mut ModuleFactoryCreateData,
+  resource_data: &mut ResourceData,
+) -> Result<Option<bool>> {
+  if resource_data.get_scheme().is_http() {
+    let url = resource_data.resource.clone();
+    self.resolve_resource(&url, resource_data).await?;
+    return Ok(Some(true));
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryResolveInScheme for HttpUriPlugin)]
+async fn resolve_in_scheme(
+  &self,
+  data: &ModuleFactoryCreateData,
+  resource_data: &mut ResourceData,
+) -> Result<Option<bool>> {
+  let request = resource_data.resource.as_str();
+  if get_scheme(data.context.as_str()).is_http()
+    && (request.starts_with("./") || request.starts_with("../") || request.starts_with('/'))
+  {
+    let base = format!("{}/", data.context.as_str());
+    let url = Url::parse(&base)
+      .and_then(|base| base.join(request))
+      .map_err(|e| error!("Failed to resolve {request} in {base}: {e}"))?
+      .to_string();
+    self.resolve_resource(&url, resource_data).await?;
+    return Ok(Some(true));
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleReadResource for HttpUriPlugin)]
+async fn read_resource(&self, resource_data: &mut ResourceData) -> Result<Option<Content>> {
+  if resource_data.get_scheme().is_http() {
+    let (_, content) = self.get_info(&resource_data.resource).await?;
+    return Ok(Some(Content::Buffer(content)));
+  }
+  Ok(None)
+}
+
+#[async_trait::async_trait]
+impl Plugin for HttpUriPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HttpUriPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let lockfile = self
+      .options
+      .lockfile_location
+      .clone()
+      .unwrap_or_else(|| Path::new(options.context.as_str()).join("rspack.lock"));
+    let cache = self.options.cache_location.clone().unwrap_or_else(|| {
+      let mut cache = lockfile.clone().into_os_string();
+      cache.push(".data");
+      cache.into()
+    });
+    self
+      .locations
+      .set(Locations { lockfile, cache })
+      .map_err(|_| error!("HttpUriPlugin should only be applied once"))?;
+
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_for_scheme
+      .tap(resolve_for_scheme::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .resolve_in_scheme
+      .tap(resolve_in_scheme::new(self));
+    ctx
+      .context
+      .normal_module_hooks
+      .read_resource
+      .tap(read_resource::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
+
+  use super::*;
+
+  fn create_plugin(dir: &Path, frozen: bool, http_client: HttpClientFn) -> HttpUriPlugin {
+    let plugin = HttpUriPlugin::new(HttpUriPluginOptions {
+      allowed_uris: vec![AllowedUri::Prefix("http://".into())],
+      lockfile_location: None,
+      cache_location: None,
+      frozen,
+      upgrade: false,
+      http_client,
+    });
+    let lockfile = dir.join("rspack.lock");
+    plugin
+      .locations
+      .set(Locations {
+        cache: dir.join("rspack.lock.data"),
+        lockfile,
+      })
+      .expect("should set locations");
+    plugin
+  }
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rspack_http_uri_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("should create temp dir");
+    dir
+  }
+
+  #[tokio::test]
+  async fn frozen_reads_lockfile_and_cache() {
+    let dir = temp_dir("frozen");
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let client: HttpClientFn = Box::new(move |url, _| {
+      counter.fetch_add(1, Ordering::SeqCst);
+      Box::pin(async move {
+        let (status, headers) = if url.ends_with("/old.js") {
+          (301, HashMap::from([("location".into(), "/a.js".into())]))
+        } else {
+          (
+            200,
+            HashMap::from([("content-type".into(), "text/javascript".into())]),
+          )
+        };
+        Ok(HttpResponse {
+          status,
+          headers,
+          body: b"export default 1".to_vec(),
+        })
+      })
+    });
+    let plugin = create_plugin(&dir, false, client);
+    let (entry, content) = plugin
+      .get_info("http://example.com/old.js")
+      .await
+      .expect("should fetch");
+    assert_eq!(entry.resolved, "http://example.com/a.js");
+    assert_eq!(entry.content_type, "text/javascript");
+    assert_eq!(content, b"export default 1");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let lockfile = std::fs::read_to_string(dir.join("rspack.lock")).expect("should write lockfile");
+    let lockfile = Lockfile::parse(&lockfile).expect("should parse lockfile");
+    assert_eq!(lockfile.get("http://example.com/old.js"), Some(&entry));
+
+    let frozen = create_plugin(
+      &dir,
+      true,
+      Box::new(|url, _| Box::pin(async move { error_bail!("should not fetch {url}") })),
+    );
+    let (locked, content) = frozen
+      .get_info("http://example.com/old.js")
+      .await
+      .expect("should read from cache");
+    assert_eq!(locked, entry);
+    assert_eq!(content, b"export default 1");
+    assert!(frozen.get_info("http://example.com/b.js").await.is_err());
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
@@ -114,6 +114,28 @@ impl SplitChunkSizes {
     })
   }
 
+  /// Port of `checkMinSize` in webpack's SplitChunksPlugin
+  ///
+  /// Return `true` if no size of the `SourceType`s in `min_size` is smaller than it.
+  pub fn fit_min_size(&self, min_size: &Self) -> bool {
+    min_size.iter().all(|(ty, min)| {
+      let size = self.get(ty).copied().unwrap_or_default();
+      size == 0.0 || size >= *min
+    })
+  }
+
+  /// Port of `checkMinSizeReduction` in webpack's SplitChunksPlugin
+  pub fn fit_min_size_reduction(&self, min_size_reduction: &Self, chunk_count: usize) -> bool {
+    min_size_reduction.iter().all(|(ty, min)| {
+      let size = self.get(ty).copied().unwrap_or_default();
+      size == 0.0 || size * chunk_count as f64 >= *min
+    })
+  }
+
+  pub fn has_non_zero_size(&self) -> bool {
+    self.values().any(|size| *size > 0.0)
+  }
+
   pub fn add_by(&mut self, other: &Self) {
     self.combine_with(other, &|a, b| a + b)
   }
@@ -139,6 +161,8 @@ pub struct FallbackCacheGroup {
   #[derivative(Debug = "ignore")]
   pub chunks_filter: ChunkFilter,
   pub min_size: SplitChunkSizes,
+  /// Fallback of `max_async_size` and `max_initial_size`
+  pub max_size: SplitChunkSizes,
   pub max_async_size: SplitChunkSizes,
   pub max_initial_size: SplitChunkSizes,
   pub automatic_name_delimiter: String,
@@ -13,6 +13,7 @@ pub use common::{
   SplitChunkSizes,
 };
 pub use options::cache_group::CacheGroup;
+pub use options::cache_group_layer::{CacheGroupLayer, CacheGroupLayerFnCtx};
 pub use options::cache_group_test::{CacheGroupTest, CacheGroupTestFnCtx};
 pub use options::chunk_name::{ChunkNameGetter, ChunkNameGetterFnCtx};
 pub use plugin::{PluginOptions, SplitChunksPlugin};
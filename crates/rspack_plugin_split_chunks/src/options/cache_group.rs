@@ -1,6 +1,7 @@
 use derivative::Derivative;
 use rspack_core::Filename;
 
+use super::cache_group_layer::CacheGroupLayer;
 use super::cache_group_test::CacheGroupTest;
 use super::chunk_name::ChunkNameGetter;
 use crate::common::{ChunkFilter, ModuleTypeFilter, SplitChunkSizes};
@@ -23,6 +24,9 @@ pub struct CacheGroup {
   pub chunk_filter: ChunkFilter,
   #[derivative(Debug = "ignore")]
   pub test: CacheGroupTest,
+  /// Only modules of the matched layer are selected
+  #[derivative(Debug = "ignore")]
+  pub layer: CacheGroupLayer,
   #[derivative(Debug = "ignore")]
   pub r#type: ModuleTypeFilter,
   /// `name` is used to create chunk
@@ -30,12 +34,25 @@ pub struct CacheGroup {
   pub name: ChunkNameGetter,
   pub priority: f64,
   pub min_size: SplitChunkSizes,
+  /// The minimum size reduction of the total size across all chunks that splitting out a chunk has to
+  /// achieve, which is the size of the `ModuleGroup` multiplied by the number of chunks it is in
+  pub min_size_reduction: SplitChunkSizes,
+  /// The minimum size of the remaining chunk when a `ModuleGroup` is split out of a single chunk
+  pub min_remaining_size: SplitChunkSizes,
+  /// `ModuleGroup`s reaching this size ignore `min_remaining_size`, `max_async_requests` and
+  /// `max_initial_requests`
+  pub enforce_size_threshold: SplitChunkSizes,
+  /// Modules are only put into the same chunk with chunks of the runtimes that use their exports
+  /// in the same way
+  pub used_exports: bool,
   pub reuse_existing_chunk: bool,
   /// number of referenced chunks
   pub min_chunks: u32,
   pub id_hint: String,
   pub max_initial_requests: u32,
   pub max_async_requests: u32,
+  /// Fallback of `max_async_size` and `max_initial_size`
+  pub max_size: SplitChunkSizes,
   pub max_async_size: SplitChunkSizes,
   pub max_initial_size: SplitChunkSizes,
   pub filename: Option<Filename>,
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use rspack_error::Result;
+
+pub struct CacheGroupLayerFnCtx<'a> {
+  pub layer: Option<&'a str>,
+}
+
+type CacheGroupLayerFn =
+  Arc<dyn Fn(CacheGroupLayerFnCtx<'_>) -> Result<Option<bool>> + Send + Sync>;
+
+#[derive(Clone)]
+pub enum CacheGroupLayer {
+  String(String),
+  Fn(CacheGroupLayerFn),
+  RegExp(rspack_regex::RspackRegex),
+  Enabled,
+}
+
+impl CacheGroupLayer {
+  /// Port of `checkModuleLayer` in webpack's SplitChunksPlugin.
+  ///
+  /// A string matches layers starting with it, the empty string only matches modules without a
+  /// layer.
+  pub fn test(&self, layer: Option<&str>) -> Result<bool> {
+    Ok(match self {
+      Self::String(str) if str.is_empty() => layer.map_or(true, str::is_empty),
+      Self::String(str) => layer.map_or(false, |layer| layer.starts_with(str.as_str())),
+      Self::RegExp(regexp) => layer.map_or(false, |layer| regexp.test(layer)),
+      Self::Fn(f) => f(CacheGroupLayerFnCtx { layer })?.unwrap_or_default(),
+      Self::Enabled => true,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_cache_group_layer() {
+    let empty = CacheGroupLayer::String(String::new());
+    assert!(empty.test(None).unwrap());
+    assert!(!empty.test(Some("client")).unwrap());
+
+    let prefix = CacheGroupLayer::String("client".to_string());
+    assert!(prefix.test(Some("client")).unwrap());
+    assert!(prefix.test(Some("client-legacy")).unwrap());
+    assert!(!prefix.test(Some("server")).unwrap());
+    assert!(!prefix.test(None).unwrap());
+
+    let regexp =
+      CacheGroupLayer::RegExp(rspack_regex::RspackRegex::new("^server$").expect("should be valid"));
+    assert!(regexp.test(Some("server")).unwrap());
+    assert!(!regexp.test(Some("server-legacy")).unwrap());
+    assert!(!regexp.test(None).unwrap());
+
+    let func = CacheGroupLayer::Fn(Arc::new(|ctx| Ok(Some(ctx.layer.is_none()))));
+    assert!(func.test(None).unwrap());
+    assert!(!func.test(Some("client")).unwrap());
+
+    assert!(CacheGroupLayer::Enabled.test(None).unwrap());
+    assert!(CacheGroupLayer::Enabled.test(Some("client")).unwrap());
+  }
+}
@@ -213,6 +213,14 @@ impl SplitChunksPlugin {
     max_size_setting_map: FxHashMap<ChunkUkey, MaxSizeSetting>,
   ) -> Result<()> {
     let fallback_cache_group = &self.fallback_cache_group;
+    let fallback_max_async_size = fallback_cache_group
+      .max_async_size
+      .clone()
+      .merge(&fallback_cache_group.max_size);
+    let fallback_max_initial_size = fallback_cache_group
+      .max_initial_size
+      .clone()
+      .merge(&fallback_cache_group.max_size);
     let chunk_group_db = &compilation.chunk_group_by_ukey;
     let compilation_ref = &*compilation;
 
@@ -236,10 +244,10 @@ impl SplitChunksPlugin {
         .unwrap_or(&fallback_cache_group.min_size);
       let max_async_size = max_size_setting
         .map(|s| &s.max_async_size)
-        .unwrap_or(&fallback_cache_group.max_async_size);
+        .unwrap_or(&fallback_max_async_size);
       let max_initial_size: &SplitChunkSizes = max_size_setting
         .map(|s| &s.max_initial_size)
-        .unwrap_or(&fallback_cache_group.max_initial_size);
+        .unwrap_or(&fallback_max_initial_size);
       let automatic_name_delimiter = max_size_setting.map(|s| &s.automatic_name_delimiter).unwrap_or(&fallback_cache_group.automatic_name_delimiter);
 
       let mut allow_max_size = if chunk.is_only_initial(chunk_group_db) {
@@ -1,9 +1,9 @@
 use std::ops::Deref;
 
-use rspack_core::{Compilation, SourceType};
+use rspack_core::{ChunkUkey, Compilation, SourceType};
 
 use super::ModuleGroupMap;
-use crate::{module_group::ModuleGroup, CacheGroup, SplitChunksPlugin};
+use crate::{module_group::ModuleGroup, CacheGroup, SplitChunkSizes, SplitChunksPlugin};
 
 impl SplitChunksPlugin {
   /// Return `true` if the `ModuleGroup` become empty.
@@ -40,8 +40,90 @@ impl SplitChunksPlugin {
     })
     .collect::<Box<[_]>>();
 
+    // question: After removing violating modules, the size of other `SourceType`s of this `ModuleGroup`
+    // may not fit again. But Webpack seems ignore this case. Not sure if it is on purpose.
+    Self::remove_modules_with_source_types(compilation, module_group, &violating_source_types);
+
+    module_group.modules.is_empty()
+  }
+
+  /// Affected by `splitChunks.minSizeReduction`/`splitChunks.cacheGroups.{cacheGroup}.minSizeReduction`
+  pub(crate) fn fit_min_size_reduction(
+    module_group_key: &str,
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+  ) -> bool {
+    let is_fit = module_group
+      .sizes
+      .fit_min_size_reduction(&cache_group.min_size_reduction, module_group.chunks.len());
+    if !is_fit {
+      tracing::trace!(
+        "ModuleGroup({}) violates CacheGroup({}).min_size_reduction({:?}) with sizes({:?}) in {} chunks",
+        module_group_key,
+        cache_group.key,
+        cache_group.min_size_reduction,
+        module_group.sizes,
+        module_group.chunks.len(),
+      );
+    }
+    is_fit
+  }
+
+  /// Affected by `splitChunks.minRemainingSize`/`splitChunks.cacheGroups.{cacheGroup}.minRemainingSize`
+  ///
+  /// Only applies when the `ModuleGroup` is split out of a single chunk. Return the `SourceType`s
+  /// whose remaining size in that chunk would be smaller than `min_remaining_size`.
+  pub(crate) fn get_min_remaining_size_violating_source_types(
+    module_group_key: &str,
+    compilation: &Compilation,
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+    chunk: &ChunkUkey,
+  ) -> Option<Box<[SourceType]>> {
+    let module_graph = compilation.get_module_graph();
+    let mut remaining_sizes = SplitChunkSizes::empty();
+    for module in compilation
+      .chunk_graph
+      .get_chunk_modules(chunk, &module_graph)
+    {
+      if module_group.modules.contains(&module.identifier()) {
+        continue;
+      }
+      for ty in module.source_types() {
+        *remaining_sizes.entry(*ty).or_default() += module.size(ty);
+      }
+    }
+
+    let violating_source_types = cache_group
+      .min_remaining_size
+      .iter()
+      .filter_map(|(ty, min_remaining_size)| {
+        let remaining_size = remaining_sizes.get(ty).copied().unwrap_or_default();
+        (remaining_size != 0.0 && remaining_size < *min_remaining_size).then_some(*ty)
+      })
+      .collect::<Box<[_]>>();
+
+    if violating_source_types.is_empty() {
+      return None;
+    }
+    tracing::trace!(
+      "ModuleGroup({}) have violating SourceTypes({:?}). Reason: remaining_sizes({:?}) < CacheGroup({}).min_remaining_size({:?})",
+      module_group_key,
+      violating_source_types,
+      remaining_sizes,
+      cache_group.key,
+      cache_group.min_remaining_size,
+    );
+    Some(violating_source_types)
+  }
+
+  /// Remove modules having any of the `violating_source_types` from the `ModuleGroup`.
+  pub(crate) fn remove_modules_with_source_types(
+    compilation: &Compilation,
+    module_group: &mut ModuleGroup,
+    violating_source_types: &[SourceType],
+  ) {
     let module_graph = compilation.get_module_graph();
-    // Remove modules having violating SourceType
     let violating_modules = module_group
       .modules
       .iter()
@@ -49,24 +131,16 @@ impl SplitChunksPlugin {
         let module = module_graph
           .module_by_identifier(module_id)
           .expect("Should have a module");
-        let having_violating_source_type = violating_source_types
+        module
+          .source_types()
           .iter()
-          .any(|ty: &SourceType| module.source_types().contains(ty));
-        if having_violating_source_type {
-          Some(module)
-        } else {
-          None
-        }
+          .any(|ty| violating_source_types.contains(ty))
+          .then_some(module)
       })
       .collect::<Vec<_>>();
-
-    // question: After removing violating modules, the size of other `SourceType`s of this `ModuleGroup`
-    // may not fit again. But Webpack seems ignore this case. Not sure if it is on purpose.
     violating_modules
       .into_iter()
       .for_each(|violating_module| module_group.remove_module(violating_module.deref()));
-
-    module_group.modules.is_empty()
   }
 
   /// Affected by `splitChunks.minSize`/`splitChunks.cacheGroups.{cacheGroup}.minSize`
@@ -87,25 +161,26 @@ impl SplitChunksPlugin {
             module_group_key,
             cache_group.key,
           );
-          return None;
-        }
-
-        if Self::remove_min_size_violating_modules(
+        } else if Self::remove_min_size_violating_modules(
           module_group_key,
           compilation,
           module_group,
           cache_group,
         ) {
-          Some(module_group_key.clone())
-        } else {
-          None
+          return Some(module_group_key.clone());
         }
+
+        if !Self::fit_min_size_reduction(module_group_key, module_group, cache_group) {
+          return Some(module_group_key.clone());
+        }
+
+        None
       })
       .collect::<Vec<_>>();
 
     invalidated_module_groups.into_iter().for_each(|key| {
       tracing::debug!(
-        "ModuleGroup({}) is removed. Reason: empty modules cause by `minSize` checking",
+        "ModuleGroup({}) is removed. Reason: empty modules or too small size reduction cause by `minSize`/`minSizeReduction` checking",
         key,
       );
       module_group_map.remove(&key);
@@ -99,7 +99,13 @@ impl SplitChunksPlugin {
 
       let mut used_chunks = Cow::Borrowed(&module_group.chunks);
 
-      self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      // `ModuleGroup`s reaching `enforce_size_threshold` ignore `max_*_requests` and `min_remaining_size`
+      let enforced = cache_group.enforce_size_threshold.has_non_zero_size()
+        && module_group.sizes.fit_min_size(&cache_group.enforce_size_threshold);
+
+      if !enforced {
+        self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      }
 
       if used_chunks.len() != module_group.chunks.len() {
         // There are some chunks removed by `ensure_max_request_fit`
@@ -116,13 +122,42 @@ impl SplitChunksPlugin {
         }
       }
 
-      if !cache_group.max_initial_size.is_empty() || !cache_group.max_async_size.is_empty() {
+      if !enforced
+        && used_chunks.len() == 1
+        && cache_group.min_remaining_size.has_non_zero_size()
+        && let Some(chunk) = used_chunks.iter().next().copied()
+      {
+        if let Some(violating_source_types) = Self::get_min_remaining_size_violating_source_types(
+          &module_group_key,
+          compilation,
+          &module_group,
+          cache_group,
+          &chunk,
+        ) {
+          let old_modules_len = module_group.modules.len();
+          Self::remove_modules_with_source_types(
+            compilation,
+            &mut module_group,
+            &violating_source_types,
+          );
+          if !module_group.modules.is_empty() && module_group.modules.len() != old_modules_len {
+            // Process this `ModuleGroup` again without the violating modules
+            module_group_map.insert(module_group_key.clone(), module_group);
+          }
+          return;
+        }
+      }
+
+      if !cache_group.max_initial_size.is_empty()
+        || !cache_group.max_async_size.is_empty()
+        || !cache_group.max_size.is_empty()
+      {
         max_size_setting_map.insert(
           new_chunk,
           MaxSizeSetting {
             min_size: cache_group.min_size.clone(),
-            max_async_size: cache_group.max_async_size.clone(),
-            max_initial_size: cache_group.max_initial_size.clone(),
+            max_async_size: cache_group.max_async_size.clone().merge(&cache_group.max_size),
+            max_initial_size: cache_group.max_initial_size.clone().merge(&cache_group.max_size),
             automatic_name_delimiter: cache_group.automatic_name_delimiter.clone(),
           },
         );
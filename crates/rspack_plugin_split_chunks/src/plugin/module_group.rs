@@ -3,8 +3,12 @@ use std::hash::{BuildHasherDefault, Hash, Hasher};
 
 use dashmap::DashMap;
 use rayon::prelude::*;
-use rspack_core::{Chunk, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph};
+use rspack_core::{
+  Chunk, ChunkByUkey, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph, ModuleIdentifier,
+  UsageKey,
+};
 use rspack_error::Result;
+use rspack_identifier::IdentifierMap;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
 use super::ModuleGroupMap;
@@ -88,30 +92,51 @@ impl SplitChunksPlugin {
     let (chunk_sets_in_graph, chunk_sets_by_count) =
       { Self::prepare_combination_maps(&module_graph, &compilation.chunk_graph) };
 
-    let combinations_cache =
-      DashMap::<ChunksKey, Vec<FxHashSet<ChunkUkey>>, ChunksKeyHashBuilder>::default();
-
-    let get_combination = |chunks_key: ChunksKey| match combinations_cache.entry(chunks_key) {
-      dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().clone(),
-      dashmap::mapref::entry::Entry::Vacant(entry) => {
-        let chunks_set = chunk_sets_in_graph
-          .get(&chunks_key)
-          .expect("This should never happen, please file an issue");
-        let mut result = vec![chunks_set.clone()];
-
-        for (count, array_of_set) in &chunk_sets_by_count {
-          if *count < chunks_set.len() {
-            for set in array_of_set {
-              if set.is_subset(chunks_set) {
-                result.push(set.clone());
-              }
-            }
-          }
-        }
+    let combinations_cache = CombinationsCache::default();
+
+    let get_combination = |chunks_key: ChunksKey| {
+      get_chunk_set_combination(
+        chunks_key,
+        &combinations_cache,
+        &chunk_sets_in_graph,
+        &chunk_sets_by_count,
+      )
+    };
 
-        entry.insert(result.clone());
-        result
+    // chunks of each module grouped by the usage of the module's exports in their runtimes,
+    // only needed by `CacheGroup`s with `used_exports`
+    let (chunks_grouped_by_exports, exports_chunk_sets_in_graph, exports_chunk_sets_by_count) =
+      if self
+        .cache_groups
+        .iter()
+        .any(|cache_group| cache_group.used_exports)
+      {
+        Self::prepare_exports_combination_maps(&module_graph, &compilation.chunk_graph, chunk_db)
+      } else {
+        Default::default()
+      };
+
+    let exports_combinations_cache = CombinationsCache::default();
+
+    let get_exports_combination = |module: ModuleIdentifier| {
+      let mut result = FxHashMap::<ChunksKey, FxHashSet<ChunkUkey>>::default();
+      for chunks in chunks_grouped_by_exports
+        .get(&module)
+        .map(|groups| groups.as_slice())
+        .unwrap_or_default()
+      {
+        for combination in get_chunk_set_combination(
+          Self::get_key(chunks.iter()),
+          &exports_combinations_cache,
+          &exports_chunk_sets_in_graph,
+          &exports_chunk_sets_by_count,
+        ) {
+          result
+            .entry(Self::get_key(combination.iter()))
+            .or_insert(combination);
+        }
       }
+      result.into_values().collect::<Vec<_>>()
     };
 
     module_graph.modules().values().par_bridge().map(|module| {
@@ -142,14 +167,19 @@ impl SplitChunksPlugin {
           CacheGroupTest::Enabled => true,
         };
         let is_match_the_type: bool = (cache_group.r#type)(module);
-        let is_match = is_match_the_test && is_match_the_type;
+        // Filter by `splitChunks.cacheGroups.{cacheGroup}.layer`
+        let is_match_the_layer = cache_group
+          .layer
+          .test(module.get_layer().map(|layer| layer.as_str()))?;
+        let is_match = is_match_the_test && is_match_the_type && is_match_the_layer;
         if !is_match {
           tracing::trace!(
-            "Module({:?}) is ignored by CacheGroup({:?}). Reason: !(is_match_the_test({:?}) && is_match_the_type({:?}))",
+            "Module({:?}) is ignored by CacheGroup({:?}). Reason: !(is_match_the_test({:?}) && is_match_the_type({:?}) && is_match_the_layer({:?}))",
             module.identifier(),
             cache_group.key,
             is_match_the_test,
-            is_match_the_type
+            is_match_the_type,
+            is_match_the_layer
           );
         }
 
@@ -165,7 +195,12 @@ impl SplitChunksPlugin {
         .filter(|(index, _)| temp[*index].1);
 
       for (cache_group_index, (idx, cache_group)) in filtered.enumerate() {
-        let combs = get_combination(chunks_key);
+        // Filter by `splitChunks.cacheGroups.{cacheGroup}.usedExports`
+        let combs = if cache_group.used_exports {
+          get_exports_combination(module.identifier())
+        } else {
+          get_combination(chunks_key)
+        };
 
         for chunk_combination in combs {
           // Filter by `splitChunks.cacheGroups.{cacheGroup}.minChunks`
@@ -370,6 +405,12 @@ impl SplitChunksPlugin {
           return Some(key.clone());
         }
 
+        // Validate `min_size_reduction` again
+        if !Self::fit_min_size_reduction(key, other_module_group, cache_group) {
+          tracing::trace!("{key} is deleted for violating min_size_reduction");
+          return Some(key.clone());
+        }
+
         None
       })
       .collect::<Vec<_>>();
@@ -423,4 +464,83 @@ impl SplitChunksPlugin {
 
     (chunk_sets_in_graph, chunk_sets_by_count)
   }
+
+  #[allow(clippy::type_complexity)]
+  fn prepare_exports_combination_maps(
+    module_graph: &ModuleGraph,
+    chunk_graph: &ChunkGraph,
+    chunk_db: &ChunkByUkey,
+  ) -> (
+    IdentifierMap<Vec<FxHashSet<ChunkUkey>>>,
+    HashMap<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>,
+    FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
+  ) {
+    let mut chunks_grouped_by_exports = IdentifierMap::default();
+    let mut chunk_sets_in_graph =
+      HashMap::<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>::default();
+
+    for module in module_graph.modules().keys() {
+      let exports_info = module_graph.get_exports_info(module);
+      let mut grouped = FxHashMap::<UsageKey, FxHashSet<ChunkUkey>>::default();
+      for chunk in chunk_graph.get_module_chunks(*module) {
+        let runtime = &chunk_db.expect_get(chunk).runtime;
+        grouped
+          .entry(exports_info.get_usage_key(Some(runtime), module_graph))
+          .or_default()
+          .insert(*chunk);
+      }
+      let groups = grouped.into_values().collect::<Vec<_>>();
+      for chunks in &groups {
+        chunk_sets_in_graph.insert(Self::get_key(chunks.iter()), chunks.clone());
+      }
+      chunks_grouped_by_exports.insert(*module, groups);
+    }
+
+    let mut chunk_sets_by_count = FxHashMap::<usize, Vec<FxHashSet<ChunkUkey>>>::default();
+    for chunks in chunk_sets_in_graph.values() {
+      chunk_sets_by_count
+        .entry(chunks.len())
+        .or_default()
+        .push(chunks.clone());
+    }
+
+    (
+      chunks_grouped_by_exports,
+      chunk_sets_in_graph,
+      chunk_sets_by_count,
+    )
+  }
+}
+
+type CombinationsCache = DashMap<ChunksKey, Vec<FxHashSet<ChunkUkey>>, ChunksKeyHashBuilder>;
+
+/// Return the chunk set of `chunks_key` and all the chunk sets in graph which are its subsets.
+fn get_chunk_set_combination(
+  chunks_key: ChunksKey,
+  combinations_cache: &CombinationsCache,
+  chunk_sets_in_graph: &HashMap<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>,
+  chunk_sets_by_count: &FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
+) -> Vec<FxHashSet<ChunkUkey>> {
+  match combinations_cache.entry(chunks_key) {
+    dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().clone(),
+    dashmap::mapref::entry::Entry::Vacant(entry) => {
+      let chunks_set = chunk_sets_in_graph
+        .get(&chunks_key)
+        .expect("This should never happen, please file an issue");
+      let mut result = vec![chunks_set.clone()];
+
+      for (count, array_of_set) in chunk_sets_by_count {
+        if *count < chunks_set.len() {
+          for set in array_of_set {
+            if set.is_subset(chunks_set) {
+              result.push(set.clone());
+            }
+          }
+        }
+      }
+
+      entry.insert(result.clone());
+      result
+    }
+  }
 }
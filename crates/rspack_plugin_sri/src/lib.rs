@@ -0,0 +1,308 @@
+#![feature(let_chains)]
+
+mod runtime;
+
+use std::str::FromStr;
+
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, ChunkUkey, Compilation, CompilationProcessAssets,
+  CompilationRuntimeRequirementInTree, CompilerOptions, Plugin, PluginContext, RuntimeGlobals,
+  SourceType,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashMap as HashMap;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::runtime::SriHashesRuntimeModule;
+
+const PLACEHOLDER_PREFIX: &str = "*-*-*-CHUNK-SRI-HASH-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubresourceIntegrityHashFunction {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl FromStr for SubresourceIntegrityHashFunction {
+  type Err = rspack_error::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "sha256" => Ok(Self::Sha256),
+      "sha384" => Ok(Self::Sha384),
+      "sha512" => Ok(Self::Sha512),
+      _ => Err(error!(
+        "Expect hash function of SubresourceIntegrityPlugin to be 'sha256', 'sha384' or 'sha512', got '{s}'"
+      )),
+    }
+  }
+}
+
+impl SubresourceIntegrityHashFunction {
+  fn digest(&self, content: &[u8]) -> String {
+    match self {
+      Self::Sha256 => format!(
+        "sha256-{}",
+        rspack_base64::encode_to_string(Sha256::digest(content))
+      ),
+      Self::Sha384 => format!(
+        "sha384-{}",
+        rspack_base64::encode_to_string(Sha384::digest(content))
+      ),
+      Self::Sha512 => format!(
+        "sha512-{}",
+        rspack_base64::encode_to_string(Sha512::digest(content))
+      ),
+    }
+  }
+}
+
+/// The integrity metadata of `content`, one digest per hash function.
+pub fn compute_integrity(
+  hash_func_names: &[SubresourceIntegrityHashFunction],
+  content: &[u8],
+) -> String {
+  hash_func_names
+    .iter()
+    .map(|hash_func| hash_func.digest(content))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// A placeholder with the same length as the integrity of the file, so that
+/// replacing it keeps the offsets of the source maps valid.
+fn make_placeholder(
+  hash_func_names: &[SubresourceIntegrityHashFunction],
+  file_type: &str,
+  chunk_id: &str,
+) -> String {
+  let filler = compute_integrity(
+    hash_func_names,
+    format!("{file_type}-{chunk_id}").as_bytes(),
+  );
+  format!(
+    "{PLACEHOLDER_PREFIX}{}",
+    &filler[PLACEHOLDER_PREFIX.len()..]
+  )
+}
+
+/// The types of files a chunk emits, that can be loaded by the runtime.
+fn chunk_file_types(chunk_ukey: &ChunkUkey, compilation: &Compilation) -> Vec<&'static str> {
+  let module_graph = compilation.get_module_graph();
+  let mut has_js = false;
+  let mut has_css = false;
+  for module in compilation
+    .chunk_graph
+    .get_chunk_modules(chunk_ukey, &module_graph)
+  {
+    for source_type in module.source_types() {
+      match source_type {
+        SourceType::JavaScript => has_js = true,
+        SourceType::Css => has_css = true,
+        SourceType::Custom(source_type) if source_type.starts_with("css/") => has_css = true,
+        _ => {}
+      }
+    }
+  }
+  let mut file_types = vec![];
+  if has_js {
+    file_types.push("js");
+  }
+  if has_css {
+    file_types.push("css");
+  }
+  file_types
+}
+
+fn get_file_type(filename: &str) -> Option<&'static str> {
+  let filename = filename.split(['?', '#']).next().unwrap_or(filename);
+  if filename.ends_with(".js") || filename.ends_with(".mjs") || filename.ends_with(".cjs") {
+    Some("js")
+  } else if filename.ends_with(".css") {
+    Some("css")
+  } else {
+    None
+  }
+}
+
+#[derive(Debug)]
+pub struct SubresourceIntegrityPluginOptions {
+  pub hash_func_names: Vec<SubresourceIntegrityHashFunction>,
+}
+
+/// Computes the subresource integrity of the chunk files and stores it in
+/// [rspack_core::AssetInfo::integrity], async chunks loaded by the runtime
+/// get `integrity` and `crossOrigin` set on their script and link tags.
+#[plugin]
+#[derive(Debug)]
+pub struct SubresourceIntegrityPlugin {
+  options: SubresourceIntegrityPluginOptions,
+}
+
+impl SubresourceIntegrityPlugin {
+  pub fn new(options: SubresourceIntegrityPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for SubresourceIntegrityPlugin)]
+fn runtime_requirement_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
+    runtime_requirements_mut.insert(RuntimeGlobals::SRI_HASHES);
+  }
+  if runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+    compilation.add_runtime_module(
+      chunk_ukey,
+      Box::new(SriHashesRuntimeModule::new(
+        self.options.hash_func_names.clone(),
+      )),
+    )?;
+  }
+  Ok(None)
+}
+
+/// Runs after RealContentHashPlugin, so that the embedded digests are computed from the final
+/// content of the files.
+#[plugin_hook(CompilationProcessAssets for SubresourceIntegrityPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_HASH + 1)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let hash_func_names = &self.options.hash_func_names;
+
+  // placeholder -> file it stands for
+  let mut placeholders = HashMap::default();
+  let mut files = vec![];
+  for chunk in compilation.chunk_by_ukey.values() {
+    for file in &chunk.files {
+      let Some(file_type) = get_file_type(file) else {
+        continue;
+      };
+      let Some(asset) = compilation.assets().get(file) else {
+        continue;
+      };
+      if asset.info.hot_module_replacement {
+        continue;
+      }
+      if let Some(id) = &chunk.id {
+        placeholders.insert(
+          make_placeholder(hash_func_names, file_type, id),
+          file.clone(),
+        );
+      }
+      files.push(file.clone());
+    }
+  }
+  files.sort();
+  files.dedup();
+
+  // a file can only be hashed once the placeholders of the files it loads are replaced
+  let mut references = HashMap::default();
+  for file in &files {
+    let Some(source) = compilation
+      .assets()
+      .get(file)
+      .and_then(|asset| asset.get_source())
+    else {
+      continue;
+    };
+    let content = source.source();
+    if !content.contains(PLACEHOLDER_PREFIX) {
+      continue;
+    }
+    let referenced = placeholders
+      .iter()
+      .filter(|(placeholder, referenced)| *referenced != file && content.contains(*placeholder))
+      .map(|(placeholder, referenced)| (placeholder.clone(), referenced.clone()))
+      .collect::<Vec<_>>();
+    references.insert(file.clone(), referenced);
+  }
+
+  let mut warnings = vec![];
+  let mut integrities: HashMap<String, String> = HashMap::default();
+  let mut pending = files;
+  while !pending.is_empty() {
+    let (ready, rest): (Vec<_>, Vec<_>) = pending.into_iter().partition(|file| {
+      references.get(file).map_or(true, |referenced| {
+        referenced
+          .iter()
+          .all(|(_, referenced)| integrities.contains_key(referenced))
+      })
+    });
+    // files loading each other are hashed one after another, the placeholders of the files
+    // not hashed yet are dropped
+    let (ready, rest) = if ready.is_empty() {
+      (rest, vec![])
+    } else {
+      (ready, rest)
+    };
+    for file in ready {
+      let Some(asset) = compilation.assets_mut().get_mut(&file) else {
+        continue;
+      };
+      if let Some(referenced) = references.get(&file)
+        && let Some(source) = asset.get_source()
+      {
+        let mut content = source.source().to_string();
+        for (placeholder, referenced) in referenced {
+          let replacement = if let Some(integrity) = integrities.get(referenced) {
+            format!("\"{integrity}\"")
+          } else {
+            warnings.push(format!(
+              "The integrity of {referenced} is not embedded in {file}, since {file} and {referenced} load each other"
+            ));
+            // keeps the length, the runtime loads the file without integrity
+            format!("{:<width$}", "null", width = placeholder.len() + 2)
+          };
+          content = content.replace(&format!("\"{placeholder}\""), &replacement);
+        }
+        asset.set_source(Some(RawSource::from(content).boxed()));
+      }
+      let Some(source) = asset.get_source() else {
+        continue;
+      };
+      let integrity = compute_integrity(hash_func_names, &source.buffer());
+      asset.info.integrity = Some(integrity.clone());
+      integrities.insert(file, integrity);
+    }
+    pending = rest;
+  }
+
+  for warning in warnings {
+    compilation.push_diagnostic(Diagnostic::warn(
+      "SubresourceIntegrityPlugin".to_string(),
+      warning,
+    ));
+  }
+  Ok(())
+}
+
+impl Plugin for SubresourceIntegrityPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SubresourceIntegrityPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirement_in_tree::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_util::{json_stringify, source_map::SourceMapKind};
+
+use crate::{chunk_file_types, make_placeholder, SubresourceIntegrityHashFunction};
+
+/// Defines [RuntimeGlobals::SRI_HASHES] with a placeholder for every file of
+/// the async chunks, the placeholders are replaced with the digests once the
+/// assets are final.
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct SriHashesRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+  hash_func_names: Vec<SubresourceIntegrityHashFunction>,
+}
+
+impl SriHashesRuntimeModule {
+  pub fn new(hash_func_names: Vec<SubresourceIntegrityHashFunction>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/sri_hashes"),
+      chunk: None,
+      hash_func_names,
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for SriHashesRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk_ukey = self.chunk.expect("should attach chunk");
+    let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+    let mut hashes: BTreeMap<&str, BTreeMap<&str, String>> =
+      BTreeMap::from([("js", BTreeMap::new()), ("css", BTreeMap::new())]);
+    for async_chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let async_chunk = compilation.chunk_by_ukey.expect_get(&async_chunk_ukey);
+      let Some(id) = &async_chunk.id else {
+        continue;
+      };
+      for file_type in chunk_file_types(&async_chunk_ukey, compilation) {
+        hashes
+          .entry(file_type)
+          .or_default()
+          .insert(id, make_placeholder(&self.hash_func_names, file_type, id));
+      }
+    }
+    Ok(
+      RawSource::from(format!(
+        "{} = {};\n",
+        RuntimeGlobals::SRI_HASHES,
+        json_stringify(&hashes)
+      ))
+      .boxed(),
+    )
+  }
+}
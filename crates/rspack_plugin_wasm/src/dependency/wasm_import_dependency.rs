@@ -3,6 +3,7 @@ use rspack_core::{
   DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
 };
 use swc_core::ecma::atoms::Atom;
+use wasmparser::FuncType;
 
 use crate::WasmNode;
 
@@ -11,27 +12,40 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  /// the reason why this import can only come from another wasm module
+  only_direct_import: Option<String>,
   /// the WASM AST node
   pub desc: WasmNode,
+  /// the signature of an imported function
+  pub signature: Option<FuncType>,
 
   span: Option<ErrorSpan>,
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(
+    request: String,
+    name: String,
+    desc: WasmNode,
+    signature: Option<FuncType>,
+    only_direct_import: Option<String>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      signature,
+      only_direct_import,
       span: None,
     }
   }
   pub fn name(&self) -> &str {
     &self.name
   }
+  pub fn only_direct_import(&self) -> Option<&str> {
+    self.only_direct_import.as_deref()
+  }
 }
 
 impl Dependency for WasmImportDependency {
@@ -5,6 +5,9 @@ mod dependency;
 mod loading_plugin;
 mod parser_and_generator;
 mod runtime;
+mod sync_parser_and_generator;
+mod sync_wasm_plugin;
+mod wasm_finalize_exports_plugin;
 mod wasm_plugin;
 
 pub use ast::*;
@@ -12,6 +15,9 @@ pub use loading_plugin::*;
 pub use parser_and_generator::*;
 use rspack_core::AssetInfo;
 pub use runtime::*;
+pub use sync_parser_and_generator::*;
+pub use sync_wasm_plugin::*;
+pub use wasm_finalize_exports_plugin::*;
 pub use wasm_plugin::*;
 
 // TODO(ahabhgk): remove this
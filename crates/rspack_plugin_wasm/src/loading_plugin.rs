@@ -1,17 +1,166 @@
 use rspack_core::{
-  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginContext,
-  PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoadingType,
+  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, ModuleType, Plugin,
+  PluginContext, PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoading, WasmLoadingType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin::default().boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin::default().boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::default().boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed())
+    }
+    // Registered with `ApplyContext::register_wasm_loading_type` by the plugin
+    // providing its runtime, entries using it fail to build otherwise.
+    WasmLoadingType::Custom(_) => {}
+  }
+}
+
+/// Whether wasm of the chunk is loaded with `expected`, plugins providing
+/// wasm loading runtime should only add it when this is true. The wasm loading
+/// of the chunk's entry takes precedence over `output.wasmLoading`.
+pub fn is_wasm_loading_enabled(
+  expected: &WasmLoadingType,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> bool {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  let wasm_loading = chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.wasm_loading.as_ref())
+    .unwrap_or(&compilation.options.output.wasm_loading);
+  matches!(wasm_loading, WasmLoading::Enable(ty) if ty == expected)
+}
+
+/// Whether the chunk or its async chunks contain `webassembly/sync` modules,
+/// which are instantiated while loading chunks.
+fn has_sync_wasm_in_graph(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> bool {
+  let module_graph = compilation.get_module_graph();
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  std::iter::once(*chunk_ukey)
+    .chain(chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey))
+    .any(|chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk, &module_graph)
+        .into_iter()
+        .any(|m| *m.module_type() == ModuleType::WasmSync)
+    })
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && is_wasm_loading_enabled(&WasmLoadingType::Fetch, compilation, chunk_ukey)
+    && has_sync_wasm_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(
+        self,
+      ));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct ReadFileCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && is_wasm_loading_enabled(&WasmLoadingType::AsyncNode, compilation, chunk_ukey)
+    && has_sync_wasm_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        include_str!("runtime/read_file_compile_async_wasm.js").to_string(),
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
   }
 }
 
@@ -27,7 +176,9 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM)
+    && is_wasm_loading_enabled(&WasmLoadingType::Fetch, compilation, chunk_ukey)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
@@ -82,7 +233,14 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  let wasm_loading_type = if self.import {
+    WasmLoadingType::AsyncNodeModule
+  } else {
+    WasmLoadingType::AsyncNode
+  };
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM)
+    && is_wasm_loading_enabled(&wasm_loading_type, compilation, chunk_ukey)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
@@ -1,4 +1,4 @@
-use std::collections::hash_map::DefaultHasher;
+use std::collections::{hash_map::DefaultHasher, BTreeMap};
 use std::hash::{Hash, Hasher};
 
 use indexmap::IndexMap;
@@ -14,7 +14,9 @@ use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnostic
 use rspack_identifier::Identifier;
 use rspack_util::infallible::ResultInfallibleExt as _;
 use swc_core::atoms::Atom;
-use wasmparser::{Import, Parser, Payload};
+use wasmparser::{
+  CompositeType, ExternalKind, FuncType, Import, Parser, Payload, TypeRef, ValType,
+};
 
 use crate::dependency::WasmImportDependency;
 use crate::ModuleIdToFileName;
@@ -38,51 +40,12 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
 
     let source = parse_context.source;
 
-    let mut exports = Vec::with_capacity(1);
-    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
-    let mut diagnostic = Vec::with_capacity(1);
-
-    for payload in Parser::new(0).parse_all(&source.buffer()) {
-      match payload {
-        Ok(payload) => match payload {
-          Payload::ExportSection(s) => {
-            for export in s {
-              match export {
-                Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                )),
-              };
-            }
-          }
-          Payload::ImportSection(s) => {
-            for import in s {
-              match import {
-                Ok(Import { module, name, ty }) => {
-                  dependencies.push(Box::new(WasmImportDependency::new(
-                    module.into(),
-                    name.into(),
-                    ty,
-                  )));
-                }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                )),
-              }
-            }
-          }
-          _ => {}
-        },
-        Err(err) => {
-          diagnostic.push(Diagnostic::error(
-            "Wasm Parse Error".into(),
-            err.to_string(),
-          ));
-        }
-      }
-    }
+    let ParsedWasm {
+      exports,
+      mut dependencies,
+      diagnostic,
+      ..
+    } = parse_wasm(&source.buffer());
 
     dependencies.push(Box::new(StaticExportsDependency::new(
       StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
@@ -295,7 +258,140 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+pub(crate) struct ParsedWasm {
+  pub exports: Vec<String>,
+  pub dependencies: Vec<BoxDependency>,
+  /// exports which can't be used from javascript, see [get_js_incompatible_type]
+  pub js_incompatible_exports: BTreeMap<String, String>,
+  pub diagnostic: Vec<Diagnostic>,
+}
+
+pub(crate) fn parse_wasm(buffer: &[u8]) -> ParsedWasm {
+  let mut exports = Vec::with_capacity(1);
+  let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+  let mut js_incompatible_exports = BTreeMap::new();
+  let mut diagnostic = Vec::with_capacity(1);
+
+  // signatures in the type section, and the type index of each function
+  let mut types: Vec<Option<FuncType>> = vec![];
+  let mut func_types: Vec<u32> = vec![];
+
+  for payload in Parser::new(0).parse_all(buffer) {
+    match payload {
+      Ok(payload) => match payload {
+        Payload::TypeSection(s) => {
+          for rec_group in s {
+            match rec_group {
+              Ok(rec_group) => {
+                types.extend(rec_group.into_types().map(|ty| match ty.composite_type {
+                  CompositeType::Func(func_type) => Some(func_type),
+                  _ => None,
+                }))
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Type Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        Payload::FunctionSection(s) => {
+          for type_index in s {
+            match type_index {
+              Ok(type_index) => func_types.push(type_index),
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Function Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        Payload::ExportSection(s) => {
+          for export in s {
+            match export {
+              Ok(export) => {
+                if export.kind == ExternalKind::Func
+                  && let Some(incompatible_type) = func_types
+                    .get(export.index as usize)
+                    .and_then(|type_index| types.get(*type_index as usize))
+                    .and_then(|ty| ty.as_ref())
+                    .and_then(get_js_incompatible_type)
+                {
+                  js_incompatible_exports.insert(export.name.to_string(), incompatible_type);
+                }
+                exports.push(export.name.to_string())
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Export Parse Error".into(),
+                err.to_string(),
+              )),
+            };
+          }
+        }
+        Payload::ImportSection(s) => {
+          for import in s {
+            match import {
+              Ok(Import { module, name, ty }) => {
+                let mut signature = None;
+                let only_direct_import = match ty {
+                  TypeRef::Func(type_index) => {
+                    func_types.push(type_index);
+                    signature = types.get(type_index as usize).cloned().flatten();
+                    signature
+                      .as_ref()
+                      .and_then(get_js_incompatible_type)
+                      .map(|ty| format!("Non-JS-compatible Func Signature ({ty})"))
+                  }
+                  TypeRef::Table(_) => Some("Table".to_string()),
+                  TypeRef::Memory(_) => Some("Memory".to_string()),
+                  TypeRef::Global(_) => Some("Global".to_string()),
+                  TypeRef::Tag(_) => Some("Tag".to_string()),
+                };
+                dependencies.push(Box::new(WasmImportDependency::new(
+                  module.into(),
+                  name.into(),
+                  ty,
+                  signature,
+                  only_direct_import,
+                )));
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Import Parse Error".into(),
+                err.to_string(),
+              )),
+            }
+          }
+        }
+        _ => {}
+      },
+      Err(err) => {
+        diagnostic.push(Diagnostic::error(
+          "Wasm Parse Error".into(),
+          err.to_string(),
+        ));
+      }
+    }
+  }
+
+  ParsedWasm {
+    exports,
+    dependencies,
+    js_incompatible_exports,
+    diagnostic,
+  }
+}
+
+/// i64 can't be passed between javascript and wasm without BigInt integration.
+fn get_js_incompatible_type(signature: &FuncType) -> Option<String> {
+  signature
+    .params()
+    .iter()
+    .chain(signature.results())
+    .any(|ty| *ty == ValType::I64)
+    .then(|| "i64".to_string())
+}
+
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &FilenameTemplate,
@@ -312,12 +408,12 @@ fn render_wasm_name(
     .always_ok()
 }
 
-fn render_import_stmt(import_var: &str, module_id: &str) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
@@ -1,12 +1,19 @@
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, BoxModule, ChunkUkey, Compilation, Module,
+  ModuleDependency, ModuleIdentifier, ModuleType, NormalModuleSource, PathData, RuntimeGlobals,
+  RuntimeModule, RuntimeModuleStage, RuntimeSpec, UsedName,
 };
 use rspack_identifier::Identifier;
 use rspack_util::infallible::ResultInfallibleExt as _;
 use rspack_util::source_map::SourceMapKind;
 
+use crate::dependency::WasmImportDependency;
+use crate::parser_and_generator::hash_for_source;
+
 #[impl_runtime_module]
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -38,29 +45,7 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
     self.id
   }
   fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
-    let (fake_filename, hash_len_map) =
-      get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
-
-    // Even use content hash when [hash] in webpack
-    let hash = match hash_len_map
-      .get("[contenthash]")
-      .or(hash_len_map.get("[hash]"))
-    {
-      Some(hash_len) => format!("\" + wasmModuleHash.slice(0, {}) + \"", hash_len),
-      None => "\" + wasmModuleHash + \"".to_string(),
-    };
-
-    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
-    let path = compilation
-      .get_path(
-        &fake_filename,
-        PathData::default()
-          .hash(&hash)
-          .content_hash(&hash)
-          .id("\" + wasmModuleId + \"")
-          .runtime(&chunk.runtime),
-      )
-      .always_ok();
+    let path = get_wasm_module_path(compilation, &self.chunk);
     Ok(
       RawSource::from(get_async_wasm_loading(
         &self
@@ -77,6 +62,34 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   }
 }
 
+/// The path of a wasm module, as a string expression of `wasmModuleId` and
+/// `wasmModuleHash`.
+fn get_wasm_module_path(compilation: &Compilation, chunk: &ChunkUkey) -> String {
+  let (fake_filename, hash_len_map) =
+    get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
+
+  // Even use content hash when [hash] in webpack
+  let hash = match hash_len_map
+    .get("[contenthash]")
+    .or(hash_len_map.get("[hash]"))
+  {
+    Some(hash_len) => format!("\" + wasmModuleHash.slice(0, {}) + \"", hash_len),
+    None => "\" + wasmModuleHash + \"".to_string(),
+  };
+
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  compilation
+    .get_path(
+      &fake_filename,
+      PathData::default()
+        .hash(&hash)
+        .content_hash(&hash)
+        .id("\" + wasmModuleId + \"")
+        .runtime(&chunk.runtime),
+    )
+    .always_ok()
+}
+
 fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
   let fallback_code = r#"
           .then(function(x) { return x.arrayBuffer();})
@@ -126,3 +139,307 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     )
   }
 }
+
+/// Loads and instantiates the `webassembly/sync` modules of a chunk while the
+/// chunk is being loaded.
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk,
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let module_graph = compilation.get_module_graph();
+    let chunk_graph = &compilation.chunk_graph;
+
+    let mut wasm_module_map: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut wasm_modules = IndexMap::<ModuleIdentifier, &BoxModule>::new();
+    for async_chunk in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let Some(chunk_id) = compilation
+        .chunk_by_ukey
+        .expect_get(&async_chunk)
+        .id
+        .as_deref()
+      else {
+        continue;
+      };
+      let module_ids = chunk_graph
+        .get_ordered_chunk_modules(&async_chunk, &module_graph)
+        .into_iter()
+        .filter(|m| *m.module_type() == ModuleType::WasmSync)
+        .filter_map(|m| {
+          wasm_modules.insert(m.identifier(), m);
+          chunk_graph.get_module_id(m.identifier()).as_deref()
+        })
+        .collect::<Vec<_>>();
+      if !module_ids.is_empty() {
+        wasm_module_map.insert(chunk_id, module_ids);
+      }
+    }
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut wasm_module_hashes = BTreeMap::new();
+    for module in wasm_modules.values() {
+      let Some(module_id) = chunk_graph.get_module_id(module.identifier()).as_deref() else {
+        continue;
+      };
+      if let Some(NormalModuleSource::BuiltSucceed(source)) =
+        module.as_normal_module().map(|m| m.source())
+      {
+        wasm_module_hashes.insert(module_id, hash_for_source(source));
+      }
+      import_objects.push(generate_import_object(
+        compilation,
+        module.as_ref(),
+        module_id,
+        &chunk.runtime,
+        &mut declarations,
+      ));
+    }
+
+    let path = get_wasm_module_path(compilation, &self.chunk);
+    let load_binary_code = self
+      .generate_load_binary_code
+      .replace("$PATH", &format!("\"{}\"", path));
+    let load_binary_code = load_binary_code.trim_end_matches(';');
+
+    let instantiate_code = if self.supports_streaming {
+      r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+        promise = WebAssembly.instantiateStreaming(req, importObject);"#
+    } else {
+      r#"if(importObject && typeof importObject.then === 'function') {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = Promise.all([
+          bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+          importObject
+        ]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });"#
+    };
+
+    Ok(
+      RawSource::from(format!(
+        r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {wasm_module_map};
+var wasmModuleHashes = {wasm_module_hashes};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var wasmModuleHash = wasmModuleHashes[wasmModuleId];
+      var req = {load_binary_code};
+      var promise;
+      {instantiate_code}
+      }} else {{
+        var bytesPromise = req.then(function(x) {{ return x.arrayBuffer(); }});
+        promise = bytesPromise.then(function(bytes) {{
+          return WebAssembly.instantiate(bytes, importObject);
+        }});
+      }}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+        declarations = declarations.join("\n"),
+        import_objects = import_objects.join("\n"),
+        wasm_module_map = serde_json::to_string(&wasm_module_map).expect("should be ok"),
+        wasm_module_hashes = serde_json::to_string(&wasm_module_hashes).expect("should be ok"),
+        wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+        ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      ))
+      .boxed(),
+    )
+  }
+}
+
+/// Generate the factory of the import object of a wasm module, imports from
+/// other wasm modules wait for their instances, and imported functions of
+/// javascript modules are looked up lazily from the module cache.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &dyn Module,
+  module_id: &str,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = compilation.get_module_graph();
+  let chunk_graph = &compilation.chunk_graph;
+
+  let mut wait_for_instances = IndexMap::<String, &str>::new();
+  let mut properties = IndexMap::<&str, Vec<String>>::new();
+  for dep_id in module.get_dependencies() {
+    let Some(dep) = module_graph
+      .dependency_by_id(dep_id)
+      .and_then(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+    else {
+      continue;
+    };
+    let Some(imported_module) = module_graph.get_module_by_dependency_id(dep_id) else {
+      continue;
+    };
+    let Some(imported_module_id) = chunk_graph
+      .get_module_id(imported_module.identifier())
+      .as_deref()
+    else {
+      continue;
+    };
+    let Some(UsedName::Str(used_name)) = module_graph
+      .get_exports_info(&imported_module.identifier())
+      .id
+      .get_used_name(
+        &module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      )
+    else {
+      continue;
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should be ok");
+
+    let value = if dep.only_direct_import().is_some() {
+      let instance_var = format!("m{}", wait_for_instances.len());
+      let value = format!("{instance_var}[{used_name}]");
+      wait_for_instances.insert(instance_var, imported_module_id);
+      value
+    } else {
+      let params = dep
+        .signature
+        .as_ref()
+        .map(|signature| {
+          signature
+            .params()
+            .iter()
+            .enumerate()
+            .map(|(index, ty)| format!("p{index}{ty}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+        })
+        .unwrap_or_default();
+      let imported_module_code = format!(
+        "{}[{}]",
+        RuntimeGlobals::MODULE_CACHE,
+        serde_json::to_string(imported_module_id).expect("should be ok")
+      );
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      let wasm_code = if imported_module.module_type().is_wasm_like() {
+        format!("{imported_module_code} ? {imported_module_code}.exports[{used_name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        r#"{wasm_code}function({params}) {{
+  if({cache} === undefined) {cache} = {imported_module_code}.exports;
+  return {cache}[{used_name}]({params});
+}}"#
+      )
+    };
+    properties.entry(dep.request()).or_default().push(format!(
+      "{}: {value}",
+      serde_json::to_string(dep.name()).expect("should be ok")
+    ));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties
+      .into_iter()
+      .map(|(request, properties)| format!(
+        "{}: {{\n{}\n}}",
+        serde_json::to_string(request).expect("should be ok"),
+        properties.join(",\n")
+      ))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+  let module_id = serde_json::to_string(module_id).expect("should be ok");
+  let installed = |id: &str| {
+    format!(
+      "installedWasmModules[{}]",
+      serde_json::to_string(id).expect("should be ok")
+    )
+  };
+
+  match wait_for_instances.len() {
+    0 => format!("{module_id}: function() {{\n{import_object}\n}},"),
+    1 => {
+      let (instance_var, id) = wait_for_instances
+        .first()
+        .expect("should have one instance");
+      format!(
+        "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return {}; }}).then(function({instance_var}) {{\n{import_object}\n}});\n}},",
+        installed(id)
+      )
+    }
+    _ => {
+      let promises = wait_for_instances
+        .values()
+        .map(|id| installed(id))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = wait_for_instances
+        .keys()
+        .enumerate()
+        .map(|(index, instance_var)| format!("{instance_var} = array[{index}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{\nvar {variables};\n{import_object}\n}});\n}},"
+      )
+    }
+  }
+}
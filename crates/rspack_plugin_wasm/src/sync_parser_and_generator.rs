@@ -0,0 +1,164 @@
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BuildMetaExportsType, GenerateContext, Module, ModuleIdentifier, ParseContext, ParseResult,
+  ParserAndGenerator, RuntimeGlobals, SourceType, StaticExportsDependency, StaticExportsSpec,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use swc_core::atoms::Atom;
+
+use crate::parser_and_generator::{
+  hash_for_source, parse_wasm, render_import_stmt, render_wasm_name, ParsedWasm, WASM_SOURCE_TYPE,
+};
+use crate::ModuleIdToFileName;
+
+/// Parser and generator of `webassembly/sync` modules, the wasm instance is
+/// created while loading the chunk, so the module itself can be required
+/// synchronously.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let ParsedWasm {
+      exports,
+      mut dependencies,
+      js_incompatible_exports,
+      diagnostic,
+    } = parse_wasm(&source.buffer());
+
+    parse_context.build_meta.js_incompatible_exports =
+      (!js_incompatible_exports.is_empty()).then_some(js_incompatible_exports);
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => {
+        40.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let hash = hash_for_source(source);
+    let wasm_path_with_info = render_wasm_name(
+      compilation,
+      normal_module,
+      &compilation.options.output.webassembly_module_filename,
+      &hash,
+    );
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = compilation.get_module_graph();
+        let chunk_graph = &compilation.chunk_graph;
+
+        // imported modules are executed before the wasm module, to keep the
+        // same order as esm
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        for dep_id in module.get_dependencies() {
+          let Some(dep) = module_graph.dependency_by_id(dep_id) else {
+            continue;
+          };
+          if dep.dependency_type() != &WasmImport {
+            continue;
+          }
+          let Some(mgm) = module_graph.module_graph_module_by_dependency_id(dep_id) else {
+            continue;
+          };
+          if !dep_modules.contains_key(&mgm.module_identifier) {
+            let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+            dep_modules.insert(mgm.module_identifier, (import_var, mgm.id(chunk_graph)));
+          }
+        }
+        if !dep_modules.is_empty() {
+          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        }
+
+        let imports_code = dep_modules
+          .values()
+          .map(|(import_var, module_id)| render_import_stmt(import_var, module_id))
+          .collect::<Vec<_>>()
+          .join("");
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[{}];
+// export exports from WebAssembly module
+module.exports = wasmExports;
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES,
+            RuntimeGlobals::MODULE_ID,
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<String> {
+    Some(String::from(
+      "Module Concatenation is not implemented for SyncWasmParserAndGenerator",
+    ))
+  }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, ChunkUkey, Compilation, CompilationParams, CompilationRenderManifest,
+  CompilerCompilation, CompilerOptions, DependencyType, ModuleType, ParserAndGenerator, Plugin,
+  PluginContext, RenderManifestEntry,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::{render_wasm_files, ModuleIdToFileName, SyncWasmParserAndGenerator};
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+#[plugin_hook(CompilerCompilation for SyncWasmPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationRenderManifest for SyncWasmPlugin)]
+async fn render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+    let module_graph = compilation.get_module_graph();
+    let mut wasm_modules = compilation
+      .chunk_graph
+      .get_chunk_modules(chunk_ukey, &module_graph)
+      .into_iter()
+      .filter(|m| *m.module_type() == ModuleType::WasmSync)
+      .map(|m| format!("* {}", m.readable_identifier(&compilation.options.context)))
+      .collect::<Vec<_>>();
+    if !wasm_modules.is_empty() {
+      wasm_modules.sort();
+      diagnostics.push(Diagnostic::error(
+        "Wasm In Initial Chunk Error".into(),
+        format!(
+          r#"WebAssembly module is included in initial chunk.
+This is not allowed, because WebAssembly download and compilation must happen asynchronous.
+Add an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module:
+{}"#,
+          wasm_modules.join("\n")
+        ),
+      ));
+    }
+  }
+
+  manifest.extend(render_wasm_files(
+    compilation,
+    chunk_ukey,
+    &ModuleType::WasmSync,
+    &self.module_id_to_filename_without_ext,
+  )?);
+
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .render_manifest
+      .tap(render_manifest::new(self));
+
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::WasmSync,
+      Box::new(move |_, _| {
+        Box::new({
+          SyncWasmParserAndGenerator {
+            module_id_to_filename: module_id_to_filename_without_ext.clone(),
+          }
+        }) as Box<dyn ParserAndGenerator>
+      }),
+    );
+
+    Ok(())
+  }
+}
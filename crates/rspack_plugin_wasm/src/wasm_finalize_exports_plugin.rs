@@ -0,0 +1,117 @@
+use rspack_core::{
+  ApplyContext, Compilation, CompilationFinishModules, CompilerOptions, ExtendedReferencedExport,
+  ModuleDependency, ModuleType, Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::dependency::WasmImportDependency;
+
+/// Reports usages of sync wasm imports and exports which can't cross the
+/// boundary between javascript and wasm.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct WasmFinalizeExportsPlugin;
+
+#[plugin_hook(CompilationFinishModules for WasmFinalizeExportsPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+  let mut diagnostics = vec![];
+
+  for module in module_graph.modules().values() {
+    if *module.module_type() != ModuleType::WasmSync {
+      continue;
+    }
+
+    for dep_id in module.get_dependencies() {
+      let Some(dep) = module_graph
+        .dependency_by_id(dep_id)
+        .and_then(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+      else {
+        continue;
+      };
+      let Some(only_direct_import) = dep.only_direct_import() else {
+        continue;
+      };
+      if let Some(imported_module) = module_graph.get_module_by_dependency_id(dep_id)
+        && !imported_module.module_type().is_wasm_like()
+      {
+        diagnostics.push(Diagnostic::error(
+          "Unsupported WebAssembly Feature Error".into(),
+          format!(
+            "Import \"{}\" from \"{}\" with {only_direct_import} can only be used for direct wasm to wasm dependencies\nIt's used from {}.",
+            dep.name(),
+            dep.request(),
+            module.readable_identifier(context),
+          ),
+        ));
+      }
+    }
+
+    let Some(js_incompatible_exports) = module
+      .build_meta()
+      .and_then(|meta| meta.js_incompatible_exports.as_ref())
+    else {
+      continue;
+    };
+    for connection in module_graph.get_incoming_connections(&module.identifier()) {
+      let Some(origin_module) = connection
+        .original_module_identifier
+        .and_then(|id| module_graph.module_by_identifier(&id))
+      else {
+        continue;
+      };
+      if origin_module.module_type().is_wasm_like() {
+        continue;
+      }
+      let Some(dep) = module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .and_then(|dep| dep.as_module_dependency())
+      else {
+        continue;
+      };
+      for referenced_export in dep.get_referenced_exports(&module_graph, None) {
+        let name = match &referenced_export {
+          ExtendedReferencedExport::Array(name) => name.first(),
+          ExtendedReferencedExport::Export(export) => export.name.first(),
+        };
+        let Some((name, ty)) =
+          name.and_then(|name| js_incompatible_exports.get_key_value(name.as_str()))
+        else {
+          continue;
+        };
+        diagnostics.push(Diagnostic::error(
+          "Unsupported WebAssembly Feature Error".into(),
+          format!(
+            "Export \"{name}\" with {ty} can only be used for direct wasm to wasm dependencies\nIt's used from {}.",
+            origin_module.readable_identifier(context),
+          ),
+        ));
+      }
+    }
+  }
+
+  drop(module_graph);
+  compilation.push_batch_diagnostic(diagnostics);
+  Ok(())
+}
+
+impl Plugin for WasmFinalizeExportsPlugin {
+  fn name(&self) -> &'static str {
+    "WasmFinalizeExportsPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
+    Ok(())
+  }
+}
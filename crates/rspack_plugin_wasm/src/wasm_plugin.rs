@@ -45,6 +45,23 @@ async fn render_manifest(
   manifest: &mut Vec<RenderManifestEntry>,
   _diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<()> {
+  manifest.extend(render_wasm_files(
+    compilation,
+    chunk_ukey,
+    &ModuleType::WasmAsync,
+    &self.module_id_to_filename_without_ext,
+  )?);
+
+  Ok(())
+}
+
+/// Emit the binaries of the wasm modules with `module_type` in the chunk.
+pub(crate) fn render_wasm_files(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module_type: &ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
+) -> Result<Vec<RenderManifestEntry>> {
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
   let module_graph = &compilation.get_module_graph();
 
@@ -54,15 +71,14 @@ async fn render_manifest(
 
   let files = ordered_modules
     .par_iter()
-    .filter(|m| *m.module_type() == ModuleType::WasmAsync)
+    .filter(|m| m.module_type() == module_type)
     .map(|m| {
       let code_gen_result = compilation
         .code_generation_results
         .get(&m.identifier(), Some(&chunk.runtime));
 
       let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-        let (output_path, asset_info) = self
-          .module_id_to_filename_without_ext
+        let (output_path, asset_info) = module_id_to_filename
           .get(&m.identifier())
           .map(|s| s.clone())
           .expect("should have wasm_filename");
@@ -75,9 +91,7 @@ async fn render_manifest(
     .into_iter()
     .flatten()
     .collect::<Vec<RenderManifestEntry>>();
-  manifest.extend(files);
-
-  Ok(())
+  Ok(files)
 }
 
 #[async_trait]
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::Range;
 
 use regex_syntax::hir::literal::ExtractKind;
 use regex_syntax::hir::{Hir, HirKind, Look};
@@ -97,6 +98,21 @@ impl Algo {
     }
   }
 
+  pub(crate) fn exec(&self, str: &str) -> Option<Vec<Option<Range<usize>>>> {
+    match self {
+      Algo::Regress(regex) => regex.find(str).map(|m| {
+        let mut groups = Vec::with_capacity(m.captures.len() + 1);
+        groups.push(Some(m.range()));
+        groups.extend(m.captures);
+        groups
+      }),
+      Algo::EndWith { pats } => pats
+        .iter()
+        .find(|pat| str.ends_with(pat.as_str()))
+        .map(|pat| vec![Some(str.len() - pat.len()..str.len())]),
+    }
+  }
+
   pub(crate) fn global(&self) -> bool {
     match self {
       Algo::Regress(reg) => reg.flags.contains('g'),
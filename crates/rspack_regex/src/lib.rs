@@ -1,6 +1,7 @@
 #![feature(let_chains)]
 
 use std::fmt::{Debug, Display};
+use std::ops::Range;
 
 use rspack_error::Error;
 use swc_core::ecma::ast::Regex as SwcRegex;
@@ -30,6 +31,12 @@ impl RspackRegex {
     self.algo.test(text)
   }
 
+  /// Returns the range of the whole match followed by the ranges of the capture groups,
+  /// like `RegExp.prototype.exec` in JavaScript.
+  pub fn exec(&self, text: &str) -> Option<Vec<Option<Range<usize>>>> {
+    self.algo.exec(text)
+  }
+
   pub fn global(&self) -> bool {
     self.algo.global()
   }
@@ -2,7 +2,7 @@ mod react;
 pub use react::ReactOptions;
 
 mod define;
-pub use define::{define, Define, RawDefine};
+pub use define::{Define, RawDefine};
 
 pub type Provide = std::collections::HashMap<String, Vec<String>>;
 
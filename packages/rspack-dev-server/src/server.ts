@@ -13,7 +13,6 @@ import type { Socket } from "net";
 import type { FSWatcher } from "chokidar";
 import rdm from "webpack-dev-middleware";
 import type { Server } from "http";
-import fs from "fs";
 import WebpackDevServer from "webpack-dev-server";
 import type { ResolvedDevServer, DevServer } from "./config";
 import { applyDevServerPatch } from "./patch";
@@ -215,57 +214,6 @@ export class RspackDevServer extends WebpackDevServer {
 		this.middleware = rdm(this.compiler, this.options.devMiddleware);
 	}
 
-	private override setupMiddlewares() {
-		const middlewares: WebpackDevServer.Middleware[] = [];
-		const compilers =
-			this.compiler instanceof MultiCompiler
-				? this.compiler.compilers
-				: [this.compiler];
-
-		compilers.forEach(compiler => {
-			if (compiler.options.experiments.lazyCompilation) {
-				middlewares.push({
-					// @ts-expect-error
-					middleware: (req, res) => {
-						if (req.url.indexOf("/lazy-compilation-web/") > -1) {
-							const path = req.url.replace("/lazy-compilation-web/", "");
-							if (fs.existsSync(path)) {
-								compiler.__internal__rebuild(
-									new Set([path]),
-									new Set(),
-									error => {
-										if (error) {
-											throw error;
-										}
-										res.write("");
-										res.end();
-										console.log("lazy compiler success");
-									}
-								);
-							}
-						}
-					}
-				});
-			}
-		});
-
-		middlewares.forEach(middleware => {
-			if (typeof middleware === "function") {
-				// @ts-expect-error
-				this.app.use(middleware);
-			} else if (typeof middleware.path !== "undefined") {
-				// @ts-expect-error
-				this.app.use(middleware.path, middleware.middleware);
-			} else {
-				// @ts-expect-error
-				this.app.use(middleware.middleware);
-			}
-		});
-
-		// @ts-expect-error
-		super.setupMiddlewares();
-	}
-
 	private override addAdditionalEntries(compiler: Compiler) {
 		const additionalEntries = [];
 		// @ts-expect-error
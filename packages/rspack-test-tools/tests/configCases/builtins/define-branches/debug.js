@@ -0,0 +1 @@
+module.exports = "debug module";
@@ -0,0 +1,37 @@
+const fs = require("fs");
+
+const source = () => fs.readFileSync(__filename, "utf-8");
+
+it("should replace typeof of the defined keys", () => {
+	if (typeof DEBUG !== "boolean") require("./other");
+	expect(typeof DEBUG).toBe("boolean");
+	expect(typeof FEATURE).toBe("string");
+	expect(typeof window).toBe("object");
+	expect(source()).not.toContain("other" + " module");
+});
+
+it("should define the properties of object values", () => {
+	if (process.env.DEBUG) require("./debug");
+	// the definitions of the user are kept over `optimization.nodeEnv`
+	expect(process.env.NODE_ENV).toBe("test");
+	expect(process.env).toEqual({ NODE_ENV: "test", DEBUG: false });
+	expect(source()).not.toContain("debug" + " module");
+});
+
+it("should keep assignments to the defined keys", () => {
+	function assign() {
+		ASSIGNED = false;
+	}
+	expect(typeof assign).toBe("function");
+	expect(ASSIGNED).toBe(true);
+	expect(source()).toContain("ASSIGNED" + " = false");
+});
+
+it("should drop the dead branches of logical expressions", () => {
+	BUILD_MODE !== "production" && require("./dev");
+	BUILD_MODE === "production" || require("./not-prod");
+	const prod = BUILD_MODE === "production" && require("./prod");
+	expect(prod).toBe("prod module");
+	expect(source()).not.toContain("dev" + " module");
+	expect(source()).not.toContain("not-prod" + " module");
+});
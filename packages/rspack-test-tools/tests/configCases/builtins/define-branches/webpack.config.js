@@ -0,0 +1,16 @@
+const { DefinePlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	plugins: [
+		new DefinePlugin({
+			DEBUG: "true",
+			FEATURE: "'feature'",
+			"typeof window": "'object'",
+			"process.env": { NODE_ENV: "'test'", DEBUG: false },
+			ASSIGNED: "true",
+			BUILD_MODE: "'production'"
+		})
+	]
+};
@@ -0,0 +1 @@
+module.exports = [[/Chunk loading type "custom" is not enabled/]];
@@ -0,0 +1,3 @@
+it("should not build entries with a chunk loading type nobody registered", () => {
+	throw new Error("should not run");
+});
@@ -0,0 +1,18 @@
+import * as style from "./style.module.css";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should substitute ICSS imported values", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+	expect(css).toContain("color: red");
+	expect(css).not.toContain(":import");
+});
+
+it("should export ICSS imported values", () => {
+	expect(style.buttonColor).toBe("red");
+	expect(style.button).toBe("button");
+});
@@ -0,0 +1,14 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[local]",
+				exportsOnly: false
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,30 @@
+require("./a.css");
+require("./d.css");
+const fs = require("fs");
+const path = require("path");
+
+// the removed `@import`s leave blank lines behind
+const css = () =>
+	fs
+		.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8")
+		.split("\n")
+		.filter(line => line)
+		.join("\n");
+
+it("should wrap the conditions of a module in the conditions of its issuer", () => {
+	const content = css();
+	expect(content).toContain(
+		"@layer base {\n@supports (display: grid) {\n@media screen {\n@media print {\n.c {"
+	);
+	expect(content).toContain(
+		"@layer base {\n@supports (display: grid) {\n@media screen {\n.b {"
+	);
+	expect(content).not.toContain("@media screen {\n.a {");
+});
+
+it("should render a module once for every set of import conditions", () => {
+	const content = css();
+	expect(content).toContain("@media screen {\n.e {");
+	expect(content).toContain("@media print {\n.e {");
+	expect(content.match(/\.e \{/g)).toHaveLength(3);
+});
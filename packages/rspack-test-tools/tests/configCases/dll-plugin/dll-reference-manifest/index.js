@@ -0,0 +1,3 @@
+it("should delegate modules found in the manifest file to the dll", () => {
+	expect(require("./dll-module").value).toBe("from dll");
+});
@@ -0,0 +1,48 @@
+const path = require("path");
+const { DllPlugin, DllReferencePlugin } = require("@rspack/core");
+
+const manifest = path.resolve(__dirname, "manifest.json");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			dll: ["./dll-module"]
+		},
+		output: {
+			filename: "dll.js",
+			library: {
+				type: "commonjs2"
+			}
+		},
+		optimization: {
+			moduleIds: "named"
+		},
+		plugins: [
+			new DllPlugin({
+				name: "dll_lib",
+				path: "dll-manifest.json"
+			})
+		]
+	},
+	{
+		entry: "./index",
+		output: {
+			filename: "main.js"
+		},
+		plugins: [
+			new DllReferencePlugin({ manifest }),
+			{
+				apply(compiler) {
+					compiler.hooks.afterCompile.tap("Test", compilation => {
+						if (!compilation.fileDependencies.has(manifest)) {
+							compilation.errors.push(
+								new Error("the manifest should be a file dependency")
+							);
+						}
+					});
+				}
+			}
+		]
+	}
+];
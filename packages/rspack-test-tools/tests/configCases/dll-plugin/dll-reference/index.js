@@ -0,0 +1,21 @@
+import fs from "fs";
+import path from "path";
+
+it("should delegate modules found in the manifest to the dll", () => {
+	expect(require("./dll-module").value).toBe("from dll");
+});
+
+it("should delegate scoped requests to the dll", () => {
+	expect(require("dll/dll-module").value).toBe("from dll");
+});
+
+it("should emit the manifest of the dll", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "dll-manifest.json"), "utf-8")
+	);
+	expect(manifest.name).toBe("dll_lib");
+	expect(manifest.content["./dll-module.js"]).toMatchObject({
+		id: "./dll-module.js",
+		exports: ["value"]
+	});
+});
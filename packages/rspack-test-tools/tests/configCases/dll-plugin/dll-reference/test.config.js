@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle(index) {
+		return index === 0 ? [] : ["main.js"];
+	}
+};
@@ -0,0 +1,53 @@
+const { DllPlugin, DllReferencePlugin } = require("@rspack/core");
+
+const content = {
+	"./dll-module.js": {
+		id: "./dll-module.js",
+		buildMeta: { exportsType: "namespace" },
+		exports: ["value"]
+	}
+};
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			dll: ["./dll-module"]
+		},
+		output: {
+			filename: "dll.js",
+			library: {
+				type: "commonjs2"
+			}
+		},
+		optimization: {
+			moduleIds: "named"
+		},
+		plugins: [
+			new DllPlugin({
+				name: "dll_lib",
+				path: "dll-manifest.json",
+				format: true
+			})
+		]
+	},
+	{
+		entry: "./index",
+		output: {
+			filename: "main.js"
+		},
+		plugins: [
+			new DllReferencePlugin({
+				name: "./dll.js",
+				sourceType: "commonjs2",
+				content
+			}),
+			new DllReferencePlugin({
+				name: "./dll.js",
+				sourceType: "commonjs2",
+				scope: "dll",
+				content
+			})
+		]
+	}
+];
@@ -0,0 +1,6 @@
+exports.keepAlive = function (options) {
+	(global.__lazyCompilationClients = global.__lazyCompilationClients || []).push(
+		{ data: options.data, active: options.active }
+	);
+	return function () {};
+};
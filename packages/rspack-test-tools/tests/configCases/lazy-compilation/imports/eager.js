@@ -0,0 +1 @@
+export default "eager module content";
@@ -0,0 +1,21 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should not proxy the modules filtered out by test", async () => {
+	const eager = await import(/* webpackChunkName: "eager" */ "./eager");
+	expect(eager.default).toBe("eager module content");
+});
+
+it("should replace the lazily compiled module with an inactive proxy", async () => {
+	import(/* webpackChunkName: "lazy" */ "./lazy");
+	await new Promise(resolve => setTimeout(resolve, 100));
+	const clients = global.__lazyCompilationClients;
+	expect(clients).toHaveLength(1);
+	expect(clients[0].active).toBe(false);
+	expect(clients[0].data).toMatch(/lazy\.js$/);
+	expect(clients[0].data).not.toMatch(/eager\.js/);
+	// the proxy only loads the client until the module is requested
+	const lazy = fs.readFileSync(path.resolve(__dirname, "lazy.chunk.js"), "utf-8");
+	expect(lazy).toContain("client.keepAlive");
+	expect(lazy).not.toContain("lazy module" + " content");
+});
@@ -0,0 +1,18 @@
+const path = require("path");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	mode: "development",
+	devtool: false,
+	output: {
+		chunkFilename: "[name].chunk.js"
+	},
+	experiments: {
+		lazyCompilation: {
+			entries: false,
+			test: /lazy\.js$/,
+			backend: {
+				client: path.resolve(__dirname, "client.js")
+			}
+		}
+	}
+});
@@ -0,0 +1,5 @@
+it("should load the async chunks", () =>
+	Promise.all([
+		import(/* webpackChunkName: "x" */ "./x.js"),
+		import(/* webpackChunkName: "y" */ "./y.js")
+	]));
@@ -0,0 +1,124 @@
+const crypto = require("crypto");
+const {
+	HashedModuleIdsPlugin,
+	OccurrenceModuleIdsPlugin
+} = require("@rspack/core");
+
+/** The ids of the modules by their readable identifier and of the chunks by their name. */
+const ids = stats => {
+	const { modules, chunks } = stats.toJson({
+		modules: true,
+		chunks: true,
+		ids: true
+	});
+	return {
+		modules: Object.fromEntries(
+			modules
+				.filter(module => module.name.startsWith("./"))
+				.map(module => [module.name, module.id])
+		),
+		chunks: Object.fromEntries(chunks.map(chunk => [chunk.names[0], chunk.id]))
+	};
+};
+
+// `shared.js` is in both async chunks, `index.js` is the only module of the initial chunk
+const config = (index, { moduleIds, chunkIds, plugins = [], check }) => ({
+	mode: "development",
+	output: {
+		chunkFilename: `[name].${index}.js`
+	},
+	optimization: { moduleIds, chunkIds },
+	plugins: [
+		...plugins,
+		compiler => {
+			compiler.hooks.done.tap("ids", stats => check(ids(stats)));
+		}
+	]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		moduleIds: "natural",
+		chunkIds: "natural",
+		check: ({ modules, chunks }) => {
+			// modules are numbered in the order they are first imported
+			expect(modules).toEqual({
+				"./index.js": "0",
+				"./x.js": "1",
+				"./shared.js": "2",
+				"./y.js": "3"
+			});
+			expect(chunks).toEqual({ main: "0", x: "1", y: "2" });
+		}
+	},
+	{
+		moduleIds: "size",
+		chunkIds: "size",
+		check: ({ modules, chunks }) => {
+			// modules of and imported from initial chunks come first
+			expect(modules).toEqual({
+				"./index.js": "0",
+				"./x.js": "1",
+				"./y.js": "2",
+				"./shared.js": "3"
+			});
+			// chunks loaded from initial chunks come first
+			expect(chunks).toEqual({ x: "0", y: "1", main: "2" });
+		}
+	},
+	{
+		moduleIds: "size",
+		chunkIds: "total-size",
+		check: ({ chunks }) => {
+			expect(chunks).toEqual({ main: "0", x: "1", y: "2" });
+		}
+	},
+	{
+		moduleIds: "natural",
+		chunkIds: "natural",
+		// the ids are assigned before the builtin plugin, which skips modules with an id
+		plugins: [new OccurrenceModuleIdsPlugin({ prioritizeInitial: false })],
+		check: ({ modules }) => {
+			// `shared.js` occurs in both async chunks
+			expect(modules).toEqual({
+				"./shared.js": "0",
+				"./index.js": "1",
+				"./x.js": "2",
+				"./y.js": "3"
+			});
+		}
+	},
+	{
+		moduleIds: "natural",
+		chunkIds: "natural",
+		plugins: [new HashedModuleIdsPlugin()],
+		check: ({ modules }) => {
+			const values = Object.values(modules);
+			expect(values).toHaveLength(4);
+			for (const id of values) {
+				expect(id).toMatch(/^[A-Za-z0-9+/=]{4}$/);
+			}
+			expect(new Set(values).size).toBe(4);
+		}
+	},
+	{
+		moduleIds: "natural",
+		chunkIds: "natural",
+		plugins: [
+			new HashedModuleIdsPlugin({
+				hashFunction: "sha256",
+				hashDigest: "hex",
+				hashDigestLength: 8
+			})
+		],
+		check: ({ modules }) => {
+			// the ids only depend on the module path
+			for (const [name, id] of Object.entries(modules)) {
+				expect(id).toBe(
+					crypto.createHash("sha256").update(name).digest("hex").slice(0, 8)
+				);
+			}
+		}
+	}
+].map((options, index) => config(index, options));
@@ -0,0 +1,7 @@
+import { a, missing } from "./a";
+export { gone } from "./a";
+
+it("should not report missing exports when exportsPresence is false", () => {
+	expect(a).toBe(1);
+	expect(missing).toBe(undefined);
+});
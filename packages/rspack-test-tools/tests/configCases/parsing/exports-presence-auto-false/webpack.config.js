@@ -0,0 +1,15 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	module: {
+		parser: {
+			"javascript/auto": {
+				exportsPresence: false
+			}
+		}
+	},
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/export 'gone' \(reexported as 'gone'\) was not found in '.\/a'/]
+];
@@ -0,0 +1,7 @@
+import { a, missing } from "./a";
+export { gone } from "./a";
+
+it("should report missing exports as configured", () => {
+	expect(a).toBe(1);
+	expect(missing).toBe(undefined);
+});
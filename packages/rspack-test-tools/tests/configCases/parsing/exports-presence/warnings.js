@@ -0,0 +1,3 @@
+module.exports = [
+	[/export 'missing' \(imported as 'missing'\) was not found in '.\/a'/]
+];
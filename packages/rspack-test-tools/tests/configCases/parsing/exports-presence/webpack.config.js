@@ -0,0 +1,16 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	module: {
+		parser: {
+			javascript: {
+				importExportsPresence: "warn",
+				reexportExportsPresence: "error"
+			}
+		}
+	},
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	}
+};
@@ -0,0 +1,4 @@
+it("should not report the replaced expression context as critical", function () {
+	const name = "./en";
+	expect(require(name)).toBe("en");
+});
@@ -0,0 +1,9 @@
+const path = require("path");
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		// the context of an expression is empty without a regexp
+		new ContextReplacementPlugin(/./, path.resolve(__dirname, "locale"), /\.js$/)
+	]
+});
@@ -0,0 +1,11 @@
+it("should only include the locales matching the new regexp", function () {
+	const load = name => require("./locale/" + name);
+	expect(load("en")).toBe("en");
+	expect(load("de")).toBe("de");
+	expect(() => load("fr")).toThrow();
+});
+
+it("should resolve the context from the provided map", function () {
+	const load = name => require("./replaced/" + name);
+	expect(load("a")).toBe("b");
+});
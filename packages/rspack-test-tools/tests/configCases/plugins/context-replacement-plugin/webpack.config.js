@@ -0,0 +1,11 @@
+const path = require("path");
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		new ContextReplacementPlugin(/locale$/, /^\.\/(en|de)/),
+		new ContextReplacementPlugin(/replaced$/, path.resolve(__dirname, "map"), {
+			"./a": "./b.js"
+		})
+	]
+});
@@ -0,0 +1,16 @@
+it("should replace environment variables", () => {
+	expect(process.env.RSPACK_ENV_DEFINED).toBe("defined");
+	expect(process.env.RSPACK_ENV_DEFAULT).toBe("default");
+	expect(process.env.RSPACK_ENV_OBJECT).toEqual({ nested: true });
+});
+
+it("should evaluate environment variables", () => {
+	let branch = "none";
+	if (process.env.RSPACK_ENV_DEFINED === "defined") {
+		branch = "defined";
+	} else {
+		require("./fail");
+	}
+	expect(branch).toBe("defined");
+	expect(typeof process.env.RSPACK_ENV_DEFAULT).toBe("string");
+});
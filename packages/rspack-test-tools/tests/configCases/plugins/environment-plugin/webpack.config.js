@@ -0,0 +1,13 @@
+const { EnvironmentPlugin } = require("@rspack/core");
+
+process.env.RSPACK_ENV_DEFINED = "defined";
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		new EnvironmentPlugin(["RSPACK_ENV_DEFINED"]),
+		new EnvironmentPlugin({
+			RSPACK_ENV_DEFAULT: "default",
+			RSPACK_ENV_OBJECT: { nested: true }
+		})
+	]
+});
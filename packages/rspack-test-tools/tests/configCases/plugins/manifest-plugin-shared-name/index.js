@@ -0,0 +1,17 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should list every file sharing a name", async () => {
+	await import(/* webpackChunkName: "async" */ "./chunk");
+	expect(new URL("./async.js", import.meta.url).pathname).toMatch(
+		/assets\/async\.js$/
+	);
+	const manifest = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "manifest.json"), "utf-8")
+	);
+	expect(manifest.files["async.js"].sort()).toEqual([
+		"/static/assets/async.js",
+		"/static/async.js"
+	]);
+	expect(manifest.files["main.js"]).toEqual(["/static/bundle0.js"]);
+});
@@ -0,0 +1,13 @@
+const { ManifestPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	output: {
+		publicPath: "/static/",
+		chunkFilename: "[name].js",
+		assetModuleFilename: "assets/[name][ext]"
+	},
+	module: {
+		rules: [{ test: /async\.js$/, type: "asset/resource" }]
+	},
+	plugins: [new ManifestPlugin()]
+});
@@ -0,0 +1,15 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the manifest of the compilation", async () => {
+	await import(/* webpackChunkName: "async" */ "./async");
+	const manifest = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "manifest.json"), "utf-8")
+	);
+	expect(manifest.files["main.js"]).toEqual(["/static/bundle0.js"]);
+	expect(manifest.files["main.js.map"]).toEqual(["/static/bundle0.js.map"]);
+	expect(manifest.files["async.js"]).toEqual(["/static/async.js"]);
+	expect(manifest.files["async.js.map"]).toBeUndefined();
+	expect(manifest.entrypoints.main.initial.js).toEqual(["/static/bundle0.js"]);
+	expect(manifest.entrypoints.main.async.js).toEqual(["/static/async.js"]);
+});
@@ -0,0 +1,14 @@
+const { ManifestPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	devtool: "source-map",
+	output: {
+		publicPath: "/static/",
+		chunkFilename: "[name].js"
+	},
+	plugins: [
+		new ManifestPlugin({
+			filter: file => !(file.isAuxiliary && file.chunkName === "async")
+		})
+	]
+});
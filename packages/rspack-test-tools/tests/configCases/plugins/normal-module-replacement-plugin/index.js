@@ -7,3 +7,7 @@ it("should use replaced resource", function () {
 	expect(require("./resource.foo")).toBe("bar");
 	expect(require("./resource.foo.js")).toBe("bar");
 });
+
+it("should replace the request with a string", function () {
+	expect(require("./string.a.js")).toBe("string.b");
+});
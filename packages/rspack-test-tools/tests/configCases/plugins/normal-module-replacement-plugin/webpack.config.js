@@ -5,6 +5,7 @@ module.exports = /** @type {import("@rspack/core").Configuration} */ ({
 		new NormalModuleReplacementPlugin(/request.v1(\.|$)/, r => {
 			r.request = r.request.replace(/request\.v1(\.|$)/, "request.v2$1");
 		}),
+		new NormalModuleReplacementPlugin(/string\.a\.js$/, "./string.b.js"),
 		new NormalModuleReplacementPlugin(
 			/resource\.foo\.js$/,
 			({ createData }) => {
@@ -0,0 +1,3 @@
+export default "a";
+export const url = new URL("./a.txt", import.meta.url);
+export const b = () => import(/* webpackChunkName: "b" */ "./b");
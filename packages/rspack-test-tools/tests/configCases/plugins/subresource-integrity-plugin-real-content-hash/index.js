@@ -0,0 +1,20 @@
+const crypto = require("crypto");
+const fs = require("fs");
+const path = require("path");
+
+const integrity = name => {
+	const file = fs
+		.readdirSync(__dirname)
+		.find(file => new RegExp(`^${name}\\.[0-9a-f]+\\.js$`).test(file));
+	const content = fs.readFileSync(path.resolve(__dirname, file));
+	return `sha384-${crypto.createHash("sha384").update(content).digest("base64")}`;
+};
+
+it("should embed the integrity of the files rewritten by real content hash", async () => {
+	const a = await import(/* webpackChunkName: "a" */ "./a");
+	await a.b();
+	expect(__webpack_require__.sriHashes.js.a).toBe(integrity("a"));
+	expect(__webpack_require__.sriHashes.js.b).toBe(integrity("b"));
+	const mainContent = fs.readFileSync(__filename, "utf-8");
+	expect(mainContent).not.toContain("CHUNK-SRI-HASH");
+});
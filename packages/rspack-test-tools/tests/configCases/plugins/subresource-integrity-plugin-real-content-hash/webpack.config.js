@@ -0,0 +1,18 @@
+const { SubresourceIntegrityPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	mode: "production",
+	output: {
+		chunkFilename: "[name].[contenthash].js",
+		assetModuleFilename: "[contenthash][ext]"
+	},
+	module: {
+		rules: [{ test: /\.txt$/, type: "asset/resource" }]
+	},
+	optimization: {
+		chunkIds: "named",
+		minimize: false,
+		realContentHash: true
+	},
+	plugins: [new SubresourceIntegrityPlugin()]
+});
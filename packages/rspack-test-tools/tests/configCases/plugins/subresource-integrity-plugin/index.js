@@ -0,0 +1,15 @@
+const crypto = require("crypto");
+const fs = require("fs");
+const path = require("path");
+
+it("should inject the integrity of async chunks into the runtime", async () => {
+	await import(/* webpackChunkName: "async" */ "./async");
+	const asyncContent = fs.readFileSync(path.resolve(__dirname, "async.js"));
+	const integrity = `sha384-${crypto
+		.createHash("sha384")
+		.update(asyncContent)
+		.digest("base64")}`;
+	expect(__webpack_require__.sriHashes.js.async).toBe(integrity);
+	const mainContent = fs.readFileSync(__filename, "utf-8");
+	expect(mainContent).not.toContain("CHUNK-SRI-HASH");
+});
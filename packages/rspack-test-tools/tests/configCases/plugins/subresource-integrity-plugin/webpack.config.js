@@ -0,0 +1,11 @@
+const { SubresourceIntegrityPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	output: {
+		chunkFilename: "[name].js"
+	},
+	optimization: {
+		chunkIds: "named"
+	},
+	plugins: [new SubresourceIntegrityPlugin()]
+});
@@ -0,0 +1,11 @@
+const path = require("path");
+const fs = require("fs");
+import shared from "./shared";
+import other from "./other";
+
+it("should not split modules that don't reduce enough size", () => {
+	expect(shared).toBe("shared");
+	expect(other).toBe("other");
+	expect(fs.existsSync(path.resolve(__dirname, "./shared.js"))).toBe(false);
+	expect(fs.existsSync(path.resolve(__dirname, "./other.js"))).toBe(true);
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	findBundle: function (i, options) {
+		return ["other.js", "main.js"];
+	}
+};
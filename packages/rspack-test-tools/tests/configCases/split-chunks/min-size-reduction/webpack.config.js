@@ -0,0 +1,24 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	target: "node",
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			cacheGroups: {
+				shared: {
+					test: /shared\.js/,
+					name: "shared",
+					minSizeReduction: 1000000
+				},
+				other: {
+					test: /other\.js/,
+					name: "other"
+				}
+			}
+		}
+	}
+};
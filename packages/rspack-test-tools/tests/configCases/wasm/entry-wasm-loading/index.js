@@ -0,0 +1,14 @@
+const fs = require("fs");
+const path = require("path");
+
+const read = name => fs.readFileSync(path.resolve(__dirname, name), "utf-8");
+
+it("should load wasm with the wasm loading of the entry", () => {
+	// only `fetch` of the output is used by the entries without one
+	const main = read("main.js");
+	expect(main).toContain("fetch(");
+	expect(main).not.toContain("readFile");
+	const node = read("node.js");
+	expect(node).toContain("readFile");
+	expect(node).not.toContain("fetch(");
+});
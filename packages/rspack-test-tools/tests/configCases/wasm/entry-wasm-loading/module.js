@@ -0,0 +1 @@
+import("./module.wasm").then(m => console.log(m));
@@ -0,0 +1,20 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	entry: {
+		main: "./module.js",
+		node: {
+			import: "./module.js",
+			wasmLoading: "async-node"
+		},
+		test: "./index.js"
+	},
+	output: {
+		filename: "[name].js",
+		wasmLoading: "fetch",
+		enabledWasmLoadingTypes: ["fetch", "async-node"]
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
@@ -0,0 +1,11 @@
+module.exports = [
+	[
+		/Import "memory" from "\.\/env\.js" with Memory can only be used for direct wasm to wasm dependencies/,
+		/It's used from \.\/math\.wasm/
+	],
+	// `small` can be called from js
+	[
+		/Export "big" with i64 can only be used for direct wasm to wasm dependencies/,
+		/It's used from \.\/math\.js/
+	]
+];
@@ -0,0 +1 @@
+import("./math.js").then(math => math.run());
@@ -0,0 +1,3 @@
+import { big, small } from "./math.wasm";
+
+export const run = () => [big(1n), small(1)];
@@ -0,0 +1,4 @@
+module.exports = {
+	// the errors are the result, the bundle isn't runnable
+	findBundle: () => []
+};
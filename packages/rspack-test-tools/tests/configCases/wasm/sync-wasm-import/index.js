@@ -0,0 +1,13 @@
+const fs = require("fs");
+
+it("should instantiate the wasm module while loading its chunk", async () => {
+	const math = await import(/* webpackChunkName: "math" */ "./math.js");
+	expect(math.run()).toBe(3);
+	// the import object calls the export of the js module
+	expect(math.logged).toEqual([3]);
+});
+
+it("should emit the wasm module", () => {
+	const files = fs.readdirSync(__dirname);
+	expect(files.filter(file => file.endsWith(".module.wasm"))).toHaveLength(1);
+});
@@ -0,0 +1,5 @@
+import { add } from "./add.wasm";
+
+export { logged } from "./env.js";
+
+export const run = () => add(1, 2);
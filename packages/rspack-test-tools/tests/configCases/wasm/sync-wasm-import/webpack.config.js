@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	output: {
+		chunkFilename: "[name].chunk.js"
+	},
+	experiments: {
+		syncWebAssembly: true
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/WebAssembly module is included in initial chunk/, /\* \.\/add\.wasm/]
+];
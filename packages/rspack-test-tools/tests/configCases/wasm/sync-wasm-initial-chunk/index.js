@@ -0,0 +1,3 @@
+import { add } from "./add.wasm";
+
+add(1, 2);
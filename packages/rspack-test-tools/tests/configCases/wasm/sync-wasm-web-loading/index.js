@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should fetch and instantiate the wasm modules of a chunk on the web", () => {
+	const web = fs.readFileSync(path.resolve(__dirname, "web.js"), "utf-8");
+	expect(web).toContain("Fetch + compile chunk loading for webassembly");
+	expect(web).toContain("WebAssembly.instantiateStreaming");
+	// the import object calls the export of the js module
+	expect(web).toContain('"./add.wasm": function()');
+	expect(web).toContain('"./env.js": {');
+	expect(web).toContain('"log": function(p0i32)');
+});
@@ -0,0 +1 @@
+import("./add.wasm").then(m => m.add(1, 2));
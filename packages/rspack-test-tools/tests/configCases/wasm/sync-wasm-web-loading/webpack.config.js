@@ -0,0 +1,18 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		target: "web",
+		mode: "development",
+		entry: "./module.js",
+		output: {
+			filename: "web.js",
+			chunkFilename: "web.[name].js"
+		},
+		experiments: {
+			syncWebAssembly: true
+		}
+	},
+	{
+		entry: "./index.js"
+	}
+];
@@ -0,0 +1 @@
+module.exports = [[/Wasm loading type "fecth" is not enabled/]];
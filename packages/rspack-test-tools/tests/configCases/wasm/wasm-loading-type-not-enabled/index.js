@@ -0,0 +1,3 @@
+it("should not build entries with a misspelled wasm loading type", () => {
+	throw new Error("should not run");
+});
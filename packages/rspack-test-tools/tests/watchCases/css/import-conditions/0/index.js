@@ -0,0 +1,17 @@
+require("./a.css");
+require("./b.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should keep the import conditions of the modules after a rebuild", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle.css"), "utf-8");
+	if (WATCH_STEP === "0") {
+		expect(css).toContain("@media screen {\n.c {");
+		expect(css).not.toContain("@media print");
+	} else {
+		expect(css).toContain("@media print {\n.c {");
+		expect(css).not.toContain("@media screen");
+	}
+	// the unchanged module keeps the conditions it was imported with
+	expect(css).toContain("@layer base {\n.d {");
+});
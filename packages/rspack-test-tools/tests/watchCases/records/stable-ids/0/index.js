@@ -0,0 +1,17 @@
+import(/* webpackChunkName: "x" */ "./x.js");
+import(/* webpackChunkName: "y" */ "./y.js");
+
+const fs = require("fs");
+const path = require("path");
+
+it("should write the ids to the records", () => {
+	const records = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "records.json"), "utf-8")
+	);
+	expect(records.modules.byIdentifier).toEqual({
+		"./index.js": "0",
+		"./x.js": "1",
+		"./y.js": "2"
+	});
+	expect(records.chunks.byName).toEqual({ main: "0", x: "1", y: "2" });
+});
@@ -0,0 +1,19 @@
+import(/* webpackChunkName: "new" */ "./new.js");
+import(/* webpackChunkName: "x" */ "./x.js");
+import(/* webpackChunkName: "y" */ "./y.js");
+
+const fs = require("fs");
+const path = require("path");
+
+it("should keep the ids of the records", () => {
+	const records = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "records.json"), "utf-8")
+	);
+	// without records the new module and chunk would take the ids of `x.js` and `x`
+	const { "./new.js": newModule, ...modules } = records.modules.byIdentifier;
+	expect(modules).toEqual({ "./index.js": "0", "./x.js": "1", "./y.js": "2" });
+	expect(["0", "1", "2"]).not.toContain(newModule);
+	const { new: newChunk, ...chunks } = records.chunks.byName;
+	expect(chunks).toEqual({ main: "0", x: "1", y: "2" });
+	expect(["0", "1", "2"]).not.toContain(newChunk);
+});
@@ -0,0 +1,18 @@
+const fs = require("fs");
+const path = require("path");
+
+const recordsPath = path.resolve(
+	__dirname,
+	"../../../js/WatchTestCases/records/stable-ids/records.json"
+);
+// the records of a previous run would keep their ids
+fs.rmSync(recordsPath, { force: true });
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	recordsPath,
+	optimization: {
+		moduleIds: "natural",
+		chunkIds: "natural"
+	}
+};
@@ -195,6 +195,24 @@ export class Watching {
 		this.#invalidate();
 	}
 
+	/**
+	 * Invalidate the current watching with the given files marked as changed or removed.
+	 */
+	invalidateWithChangesAndRemovals(
+		changedFiles?: Set<string>,
+		removedFiles?: Set<string>,
+		callback?: Callback<Error, void>
+	) {
+		if (callback) {
+			this.callbacks.push(callback);
+		}
+		if (!this.#invalidReported) {
+			this.#invalidReported = true;
+			this.compiler.hooks.invalid.call(null, Date.now());
+		}
+		this.#invalidate(undefined, undefined, changedFiles, removedFiles);
+	}
+
 	#invalidate(
 		fileTimeInfoEntries?: Map<string, FileSystemInfoEntry | "ignore">,
 		contextTimeInfoEntries?: Map<string, FileSystemInfoEntry | "ignore">,
@@ -0,0 +1,43 @@
+import {
+	BuiltinPluginName,
+	RawContextReplacementPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export const ContextReplacementPlugin = create(
+	BuiltinPluginName.ContextReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newContentResource?: string | boolean | RegExp,
+		newContentRecursive?: boolean | RegExp | Record<string, string>,
+		newContentRegExp?: RegExp
+	): RawContextReplacementPluginOptions => {
+		const rawOptions: RawContextReplacementPluginOptions = {
+			resourceRegExp
+		};
+		if (newContentResource instanceof RegExp && !newContentRecursive) {
+			rawOptions.newContentRegExp = newContentResource;
+		} else if (
+			typeof newContentResource === "string" &&
+			typeof newContentRecursive === "object" &&
+			!(newContentRecursive instanceof RegExp)
+		) {
+			rawOptions.newContentResource = newContentResource;
+			rawOptions.newContentCreateContextMap = newContentRecursive;
+		} else {
+			if (typeof newContentResource !== "string") {
+				newContentRegExp = newContentRecursive as RegExp | undefined;
+				newContentRecursive = newContentResource;
+				newContentResource = undefined;
+			}
+			if (typeof newContentRecursive !== "boolean") {
+				newContentRegExp = newContentRecursive as RegExp | undefined;
+				newContentRecursive = undefined;
+			}
+			rawOptions.newContentResource = newContentResource;
+			rawOptions.newContentRecursive = newContentRecursive;
+			rawOptions.newContentRegExp = newContentRegExp;
+		}
+		return rawOptions;
+	}
+);
@@ -1,17 +1,80 @@
 import { BuiltinPluginName } from "@rspack/binding";
 import { create } from "./base";
 
-export type DefinePluginOptions = Record<string, string | boolean | undefined>;
+type CodeValuePrimitive =
+	| null
+	| undefined
+	| RegExp
+	| Function
+	| string
+	| number
+	| boolean
+	| bigint;
+
+export type CodeValue =
+	| CodeValuePrimitive
+	| CodeValue[]
+	| { [key: string]: CodeValue };
+
+export type DefinePluginOptions = Record<string, CodeValue>;
+
+const isObjectDefine = (value: CodeValue): value is Record<string, CodeValue> =>
+	value !== null &&
+	typeof value === "object" &&
+	!Array.isArray(value) &&
+	!(value instanceof RegExp);
+
+const toCode = (value: CodeValue): string => {
+	if (value === null) {
+		return "null";
+	}
+	if (value === undefined) {
+		return "undefined";
+	}
+	if (typeof value === "string") {
+		return value;
+	}
+	if (typeof value === "bigint") {
+		return `${value}n`;
+	}
+	if (typeof value === "function") {
+		return `(${value.toString()})`;
+	}
+	if (value instanceof RegExp) {
+		return value.toString();
+	}
+	if (Array.isArray(value)) {
+		return `[${value.map(toCode).join(",")}]`;
+	}
+	if (typeof value === "object") {
+		const properties = Object.entries(value).map(
+			([key, value]) => `${JSON.stringify(key)}:${toCode(value)}`
+		);
+		return `{${properties.join(",")}}`;
+	}
+	return `${value}`;
+};
+
+const addDefinitions = (
+	definitions: Record<string, string>,
+	prefix: string,
+	define: DefinePluginOptions
+) => {
+	for (const [key, value] of Object.entries(define)) {
+		definitions[`${prefix}${key}`] = toCode(value);
+		// `"process.env": { NODE_ENV: ... }` also defines `process.env.NODE_ENV`
+		if (isObjectDefine(value)) {
+			addDefinitions(definitions, `${prefix}${key}.`, value);
+		}
+	}
+};
+
 export const DefinePlugin = create(
 	BuiltinPluginName.DefinePlugin,
 	(define: DefinePluginOptions): Record<string, string> => {
-		const entries = Object.entries(define).map(([key, value]) => {
-			if (typeof value !== "string") {
-				value = value === undefined ? "undefined" : JSON.stringify(value);
-			}
-			return [key, value];
-		});
-		return Object.fromEntries(entries);
+		const definitions: Record<string, string> = {};
+		addDefinitions(definitions, "", define);
+		return definitions;
 	},
 	"compilation"
 );
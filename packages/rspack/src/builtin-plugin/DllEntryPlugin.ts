@@ -0,0 +1,20 @@
+import { BuiltinPluginName, RawDllEntryPluginOptions } from "@rspack/binding";
+import { create } from "./base";
+
+export type DllEntryPluginOptions = {
+	name: string;
+};
+
+export const DllEntryPlugin = create(
+	BuiltinPluginName.DllEntryPlugin,
+	(
+		context: string,
+		entries: string[],
+		options: DllEntryPluginOptions
+	): RawDllEntryPluginOptions => ({
+		context,
+		entries,
+		name: options.name
+	}),
+	"make"
+);
@@ -0,0 +1,13 @@
+import {
+	BuiltinPluginName,
+	RawDllReferenceAgencyPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export const DllReferenceAgencyPlugin = create(
+	BuiltinPluginName.DllReferenceAgencyPlugin,
+	(
+		options: RawDllReferenceAgencyPluginOptions
+	): RawDllReferenceAgencyPluginOptions => options,
+	"compilation"
+);
@@ -10,6 +10,7 @@ import {
 	FilenameTemplate,
 	LibraryOptions,
 	PublicPath,
+	WasmLoading,
 	getRawChunkLoading,
 	getRawEntryRuntime,
 	getRawLibrary
@@ -20,12 +21,14 @@ export type EntryOptions = {
 	name?: string;
 	runtime?: EntryRuntime;
 	chunkLoading?: ChunkLoading;
+	wasmLoading?: WasmLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
 	baseUri?: string;
 	filename?: FilenameTemplate;
 	library?: LibraryOptions;
 	dependOn?: string[];
+	layer?: string | null;
 };
 export const EntryPlugin = create(
 	BuiltinPluginName.EntryPlugin,
@@ -48,6 +51,7 @@ export const EntryPlugin = create(
 function getRawEntryOptions(entry: EntryOptions): RawEntryOptions {
 	const runtime = entry.runtime;
 	const chunkLoading = entry.chunkLoading;
+	const wasmLoading = entry.wasmLoading;
 	return {
 		name: entry.name,
 		publicPath: entry.publicPath,
@@ -56,9 +60,15 @@ function getRawEntryOptions(entry: EntryOptions): RawEntryOptions {
 		chunkLoading: !isNil(chunkLoading)
 			? getRawChunkLoading(chunkLoading)
 			: undefined,
+		wasmLoading: !isNil(wasmLoading)
+			? wasmLoading === false
+				? "false"
+				: wasmLoading
+			: undefined,
 		asyncChunks: entry.asyncChunks,
 		filename: entry.filename,
 		library: entry.library && getRawLibrary(entry.library),
-		dependOn: entry.dependOn
+		dependOn: entry.dependOn,
+		layer: entry.layer ?? undefined
 	};
 }
@@ -0,0 +1,30 @@
+import {
+	BuiltinPluginName,
+	RawEnvironmentPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type EnvironmentPluginOptions = string[] | Record<string, unknown>;
+
+export const EnvironmentPlugin = create(
+	BuiltinPluginName.EnvironmentPlugin,
+	(
+		...keys: [EnvironmentPluginOptions] | string[]
+	): RawEnvironmentPluginOptions => {
+		const [options] = keys;
+		if (keys.length === 1 && Array.isArray(options)) {
+			return { keys: options, defaults: {} };
+		}
+		if (keys.length === 1 && options && typeof options === "object") {
+			const defaults: Record<string, string> = {};
+			for (const [key, value] of Object.entries(options)) {
+				if (value !== undefined) {
+					defaults[key] = JSON.stringify(value);
+				}
+			}
+			return { keys: Object.keys(options), defaults };
+		}
+		return { keys: keys as string[], defaults: {} };
+	},
+	"compilation"
+);
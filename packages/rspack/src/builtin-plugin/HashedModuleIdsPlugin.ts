@@ -0,0 +1,20 @@
+import {
+	BuiltinPluginName,
+	RawHashedModuleIdsPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type HashedModuleIdsPluginOptions = {
+	context?: string;
+	hashFunction?: "md4" | "xxhash64" | "xxhash128" | "sha256" | "sha512";
+	hashDigest?: "hex" | "base64" | "base64url" | "base32" | "base58" | "base62";
+	hashDigestLength?: number;
+};
+
+export const HashedModuleIdsPlugin = create(
+	BuiltinPluginName.HashedModuleIdsPlugin,
+	(
+		options: HashedModuleIdsPluginOptions = {}
+	): RawHashedModuleIdsPluginOptions => options,
+	"compilation"
+);
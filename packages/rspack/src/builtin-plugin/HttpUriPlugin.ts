@@ -0,0 +1,56 @@
+import http from "http";
+import https from "https";
+import {
+	BuiltinPluginName,
+	RawHttpResponse,
+	RawHttpUriPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type HttpUriPluginOptions = {
+	allowedUris: (string | RegExp)[];
+	lockfileLocation?: string;
+	cacheLocation?: string;
+	frozen?: boolean;
+	upgrade?: boolean;
+	httpClient?: RawHttpUriPluginOptions["httpClient"];
+};
+
+const defaultHttpClient = (
+	url: string,
+	headers: Record<string, string>
+): Promise<RawHttpResponse> =>
+	new Promise((resolve, reject) => {
+		const request = (url.startsWith("https:") ? https : http).get(
+			url,
+			{ headers },
+			res => {
+				const chunks: Buffer[] = [];
+				res.on("data", chunk => chunks.push(chunk));
+				res.on("error", reject);
+				res.on("end", () => {
+					const responseHeaders: Record<string, string> = {};
+					for (const [key, value] of Object.entries(res.headers)) {
+						if (value === undefined) continue;
+						responseHeaders[key] = Array.isArray(value)
+							? value.join(", ")
+							: value;
+					}
+					resolve({
+						status: res.statusCode ?? 0,
+						headers: responseHeaders,
+						body: Buffer.concat(chunks)
+					});
+				});
+			}
+		);
+		request.on("error", reject);
+	});
+
+export const HttpUriPlugin = create(
+	BuiltinPluginName.HttpUriPlugin,
+	(options: HttpUriPluginOptions): RawHttpUriPluginOptions => ({
+		...options,
+		httpClient: options.httpClient ?? defaultHttpClient
+	})
+);
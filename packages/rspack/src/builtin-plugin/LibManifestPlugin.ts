@@ -0,0 +1,20 @@
+import {
+	BuiltinPluginName,
+	RawLibManifestPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type LibManifestPluginOptions = {
+	context?: string;
+	entryOnly?: boolean;
+	format?: boolean;
+	name?: string;
+	path: string;
+	type?: string;
+};
+
+export const LibManifestPlugin = create(
+	BuiltinPluginName.LibManifestPlugin,
+	(options: LibManifestPluginOptions): RawLibManifestPluginOptions => options,
+	"emit"
+);
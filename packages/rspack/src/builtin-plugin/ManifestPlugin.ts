@@ -0,0 +1,41 @@
+import {
+	BuiltinPluginName,
+	RawManifestFile,
+	RawManifestPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type ManifestFile = RawManifestFile;
+
+export type ManifestEntrypoint = {
+	initial: Record<string, string[]>;
+	async: Record<string, string[]>;
+};
+
+export type Manifest = {
+	files: Record<string, string[]>;
+	entrypoints: Record<string, ManifestEntrypoint>;
+};
+
+export type ManifestPluginOptions = {
+	filename?: string;
+	publicPath?: string;
+	filter?: (file: ManifestFile) => boolean;
+	serialize?: (manifest: Manifest) => string;
+};
+
+export const ManifestPlugin = create(
+	BuiltinPluginName.ManifestPlugin,
+	(options: ManifestPluginOptions = {}): RawManifestPluginOptions => {
+		const { serialize } = options;
+		return {
+			filename: options.filename ?? "manifest.json",
+			publicPath: options.publicPath,
+			filter: options.filter,
+			serialize: serialize
+				? (manifest: string) => serialize(JSON.parse(manifest))
+				: undefined
+		};
+	},
+	"thisCompilation"
+);
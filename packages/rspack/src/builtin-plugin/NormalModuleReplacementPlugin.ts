@@ -0,0 +1,45 @@
+import {
+	BuiltinPluginName,
+	RawNormalModuleReplacementData,
+	RawNormalModuleReplacementPluginOptions
+} from "@rspack/binding";
+import { ResolveData } from "../Module";
+import { create } from "./base";
+
+type ModuleReplacer = (createData: ResolveData) => void;
+
+export const NormalModuleReplacementPlugin = create(
+	BuiltinPluginName.NormalModuleReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newResource: string | ModuleReplacer
+	): RawNormalModuleReplacementPluginOptions => {
+		if (typeof newResource === "string") {
+			return { resourceRegExp, newResource };
+		}
+		return {
+			resourceRegExp,
+			newResource: (
+				data: RawNormalModuleReplacementData
+			): RawNormalModuleReplacementData => {
+				const resolveData: ResolveData = {
+					request: data.request,
+					context: data.context,
+					fileDependencies: [],
+					missingDependencies: [],
+					contextDependencies: [],
+					createData:
+						data.resource !== undefined
+							? { resource: data.resource }
+							: undefined
+				};
+				newResource(resolveData);
+				return {
+					request: resolveData.request,
+					context: resolveData.context,
+					resource: resolveData.createData?.resource
+				};
+			}
+		};
+	}
+);
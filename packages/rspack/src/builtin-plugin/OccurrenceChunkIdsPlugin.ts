@@ -0,0 +1,17 @@
+import {
+	BuiltinPluginName,
+	RawOccurrenceChunkIdsPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type OccurrenceChunkIdsPluginOptions = {
+	prioritizeInitial?: boolean;
+};
+
+export const OccurrenceChunkIdsPlugin = create(
+	BuiltinPluginName.OccurrenceChunkIdsPlugin,
+	(
+		options: OccurrenceChunkIdsPluginOptions = {}
+	): RawOccurrenceChunkIdsPluginOptions => options,
+	"compilation"
+);
@@ -0,0 +1,17 @@
+import {
+	BuiltinPluginName,
+	RawOccurrenceModuleIdsPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type OccurrenceModuleIdsPluginOptions = {
+	prioritizeInitial?: boolean;
+};
+
+export const OccurrenceModuleIdsPlugin = create(
+	BuiltinPluginName.OccurrenceModuleIdsPlugin,
+	(
+		options: OccurrenceModuleIdsPluginOptions = {}
+	): RawOccurrenceModuleIdsPluginOptions => options,
+	"compilation"
+);
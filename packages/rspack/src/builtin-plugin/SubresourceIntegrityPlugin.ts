@@ -0,0 +1,23 @@
+import {
+	BuiltinPluginName,
+	RawSubresourceIntegrityPluginOptions
+} from "@rspack/binding";
+import { create } from "./base";
+
+export type SubresourceIntegrityHashFunction = "sha256" | "sha384" | "sha512";
+
+export type SubresourceIntegrityPluginOptions = {
+	hashFuncNames?: SubresourceIntegrityHashFunction[];
+};
+
+export const SubresourceIntegrityPlugin = create(
+	BuiltinPluginName.SubresourceIntegrityPlugin,
+	(
+		options: SubresourceIntegrityPluginOptions = {}
+	): RawSubresourceIntegrityPluginOptions => {
+		return {
+			hashFuncNames: options.hashFuncNames ?? ["sha384"]
+		};
+	},
+	"thisCompilation"
+);
@@ -2,8 +2,11 @@ export { RspackBuiltinPlugin } from "./base";
 
 export * from "./DefinePlugin";
 export * from "./ProvidePlugin";
+export * from "./EnvironmentPlugin";
 export * from "./BannerPlugin";
 export * from "./IgnorePlugin";
+export * from "./ContextReplacementPlugin";
+export * from "./NormalModuleReplacementPlugin";
 export * from "./ProgressPlugin";
 export * from "./EntryPlugin";
 export * from "./ExternalsPlugin";
@@ -27,17 +30,32 @@ export * from "./NamedModuleIdsPlugin";
 export * from "./DeterministicModuleIdsPlugin";
 export * from "./NamedChunkIdsPlugin";
 export * from "./DeterministicChunkIdsPlugin";
+export * from "./NaturalModuleIdsPlugin";
+export * from "./NaturalChunkIdsPlugin";
+export * from "./OccurrenceModuleIdsPlugin";
+export * from "./OccurrenceChunkIdsPlugin";
+export * from "./HashedModuleIdsPlugin";
+export * from "./RecordIdsPlugin";
+export * from "./DllEntryPlugin";
+export * from "./DllReferenceAgencyPlugin";
+export * from "./LibManifestPlugin";
+export * from "./ManifestPlugin";
+export * from "./SubresourceIntegrityPlugin";
+export * from "./FlagAllModulesAsUsedPlugin";
 export * from "./RealContentHashPlugin";
 export * from "./RemoveEmptyChunksPlugin";
 export * from "./EnsureChunkConditionsPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
 export * from "./DataUriPlugin";
 export * from "./FileUriPlugin";
+export * from "./HttpUriPlugin";
+export * from "./lazy-compilation";
 export * from "./RuntimePlugin";
 export * from "./JsonModulesPlugin";
 export * from "./InferAsyncModulesPlugin";
 export * from "./JavascriptModulesPlugin";
 export * from "./AsyncWebAssemblyModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./AssetModulesPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./EvalSourceMapDevToolPlugin";
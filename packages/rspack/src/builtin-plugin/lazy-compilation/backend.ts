@@ -0,0 +1,149 @@
+import http, { IncomingMessage, ServerResponse } from "http";
+import type { AddressInfo } from "net";
+import type { Compiler } from "../../Compiler";
+
+export interface LazyCompilationBackendOptions {
+	/**
+	 * Request of the client module which connects to the backend
+	 */
+	client?: string;
+	/**
+	 * Port or listen options of the backend server
+	 */
+	listen?: number | { port?: number; host?: string };
+}
+
+export interface LazyCompilationBackendModule {
+	module: string;
+	path: string;
+}
+
+export interface LazyCompilationBackend {
+	dispose(callback: (err?: Error | null) => void): void;
+	module(args: LazyCompilationBackendModule): {
+		data: string;
+		client: string;
+		active: boolean;
+	};
+}
+
+const PREFIX = "/lazy-compilation-using-";
+
+const getKey = (identifier: string) =>
+	encodeURIComponent(
+		identifier.replace(/\\/g, "/").replace(/@/g, "_")
+	).replace(/%(2F|3A|24|26|2B|2C|3B|3D|3A)/g, decodeURIComponent);
+
+export const getDefaultClient = (compiler: Compiler) =>
+	require.resolve(
+		`../../../hot/lazy-compilation-${
+			compiler.options.externalsPresets.node ? "node" : "web"
+		}.js`
+	);
+
+/**
+ * Starts a server which keeps track of the modules used by the clients
+ * (via server-sent events), and rebuilds the proxies of newly used modules.
+ */
+export const createBackend = (
+	compiler: Compiler,
+	options: LazyCompilationBackendOptions,
+	callback: (err: Error | null, backend?: LazyCompilationBackend) => void
+) => {
+	const logger = compiler.getInfrastructureLogger("LazyCompilationBackend");
+	const activeModules = new Map<string, number>();
+	const keyToPath = new Map<string, string>();
+	const client = options.client ?? getDefaultClient(compiler);
+
+	const requestListener = (req: IncomingMessage, res: ServerResponse) => {
+		if (!req.url || !req.url.startsWith(PREFIX)) {
+			res.writeHead(404);
+			res.end();
+			return;
+		}
+		const keys = req.url.slice(PREFIX.length).split("@");
+		req.socket.on("close", () => {
+			setTimeout(() => {
+				for (const key of keys) {
+					const oldValue = activeModules.get(key) || 0;
+					activeModules.set(key, oldValue - 1);
+					if (oldValue === 1) {
+						logger.log(
+							`${key} is no longer in use. Next compilation will skip this module.`
+						);
+					}
+				}
+			}, 120000);
+		});
+		req.socket.setNoDelay(true);
+		res.writeHead(200, {
+			"content-type": "text/event-stream",
+			"Access-Control-Allow-Origin": "*",
+			"Access-Control-Allow-Methods": "*",
+			"Access-Control-Allow-Headers": "*"
+		});
+		res.write("\n");
+		const activated = new Set<string>();
+		for (const key of keys) {
+			const oldValue = activeModules.get(key) || 0;
+			activeModules.set(key, oldValue + 1);
+			if (oldValue === 0) {
+				logger.log(`${key} is now in use and will be compiled.`);
+				const path = keyToPath.get(key);
+				if (path) activated.add(path);
+			}
+		}
+		if (activated.size && compiler.watching) {
+			compiler.watching.invalidateWithChangesAndRemovals(activated);
+		}
+	};
+
+	const server = http.createServer(requestListener);
+	const sockets = new Set<import("net").Socket>();
+	server.on("connection", socket => {
+		sockets.add(socket);
+		socket.on("close", () => {
+			sockets.delete(socket);
+		});
+	});
+	server.on("clientError", e => {
+		if (e.message !== "Server is disposing") logger.warn(e);
+	});
+	server.once("listening", () => {
+		const addr = server.address() as AddressInfo;
+		const host =
+			addr.family === "IPv6" ? `[${addr.address}]` : addr.address;
+		const urlBase = `http://${
+			addr.address === "::" || addr.address === "0.0.0.0" ? "localhost" : host
+		}:${addr.port}`;
+		logger.log(`Server-Sent-Events server for lazy compilation open at ${urlBase}.`);
+		callback(null, {
+			dispose(callback) {
+				server.off("request", requestListener);
+				server.close(err => {
+					callback(err);
+				});
+				for (const socket of sockets) {
+					socket.destroy(new Error("Server is disposing"));
+				}
+			},
+			module({ module, path }) {
+				const key = getKey(module);
+				keyToPath.set(key, path);
+				const active = (activeModules.get(key) || 0) > 0;
+				return {
+					client: `${client}?${encodeURIComponent(urlBase + PREFIX)}`,
+					data: key,
+					active
+				};
+			}
+		});
+	});
+	server.once("error", err => callback(err));
+	const listen = options.listen;
+	if (typeof listen === "object") {
+		server.listen(listen.port, listen.host);
+	} else {
+		server.listen(listen);
+	}
+};
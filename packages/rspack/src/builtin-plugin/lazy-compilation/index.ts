@@ -0,0 +1,67 @@
+import { BuiltinPluginName, RawLazyCompilationOption } from "@rspack/binding";
+import type { Compiler } from "../../Compiler";
+import { create } from "../base";
+import {
+	createBackend,
+	LazyCompilationBackend,
+	LazyCompilationBackendOptions
+} from "./backend";
+
+export type { LazyCompilationBackendOptions } from "./backend";
+
+const BuiltinLazyCompilationPlugin = create(
+	BuiltinPluginName.LazyCompilationPlugin,
+	(
+		module: RawLazyCompilationOption["module"],
+		entries: boolean,
+		imports: boolean,
+		test?: RawLazyCompilationOption["test"]
+	): RawLazyCompilationOption => ({ module, entries, imports, test }),
+	"thisCompilation"
+);
+
+export interface LazyCompilationPluginOptions {
+	backend?: LazyCompilationBackendOptions;
+	entries?: boolean;
+	imports?: boolean;
+	test?: RegExp | ((name: string) => boolean);
+}
+
+export class LazyCompilationPlugin {
+	private backend?: LazyCompilationBackend;
+
+	constructor(private options: LazyCompilationPluginOptions = {}) {}
+
+	apply(compiler: Compiler) {
+		const { entries = true, imports = true, test } = this.options;
+		new BuiltinLazyCompilationPlugin(
+			args => {
+				if (!this.backend) {
+					throw new Error("Lazy compilation backend is not started");
+				}
+				return this.backend.module(args);
+			},
+			entries,
+			imports,
+			test
+		).apply(compiler);
+
+		compiler.hooks.beforeCompile.tapAsync(
+			"LazyCompilationPlugin",
+			(_params, callback) => {
+				if (this.backend) return callback();
+				createBackend(compiler, this.options.backend ?? {}, (err, backend) => {
+					if (err) return callback(err);
+					this.backend = backend;
+					callback();
+				});
+			}
+		);
+		compiler.hooks.shutdown.tapAsync("LazyCompilationPlugin", callback => {
+			if (!this.backend) return callback();
+			const backend = this.backend;
+			this.backend = undefined;
+			backend.dispose(callback);
+		});
+	}
+}
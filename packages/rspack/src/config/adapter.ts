@@ -120,10 +120,13 @@ export const getRawOptions = (
 		},
 		experiments,
 		node: getRawNode(options.node),
+		amd: options.amd === false ? undefined : JSON.stringify(options.amd || {}),
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// TODO: remove this
 		builtins: options.builtins as any
 	};
@@ -403,6 +406,9 @@ const getRawModuleRule = (
 		include: rule.include ? getRawRuleSetCondition(rule.include) : undefined,
 		exclude: rule.exclude ? getRawRuleSetCondition(rule.exclude) : undefined,
 		issuer: rule.issuer ? getRawRuleSetCondition(rule.issuer) : undefined,
+		issuerLayer: rule.issuerLayer
+			? getRawRuleSetCondition(rule.issuerLayer)
+			: undefined,
 		dependency: rule.dependency
 			? getRawRuleSetCondition(rule.dependency)
 			: undefined,
@@ -424,6 +430,7 @@ const getRawModuleRule = (
 		scheme: rule.scheme ? getRawRuleSetCondition(rule.scheme) : undefined,
 		mimetype: rule.mimetype ? getRawRuleSetCondition(rule.mimetype) : undefined,
 		sideEffects: rule.sideEffects,
+		layer: rule.layer,
 		use:
 			typeof rule.use === "function"
 				? { type: "function", funcUse }
@@ -630,7 +637,18 @@ function getRawJavascriptParserOptions(
 					? parser.url
 					: "true",
 		exprContextCritical: parser.exprContextCritical ?? true,
-		wrappedContextCritical: parser.wrappedContextCritical ?? false
+		wrappedContextCritical: parser.wrappedContextCritical ?? false,
+		exportsPresence:
+			parser.exportsPresence === false ? "false" : parser.exportsPresence,
+		importExportsPresence:
+			parser.importExportsPresence === false
+				? "false"
+				: parser.importExportsPresence,
+		reexportExportsPresence:
+			parser.reexportExportsPresence === false
+				? "false"
+				: parser.reexportExportsPresence,
+		strictExportPresence: parser.strictExportPresence ?? false
 	};
 }
 
@@ -798,14 +816,15 @@ function getRawOptimization(
 		providedExports: optimization.providedExports,
 		innerGraph: optimization.innerGraph,
 		concatenateModules: !!optimization.concatenateModules,
-		mangleExports: String(optimization.mangleExports)
+		mangleExports: String(optimization.mangleExports),
+		realContentHash: optimization.realContentHash
 	};
 }
 
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
 	assert(!isNil(resolve) && !isNil(module));
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
@@ -823,19 +842,25 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
-		}
+		},
+		immutablePaths,
+		managedPaths
 	};
 }
 
 function getRawExperiments(
 	experiments: ExperimentsNormalized
 ): RawOptions["experiments"] {
-	const { newSplitChunks, topLevelAwait, rspackFuture } = experiments;
+	const { layers, newSplitChunks, topLevelAwait, rspackFuture } = experiments;
 	assert(
-		!isNil(newSplitChunks) && !isNil(topLevelAwait) && !isNil(rspackFuture)
+		!isNil(layers) &&
+			!isNil(newSplitChunks) &&
+			!isNil(topLevelAwait) &&
+			!isNil(rspackFuture)
 	);
 
 	return {
+		layers,
 		newSplitChunks,
 		topLevelAwait,
 		rspackFuture: getRawRspackFutureOptions(rspackFuture)
@@ -81,13 +81,15 @@ export const applyRspackOptionsDefaults = (
 	F(options, "cache", () => development);
 
 	applyExperimentsDefaults(options.experiments, {
-		cache: options.cache!
+		cache: options.cache!,
+		production
 	});
 
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		css: options.experiments.css!,
 		targetProperties
 	});
@@ -164,14 +166,26 @@ const applyInfrastructureLoggingDefaults = (
 
 const applyExperimentsDefaults = (
 	experiments: ExperimentsNormalized,
-	{ cache }: { cache: boolean }
+	{ cache, production }: { cache: boolean; production: boolean }
 ) => {
 	D(experiments, "lazyCompilation", false);
+	D(experiments, "layers", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
 
+	if (typeof experiments.lazyCompilation === "object") {
+		D(experiments.lazyCompilation, "entries", true);
+		D(experiments.lazyCompilation, "imports", true);
+	}
+
+	if (typeof experiments.buildHttp === "object") {
+		D(experiments.buildHttp, "frozen", production);
+		D(experiments.buildHttp, "upgrade", false);
+	}
+
 	D(experiments, "rspackFuture", {});
 	if (typeof experiments.rspackFuture === "object") {
 		D(experiments.rspackFuture, "newTreeshaking", true);
@@ -201,6 +215,7 @@ const applySnapshotDefaults = (
 			? { timestamp: true, hash: true }
 			: { timestamp: true, hash: false }
 	);
+	F(snapshot, "managedPaths", () => [/^(.+?[\\/]node_modules[\\/])/]);
 };
 
 const applyJavascriptParserOptionsDefaults = (
@@ -235,10 +250,12 @@ const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
 		asyncWebAssembly,
+		syncWebAssembly,
 		css,
 		targetProperties
 	}: {
 		asyncWebAssembly: boolean;
+		syncWebAssembly: boolean;
 		css: boolean;
 		targetProperties: any;
 	}
@@ -408,6 +425,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -813,7 +852,7 @@ const applyOptimizationDefaults = (
 	F(optimization, "chunkIds", (): "named" | "deterministic" => {
 		if (production) return "deterministic";
 		if (development) return "named";
-		return "named"; // we have not implemented 'natural' so use 'named' now
+		return "named"; // webpack uses 'natural' here, keep 'named' for compatibility
 	});
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "mangleExports", production);
@@ -840,7 +879,7 @@ const applyOptimizationDefaults = (
 		);
 		D(splitChunks, "hidePathInfo", production);
 		D(splitChunks, "chunks", "async");
-		// D(splitChunks, "usedExports", optimization.usedExports === true);
+		D(splitChunks, "usedExports", optimization.usedExports === true);
 		D(splitChunks, "minChunks", 1);
 		F(splitChunks, "minSize", () => (production ? 20000 : 10000));
 		// F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
@@ -947,7 +986,7 @@ const getResolveDefaults = ({
 				preferRelative: true
 			},
 			commonjs: cjsDeps(),
-			// amd: cjsDeps(),
+			amd: cjsDeps(),
 			// for backward-compat: loadModule
 			// loader: cjsDeps(),
 			// for backward-compat: Custom Dependency and getResolve without dependencyType
@@ -14,6 +14,7 @@ import type {
 	Context,
 	Dependencies,
 	Node,
+	Amd,
 	DevTool,
 	EntryStatic,
 	Externals,
@@ -36,6 +37,8 @@ import type {
 	DevServer,
 	Profile,
 	Bail,
+	RecordsInputPath,
+	RecordsOutputPath,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
@@ -73,6 +76,8 @@ import type {
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	RspackFutureOptions,
+	HttpUriOptions,
+	LazyCompilationOptions,
 	HotUpdateGlobal,
 	ScriptType,
 	NoParseOption,
@@ -240,6 +245,7 @@ export const getNormalizedRspackOptions = (
 					...node
 				}
 		),
+		amd: config.amd,
 		snapshot: nestedConfig(config.snapshot, snapshot => ({
 			resolve: optionalNestedConfig(snapshot.resolve, resolve => ({
 				timestamp: resolve.timestamp,
@@ -248,7 +254,12 @@ export const getNormalizedRspackOptions = (
 			module: optionalNestedConfig(snapshot.module, module => ({
 				timestamp: module.timestamp,
 				hash: module.hash
-			}))
+			})),
+			immutablePaths: optionalNestedArray(
+				snapshot.immutablePaths,
+				p => [...p]
+			),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p])
 		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
@@ -292,13 +303,28 @@ export const getNormalizedRspackOptions = (
 		}),
 		plugins: nestedArray(config.plugins, p => [...p]),
 		experiments: nestedConfig(config.experiments, experiments => ({
-			...experiments
+			...experiments,
+			lazyCompilation: optionalNestedConfig(
+				experiments.lazyCompilation,
+				options => (options === true ? {} : options)
+			),
+			buildHttp: optionalNestedConfig(experiments.buildHttp, options =>
+				Array.isArray(options) ? { allowedUris: options } : options
+			)
 		})),
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
 		bail: config.bail,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
 		}))
@@ -338,6 +364,7 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 				publicPath: value.publicPath,
 				baseUri: value.baseUri,
 				chunkLoading: value.chunkLoading,
+				wasmLoading: value.wasmLoading,
 				asyncChunks: value.asyncChunks,
 				filename: value.filename,
 				library: value.library,
@@ -345,7 +372,8 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 					? value.dependOn
 					: value.dependOn
 						? [value.dependOn]
-						: undefined
+						: undefined,
+				layer: value.layer
 			};
 		}
 	}
@@ -427,12 +455,14 @@ export interface EntryDescriptionNormalized {
 	import?: string[];
 	runtime?: EntryRuntime;
 	chunkLoading?: ChunkLoading;
+	wasmLoading?: WasmLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
 	baseUri?: string;
 	filename?: EntryFilename;
 	library?: LibraryOptions;
 	dependOn?: string[];
+	layer?: string | null;
 }
 
 export interface OutputNormalized {
@@ -489,14 +519,17 @@ export interface ModuleOptionsNormalized {
 }
 
 export interface ExperimentsNormalized {
-	lazyCompilation?: boolean;
+	layers?: boolean;
+	lazyCompilation?: false | LazyCompilationOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
+	buildHttp?: HttpUriOptions;
 }
 
 export type IgnoreWarningsNormalized = ((
@@ -527,6 +560,7 @@ export interface RspackOptionsNormalized {
 	infrastructureLogging: InfrastructureLogging;
 	devtool?: DevTool;
 	node: Node;
+	amd?: Amd;
 	snapshot: SnapshotOptions;
 	cache?: CacheOptions;
 	stats: StatsValue;
@@ -539,5 +573,7 @@ export interface RspackOptionsNormalized {
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	profile?: Profile;
 	bail?: Bail;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 	builtins: Builtins;
 }
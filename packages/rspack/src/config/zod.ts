@@ -177,7 +177,8 @@ const entryDescription = z.strictObject({
 	wasmLoading: wasmLoading.optional(),
 	filename: entryFilename.optional(),
 	library: libraryOptions.optional(),
-	dependOn: entryDependOn.optional()
+	dependOn: entryDependOn.optional(),
+	layer: z.string().or(z.null()).optional()
 });
 export type EntryDescription = z.infer<typeof entryDescription>;
 
@@ -289,7 +290,13 @@ export type HashDigest = z.infer<typeof hashDigest>;
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z.enum([
+	"md4",
+	"xxhash64",
+	"xxhash128",
+	"sha256",
+	"sha512"
+]);
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -493,6 +500,7 @@ const baseRuleSetRule = z.strictObject({
 	exclude: ruleSetCondition.optional(),
 	include: ruleSetCondition.optional(),
 	issuer: ruleSetCondition.optional(),
+	issuerLayer: ruleSetCondition.optional(),
 	dependency: ruleSetCondition.optional(),
 	resource: ruleSetCondition.optional(),
 	resourceFragment: ruleSetCondition.optional(),
@@ -508,6 +516,7 @@ const baseRuleSetRule = z.strictObject({
 	generator: z.record(z.any()).optional(),
 	resolve: resolveOptions.optional(),
 	sideEffects: z.boolean().optional(),
+	layer: z.string().optional(),
 	enforce: z.literal("pre").or(z.literal("post")).optional()
 });
 
@@ -564,6 +573,14 @@ const dynamicImportPrefetch = z.union([z.boolean(), z.number()]);
 const javascriptParserUrl = z.union([z.literal("relative"), z.boolean()]);
 const exprContextCritical = z.boolean();
 const wrappedContextCritical = z.boolean();
+const exportsPresence = z.enum(["error", "warn", "auto"]).or(z.literal(false));
+const importExportsPresence = z
+	.enum(["error", "warn", "auto"])
+	.or(z.literal(false));
+const reexportExportsPresence = z
+	.enum(["error", "warn", "auto"])
+	.or(z.literal(false));
+const strictExportPresence = z.boolean();
 
 const javascriptParserOptions = z.strictObject({
 	dynamicImportMode: dynamicImportMode.optional(),
@@ -571,7 +588,11 @@ const javascriptParserOptions = z.strictObject({
 	dynamicImportPrefetch: dynamicImportPrefetch.optional(),
 	url: javascriptParserUrl.optional(),
 	exprContextCritical: exprContextCritical.optional(),
-	wrappedContextCritical: wrappedContextCritical.optional()
+	wrappedContextCritical: wrappedContextCritical.optional(),
+	exportsPresence: exportsPresence.optional(),
+	importExportsPresence: importExportsPresence.optional(),
+	reexportExportsPresence: reexportExportsPresence.optional(),
+	strictExportPresence: strictExportPresence.optional()
 });
 export type JavascriptParserOptions = z.infer<typeof javascriptParserOptions>;
 
@@ -975,6 +996,11 @@ const node = z.literal(false).or(nodeOptions);
 export type Node = z.infer<typeof node>;
 //#endregion
 
+//#region Amd
+const amd = z.literal(false).or(z.record(z.any()));
+export type Amd = z.infer<typeof amd>;
+//#endregion
+
 //#region Snapshot
 const snapshotOptions = z.strictObject({
 	module: z
@@ -988,7 +1014,9 @@ const snapshotOptions = z.strictObject({
 			hash: z.boolean().optional(),
 			timestamp: z.boolean().optional()
 		})
-		.optional()
+		.optional(),
+	immutablePaths: z.array(z.string().or(z.instanceof(RegExp))).optional(),
+	managedPaths: z.array(z.string().or(z.instanceof(RegExp))).optional()
 });
 export type SnapshotOptions = z.infer<typeof snapshotOptions>;
 //#endregion
@@ -1106,9 +1134,13 @@ const sharedOptimizationSplitChunksCacheGroup = {
 	minChunks: z.number().min(1).optional(),
 	name: optimizationSplitChunksName.optional(),
 	minSize: optimizationSplitChunksSizes.optional(),
+	minSizeReduction: optimizationSplitChunksSizes.optional(),
+	minRemainingSize: optimizationSplitChunksSizes.optional(),
+	enforceSizeThreshold: optimizationSplitChunksSizes.optional(),
 	maxSize: optimizationSplitChunksSizes.optional(),
 	maxAsyncSize: optimizationSplitChunksSizes.optional(),
 	maxInitialSize: optimizationSplitChunksSizes.optional(),
+	usedExports: z.boolean().optional(),
 	automaticNameDelimiter: z.string().optional()
 };
 const optimizationSplitChunksCacheGroup = z.strictObject({
@@ -1127,6 +1159,11 @@ const optimizationSplitChunksCacheGroup = z.strictObject({
 	reuseExistingChunk: z.boolean().optional(),
 	type: z.string().or(z.instanceof(RegExp)).optional(),
 	idHint: z.string().optional(),
+	layer: z
+		.string()
+		.or(z.instanceof(RegExp))
+		.or(z.function().args(z.string().optional()).returns(z.boolean()))
+		.optional(),
 	...sharedOptimizationSplitChunksCacheGroup
 });
 export type OptimizationSplitChunksCacheGroup = z.infer<
@@ -1157,8 +1194,10 @@ export type OptimizationSplitChunksOptions = z.infer<
 >;
 
 const optimization = z.strictObject({
-	moduleIds: z.enum(["named", "deterministic"]).optional(),
-	chunkIds: z.enum(["named", "deterministic"]).optional(),
+	moduleIds: z.enum(["named", "natural", "deterministic", "size"]).optional(),
+	chunkIds: z
+		.enum(["named", "natural", "deterministic", "size", "total-size"])
+		.optional(),
 	minimize: z.boolean().optional(),
 	minimizer: z.literal("...").or(plugin).array().optional(),
 	mergeDuplicateChunks: z.boolean().optional(),
@@ -1193,9 +1232,46 @@ const rspackFutureOptions = z.strictObject({
 });
 export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 
+const httpUriAllowedUris = z.array(z.string().or(z.instanceof(RegExp)));
+
+const httpUriOptions = z.strictObject({
+	allowedUris: httpUriAllowedUris,
+	lockfileLocation: z.string().optional(),
+	cacheLocation: z.string().optional(),
+	frozen: z.boolean().optional(),
+	upgrade: z.boolean().optional()
+});
+export type HttpUriOptions = z.infer<typeof httpUriOptions>;
+
+const lazyCompilationOptions = z.strictObject({
+	backend: z
+		.strictObject({
+			client: z.string().optional(),
+			listen: z
+				.number()
+				.or(
+					z.strictObject({
+						port: z.number().optional(),
+						host: z.string().optional()
+					})
+				)
+				.optional()
+		})
+		.optional(),
+	entries: z.boolean().optional(),
+	imports: z.boolean().optional(),
+	test: z
+		.instanceof(RegExp)
+		.or(z.function().args(z.string()).returns(z.boolean()))
+		.optional()
+});
+export type LazyCompilationOptions = z.infer<typeof lazyCompilationOptions>;
+
 const experiments = z.strictObject({
-	lazyCompilation: z.boolean().optional(),
+	layers: z.boolean().optional(),
+	lazyCompilation: z.boolean().or(lazyCompilationOptions).optional(),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	newSplitChunks: z
@@ -1214,7 +1290,8 @@ const experiments = z.strictObject({
 		}),
 	css: z.boolean().optional(),
 	futureDefaults: z.boolean().optional(),
-	rspackFuture: rspackFutureOptions.optional()
+	rspackFuture: rspackFutureOptions.optional(),
+	buildHttp: httpUriAllowedUris.or(httpUriOptions).optional()
 });
 export type Experiments = z.infer<typeof experiments>;
 //#endregion
@@ -1268,6 +1345,17 @@ const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+
+const recordsInputPath = z.literal(false).or(z.string());
+export type RecordsInputPath = z.infer<typeof recordsInputPath>;
+
+const recordsOutputPath = z.literal(false).or(z.string());
+export type RecordsOutputPath = z.infer<typeof recordsOutputPath>;
+//#endregion
+
 //#region Builtins (deprecated)
 const builtins = z.custom<oldBuiltins.Builtins>();
 export type Builtins = z.infer<typeof builtins>;
@@ -1289,6 +1377,7 @@ export const rspackOptions = z.strictObject({
 	context: context.optional(),
 	devtool: devTool.optional(),
 	node: node.optional(),
+	amd: amd.optional(),
 	ignoreWarnings: ignoreWarnings.optional(),
 	watchOptions: watchOptions.optional(),
 	watch: watch.optional(),
@@ -1302,7 +1391,10 @@ export const rspackOptions = z.strictObject({
 	builtins: builtins.optional(),
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
-	bail: bail.optional()
+	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsInputPath.optional(),
+	recordsOutputPath: recordsOutputPath.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
 export type Configuration = RspackOptions;
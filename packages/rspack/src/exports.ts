@@ -91,11 +91,27 @@ export type { BannerPluginArgument } from "./builtin-plugin";
 
 export { IgnorePlugin, type IgnorePluginOptions } from "./builtin-plugin";
 
+export {
+	ContextReplacementPlugin,
+	NormalModuleReplacementPlugin
+} from "./builtin-plugin";
+
 export { ProvidePlugin } from "./builtin-plugin";
 export type { ProvidePluginOptions } from "./builtin-plugin";
 
 export { DefinePlugin } from "./builtin-plugin";
-export type { DefinePluginOptions } from "./builtin-plugin";
+export type { CodeValue, DefinePluginOptions } from "./builtin-plugin";
+
+export { ManifestPlugin } from "./builtin-plugin";
+export type {
+	Manifest,
+	ManifestEntrypoint,
+	ManifestFile,
+	ManifestPluginOptions
+} from "./builtin-plugin";
+
+export { SubresourceIntegrityPlugin } from "./builtin-plugin";
+export type { SubresourceIntegrityPluginOptions } from "./builtin-plugin";
 
 export { ProgressPlugin } from "./builtin-plugin";
 export type { ProgressPluginArgument } from "./builtin-plugin";
@@ -111,9 +127,19 @@ export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 
 export { LoaderTargetPlugin } from "./lib/LoaderTargetPlugin";
 
-export { EnvironmentPlugin } from "./lib/EnvironmentPlugin";
+export { EnvironmentPlugin } from "./builtin-plugin";
+export type { EnvironmentPluginOptions } from "./builtin-plugin";
 
-export { NormalModuleReplacementPlugin } from "./lib/NormalModuleReplacementPlugin";
+export { DllPlugin } from "./lib/DllPlugin";
+export type { DllPluginOptions } from "./lib/DllPlugin";
+
+export { DllReferencePlugin } from "./lib/DllReferencePlugin";
+export type {
+	DllReferencePluginOptions,
+	DllReferencePluginOptionsContent,
+	DllReferencePluginOptionsManifest,
+	DllReferencePluginOptionsSourceType
+} from "./lib/DllReferencePlugin";
 
 import NodeTemplatePlugin from "./node/NodeTemplatePlugin";
 import { NodeTargetPlugin } from "./builtin-plugin";
@@ -159,6 +185,13 @@ interface Optimize {
 }
 export const optimize: Optimize = { LimitChunkCountPlugin };
 
+import { HashedModuleIdsPlugin } from "./builtin-plugin";
+export type { HashedModuleIdsPluginOptions } from "./builtin-plugin";
+interface Ids {
+	HashedModuleIdsPlugin: typeof HashedModuleIdsPlugin;
+}
+export const ids: Ids = { HashedModuleIdsPlugin };
+
 import { ModuleFederationPlugin } from "./container/ModuleFederationPlugin";
 export type { ModuleFederationPluginOptions } from "./container/ModuleFederationPlugin";
 import { ModuleFederationPluginV1 } from "./container/ModuleFederationPluginV1";
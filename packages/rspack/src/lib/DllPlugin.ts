@@ -0,0 +1,71 @@
+/**
+ * Based on webpack/lib/DllPlugin.js
+ * Licensed with [MIT License]{@link http://www.opensource.org/licenses/mit-license.php}
+ * Original Author Tobias Koppers @sokra
+ */
+
+import { Compiler } from "../Compiler";
+import {
+	DllEntryPlugin,
+	FlagAllModulesAsUsedPlugin,
+	LibManifestPlugin
+} from "../builtin-plugin";
+
+export type DllPluginOptions = {
+	/**
+	 * Context of requests in the manifest file (defaults to the rspack context).
+	 */
+	context?: string;
+	/**
+	 * If true, only entry points will be exposed (default: true).
+	 */
+	entryOnly?: boolean;
+	/**
+	 * If true, manifest json file (output) will be formatted.
+	 */
+	format?: boolean;
+	/**
+	 * Name of the exposed dll function (external name, use value of 'output.library').
+	 */
+	name?: string;
+	/**
+	 * Absolute path to the manifest json file (output), relative paths are
+	 * resolved against 'output.path'.
+	 */
+	path: string;
+	/**
+	 * Type of the dll bundle (external type, use value of 'output.libraryTarget').
+	 */
+	type?: string;
+};
+
+export class DllPlugin {
+	private options: DllPluginOptions;
+
+	constructor(options: DllPluginOptions) {
+		this.options = {
+			...options,
+			entryOnly: options.entryOnly !== false
+		};
+	}
+
+	apply(compiler: Compiler) {
+		compiler.hooks.entryOption.tap("DllPlugin", (context, entry) => {
+			if (typeof entry === "function") {
+				throw new Error(
+					"DllPlugin doesn't support dynamic entry (function) yet"
+				);
+			}
+			for (const name of Object.keys(entry)) {
+				new DllEntryPlugin(context, entry[name].import ?? [], {
+					name
+				}).apply(compiler);
+			}
+			return true;
+		});
+		new LibManifestPlugin(this.options).apply(compiler);
+		if (!this.options.entryOnly) {
+			new FlagAllModulesAsUsedPlugin().apply(compiler);
+		}
+	}
+}
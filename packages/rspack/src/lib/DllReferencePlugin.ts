@@ -0,0 +1,160 @@
+/**
+ * Based on webpack/lib/DllReferencePlugin.js
+ * Licensed with [MIT License]{@link http://www.opensource.org/licenses/mit-license.php}
+ * Original Author Tobias Koppers @sokra
+ */
+
+import { Compiler } from "../Compiler";
+import { DllReferenceAgencyPlugin, ExternalsPlugin } from "../builtin-plugin";
+
+export type DllReferencePluginOptionsContent = {
+	/**
+	 * Module info, keyed by the lib identifier of the module.
+	 */
+	[k: string]: {
+		buildMeta?: { [k: string]: any };
+		exports?: string[];
+		id: number | string;
+	};
+};
+
+export type DllReferencePluginOptionsManifest = {
+	content: DllReferencePluginOptionsContent;
+	name?: string;
+	type?: DllReferencePluginOptionsSourceType;
+};
+
+export type DllReferencePluginOptionsSourceType =
+	| "var"
+	| "assign"
+	| "this"
+	| "window"
+	| "global"
+	| "commonjs"
+	| "commonjs2"
+	| "commonjs-module"
+	| "amd"
+	| "amd-require"
+	| "umd"
+	| "umd2"
+	| "jsonp"
+	| "system";
+
+export type DllReferencePluginOptions = {
+	/**
+	 * Context of requests in the manifest (or content property) as absolute path.
+	 */
+	context?: string;
+	/**
+	 * Extensions used to resolve modules in the dll bundle (only used when using 'scope').
+	 */
+	extensions?: string[];
+	/**
+	 * An object containing content and name or a string to the absolute path of the JSON manifest to be loaded upon compilation.
+	 */
+	manifest?: string | DllReferencePluginOptionsManifest;
+	/**
+	 * The name where the dll is exposed (external name, defaults to manifest.name).
+	 */
+	name?: string;
+	/**
+	 * The mappings from request to module info (defaults to manifest.content).
+	 */
+	content?: DllReferencePluginOptionsContent;
+	/**
+	 * Prefix which is used for accessing the content of the dll.
+	 */
+	scope?: string;
+	/**
+	 * How the dll is exposed (libraryTarget, defaults to manifest.type).
+	 */
+	sourceType?: DllReferencePluginOptionsSourceType;
+	/**
+	 * The way how the export of the dll bundle is used.
+	 */
+	type?: "require" | "object";
+};
+
+export class DllReferencePlugin {
+	private options: DllReferencePluginOptions;
+
+	constructor(options: DllReferencePluginOptions) {
+		this.options = options;
+	}
+
+	apply(compiler: Compiler) {
+		const { manifest } = this.options;
+		if (typeof manifest !== "string") {
+			this.applyManifest(compiler, manifest);
+			return;
+		}
+
+		// The manifest is read before each compilation, like webpack does. The
+		// builtin plugins are only registered before the first one, as their
+		// options are fixed once the native compiler is created.
+		let registered = false;
+		let manifestError: Error | undefined;
+		compiler.hooks.beforeCompile.tapAsync(
+			"DllReferencePlugin",
+			(_params, callback) => {
+				compiler.inputFileSystem.readFile(
+					manifest,
+					(err: Error | null, result?: Buffer) => {
+						if (err) return callback(err);
+						let manifestContent: DllReferencePluginOptionsManifest | undefined;
+						manifestError = undefined;
+						try {
+							manifestContent = JSON.parse(result!.toString("utf-8"));
+						} catch (e) {
+							manifestError = new Error(
+								`Dll manifest ${manifest}\n${(e as Error).message}`
+							);
+						}
+						if (!registered) {
+							registered = true;
+							try {
+								this.applyManifest(compiler, manifestContent);
+							} catch (e) {
+								return callback(e as Error);
+							}
+						}
+						callback();
+					}
+				);
+			}
+		);
+		compiler.hooks.compilation.tap("DllReferencePlugin", compilation => {
+			compilation.fileDependencies.add(manifest);
+			if (manifestError) {
+				compilation.errors.push(manifestError);
+			}
+		});
+	}
+
+	private applyManifest(
+		compiler: Compiler,
+		manifest?: DllReferencePluginOptionsManifest
+	) {
+		const name = this.options.name ?? manifest?.name;
+		const sourceType = this.options.sourceType ?? manifest?.type;
+		const content = this.options.content ?? manifest?.content;
+		if (!name) {
+			throw new Error(
+				"DllReferencePlugin: 'name' is required when it is not provided by the manifest"
+			);
+		}
+
+		const source = `dll-reference ${name}`;
+		new ExternalsPlugin(sourceType ?? "var", { [source]: name }).apply(
+			compiler
+		);
+		new DllReferenceAgencyPlugin({
+			context: this.options.context ?? compiler.context,
+			name,
+			extensions: this.options.extensions ?? ["", ".wasm", ".mjs", ".js", ".json"],
+			scope: this.options.scope,
+			type: this.options.type ?? "require",
+			content: JSON.stringify(content ?? {})
+		}).apply(compiler);
+	}
+}
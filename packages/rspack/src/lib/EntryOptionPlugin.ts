@@ -57,20 +57,20 @@ export default class EntryOptionPlugin {
 			name,
 			filename: desc.filename,
 			runtime: desc.runtime,
-			// layer: desc.layer,
+			layer: desc.layer,
 			dependOn: desc.dependOn,
 			baseUri: desc.baseUri,
 			publicPath: desc.publicPath,
 			chunkLoading: desc.chunkLoading,
 			asyncChunks: desc.asyncChunks,
-			// wasmLoading: desc.wasmLoading,
+			wasmLoading: desc.wasmLoading,
 			library: desc.library
 		};
-		// if (desc.layer !== undefined && !compiler.options.experiments.layers) {
-		// 	throw new Error(
-		// 		"'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
-		// 	);
-		// }
+		if (desc.layer !== undefined && !compiler.options.experiments.layers) {
+			throw new Error(
+				"'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
+			);
+		}
 		// if (desc.chunkLoading) {
 		// 	const EnableChunkLoadingPlugin = require("./javascript/EnableChunkLoadingPlugin");
 		// 	EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
@@ -255,6 +255,7 @@ export async function runLoaders(
 						request,
 						options.publicPath,
 						options.baseUri,
+						options.layer,
 						rawContext._moduleIdentifier,
 						loaderContext.context,
 						(err, res) => {
@@ -286,6 +287,7 @@ export async function runLoaders(
 				request,
 				options.publicPath,
 				options.baseUri,
+				options.layer,
 				rawContext._moduleIdentifier,
 				loaderContext.context,
 				(err, res) => {
@@ -40,15 +40,23 @@ import {
 	DeterministicModuleIdsPlugin,
 	NamedChunkIdsPlugin,
 	DeterministicChunkIdsPlugin,
+	NaturalModuleIdsPlugin,
+	NaturalChunkIdsPlugin,
+	OccurrenceModuleIdsPlugin,
+	OccurrenceChunkIdsPlugin,
+	RecordIdsPlugin,
 	RealContentHashPlugin,
 	RemoveEmptyChunksPlugin,
 	EnsureChunkConditionsPlugin,
 	WarnCaseSensitiveModulesPlugin,
 	DataUriPlugin,
 	FileUriPlugin,
+	HttpUriPlugin,
+	LazyCompilationPlugin,
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	AsyncWebAssemblyModulesPlugin,
+	WebAssemblyModulesPlugin,
 	RuntimePlugin,
 	InferAsyncModulesPlugin,
 	WorkerPlugin,
@@ -207,6 +215,9 @@ export class RspackOptionsApply {
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.css) {
 			new CssModulesPlugin().apply(compiler);
 		}
@@ -228,6 +239,15 @@ export class RspackOptionsApply {
 
 		new DataUriPlugin().apply(compiler);
 		new FileUriPlugin().apply(compiler);
+		if (options.experiments.lazyCompilation) {
+			new LazyCompilationPlugin(options.experiments.lazyCompilation).apply(
+				compiler
+			);
+		}
+
+		if (options.experiments.buildHttp) {
+			new HttpUriPlugin(options.experiments.buildHttp).apply(compiler);
+		}
 
 		new EnsureChunkConditionsPlugin().apply(compiler);
 		if (options.optimization.mergeDuplicateChunks) {
@@ -276,6 +296,9 @@ export class RspackOptionsApply {
 		if (options.optimization.realContentHash) {
 			new RealContentHashPlugin().apply(compiler);
 		}
+		if (options.recordsInputPath || options.recordsOutputPath) {
+			new RecordIdsPlugin().apply(compiler);
+		}
 		const moduleIds = options.optimization.moduleIds;
 		if (moduleIds) {
 			switch (moduleIds) {
@@ -283,10 +306,20 @@ export class RspackOptionsApply {
 					new NamedModuleIdsPlugin().apply(compiler);
 					break;
 				}
+				case "natural": {
+					new NaturalModuleIdsPlugin().apply(compiler);
+					break;
+				}
 				case "deterministic": {
 					new DeterministicModuleIdsPlugin().apply(compiler);
 					break;
 				}
+				case "size": {
+					new OccurrenceModuleIdsPlugin({ prioritizeInitial: true }).apply(
+						compiler
+					);
+					break;
+				}
 				default:
 					throw new Error(`moduleIds: ${moduleIds} is not implemented`);
 			}
@@ -298,10 +331,26 @@ export class RspackOptionsApply {
 					new NamedChunkIdsPlugin().apply(compiler);
 					break;
 				}
+				case "natural": {
+					new NaturalChunkIdsPlugin().apply(compiler);
+					break;
+				}
 				case "deterministic": {
 					new DeterministicChunkIdsPlugin().apply(compiler);
 					break;
 				}
+				case "size": {
+					new OccurrenceChunkIdsPlugin({ prioritizeInitial: true }).apply(
+						compiler
+					);
+					break;
+				}
+				case "total-size": {
+					new OccurrenceChunkIdsPlugin({ prioritizeInitial: false }).apply(
+						compiler
+					);
+					break;
+				}
 				default:
 					throw new Error(`chunkIds: ${chunkIds} is not implemented`);
 			}
@@ -762,6 +762,7 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.identifier = module.identifier;
 			object.name = module.name;
 			object.nameForCondition = module.nameForCondition;
+			object.layer = module.layer;
 			object.issuer = module.issuer;
 			object.issuerName = module.issuerName;
 			object.issuerPath = factory.create(